@@ -0,0 +1,612 @@
+use crate::core::log_level::LogLevel;
+use crate::features;
+use crate::features::container_builder::ContainerBuildOptions;
+use crate::features::rust_builder::{BuildOptions, Builder};
+use crate::features::security_scanner::ScanOptions;
+use crate::features::terraform_cleaner::{self, CleanOptions};
+use crate::features::tool_upgrader::{ToolAction, ToolUpgradeOptions};
+use crate::ui::Console;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::Duration;
+
+/// Command-line interface; without a subcommand the interactive menu is shown
+#[derive(Debug, Parser)]
+#[command(name = "ops-tools", version, about)]
+pub struct Cli {
+    /// UI language for this run (en, zh-TW, zh-CN, ja, ko); overrides OPS_TOOLS_LANG and the saved config
+    #[arg(long, global = true, value_name = "CODE")]
+    pub language: Option<String>,
+
+    /// Preview destructive actions (deletes, removals) without running them
+    #[arg(long, global = true)]
+    pub dry_run: bool,
+
+    /// Answer yes to every confirmation and take the default of every menu (for scripts);
+    /// menus without a default fail instead of waiting for input
+    #[arg(short = 'y', long, global = true)]
+    pub yes: bool,
+
+    /// Only print errors, warnings and final summaries
+    #[arg(long, global = true, conflicts_with = "verbose")]
+    pub quiet: bool,
+
+    /// Also print every external command before it runs
+    #[arg(long, global = true)]
+    pub verbose: bool,
+
+    /// Disable colored output (also enabled by a non-empty NO_COLOR environment variable)
+    #[arg(long, global = true)]
+    pub no_color: bool,
+
+    /// Read and write config files in this directory; overrides OPS_TOOLS_CONFIG_DIR and the platform default
+    #[arg(long, global = true, value_name = "DIR")]
+    pub config_dir: Option<PathBuf>,
+
+    /// Print one JSON object with the feature, success/failure counts and messages on exit;
+    /// messages go to stderr so stdout carries only the JSON
+    #[arg(long, global = true)]
+    pub json: bool,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+impl Cli {
+    /// Output level requested on the command line, if any
+    pub fn log_level(&self) -> Option<LogLevel> {
+        if self.quiet {
+            Some(LogLevel::Quiet)
+        } else if self.verbose {
+            Some(LogLevel::Verbose)
+        } else {
+            None
+        }
+    }
+}
+
+/// One subcommand per menu entry
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Remove Terraform/Terragrunt cache files
+    TerraformCleaner(TerraformCleanerArgs),
+    /// Upgrade or uninstall AI CLI tools
+    ToolUpgrader(ToolUpgraderArgs),
+    /// Install and update software packages
+    PackageManager,
+    /// Upgrade the Rust toolchain and dependencies
+    RustUpgrader,
+    /// Scan for secrets and vulnerabilities
+    SecurityScanner(SecurityScannerArgs),
+    /// Manage MCP servers for AI CLI tools
+    McpManager,
+    /// Isolate kubeconfig per tmux window
+    KubeconfigManager,
+    /// Build Rust binaries for multiple platforms
+    RustBuilder(RustBuilderArgs),
+    /// Build multi-arch container images
+    ContainerBuilder(ContainerBuilderArgs),
+    /// Install AI CLI skills and extensions
+    SkillInstaller,
+    /// Build ML packages from source with CUDA
+    CudaBuilder,
+    /// Update the system and scan for issues
+    SystemUpdater,
+    /// Update ops-tools itself to the latest release
+    SelfUpdate,
+    /// Check which external tools are installed and which features are usable
+    Doctor,
+}
+
+impl Command {
+    /// Subcommand name as typed on the command line, reported as `feature` by `--json`
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::TerraformCleaner(_) => "terraform-cleaner",
+            Self::ToolUpgrader(_) => "tool-upgrader",
+            Self::PackageManager => "package-manager",
+            Self::RustUpgrader => "rust-upgrader",
+            Self::SecurityScanner(_) => "security-scanner",
+            Self::McpManager => "mcp-manager",
+            Self::KubeconfigManager => "kubeconfig-manager",
+            Self::RustBuilder(_) => "rust-builder",
+            Self::ContainerBuilder(_) => "container-builder",
+            Self::SkillInstaller => "skill-installer",
+            Self::CudaBuilder => "cuda-builder",
+            Self::SystemUpdater => "system-updater",
+            Self::SelfUpdate => "self-update",
+            Self::Doctor => "doctor",
+        }
+    }
+}
+
+#[derive(Debug, Args)]
+pub struct TerraformCleanerArgs {
+    /// Only clean items not modified for at least this long (e.g. 24h, 7d, 2w)
+    #[arg(long, value_name = "AGE", value_parser = terraform_cleaner::parse_age)]
+    pub older_than: Option<Duration>,
+}
+
+impl From<TerraformCleanerArgs> for CleanOptions {
+    fn from(args: TerraformCleanerArgs) -> Self {
+        Self {
+            older_than: args.older_than,
+        }
+    }
+}
+
+#[derive(Debug, Args)]
+pub struct SecurityScannerArgs {
+    /// Repo-relative glob to skip in the working tree scan (repeatable)
+    #[arg(long = "exclude", value_name = "GLOB")]
+    pub excludes: Vec<String>,
+    /// Audit only: never install scanners, scan with the ones already installed
+    #[arg(long, visible_alias = "audit")]
+    pub no_install: bool,
+    /// Scan the Git repo containing this path instead of the current directory's
+    #[arg(long, value_name = "PATH")]
+    pub repo: Option<PathBuf>,
+    /// Exit 1 when findings are reported and 2 when the scan fails, instead of the usual status
+    #[arg(long, value_enum, value_name = "WHEN")]
+    pub fail_on: Option<FailOnArg>,
+    /// Choose again which scanners to install and run (the choice is saved)
+    #[arg(long)]
+    pub choose_tools: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum FailOnArg {
+    Findings,
+}
+
+impl From<SecurityScannerArgs> for ScanOptions {
+    fn from(args: SecurityScannerArgs) -> Self {
+        Self {
+            excludes: args.excludes,
+            no_install: args.no_install,
+            repo: args.repo,
+            choose_tools: args.choose_tools,
+        }
+    }
+}
+
+#[derive(Debug, Args)]
+pub struct ContainerBuilderArgs {
+    /// Build context directory (defaults to the Dockerfile's directory)
+    #[arg(long, value_name = "DIR")]
+    pub context: Option<PathBuf>,
+    /// Push the image even when the vulnerability scan reports critical findings
+    #[arg(long)]
+    pub push_vulnerable: bool,
+}
+
+impl From<ContainerBuilderArgs> for ContainerBuildOptions {
+    fn from(args: ContainerBuilderArgs) -> Self {
+        Self {
+            context: args.context,
+            push_vulnerable: args.push_vulnerable,
+        }
+    }
+}
+
+#[derive(Debug, Args)]
+pub struct ToolUpgraderArgs {
+    /// Uninstall AI tools instead of upgrading them
+    #[arg(long)]
+    pub uninstall: bool,
+    /// npm registry URL for Node package manager upgrades (overrides NPM_CONFIG_REGISTRY)
+    #[arg(long, value_name = "URL")]
+    pub registry: Option<String>,
+}
+
+impl From<ToolUpgraderArgs> for ToolUpgradeOptions {
+    fn from(args: ToolUpgraderArgs) -> Self {
+        Self {
+            action: Some(if args.uninstall {
+                ToolAction::Uninstall
+            } else {
+                ToolAction::Upgrade
+            }),
+            registry: args.registry,
+        }
+    }
+}
+
+#[derive(Debug, Args)]
+pub struct RustBuilderArgs {
+    /// Build profile
+    #[arg(long, value_enum)]
+    pub profile: Option<ProfileArg>,
+    /// Target triple (repeatable); well-formed triples outside the known list build as custom targets
+    #[arg(long = "target")]
+    pub targets: Vec<String>,
+    /// Build tool
+    #[arg(long, value_enum)]
+    pub builder: Option<BuilderArg>,
+    /// Cargo features to enable (comma separated or repeatable)
+    #[arg(long, value_delimiter = ',')]
+    pub features: Vec<String>,
+    /// Do not activate the default feature
+    #[arg(long)]
+    pub no_default_features: bool,
+    /// Copy built binaries into dist/ as <binary>-<target>
+    #[arg(long)]
+    pub dist: bool,
+    /// Strip symbols from the built binaries
+    #[arg(long)]
+    pub strip: bool,
+    /// Compress the built binaries with upx
+    #[arg(long)]
+    pub upx: bool,
+    /// Package each target's binaries into dist/<crate>-<version>-<target>.tar.gz (.zip on Windows)
+    #[arg(long)]
+    pub archive: bool,
+    /// Workspace package to build (repeatable)
+    #[arg(short = 'p', long = "package", value_name = "NAME")]
+    pub packages: Vec<String>,
+    /// List the known targets with their install status instead of building
+    #[arg(long)]
+    pub list_targets: bool,
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum ProfileArg {
+    Release,
+    Debug,
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum BuilderArg {
+    Cargo,
+    Cross,
+}
+
+impl From<RustBuilderArgs> for BuildOptions {
+    fn from(args: RustBuilderArgs) -> Self {
+        Self {
+            builder: args.builder.map(|builder| match builder {
+                BuilderArg::Cargo => Builder::Cargo,
+                BuilderArg::Cross => Builder::Cross,
+            }),
+            release: args
+                .profile
+                .map(|profile| matches!(profile, ProfileArg::Release)),
+            targets: args.targets,
+            features: (!args.features.is_empty()).then_some(args.features),
+            no_default_features: args.no_default_features.then_some(true),
+            dist: args.dist.then_some(true),
+            strip: args.strip.then_some(true),
+            upx: args.upx.then_some(true),
+            archive: args.archive.then_some(true),
+            packages: args.packages,
+            list_targets: args.list_targets,
+        }
+    }
+}
+
+/// Run a single feature and translate reported errors into the process exit code
+pub fn dispatch(command: Command) -> ExitCode {
+    crate::core::json_report::set_feature(command.name());
+    match command {
+        Command::TerraformCleaner(args) => terraform_cleaner::run_with(args.into()),
+        Command::ToolUpgrader(args) => features::tool_upgrader::run_with(args.into()),
+        Command::PackageManager => features::package_manager::run(),
+        Command::RustUpgrader => features::rust_upgrader::run(),
+        Command::SecurityScanner(args) => {
+            let fail_on = args.fail_on;
+            let result = features::security_scanner::run_with(args.into());
+            if fail_on == Some(FailOnArg::Findings) {
+                return result.exit_code();
+            }
+        }
+        Command::McpManager => features::mcp_manager::run(),
+        Command::KubeconfigManager => features::kubeconfig_manager::run(),
+        Command::RustBuilder(args) => features::rust_builder::run_with(args.into()),
+        Command::ContainerBuilder(args) => features::container_builder::run_with(args.into()),
+        Command::SkillInstaller => features::skill_installer::run(),
+        Command::CudaBuilder => features::cuda_builder::run(),
+        Command::SystemUpdater => features::system_updater::run(),
+        Command::SelfUpdate => features::package_manager::self_update::run(),
+        Command::Doctor => features::doctor::run(),
+    }
+
+    if Console::has_reported_errors() {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+
+    #[test]
+    fn cli_definition_is_valid() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn no_arguments_means_interactive() {
+        let cli = Cli::try_parse_from(["ops-tools"]).unwrap();
+        assert!(cli.command.is_none());
+    }
+
+    #[test]
+    fn parses_menu_subcommand() {
+        let cli = Cli::try_parse_from(["ops-tools", "terraform-cleaner"]).unwrap();
+        assert!(matches!(cli.command, Some(Command::TerraformCleaner(_))));
+    }
+
+    #[test]
+    fn parses_terraform_cleaner_age() {
+        let cli =
+            Cli::try_parse_from(["ops-tools", "terraform-cleaner", "--older-than", "7d"]).unwrap();
+
+        let Some(Command::TerraformCleaner(args)) = cli.command else {
+            panic!("Expected terraform-cleaner subcommand");
+        };
+        let options = CleanOptions::from(args);
+        assert_eq!(options.older_than, Some(Duration::from_secs(7 * 86_400)));
+
+        assert!(
+            Cli::try_parse_from(["ops-tools", "terraform-cleaner", "--older-than", "soon"])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn parses_rust_builder_options() {
+        let cli = Cli::try_parse_from([
+            "ops-tools",
+            "rust-builder",
+            "--profile",
+            "release",
+            "--target",
+            "x86_64-unknown-linux-gnu",
+            "--target",
+            "aarch64-unknown-linux-gnu",
+        ])
+        .unwrap();
+
+        let Some(Command::RustBuilder(args)) = cli.command else {
+            panic!("Expected rust-builder subcommand");
+        };
+        let options = BuildOptions::from(args);
+        assert_eq!(options.release, Some(true));
+        assert_eq!(options.builder, None);
+        assert_eq!(options.targets.len(), 2);
+        assert_eq!(options.features, None);
+        assert_eq!(options.no_default_features, None);
+    }
+
+    #[test]
+    fn parses_rust_builder_features() {
+        let cli = Cli::try_parse_from([
+            "ops-tools",
+            "rust-builder",
+            "--features",
+            "foo,bar",
+            "--features",
+            "baz",
+            "--no-default-features",
+            "--dist",
+            "--strip",
+            "--archive",
+            "-p",
+            "core",
+        ])
+        .unwrap();
+
+        let Some(Command::RustBuilder(args)) = cli.command else {
+            panic!("Expected rust-builder subcommand");
+        };
+        let options = BuildOptions::from(args);
+        assert_eq!(
+            options.features,
+            Some(vec![
+                "foo".to_string(),
+                "bar".to_string(),
+                "baz".to_string()
+            ])
+        );
+        assert_eq!(options.no_default_features, Some(true));
+        assert_eq!(options.dist, Some(true));
+        assert_eq!(options.strip, Some(true));
+        assert_eq!(options.upx, None);
+        assert_eq!(options.archive, Some(true));
+        assert_eq!(options.packages, ["core"]);
+    }
+
+    #[test]
+    fn parses_rust_builder_list_targets() {
+        let cli = Cli::try_parse_from(["ops-tools", "rust-builder", "--list-targets"]).unwrap();
+
+        let Some(Command::RustBuilder(args)) = cli.command else {
+            panic!("Expected rust-builder subcommand");
+        };
+        assert!(BuildOptions::from(args).list_targets);
+    }
+
+    #[test]
+    fn parses_security_scanner_excludes() {
+        let cli = Cli::try_parse_from([
+            "ops-tools",
+            "security-scanner",
+            "--exclude",
+            "vendor/**",
+            "--exclude",
+            "testdata/**",
+        ])
+        .unwrap();
+
+        let Some(Command::SecurityScanner(args)) = cli.command else {
+            panic!("Expected security-scanner subcommand");
+        };
+        let options = ScanOptions::from(args);
+        assert_eq!(options.excludes, ["vendor/**", "testdata/**"]);
+        assert!(!options.no_install);
+        assert_eq!(options.repo, None);
+    }
+
+    #[test]
+    fn parses_security_scanner_fail_on() {
+        let cli = Cli::try_parse_from(["ops-tools", "security-scanner", "--fail-on", "findings"])
+            .unwrap();
+        let Some(Command::SecurityScanner(args)) = cli.command else {
+            panic!("Expected security-scanner subcommand");
+        };
+        assert_eq!(args.fail_on, Some(FailOnArg::Findings));
+
+        assert!(
+            Cli::try_parse_from(["ops-tools", "security-scanner", "--fail-on", "warnings"])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn parses_security_scanner_repo() {
+        let cli =
+            Cli::try_parse_from(["ops-tools", "security-scanner", "--repo", "../service"]).unwrap();
+        let Some(Command::SecurityScanner(args)) = cli.command else {
+            panic!("Expected security-scanner subcommand");
+        };
+        assert_eq!(
+            ScanOptions::from(args).repo,
+            Some(PathBuf::from("../service"))
+        );
+    }
+
+    #[test]
+    fn parses_security_scanner_audit_mode() {
+        for flag in ["--no-install", "--audit"] {
+            let cli = Cli::try_parse_from(["ops-tools", "security-scanner", flag]).unwrap();
+            let Some(Command::SecurityScanner(args)) = cli.command else {
+                panic!("Expected security-scanner subcommand");
+            };
+            assert!(ScanOptions::from(args).no_install);
+        }
+    }
+
+    #[test]
+    fn parses_container_builder_context() {
+        let cli =
+            Cli::try_parse_from(["ops-tools", "container-builder", "--context", "."]).unwrap();
+
+        let Some(Command::ContainerBuilder(args)) = cli.command else {
+            panic!("Expected container-builder subcommand");
+        };
+        assert_eq!(
+            ContainerBuildOptions::from(args).context,
+            Some(PathBuf::from("."))
+        );
+
+        let cli =
+            Cli::try_parse_from(["ops-tools", "container-builder", "--push-vulnerable"]).unwrap();
+        let Some(Command::ContainerBuilder(args)) = cli.command else {
+            panic!("Expected container-builder subcommand");
+        };
+        assert!(ContainerBuildOptions::from(args).push_vulnerable);
+    }
+
+    #[test]
+    fn parses_global_language_flag() {
+        let cli =
+            Cli::try_parse_from(["ops-tools", "terraform-cleaner", "--language", "en"]).unwrap();
+        assert_eq!(cli.language.as_deref(), Some("en"));
+    }
+
+    #[test]
+    fn parses_global_dry_run_flag() {
+        let cli = Cli::try_parse_from(["ops-tools", "--dry-run", "package-manager"]).unwrap();
+        assert!(cli.dry_run);
+
+        let cli = Cli::try_parse_from(["ops-tools", "terraform-cleaner"]).unwrap();
+        assert!(!cli.dry_run);
+    }
+
+    #[test]
+    fn parses_global_json_flag_and_names_features() {
+        let cli = Cli::try_parse_from(["ops-tools", "doctor", "--json"]).unwrap();
+        assert!(cli.json);
+
+        for subcommand in Cli::command().get_subcommands() {
+            let name = subcommand.get_name();
+            let cli = Cli::try_parse_from(["ops-tools", name]).unwrap();
+            assert_eq!(cli.command.unwrap().name(), name);
+        }
+    }
+
+    #[test]
+    fn parses_global_yes_flag() {
+        let cli = Cli::try_parse_from(["ops-tools", "terraform-cleaner", "-y"]).unwrap();
+        assert!(cli.yes);
+
+        let cli = Cli::try_parse_from(["ops-tools", "--yes", "mcp-manager"]).unwrap();
+        assert!(cli.yes);
+    }
+
+    #[test]
+    fn parses_quiet_and_verbose_flags() {
+        let cli = Cli::try_parse_from(["ops-tools", "terraform-cleaner", "--quiet"]).unwrap();
+        assert_eq!(cli.log_level(), Some(LogLevel::Quiet));
+
+        let cli = Cli::try_parse_from(["ops-tools", "--verbose", "rust-builder"]).unwrap();
+        assert_eq!(cli.log_level(), Some(LogLevel::Verbose));
+
+        let cli = Cli::try_parse_from(["ops-tools"]).unwrap();
+        assert_eq!(cli.log_level(), None);
+
+        assert!(Cli::try_parse_from(["ops-tools", "--quiet", "--verbose"]).is_err());
+    }
+
+    #[test]
+    fn parses_global_no_color_flag() {
+        let cli = Cli::try_parse_from(["ops-tools", "tool-upgrader", "--no-color"]).unwrap();
+        assert!(cli.no_color);
+    }
+
+    #[test]
+    fn parses_tool_upgrader_options() {
+        let cli = Cli::try_parse_from([
+            "ops-tools",
+            "tool-upgrader",
+            "--registry",
+            "https://npm.corp.example",
+        ])
+        .unwrap();
+
+        let Some(Command::ToolUpgrader(args)) = cli.command else {
+            panic!("Expected tool-upgrader subcommand");
+        };
+        let options = ToolUpgradeOptions::from(args);
+        assert_eq!(options.action, Some(ToolAction::Upgrade));
+        assert_eq!(
+            options.registry.as_deref(),
+            Some("https://npm.corp.example")
+        );
+
+        let cli = Cli::try_parse_from(["ops-tools", "tool-upgrader", "--uninstall"]).unwrap();
+        let Some(Command::ToolUpgrader(args)) = cli.command else {
+            panic!("Expected tool-upgrader subcommand");
+        };
+        assert_eq!(
+            ToolUpgradeOptions::from(args).action,
+            Some(ToolAction::Uninstall)
+        );
+    }
+
+    #[test]
+    fn parses_global_config_dir() {
+        let cli = Cli::try_parse_from(["ops-tools", "--config-dir", "/mnt/usb/ops", "mcp-manager"])
+            .unwrap();
+        assert_eq!(cli.config_dir, Some(PathBuf::from("/mnt/usb/ops")));
+    }
+
+    #[test]
+    fn rejects_unknown_subcommand() {
+        assert!(Cli::try_parse_from(["ops-tools", "unknown"]).is_err());
+    }
+}
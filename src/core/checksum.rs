@@ -0,0 +1,61 @@
+//! SHA-256 檢查碼計算與驗證
+
+use super::{OperationError, Result};
+use sha2::{Digest, Sha256};
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+/// 計算檔案的 SHA-256（小寫十六進位）
+pub fn sha256_file(path: &Path) -> Result<String> {
+    let io_error = |err| OperationError::io(path.display().to_string(), err);
+    let mut file = File::open(path).map_err(io_error)?;
+    let mut hasher = Sha256::new();
+    let mut buffer = [0u8; 64 * 1024];
+    loop {
+        let read = file.read(&mut buffer).map_err(io_error)?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+    Ok(hasher
+        .finalize()
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect())
+}
+
+/// 驗證檔案的 SHA-256，不符時回傳 `OperationError::Checksum`（不分大小寫）
+pub fn verify_sha256(path: &Path, expected: &str) -> Result<()> {
+    let expected = expected.trim().to_ascii_lowercase();
+    let actual = sha256_file(path)?;
+    if actual == expected {
+        Ok(())
+    } else {
+        Err(OperationError::Checksum {
+            path: path.display().to_string(),
+            expected,
+            actual,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verify_sha256() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("archive.tar.gz");
+        std::fs::write(&path, b"hello").unwrap();
+
+        let hello = "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824";
+        assert_eq!(sha256_file(&path).unwrap(), hello);
+        assert!(verify_sha256(&path, &hello.to_uppercase()).is_ok());
+
+        let err = verify_sha256(&path, &"0".repeat(64)).unwrap_err();
+        assert!(matches!(err, OperationError::Checksum { actual, .. } if actual == hello));
+    }
+}
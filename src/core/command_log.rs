@@ -0,0 +1,184 @@
+//! 外部命令紀錄
+//!
+//! 啟用時（設定檔 `logging_enabled = true`）將每個命令的程式、參數、結束碼與耗時
+//! 以 JSON Lines 附加到設定目錄的 `logs/commands-YYYY-MM-DD.jsonl`，
+//! 每日一個檔案、只保留最近幾天，單檔超過上限時改名為 `.old` 後重新開始
+
+use crate::core::config::config_dir;
+use crate::core::redact::redact;
+use chrono::{Local, NaiveDate};
+use serde::Serialize;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+/// 紀錄目錄名稱（位於設定目錄）
+const LOG_DIR_NAME: &str = "logs";
+
+/// 紀錄檔名前綴
+const LOG_FILE_PREFIX: &str = "commands-";
+
+/// 保留的天數
+const KEEP_DAYS: i64 = 7;
+
+/// 單一紀錄檔大小上限
+const MAX_FILE_BYTES: u64 = 5 * 1024 * 1024;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static PRUNED: AtomicBool = AtomicBool::new(false);
+
+/// 命令的結束方式
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Exited,
+    TimedOut,
+    FailedToStart,
+}
+
+#[derive(Serialize)]
+struct Entry {
+    timestamp: String,
+    program: String,
+    args: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cwd: Option<String>,
+    outcome: Outcome,
+    exit_code: Option<i32>,
+    duration_ms: u128,
+}
+
+/// 設定是否寫入命令紀錄
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// 是否寫入命令紀錄
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// 紀錄目錄
+pub fn log_dir() -> Option<PathBuf> {
+    config_dir().map(|dir| dir.join(LOG_DIR_NAME))
+}
+
+/// 紀錄一次命令執行；寫入失敗時忽略，不影響命令本身
+pub fn record(command: &Command, outcome: Outcome, exit_code: Option<i32>, duration: Duration) {
+    if !is_enabled() {
+        return;
+    }
+    let Some(dir) = log_dir() else {
+        return;
+    };
+
+    let entry = Entry {
+        timestamp: Local::now().to_rfc3339(),
+        program: command.get_program().to_string_lossy().to_string(),
+        args: command
+            .get_args()
+            .map(|arg| redact(&arg.to_string_lossy()))
+            .collect(),
+        cwd: command
+            .get_current_dir()
+            .map(|dir| dir.display().to_string()),
+        outcome,
+        exit_code,
+        duration_ms: duration.as_millis(),
+    };
+    let Ok(line) = serde_json::to_string(&entry) else {
+        return;
+    };
+
+    let today = Local::now().date_naive();
+    if !PRUNED.swap(true, Ordering::Relaxed) {
+        prune(&dir, today);
+    }
+    let _ = append(&dir, today, &line);
+}
+
+fn log_file(dir: &Path, date: NaiveDate) -> PathBuf {
+    dir.join(format!(
+        "{LOG_FILE_PREFIX}{}.jsonl",
+        date.format("%Y-%m-%d")
+    ))
+}
+
+fn append(dir: &Path, date: NaiveDate, line: &str) -> std::io::Result<()> {
+    fs::create_dir_all(dir)?;
+    let path = log_file(dir, date);
+    if fs::metadata(&path).is_ok_and(|meta| meta.len() >= MAX_FILE_BYTES) {
+        fs::rename(&path, path.with_extension("jsonl.old"))?;
+    }
+    let mut file = OpenOptions::new().create(true).append(true).open(&path)?;
+    writeln!(file, "{line}")
+}
+
+/// 刪除超過保留天數的紀錄檔
+fn prune(dir: &Path, today: NaiveDate) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let name = entry.file_name().to_string_lossy().to_string();
+        let Some(date) = name
+            .strip_prefix(LOG_FILE_PREFIX)
+            .and_then(|rest| rest.get(..10))
+            .and_then(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d").ok())
+        else {
+            continue;
+        };
+        if (today - date).num_days() >= KEEP_DAYS {
+            let _ = fs::remove_file(entry.path());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_append_rotates_large_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let date = NaiveDate::from_ymd_opt(2026, 1, 2).unwrap();
+        let path = log_file(dir.path(), date);
+        fs::write(&path, vec![b'x'; MAX_FILE_BYTES as usize]).unwrap();
+
+        append(dir.path(), date, "{\"a\":1}").unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "{\"a\":1}\n");
+        assert!(path.with_extension("jsonl.old").is_file());
+    }
+
+    #[test]
+    fn test_prune_keeps_recent_days() {
+        let dir = tempfile::tempdir().unwrap();
+        let today = NaiveDate::from_ymd_opt(2026, 1, 10).unwrap();
+        for day in [1, 3, 4, 10] {
+            let date = NaiveDate::from_ymd_opt(2026, 1, day).unwrap();
+            fs::write(log_file(dir.path(), date), "").unwrap();
+        }
+        fs::write(dir.path().join("notes.txt"), "").unwrap();
+
+        prune(dir.path(), today);
+
+        let mut names: Vec<String> = fs::read_dir(dir.path())
+            .unwrap()
+            .flatten()
+            .map(|entry| entry.file_name().to_string_lossy().to_string())
+            .collect();
+        names.sort();
+        assert_eq!(
+            names,
+            [
+                "commands-2026-01-04.jsonl",
+                "commands-2026-01-10.jsonl",
+                "notes.txt"
+            ]
+        );
+    }
+}
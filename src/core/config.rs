@@ -1,9 +1,12 @@
+use crate::core::log_level::LogLevel;
 use crate::core::{OperationError, Result};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::env;
+use std::ffi::OsString;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
 
 #[derive(Debug, Default, Serialize, Deserialize, Clone)]
 pub struct AppConfig {
@@ -11,12 +14,33 @@ pub struct AppConfig {
     /// Menu usage statistics for sorting by frequency
     #[serde(default)]
     pub menu_usage: HashMap<String, u32>,
+    /// Last time (Unix seconds) each menu item was used, for breaking usage ties
+    #[serde(default)]
+    pub menu_last_used: HashMap<String, i64>,
     /// How many common actions to show on the top menu
     #[serde(default = "default_common_actions_limit")]
     pub common_actions_limit: u32,
     /// Pinned menu items (shown at the top)
     #[serde(default)]
     pub pinned_items: Vec<String>,
+    /// Preview destructive actions instead of running them
+    #[serde(default)]
+    pub dry_run: bool,
+    /// Seconds before a hung external command is killed (0 disables, unset uses the default)
+    #[serde(default)]
+    pub command_timeout_secs: Option<u64>,
+    /// Append every external command to a rotating log under the config dir
+    #[serde(default)]
+    pub logging_enabled: bool,
+    /// Output verbosity (quiet, normal, verbose); `--quiet` / `--verbose` take precedence
+    #[serde(default)]
+    pub log_level: LogLevel,
+    /// Fingerprints of MCP definitions at install time, keyed by `cli:name`
+    #[serde(default)]
+    pub mcp_fingerprints: HashMap<String, String>,
+    /// Last pinned install version per package name (absent means latest)
+    #[serde(default)]
+    pub package_versions: HashMap<String, String>,
     /// Local Codex source directory (root of codex-rs workspace)
     #[serde(default)]
     pub codex_source_path: Option<String>,
@@ -26,12 +50,27 @@ pub struct AppConfig {
     /// Feature branch name in the Codex fork
     #[serde(default)]
     pub codex_feature_branch: Option<String>,
+    /// Extra plugin marketplace repos (`owner/repo`) listed by the skill installer
+    #[serde(default)]
+    pub skill_marketplaces: Vec<String>,
+    /// Scanners the security scanner installs and runs, by binary name (unset runs all)
+    #[serde(default)]
+    pub scanner_tools: Option<Vec<String>>,
+    /// npm registry used by the tool upgrader (`--registry` and `NPM_CONFIG_REGISTRY` take precedence)
+    #[serde(default)]
+    pub npm_registry: Option<String>,
 }
 
 impl AppConfig {
     /// Increment usage count for a menu item
     pub fn increment_usage(&mut self, key: &str) {
+        self.record_usage_at(key, chrono::Utc::now().timestamp());
+    }
+
+    /// Increment usage count and remember when the item was used
+    pub fn record_usage_at(&mut self, key: &str, timestamp: i64) {
         *self.menu_usage.entry(key.to_string()).or_insert(0) += 1;
+        self.menu_last_used.insert(key.to_string(), timestamp);
     }
 
     /// Get usage count for a menu item
@@ -39,11 +78,22 @@ impl AppConfig {
         self.menu_usage.get(key).copied().unwrap_or(0)
     }
 
+    /// Get last-used timestamp for a menu item (0 if never used)
+    pub fn get_last_used(&self, key: &str) -> i64 {
+        self.menu_last_used.get(key).copied().unwrap_or(0)
+    }
+
     /// Number of common actions to display (at least 1)
     pub fn common_actions_limit(&self) -> usize {
         self.common_actions_limit.max(1) as usize
     }
 
+    /// Timeout for external commands in seconds (0 means no timeout)
+    pub fn command_timeout_secs(&self) -> u64 {
+        self.command_timeout_secs
+            .unwrap_or(crate::core::process::DEFAULT_TIMEOUT_SECS)
+    }
+
     /// Check if an item is pinned
     pub fn is_pinned(&self, key: &str) -> bool {
         self.pinned_items.contains(&key.to_string())
@@ -60,37 +110,143 @@ impl AppConfig {
     pub fn pinned_items(&self) -> &[String] {
         &self.pinned_items
     }
+
+    /// Fingerprint recorded when an MCP server was installed through this tool
+    pub fn mcp_fingerprint(&self, cli: &str, name: &str) -> Option<&str> {
+        self.mcp_fingerprints
+            .get(&format!("{cli}:{name}"))
+            .map(String::as_str)
+    }
+
+    /// Record (or forget, with `None`) the fingerprint of an installed MCP server
+    pub fn set_mcp_fingerprint(&mut self, cli: &str, name: &str, fingerprint: Option<String>) {
+        let key = format!("{cli}:{name}");
+        match fingerprint {
+            Some(fingerprint) => {
+                self.mcp_fingerprints.insert(key, fingerprint);
+            }
+            None => {
+                self.mcp_fingerprints.remove(&key);
+            }
+        }
+    }
+
+    /// Version last pinned for a package install
+    pub fn package_version(&self, package: &str) -> Option<&str> {
+        self.package_versions.get(package).map(String::as_str)
+    }
+
+    /// Remember (or clear, with `None` for latest) the pinned version of a package
+    pub fn set_package_version(&mut self, package: &str, version: Option<String>) {
+        match version {
+            Some(version) => {
+                self.package_versions.insert(package.to_string(), version);
+            }
+            None => {
+                self.package_versions.remove(package);
+            }
+        }
+    }
+
+    /// Remember a skill marketplace repo; returns false if it was already listed
+    pub fn add_skill_marketplace(&mut self, repo: &str) -> bool {
+        if self
+            .skill_marketplaces
+            .iter()
+            .any(|existing| existing.eq_ignore_ascii_case(repo))
+        {
+            return false;
+        }
+        self.skill_marketplaces.push(repo.to_string());
+        true
+    }
+
+    /// Move a pinned item one position up or down, returning its new index
+    pub fn move_pinned(&mut self, index: usize, up: bool) -> Option<usize> {
+        if index >= self.pinned_items.len() {
+            return None;
+        }
+        let target = if up { index.checked_sub(1)? } else { index + 1 };
+        if target >= self.pinned_items.len() {
+            return None;
+        }
+        self.pinned_items.swap(index, target);
+        Some(target)
+    }
 }
 
 fn default_common_actions_limit() -> u32 {
     3
 }
 
-pub fn config_path() -> Option<PathBuf> {
+/// Environment variable overriding the config directory (portable installs, CI)
+pub const CONFIG_DIR_ENV_VAR: &str = "OPS_TOOLS_CONFIG_DIR";
+
+const CONFIG_FILE_NAME: &str = "config.toml";
+
+/// Directory given with `--config-dir`; takes precedence over the environment variable
+static CONFIG_DIR_OVERRIDE: Mutex<Option<PathBuf>> = Mutex::new(None);
+
+/// Use `dir` as the config directory for the rest of this run
+pub fn set_config_dir(dir: PathBuf) {
+    if let Ok(mut current) = CONFIG_DIR_OVERRIDE.lock() {
+        *current = Some(dir);
+    }
+}
+
+/// Config directory requested by `--config-dir` or `OPS_TOOLS_CONFIG_DIR`, if any
+fn config_dir_override() -> Option<PathBuf> {
+    let flag = CONFIG_DIR_OVERRIDE.lock().ok().and_then(|dir| dir.clone());
+    resolve_override(flag, env::var_os(CONFIG_DIR_ENV_VAR))
+}
+
+fn resolve_override(flag: Option<PathBuf>, env_value: Option<OsString>) -> Option<PathBuf> {
+    flag.or_else(|| {
+        env_value
+            .filter(|value| !value.is_empty())
+            .map(PathBuf::from)
+    })
+    .map(|dir| std::path::absolute(&dir).unwrap_or(dir))
+}
+
+/// Platform default: `%APPDATA%`, `~/Library/Application Support` or `$XDG_CONFIG_HOME`
+fn default_config_dir() -> Option<PathBuf> {
     if cfg!(target_os = "windows") {
         env::var_os("APPDATA")
             .map(PathBuf::from)
-            .map(|base| base.join("ops-tools").join("config.toml"))
+            .map(|base| base.join("ops-tools"))
     } else if cfg!(target_os = "macos") {
         env::var_os("HOME").map(PathBuf::from).map(|base| {
             base.join("Library")
                 .join("Application Support")
                 .join("ops-tools")
-                .join("config.toml")
         })
     } else if let Some(config_home) = env::var_os("XDG_CONFIG_HOME") {
-        Some(
-            PathBuf::from(config_home)
-                .join("ops-tools")
-                .join("config.toml"),
-        )
+        Some(PathBuf::from(config_home).join("ops-tools"))
     } else {
         env::var_os("HOME")
             .map(PathBuf::from)
-            .map(|base| base.join(".config").join("ops-tools").join("config.toml"))
+            .map(|base| base.join(".config").join("ops-tools"))
     }
 }
 
+/// Directory holding `config.toml` and other per-user files
+pub fn config_dir() -> Option<PathBuf> {
+    config_dir_override().or_else(default_config_dir)
+}
+
+pub fn config_path() -> Option<PathBuf> {
+    config_dir().map(|dir| dir.join(CONFIG_FILE_NAME))
+}
+
+/// Create an overridden config directory up front, so a bad path is reported at startup
+pub fn ensure_config_dir_override() -> Result<()> {
+    let Some(dir) = config_dir_override() else {
+        return Ok(());
+    };
+    fs::create_dir_all(&dir).map_err(|err| OperationError::io(dir.display().to_string(), err))
+}
+
 pub fn load_config() -> Result<Option<AppConfig>> {
     let Some(path) = config_path() else {
         return Ok(None);
@@ -100,10 +256,8 @@ pub fn load_config() -> Result<Option<AppConfig>> {
         return Ok(None);
     }
 
-    let raw = fs::read_to_string(&path).map_err(|err| OperationError::Io {
-        path: path.display().to_string(),
-        source: err,
-    })?;
+    let raw = fs::read_to_string(&path)
+        .map_err(|err| OperationError::io(path.display().to_string(), err))?;
 
     let config = toml::from_str(&raw).map_err(|err| OperationError::Config {
         key: path.display().to_string(),
@@ -122,10 +276,8 @@ pub fn save_config(config: &AppConfig) -> Result<()> {
     };
 
     if let Some(parent) = path.parent() {
-        fs::create_dir_all(parent).map_err(|err| OperationError::Io {
-            path: parent.display().to_string(),
-            source: err,
-        })?;
+        fs::create_dir_all(parent)
+            .map_err(|err| OperationError::io(parent.display().to_string(), err))?;
     }
 
     let content = toml::to_string(config).map_err(|err| OperationError::Config {
@@ -133,14 +285,111 @@ pub fn save_config(config: &AppConfig) -> Result<()> {
         message: err.to_string(),
     })?;
 
-    fs::write(&path, content).map_err(|err| OperationError::Io {
-        path: path.display().to_string(),
-        source: err,
+    fs::write(&path, content).map_err(|err| OperationError::io(path.display().to_string(), err))?;
+
+    Ok(())
+}
+
+/// Per-machine state that is left out of exported configs and ignored on import
+const LOCAL_ONLY_KEYS: [&str; 3] = ["menu_usage", "menu_last_used", "mcp_fingerprints"];
+
+/// Write the shareable part of `config` to `path` as TOML
+pub fn export_config(config: &AppConfig, path: &Path) -> Result<()> {
+    let mut table = toml::Table::try_from(config).map_err(|err| OperationError::Config {
+        key: path.display().to_string(),
+        message: err.to_string(),
     })?;
+    for key in LOCAL_ONLY_KEYS {
+        table.remove(key);
+    }
+    write_table(&table, path)
+}
+
+/// Merge the settings from `path` into the saved config, returning the keys that changed.
+///
+/// Keys missing from the imported file keep their current value, and keys this version
+/// does not know about are carried over instead of being dropped.
+pub fn import_config(path: &Path) -> Result<Vec<String>> {
+    let imported = read_table(path)?;
+    validate_table(&imported, path)?;
+
+    let Some(config_path) = config_path() else {
+        return Err(OperationError::Config {
+            key: "config_path".to_string(),
+            message: "Unable to resolve config directory".to_string(),
+        });
+    };
+    let mut current = if config_path.exists() {
+        read_table(&config_path)?
+    } else {
+        toml::Table::new()
+    };
+
+    let changed = merge_tables(&mut current, imported);
+    if !changed.is_empty() {
+        write_table(&current, &config_path)?;
+    }
+    Ok(changed)
+}
+
+fn merge_tables(current: &mut toml::Table, imported: toml::Table) -> Vec<String> {
+    let mut changed = Vec::new();
+    for (key, value) in imported {
+        if LOCAL_ONLY_KEYS.contains(&key.as_str()) || current.get(&key) == Some(&value) {
+            continue;
+        }
+        changed.push(key.clone());
+        current.insert(key, value);
+    }
+    changed
+}
 
+/// Reject files whose known fields have the wrong type or an unsupported language
+fn validate_table(table: &toml::Table, path: &Path) -> Result<()> {
+    let config: AppConfig =
+        table
+            .clone()
+            .try_into()
+            .map_err(|err: toml::de::Error| OperationError::Config {
+                key: path.display().to_string(),
+                message: err.to_string(),
+            })?;
+
+    if let Some(code) = config.language.as_deref()
+        && crate::i18n::Language::from_code(code).is_none()
+    {
+        return Err(OperationError::Validation(format!(
+            "Unsupported language: {code}"
+        )));
+    }
     Ok(())
 }
 
+fn read_table(path: &Path) -> Result<toml::Table> {
+    let raw = fs::read_to_string(path)
+        .map_err(|err| OperationError::io(path.display().to_string(), err))?;
+    raw.parse::<toml::Table>()
+        .map_err(|err| OperationError::Config {
+            key: path.display().to_string(),
+            message: err.to_string(),
+        })
+}
+
+fn write_table(table: &toml::Table, path: &Path) -> Result<()> {
+    if let Some(parent) = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    {
+        fs::create_dir_all(parent)
+            .map_err(|err| OperationError::io(parent.display().to_string(), err))?;
+    }
+    let content = toml::to_string(table).map_err(|err| OperationError::Config {
+        key: path.display().to_string(),
+        message: err.to_string(),
+    })?;
+    fs::write(path, content).map_err(|err| OperationError::io(path.display().to_string(), err))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -169,6 +418,68 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_log_level_defaults_to_normal() {
+        let config: AppConfig = toml::from_str("dry_run = true").unwrap();
+        assert_eq!(config.log_level, LogLevel::Normal);
+
+        let config: AppConfig = toml::from_str("log_level = \"quiet\"").unwrap();
+        assert_eq!(config.log_level, LogLevel::Quiet);
+    }
+
+    #[test]
+    fn test_record_usage_tracks_last_used() {
+        let mut config = AppConfig::default();
+        config.record_usage_at("a", 100);
+        config.record_usage_at("a", 200);
+
+        assert_eq!(config.get_usage("a"), 2);
+        assert_eq!(config.get_last_used("a"), 200);
+        assert_eq!(config.get_last_used("b"), 0);
+    }
+
+    #[test]
+    fn test_mcp_fingerprint_roundtrip() {
+        let mut config = AppConfig::default();
+        config.set_mcp_fingerprint("claude", "github", Some("abc".to_string()));
+
+        assert_eq!(config.mcp_fingerprint("claude", "github"), Some("abc"));
+        assert_eq!(config.mcp_fingerprint("codex", "github"), None);
+
+        config.set_mcp_fingerprint("claude", "github", None);
+        assert_eq!(config.mcp_fingerprint("claude", "github"), None);
+    }
+
+    #[test]
+    fn test_package_version_roundtrip() {
+        let mut config = AppConfig::default();
+        assert_eq!(config.package_version("kubectl"), None);
+
+        config.set_package_version("kubectl", Some("v1.29.3".to_string()));
+        assert_eq!(config.package_version("kubectl"), Some("v1.29.3"));
+
+        config.set_package_version("kubectl", None);
+        assert_eq!(config.package_version("kubectl"), None);
+    }
+
+    #[test]
+    fn test_move_pinned() {
+        let mut config = AppConfig::default();
+        for key in ["a", "b", "c"] {
+            config.pin_item(key);
+        }
+
+        assert_eq!(config.move_pinned(2, true), Some(1));
+        assert_eq!(config.pinned_items(), ["a", "c", "b"]);
+        assert_eq!(config.move_pinned(0, false), Some(1));
+        assert_eq!(config.pinned_items(), ["c", "a", "b"]);
+
+        assert_eq!(config.move_pinned(0, true), None);
+        assert_eq!(config.move_pinned(2, false), None);
+        assert_eq!(config.move_pinned(5, true), None);
+        assert_eq!(config.pinned_items(), ["c", "a", "b"]);
+    }
+
     #[test]
     #[cfg(not(any(target_os = "windows", target_os = "macos")))]
     fn test_config_path_uses_xdg() {
@@ -187,6 +498,81 @@ mod tests {
         restore_env("HOME", old_home);
     }
 
+    #[test]
+    fn test_export_skips_local_state() {
+        let temp = tempfile::tempdir().unwrap();
+        let path = temp.path().join("team.toml");
+        let mut config = AppConfig {
+            language: Some("ja".to_string()),
+            ..Default::default()
+        };
+        config.pin_item("menu.rust_builder");
+        config.record_usage_at("menu.rust_builder", 100);
+        config.set_mcp_fingerprint("claude", "github", Some("abc".to_string()));
+
+        export_config(&config, &path).unwrap();
+
+        let table = read_table(&path).unwrap();
+        assert_eq!(table["language"].as_str(), Some("ja"));
+        assert!(table.contains_key("pinned_items"));
+        for key in LOCAL_ONLY_KEYS {
+            assert!(!table.contains_key(key), "{key}");
+        }
+    }
+
+    #[test]
+    fn test_merge_keeps_unknown_and_reports_changes() {
+        let mut current: toml::Table = toml::from_str(
+            "language = \"en\"\ncommon_actions_limit = 3\nfuture_option = true\n[menu_usage]\na = 1",
+        )
+        .unwrap();
+        let imported: toml::Table = toml::from_str(
+            "language = \"ko\"\ncommon_actions_limit = 3\npinned_items = [\"a\"]\n[menu_usage]\nb = 9",
+        )
+        .unwrap();
+
+        let changed = merge_tables(&mut current, imported);
+
+        assert_eq!(changed, ["language", "pinned_items"]);
+        assert_eq!(current["language"].as_str(), Some("ko"));
+        assert_eq!(current["future_option"].as_bool(), Some(true));
+        assert!(current["menu_usage"].as_table().unwrap().contains_key("a"));
+    }
+
+    #[test]
+    fn test_validate_rejects_bad_fields() {
+        let path = Path::new("team.toml");
+        let valid: toml::Table = toml::from_str("language = \"zh-TW\"\nextra = 1").unwrap();
+        assert!(validate_table(&valid, path).is_ok());
+
+        let bad_type: toml::Table = toml::from_str("common_actions_limit = \"three\"").unwrap();
+        assert!(validate_table(&bad_type, path).is_err());
+
+        let bad_language: toml::Table = toml::from_str("language = \"xx\"").unwrap();
+        assert!(validate_table(&bad_language, path).is_err());
+    }
+
+    #[test]
+    fn test_config_dir_override_precedence() {
+        let flag = Some(PathBuf::from("/portable/flag"));
+        let env_value = Some(OsString::from("/portable/env"));
+
+        assert_eq!(
+            resolve_override(flag, env_value.clone()),
+            Some(PathBuf::from("/portable/flag"))
+        );
+        assert_eq!(
+            resolve_override(None, env_value),
+            Some(PathBuf::from("/portable/env"))
+        );
+        assert_eq!(resolve_override(None, Some(OsString::new())), None);
+        assert_eq!(resolve_override(None, None), None);
+
+        let relative = resolve_override(Some(PathBuf::from("cfg")), None).unwrap();
+        assert!(relative.is_absolute());
+        assert!(relative.ends_with("cfg"));
+    }
+
     #[test]
     #[cfg(target_os = "windows")]
     fn test_config_path_uses_appdata() {
@@ -0,0 +1,14 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// 全域模擬執行旗標（由 `--dry-run` 或設定檔啟用）
+static DRY_RUN: AtomicBool = AtomicBool::new(false);
+
+/// 設定是否以模擬模式執行
+pub fn set_enabled(enabled: bool) {
+    DRY_RUN.store(enabled, Ordering::Relaxed);
+}
+
+/// 是否處於模擬模式：破壞性操作只預覽、不實際執行
+pub fn is_enabled() -> bool {
+    DRY_RUN.load(Ordering::Relaxed)
+}
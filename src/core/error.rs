@@ -1,3 +1,5 @@
+use crate::core::redact::redact;
+use crate::core::requirements::install_hint;
 use crate::i18n::{self, keys};
 use std::fmt;
 use std::io;
@@ -9,6 +11,12 @@ pub enum OperationError {
     /// IO 相關錯誤（檔案讀寫、目錄操作）
     Io { path: String, source: io::Error },
 
+    /// 權限不足（需要 sudo 或調整擁有者），由 `OperationError::io` 依錯誤種類區分
+    PermissionDenied { path: String },
+
+    /// 找不到需要的外部命令（附安裝提示）
+    CommandNotFound { command: String },
+
     /// 外部命令執行錯誤
     Command { command: String, message: String },
 
@@ -18,6 +26,13 @@ pub enum OperationError {
     /// 驗證錯誤（輸入不合法）
     Validation(String),
 
+    /// 下載檔案的檢查碼不符
+    Checksum {
+        path: String,
+        expected: String,
+        actual: String,
+    },
+
     /// 使用者取消操作
     Cancelled,
 
@@ -35,11 +50,38 @@ impl fmt::Display for OperationError {
                     crate::tr!(keys::ERROR_IO, path = path, source = source)
                 )
             }
+            Self::PermissionDenied { path } => {
+                write!(
+                    f,
+                    "{}",
+                    crate::tr!(keys::ERROR_PERMISSION_DENIED, path = path)
+                )
+            }
+            Self::CommandNotFound { command } => match install_hint(command) {
+                Some(hint) => write!(
+                    f,
+                    "{}",
+                    crate::tr!(
+                        keys::ERROR_COMMAND_MISSING_HINT,
+                        command = command,
+                        hint = hint
+                    )
+                ),
+                None => write!(
+                    f,
+                    "{}",
+                    crate::tr!(keys::ERROR_COMMAND_MISSING, command = command)
+                ),
+            },
             Self::Command { command, message } => {
                 write!(
                     f,
                     "{}",
-                    crate::tr!(keys::ERROR_COMMAND, command = command, message = message)
+                    crate::tr!(
+                        keys::ERROR_COMMAND,
+                        command = redact(command),
+                        message = redact(message)
+                    )
                 )
             }
             Self::Config { key, message } => {
@@ -52,6 +94,20 @@ impl fmt::Display for OperationError {
             Self::Validation(msg) => {
                 write!(f, "{}", crate::tr!(keys::ERROR_VALIDATION, message = msg))
             }
+            Self::Checksum {
+                path,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "{}",
+                crate::tr!(
+                    keys::ERROR_CHECKSUM,
+                    path = path,
+                    expected = expected,
+                    actual = actual
+                )
+            ),
             Self::Cancelled => write!(f, "{}", i18n::t(keys::ERROR_CANCELLED)),
             Self::MissingCargoToml => write!(
                 f,
@@ -71,12 +127,21 @@ impl std::error::Error for OperationError {
     }
 }
 
+impl OperationError {
+    /// 包裝 IO 錯誤；權限不足時改用 `PermissionDenied`，讓訊息能提示修正方式
+    pub fn io(path: impl Into<String>, source: io::Error) -> Self {
+        let path = path.into();
+        if source.kind() == io::ErrorKind::PermissionDenied {
+            Self::PermissionDenied { path }
+        } else {
+            Self::Io { path, source }
+        }
+    }
+}
+
 impl From<io::Error> for OperationError {
     fn from(err: io::Error) -> Self {
-        Self::Io {
-            path: String::new(),
-            source: err,
-        }
+        Self::io(String::new(), err)
     }
 }
 
@@ -96,6 +161,19 @@ mod tests {
         assert!(err.to_string().contains("/test/path"));
     }
 
+    #[test]
+    fn test_io_permission_denied_is_distinct() {
+        let err = OperationError::io(
+            "/usr/local/bin/kubectl",
+            io::Error::from(io::ErrorKind::PermissionDenied),
+        );
+        assert!(matches!(err, OperationError::PermissionDenied { .. }));
+        assert!(err.to_string().contains("/usr/local/bin/kubectl"));
+
+        let err = OperationError::io("/tmp/x", io::Error::from(io::ErrorKind::NotFound));
+        assert!(matches!(err, OperationError::Io { .. }));
+    }
+
     #[test]
     fn test_display_command_error() {
         let err = OperationError::Command {
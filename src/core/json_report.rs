@@ -0,0 +1,163 @@
+//! `--json` 結構化結果
+//!
+//! 啟用時，控制台的訊息與摘要同時累積到一份報告，程式結束時由 `main` 輸出成單一 JSON 物件：
+//! `{feature, success, failed, details}`
+//!
+//! stdout 保留給這個 JSON 物件：控制台訊息與經由 `process::status_with_timeout` 執行的子行程輸出改寫到 stderr
+
+use serde::Serialize;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// 全域 JSON 報告旗標（由 `--json` 啟用）
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+static REPORT: Mutex<Report> = Mutex::new(Report::new());
+
+/// 累積的執行結果
+#[derive(Debug, Default, PartialEq, Eq, Serialize)]
+pub struct Report {
+    /// 執行的功能（子命令名稱，例如 `terraform-cleaner`）
+    pub feature: Option<String>,
+    pub success: usize,
+    pub failed: usize,
+    pub details: Vec<Detail>,
+}
+
+/// 一則訊息
+#[derive(Debug, PartialEq, Eq, Serialize)]
+pub struct Detail {
+    pub level: Level,
+    pub message: String,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Level {
+    Info,
+    Success,
+    Warning,
+    Error,
+    Summary,
+}
+
+impl Report {
+    const fn new() -> Self {
+        Self {
+            feature: None,
+            success: 0,
+            failed: 0,
+            details: Vec::new(),
+        }
+    }
+
+    fn add_detail(&mut self, level: Level, message: &str) {
+        self.details.push(Detail {
+            level,
+            message: strip_ansi(message),
+        });
+    }
+
+    /// 同一次執行可能有多個摘要（例如多個階段），數量累加
+    fn add_summary(&mut self, title: &str, success: usize, failed: usize) {
+        self.success += success;
+        self.failed += failed;
+        self.add_detail(Level::Summary, title);
+    }
+}
+
+/// 設定是否累積 JSON 報告
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+fn with_report(update: impl FnOnce(&mut Report)) {
+    if !is_enabled() {
+        return;
+    }
+    let mut report = REPORT
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    update(&mut report);
+}
+
+/// 記錄目前執行的功能
+pub fn set_feature(feature: &str) {
+    with_report(|report| report.feature = Some(feature.to_string()));
+}
+
+/// 記錄一則訊息
+pub fn record(level: Level, message: &str) {
+    with_report(|report| report.add_detail(level, message));
+}
+
+/// 記錄一次成功 / 失敗統計
+pub fn record_summary(title: &str, success: usize, failed: usize) {
+    with_report(|report| report.add_summary(title, success, failed));
+}
+
+/// 未啟用時回傳 `None`；否則取出累積的報告並序列化
+pub fn render() -> Option<String> {
+    if !is_enabled() {
+        return None;
+    }
+    let report = std::mem::take(
+        &mut *REPORT
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()),
+    );
+    serde_json::to_string(&report).ok()
+}
+
+/// 移除訊息中的終端機色碼（例如以 `colored` 上色的參數）
+fn strip_ansi(message: &str) -> String {
+    let mut output = String::with_capacity(message.len());
+    let mut chars = message.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            // CSI 序列以 0x40..=0x7e 的字元結尾（`[` 本身除外）
+            for next in chars.by_ref() {
+                if next != '[' && ('\x40'..='\x7e').contains(&next) {
+                    break;
+                }
+            }
+        } else {
+            output.push(c);
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report_accumulates_summaries() {
+        let mut report = Report::new();
+        report.feature = Some("terraform-cleaner".to_string());
+        report.add_detail(Level::Warning, "\x1b[33mcache\x1b[0m in use");
+        report.add_summary("Clean", 3, 1);
+        report.add_summary("Clean lock files", 2, 0);
+
+        assert_eq!(report.success, 5);
+        assert_eq!(report.failed, 1);
+        assert_eq!(report.details[0].message, "cache in use");
+
+        let json: serde_json::Value = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["feature"], "terraform-cleaner");
+        assert_eq!(json["details"][0]["level"], "warning");
+        assert_eq!(json["details"][2]["level"], "summary");
+        assert_eq!(json["details"][2]["message"], "Clean lock files");
+    }
+
+    #[test]
+    fn test_strip_ansi() {
+        assert_eq!(strip_ansi("\x1b[1;32mok\x1b[0m done"), "ok done");
+        assert_eq!(strip_ansi("plain"), "plain");
+    }
+}
@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// 輸出詳細程度（由 `--quiet` / `--verbose` 或設定檔決定）
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogLevel {
+    /// 只輸出錯誤、警告與最終摘要
+    Quiet,
+    #[default]
+    Normal,
+    /// 另外顯示每個執行的外部命令
+    Verbose,
+}
+
+/// 全域輸出層級
+static LOG_LEVEL: AtomicU8 = AtomicU8::new(LogLevel::Normal as u8);
+
+/// 設定本次執行的輸出層級
+pub fn set_level(level: LogLevel) {
+    LOG_LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+/// 目前的輸出層級
+pub fn current() -> LogLevel {
+    match LOG_LEVEL.load(Ordering::Relaxed) {
+        value if value == LogLevel::Quiet as u8 => LogLevel::Quiet,
+        value if value == LogLevel::Verbose as u8 => LogLevel::Verbose,
+        _ => LogLevel::Normal,
+    }
+}
@@ -1,10 +1,20 @@
+pub mod checksum;
+pub mod command_log;
 pub mod config;
+pub mod dry_run;
 pub mod error;
+pub mod json_report;
+pub mod log_level;
 pub mod path_utils;
+pub mod process;
+pub mod redact;
+pub mod requirements;
 pub mod result;
+pub mod temp_dir;
 pub mod traits;
 
 pub use config::{AppConfig, load_config, save_config};
 pub use error::{OperationError, Result};
 pub use result::{OperationResult, OperationStats, OperationType};
+pub use temp_dir::TempDir;
 pub use traits::{FileCleaner, FileScanner};
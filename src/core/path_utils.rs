@@ -1,4 +1,5 @@
 use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
 
 /// 檢查 child 是否是 parent 的子路徑
 pub fn is_subpath(child: &Path, parent: &Path) -> bool {
@@ -39,6 +40,34 @@ pub fn count_filtered_subpaths(original: &[PathBuf], filtered: &[PathBuf]) -> us
     original.len().saturating_sub(filtered.len())
 }
 
+/// 計算檔案或目錄（遞迴）佔用的位元組數，不跟隨符號連結
+pub fn disk_usage(path: &Path) -> u64 {
+    WalkDir::new(path)
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| entry.metadata().ok())
+        .filter(|metadata| metadata.is_file())
+        .map(|metadata| metadata.len())
+        .sum()
+}
+
+/// Human-readable byte size (e.g. `4.2 MiB`)
+pub fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+
+    if unit == 0 {
+        format!("{} {}", bytes, UNITS[0])
+    } else {
+        format!("{:.1} {}", size, UNITS[unit])
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -133,4 +162,24 @@ mod tests {
         let count = count_filtered_subpaths(&original, &filtered);
         assert_eq!(count, 2);
     }
+
+    #[test]
+    fn test_disk_usage_sums_nested_files() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let nested = temp_dir.path().join("cache/nested");
+        std::fs::create_dir_all(&nested).unwrap();
+        std::fs::write(temp_dir.path().join("cache/a.txt"), b"1234").unwrap();
+        std::fs::write(nested.join("b.txt"), b"123456").unwrap();
+
+        assert_eq!(disk_usage(&temp_dir.path().join("cache")), 10);
+        assert_eq!(disk_usage(&nested.join("b.txt")), 6);
+        assert_eq!(disk_usage(&temp_dir.path().join("missing")), 0);
+    }
+
+    #[test]
+    fn test_format_size_uses_binary_units() {
+        assert_eq!(format_size(512), "512 B");
+        assert_eq!(format_size(1536), "1.5 KiB");
+        assert_eq!(format_size(5 * 1024 * 1024), "5.0 MiB");
+    }
 }
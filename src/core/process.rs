@@ -0,0 +1,154 @@
+//! 外部命令執行（含逾時）
+//!
+//! 子行程超過設定的秒數仍未結束時會被終止，並回傳 `OperationError::Command`
+
+use crate::core::command_log::{self, Outcome};
+use crate::core::{OperationError, Result, json_report};
+use crate::i18n::keys;
+use std::io::Read;
+use std::process::{Command, ExitStatus, Output, Stdio};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+use wait_timeout::ChildExt;
+
+/// 預設逾時秒數（0 表示不限制）
+pub const DEFAULT_TIMEOUT_SECS: u64 = 1800;
+
+/// 全域命令逾時秒數（由設定檔 `command_timeout_secs` 設定）
+static TIMEOUT_SECS: AtomicU64 = AtomicU64::new(DEFAULT_TIMEOUT_SECS);
+
+/// 設定命令逾時秒數，0 表示不限制
+pub fn set_timeout_secs(secs: u64) {
+    TIMEOUT_SECS.store(secs, Ordering::Relaxed);
+}
+
+/// 目前的命令逾時；未限制時回傳 None
+pub fn timeout() -> Option<Duration> {
+    match TIMEOUT_SECS.load(Ordering::Relaxed) {
+        0 => None,
+        secs => Some(Duration::from_secs(secs)),
+    }
+}
+
+/// 執行命令並擷取 stdout/stderr，逾時則終止（stdin 為 null，與 `Command::output` 相同）
+pub fn run_with_timeout(command: &mut Command) -> Result<Output> {
+    output_within(command, timeout())
+}
+
+/// 執行命令並等待結束（沿用呼叫端設定的 stdio），逾時則終止
+///
+/// `--json` 時子行程的 stdout 改寫到 stderr，讓 stdout 只留給 JSON 報告
+pub fn status_with_timeout(command: &mut Command) -> Result<ExitStatus> {
+    if json_report::is_enabled() {
+        command.stdout(std::io::stderr());
+    }
+    let started = Instant::now();
+    let mut child = command
+        .spawn()
+        .map_err(|err| unable_to_execute(command, started, err))?;
+    wait_within(command, &mut child, timeout(), started)
+}
+
+fn output_within(command: &mut Command, limit: Option<Duration>) -> Result<Output> {
+    command
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    let started = Instant::now();
+    let mut child = command
+        .spawn()
+        .map_err(|err| unable_to_execute(command, started, err))?;
+
+    // 另開執行緒讀取輸出，避免管線塞滿時子行程卡住
+    let stdout = read_in_background(child.stdout.take());
+    let stderr = read_in_background(child.stderr.take());
+    let status = wait_within(command, &mut child, limit, started)?;
+
+    Ok(Output {
+        status,
+        stdout: stdout.join().unwrap_or_default(),
+        stderr: stderr.join().unwrap_or_default(),
+    })
+}
+
+fn wait_within(
+    command: &Command,
+    child: &mut std::process::Child,
+    limit: Option<Duration>,
+    started: Instant,
+) -> Result<ExitStatus> {
+    let waited = match limit {
+        Some(limit) => child.wait_timeout(limit),
+        None => child.wait().map(Some),
+    };
+
+    match waited {
+        Ok(Some(status)) => {
+            command_log::record(command, Outcome::Exited, status.code(), started.elapsed());
+            Ok(status)
+        }
+        Ok(None) => {
+            let _ = child.kill();
+            let _ = child.wait();
+            command_log::record(command, Outcome::TimedOut, None, started.elapsed());
+            let seconds = limit.unwrap_or_default().as_secs();
+            Err(OperationError::Command {
+                command: command.get_program().to_string_lossy().to_string(),
+                message: crate::tr!(keys::ERROR_COMMAND_TIMED_OUT, seconds = seconds),
+            })
+        }
+        Err(err) => Err(unable_to_execute(command, started, err)),
+    }
+}
+
+fn read_in_background<R: Read + Send + 'static>(source: Option<R>) -> JoinHandle<Vec<u8>> {
+    thread::spawn(move || {
+        let mut buffer = Vec::new();
+        if let Some(mut source) = source {
+            let _ = source.read_to_end(&mut buffer);
+        }
+        buffer
+    })
+}
+
+fn unable_to_execute(command: &Command, started: Instant, err: std::io::Error) -> OperationError {
+    command_log::record(command, Outcome::FailedToStart, None, started.elapsed());
+    OperationError::Command {
+        command: command.get_program().to_string_lossy().to_string(),
+        message: crate::tr!(keys::ERROR_UNABLE_TO_EXECUTE, error = err),
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_output_is_captured() {
+        let mut command = Command::new("sh");
+        command.args(["-c", "echo out; echo err >&2; exit 3"]);
+
+        let output = output_within(&mut command, Some(Duration::from_secs(10))).unwrap();
+        assert_eq!(output.status.code(), Some(3));
+        assert_eq!(output.stdout, b"out\n");
+        assert_eq!(output.stderr, b"err\n");
+    }
+
+    #[test]
+    fn test_hung_command_is_killed() {
+        let mut command = Command::new("sleep");
+        command.arg("5");
+
+        let started = std::time::Instant::now();
+        let result = output_within(&mut command, Some(Duration::from_millis(100)));
+        assert!(matches!(result, Err(OperationError::Command { .. })));
+        assert!(started.elapsed() < Duration::from_secs(4));
+    }
+
+    #[test]
+    fn test_missing_program_is_reported() {
+        let mut command = Command::new("ops-tools-definitely-missing-binary");
+        assert!(output_within(&mut command, None).is_err());
+    }
+}
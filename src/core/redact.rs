@@ -0,0 +1,103 @@
+//! 遮蔽憑證
+//!
+//! 命令列與錯誤訊息在顯示或寫入紀錄前，將 Token、API Key 等值改為 `****`
+
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// 遮蔽後的值
+pub const MASK: &str = "****";
+
+/// 值本身需要遮蔽的環境變數（出現在任何位置都會被替換）
+const SENSITIVE_ENV_VARS: [&str; 5] = [
+    "GITHUB_PERSONAL_ACCESS_TOKEN",
+    "GITHUB_TOKEN",
+    "CONTEXT7_API_KEY",
+    "JIRA_API_TOKEN",
+    "OPENAI_API_KEY",
+];
+
+/// 名稱帶有這些字樣的鍵視為憑證
+const SECRET_MARKERS: [&str; 6] = [
+    "TOKEN", "SECRET", "PASSWORD", "API_KEY", "APIKEY", "API-KEY",
+];
+
+/// `Authorization: Bearer xxx`，保留驗證方式只遮蔽憑證
+static AUTHORIZATION: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"(?i)\b(authorization\s*:\s*)((?:bearer|basic|token)\s+)?[^\s"',;]+"#).unwrap()
+});
+
+/// `NAME=value` 或 `NAME: value`
+static KEY_VALUE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"([A-Za-z0-9_.-]+)(\s*[=:]\s*)("[^"]*"|'[^']*'|[^\s"',;]+)"#).unwrap()
+});
+
+/// 遮蔽字串中的憑證（完整命令列或錯誤訊息）
+pub fn redact(text: &str) -> String {
+    let mut text = text.to_string();
+    for value in SENSITIVE_ENV_VARS
+        .iter()
+        .filter_map(|name| std::env::var(name).ok())
+        .filter(|value| value.trim().len() >= 4)
+    {
+        text = text.replace(value.trim(), MASK);
+    }
+
+    let text = AUTHORIZATION.replace_all(&text, |caps: &regex::Captures| {
+        format!(
+            "{}{}{MASK}",
+            &caps[1],
+            caps.get(2).map_or("", |scheme| scheme.as_str())
+        )
+    });
+    KEY_VALUE
+        .replace_all(&text, |caps: &regex::Captures| {
+            if is_secret_name(&caps[1]) && &caps[3] != MASK {
+                format!("{}{}{MASK}", &caps[1], &caps[2])
+            } else {
+                caps[0].to_string()
+            }
+        })
+        .to_string()
+}
+
+fn is_secret_name(name: &str) -> bool {
+    let name = name.to_ascii_uppercase();
+    SECRET_MARKERS.iter().any(|marker| name.contains(marker))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_token_in_args_is_masked() {
+        assert_eq!(
+            redact(
+                "claude mcp add github --env GITHUB_PERSONAL_ACCESS_TOKEN=ghp_abc123 -- docker run"
+            ),
+            "claude mcp add github --env GITHUB_PERSONAL_ACCESS_TOKEN=**** -- docker run"
+        );
+        assert_eq!(
+            redact("--header 'Authorization: Bearer ghp_abc123'"),
+            "--header 'Authorization: Bearer ****'"
+        );
+        assert_eq!(
+            redact("CONTEXT7_API_KEY: ctx7sk-1"),
+            "CONTEXT7_API_KEY: ****"
+        );
+        assert_eq!(redact("--api-token=\"a b\""), "--api-token=****");
+    }
+
+    #[test]
+    fn test_plain_values_are_kept() {
+        for text in [
+            "GITHUB_HOST=https://ghe.example.com",
+            "docker run -e GITHUB_PERSONAL_ACCESS_TOKEN ghcr.io/github/github-mcp-server",
+            "https://api.githubcopilot.com/mcp/",
+            "Command 'apt-get install -y jq' failed: exit status 100",
+        ] {
+            assert_eq!(redact(text), text);
+        }
+    }
+}
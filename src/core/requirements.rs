@@ -0,0 +1,99 @@
+//! 外部命令檢查
+//!
+//! 各功能開始前以 [`require_commands`] 確認需要的命令都在 PATH 上，缺少時回傳附安裝提示的錯誤
+
+use super::{OperationError, Result};
+use std::env;
+use std::path::{Path, PathBuf};
+
+/// 檢查指令是否可用，回傳找到的路徑
+pub fn is_command_available(command: &str) -> Option<PathBuf> {
+    let path = Path::new(command);
+    if path.is_absolute() || command.contains(std::path::MAIN_SEPARATOR) {
+        if path.is_file() {
+            return Some(path.to_path_buf());
+        }
+        return None;
+    }
+
+    let path_var = env::var_os("PATH")?;
+    for dir in env::split_paths(&path_var) {
+        let candidate = dir.join(command);
+        if candidate.is_file() {
+            return Some(candidate);
+        }
+
+        #[cfg(windows)]
+        {
+            let extensions = ["exe", "cmd", "bat"];
+            for ext in extensions {
+                let candidate = dir.join(format!("{}.{}", command, ext));
+                if candidate.is_file() {
+                    return Some(candidate);
+                }
+            }
+        }
+    }
+
+    None
+}
+
+/// 依序檢查命令，遇到第一個缺少的就回傳 [`OperationError::CommandNotFound`]
+pub fn require_commands(commands: &[&str]) -> Result<()> {
+    match first_missing(commands, |command| is_command_available(command).is_some()) {
+        Some(command) => Err(OperationError::CommandNotFound {
+            command: command.to_string(),
+        }),
+        None => Ok(()),
+    }
+}
+
+fn first_missing<'a>(commands: &[&'a str], available: impl Fn(&str) -> bool) -> Option<&'a str> {
+    commands.iter().copied().find(|command| !available(command))
+}
+
+/// 常用命令的安裝方式；不在清單中的命令只提示找不到
+pub fn install_hint(command: &str) -> Option<&'static str> {
+    let macos = cfg!(target_os = "macos");
+    let hint = match command {
+        "cargo" | "rustup" => "curl --proto '=https' --tlsv1.2 -sSf https://sh.rustup.rs | sh",
+        "cross" => "cargo install cross --git https://github.com/cross-rs/cross",
+        "docker" => "https://docs.docker.com/get-docker/",
+        "buildah" if macos => "brew install buildah",
+        "buildah" => "sudo apt install buildah",
+        "git" if macos => "brew install git",
+        "git" => "sudo apt install git",
+        "kubectl" if macos => "brew install kubectl",
+        "kubectl" => "https://kubernetes.io/docs/tasks/tools/",
+        "tmux" if macos => "brew install tmux",
+        "tmux" => "sudo apt install tmux",
+        _ => return None,
+    };
+    Some(hint)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_first_missing_stops_at_first_gap() {
+        let available = |command: &str| command == "cargo";
+        assert_eq!(
+            first_missing(&["cargo", "rustup", "cross"], available),
+            Some("rustup")
+        );
+        assert_eq!(first_missing(&["cargo"], available), None);
+    }
+
+    #[test]
+    fn test_missing_command_error_includes_hint() {
+        let error = require_commands(&["ops-tools-surely-missing-command"]).unwrap_err();
+        assert!(matches!(
+            &error,
+            OperationError::CommandNotFound { command } if command == "ops-tools-surely-missing-command"
+        ));
+        assert!(install_hint("rustup").is_some());
+        assert!(install_hint("ops-tools-surely-missing-command").is_none());
+    }
+}
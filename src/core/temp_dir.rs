@@ -0,0 +1,289 @@
+//! 暫存目錄
+//!
+//! 各功能的暫存目錄統一命名為 `ops-tools-<用途>-<pid>-<時間>-<序號>`，由 [`TempDir`] 在離開作用域時刪除；
+//! 異常結束而殘留的目錄會在下次啟動時由 [`sweep_stale`] 清除
+
+use super::{OperationError, Result};
+use std::env;
+use std::fs;
+use std::io::ErrorKind;
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// 暫存目錄名稱前綴
+const PREFIX: &str = "ops-tools-";
+
+/// 超過此時間未修改的暫存目錄視為前次執行殘留
+pub const STALE_AFTER: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// 名稱衝突時的重試次數
+const MAX_ATTEMPTS: u32 = 16;
+
+/// 同一程序內的序號，避免同一時間建立的目錄撞名
+static SEQUENCE: AtomicU64 = AtomicU64::new(0);
+
+/// 離開作用域時自動刪除的暫存目錄
+#[derive(Debug)]
+pub struct TempDir {
+    path: PathBuf,
+}
+
+impl TempDir {
+    /// 在系統暫存目錄下建立
+    pub fn new(purpose: &str) -> Result<Self> {
+        Self::new_in(&env::temp_dir(), purpose)
+    }
+
+    /// 在 `base` 下建立；以 `create_dir` 獨佔建立，多個程序同時執行也不會共用同一目錄
+    pub fn new_in(base: &Path, purpose: &str) -> Result<Self> {
+        fs::create_dir_all(base)
+            .map_err(|err| OperationError::io(base.display().to_string(), err))?;
+
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos();
+        let pid = std::process::id();
+        let mut attempt = 0;
+        loop {
+            let sequence = SEQUENCE.fetch_add(1, Ordering::Relaxed);
+            let path = base.join(format!("{PREFIX}{purpose}-{pid}-{timestamp}-{sequence}"));
+            match fs::create_dir(&path) {
+                Ok(()) => return Ok(Self { path }),
+                Err(err) if err.kind() == ErrorKind::AlreadyExists && attempt < MAX_ATTEMPTS => {
+                    attempt += 1;
+                }
+                Err(err) => return Err(OperationError::io(path.display().to_string(), err)),
+            }
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Deref for TempDir {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl AsRef<Path> for TempDir {
+    fn as_ref(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}
+
+/// 刪除 `base` 下超過 `max_age` 未修改、且名稱符合本工具產生格式的暫存目錄，回傳刪除數量
+///
+/// 只處理 [`TempDir`] 的 `ops-tools-<用途>-<pid>-<時間>-<序號>` 與舊版的 `ops-tools-<用途>-<毫秒>`，
+/// 使用者自己建立的 `ops-tools-*` 目錄不受影響；名稱中的 pid 仍在執行（或無法確認）時即使過期也保留
+pub fn sweep_stale(base: &Path, max_age: Duration) -> usize {
+    let Ok(entries) = fs::read_dir(base) else {
+        return 0;
+    };
+    let now = SystemTime::now();
+    entries
+        .filter_map(|entry| entry.ok())
+        .filter(
+            |entry| match parse_name(&entry.file_name().to_string_lossy()) {
+                Some(GeneratedName::Current { pid }) => !is_process_alive(pid),
+                Some(GeneratedName::Legacy) => true,
+                None => false,
+            },
+        )
+        .filter(|entry| {
+            // 不跟隨符號連結，避免刪到暫存目錄以外的內容
+            entry.file_type().is_ok_and(|kind| kind.is_dir())
+                && entry
+                    .metadata()
+                    .and_then(|meta| meta.modified())
+                    .is_ok_and(|modified| is_stale(modified, now, max_age))
+        })
+        .filter(|entry| fs::remove_dir_all(entry.path()).is_ok())
+        .count()
+}
+
+/// 本工具產生的暫存目錄名稱
+#[derive(Debug, PartialEq, Eq)]
+enum GeneratedName {
+    /// `ops-tools-<用途>-<pid>-<時間>-<序號>`
+    Current { pid: u32 },
+    /// 舊版 `ops-tools-<用途>-<毫秒>`，沒有 pid
+    Legacy,
+}
+
+/// 時間戳記至少的位數（毫秒與奈秒的 Unix 時間都遠多於此），避免把 `ops-tools-v-2` 之類的名稱當成暫存目錄
+const MIN_TIMESTAMP_DIGITS: usize = 10;
+
+fn parse_name(name: &str) -> Option<GeneratedName> {
+    let rest = name.strip_prefix(PREFIX)?;
+    let is_number = |part: &str| !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit());
+    let is_timestamp = |part: &str| is_number(part) && part.len() >= MIN_TIMESTAMP_DIGITS;
+
+    // 用途可能含 `-`，從結尾往回拆
+    let parts: Vec<&str> = rest.rsplitn(4, '-').collect();
+    if let [sequence, timestamp, pid, purpose] = parts[..]
+        && !purpose.is_empty()
+        && is_number(sequence)
+        && is_timestamp(timestamp)
+        && let Ok(pid) = pid.parse()
+    {
+        return Some(GeneratedName::Current { pid });
+    }
+
+    let (purpose, millis) = rest.rsplit_once('-')?;
+    (!purpose.is_empty() && is_timestamp(millis)).then_some(GeneratedName::Legacy)
+}
+
+/// 建立者是否仍在執行；無法確認時視為仍在執行，寧可留下目錄也不誤刪
+fn is_process_alive(pid: u32) -> bool {
+    if pid == std::process::id() {
+        return true;
+    }
+    let proc_dir = Path::new("/proc");
+    if proc_dir.join("self").exists() {
+        return proc_dir.join(pid.to_string()).exists();
+    }
+    process_listed(pid).unwrap_or(true)
+}
+
+/// 沒有 `/proc` 的系統（例如 macOS）改用 `ps` 查詢
+#[cfg(unix)]
+fn process_listed(pid: u32) -> Option<bool> {
+    let output = Command::new("ps")
+        .args(["-p", &pid.to_string()])
+        .output()
+        .ok()?;
+    Some(output.status.success())
+}
+
+#[cfg(windows)]
+fn process_listed(pid: u32) -> Option<bool> {
+    let output = Command::new("tasklist")
+        .args(["/FI", &format!("PID eq {pid}"), "/NH"])
+        .output()
+        .ok()?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    Some(
+        stdout
+            .split_whitespace()
+            .any(|field| field == pid.to_string()),
+    )
+}
+
+#[cfg(not(any(unix, windows)))]
+fn process_listed(_pid: u32) -> Option<bool> {
+    None
+}
+
+fn is_stale(modified: SystemTime, now: SystemTime, max_age: Duration) -> bool {
+    now.duration_since(modified).is_ok_and(|age| age >= max_age)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_temp_dir_removed_on_drop() {
+        let base = tempfile::tempdir().unwrap();
+        let first = TempDir::new_in(base.path(), "test").unwrap();
+        let second = TempDir::new_in(base.path(), "test").unwrap();
+        assert_ne!(first.path(), second.path());
+        assert!(
+            first
+                .file_name()
+                .unwrap()
+                .to_string_lossy()
+                .starts_with("ops-tools-test-")
+        );
+
+        let path = first.path().to_path_buf();
+        fs::write(first.join("file"), "data").unwrap();
+        drop(first);
+        assert!(!path.exists());
+        assert!(second.is_dir());
+    }
+
+    #[test]
+    fn test_sweep_stale_only_removes_old_ops_tools_dirs() {
+        let base = tempfile::tempdir().unwrap();
+        // 不存在的 pid：模擬異常結束的程序留下的目錄
+        let leftover = base.path().join(format!(
+            "ops-tools-sweep-{}-1700000000000000000-0",
+            u32::MAX
+        ));
+        let legacy = base.path().join("ops-tools-pkg-1700000000000");
+        fs::create_dir(&leftover).unwrap();
+        fs::create_dir(&legacy).unwrap();
+        fs::create_dir(base.path().join("other-tool")).unwrap();
+
+        assert_eq!(sweep_stale(base.path(), STALE_AFTER), 0);
+        assert!(leftover.is_dir());
+
+        assert_eq!(sweep_stale(base.path(), Duration::ZERO), 2);
+        assert!(!leftover.exists());
+        assert!(!legacy.exists());
+        assert!(base.path().join("other-tool").is_dir());
+    }
+
+    #[test]
+    fn test_sweep_stale_keeps_user_ops_tools_dirs() {
+        let base = tempfile::tempdir().unwrap();
+        for name in ["ops-tools-notes", "ops-tools-feature", "ops-tools-v-2"] {
+            fs::create_dir(base.path().join(name)).unwrap();
+        }
+
+        assert_eq!(sweep_stale(base.path(), Duration::ZERO), 0);
+        assert!(base.path().join("ops-tools-notes").is_dir());
+        assert!(base.path().join("ops-tools-feature").is_dir());
+        assert!(base.path().join("ops-tools-v-2").is_dir());
+    }
+
+    #[test]
+    fn test_sweep_stale_keeps_dirs_of_running_process() {
+        let base = tempfile::tempdir().unwrap();
+        let ours = TempDir::new_in(base.path(), "in-use").unwrap();
+
+        assert_eq!(sweep_stale(base.path(), Duration::ZERO), 0);
+        assert!(ours.is_dir());
+    }
+
+    #[test]
+    fn test_parse_name() {
+        assert_eq!(
+            parse_name("ops-tools-pkg-build-42-1700000000000000000-3"),
+            Some(GeneratedName::Current { pid: 42 })
+        );
+        assert_eq!(
+            parse_name("ops-tools-pkg-1700000000000"),
+            Some(GeneratedName::Legacy)
+        );
+        assert_eq!(parse_name("ops-tools-notes"), None);
+        assert_eq!(parse_name("ops-tools-v-2"), None);
+        assert_eq!(parse_name("ops-tools--1700000000000"), None);
+        assert_eq!(parse_name("other-pkg-1700000000000"), None);
+    }
+
+    #[test]
+    fn test_is_stale() {
+        let now = SystemTime::now();
+        let day = Duration::from_secs(24 * 60 * 60);
+        assert!(is_stale(now - day * 2, now, STALE_AFTER));
+        assert!(!is_stale(now - Duration::from_secs(60), now, STALE_AFTER));
+        assert!(!is_stale(now + day, now, STALE_AFTER));
+    }
+}
@@ -1,6 +1,6 @@
+use crate::core::config::config_dir;
 use crate::core::{OperationError, Result};
 use serde::{Deserialize, Serialize};
-use std::env;
 use std::fs;
 use std::path::PathBuf;
 
@@ -19,34 +19,15 @@ pub struct BuilderConfig {
     /// Recently used registries
     #[serde(default)]
     pub recent_registries: Vec<String>,
+
+    /// Build arguments (`KEY=VALUE`) used by the last build
+    #[serde(default)]
+    pub last_build_args: Vec<String>,
 }
 
 /// Get the config file path for container builder
 fn config_path() -> Option<PathBuf> {
-    if cfg!(target_os = "windows") {
-        env::var_os("APPDATA")
-            .map(PathBuf::from)
-            .map(|base| base.join("ops-tools").join("container-builder.toml"))
-    } else if cfg!(target_os = "macos") {
-        env::var_os("HOME").map(PathBuf::from).map(|base| {
-            base.join("Library")
-                .join("Application Support")
-                .join("ops-tools")
-                .join("container-builder.toml")
-        })
-    } else if let Some(config_home) = env::var_os("XDG_CONFIG_HOME") {
-        Some(
-            PathBuf::from(config_home)
-                .join("ops-tools")
-                .join("container-builder.toml"),
-        )
-    } else {
-        env::var_os("HOME").map(PathBuf::from).map(|base| {
-            base.join(".config")
-                .join("ops-tools")
-                .join("container-builder.toml")
-        })
-    }
+    config_dir().map(|dir| dir.join("container-builder.toml"))
 }
 
 /// Load container builder configuration
@@ -59,10 +40,8 @@ pub fn load_builder_config() -> Result<BuilderConfig> {
         return Ok(BuilderConfig::default());
     }
 
-    let raw = fs::read_to_string(&path).map_err(|err| OperationError::Io {
-        path: path.display().to_string(),
-        source: err,
-    })?;
+    let raw = fs::read_to_string(&path)
+        .map_err(|err| OperationError::io(path.display().to_string(), err))?;
 
     let config = toml::from_str(&raw).map_err(|err| OperationError::Config {
         key: path.display().to_string(),
@@ -82,10 +61,8 @@ pub fn save_builder_config(config: &BuilderConfig) -> Result<()> {
     };
 
     if let Some(parent) = path.parent() {
-        fs::create_dir_all(parent).map_err(|err| OperationError::Io {
-            path: parent.display().to_string(),
-            source: err,
-        })?;
+        fs::create_dir_all(parent)
+            .map_err(|err| OperationError::io(parent.display().to_string(), err))?;
     }
 
     let content = toml::to_string(config).map_err(|err| OperationError::Config {
@@ -93,10 +70,7 @@ pub fn save_builder_config(config: &BuilderConfig) -> Result<()> {
         message: err.to_string(),
     })?;
 
-    fs::write(&path, content).map_err(|err| OperationError::Io {
-        path: path.display().to_string(),
-        source: err,
-    })?;
+    fs::write(&path, content).map_err(|err| OperationError::io(path.display().to_string(), err))?;
 
     Ok(())
 }
@@ -111,6 +85,7 @@ mod tests {
         assert!(config.recent_images.is_empty());
         assert!(config.recent_tags.is_empty());
         assert!(config.recent_registries.is_empty());
+        assert!(config.last_build_args.is_empty());
     }
 
     #[test]
@@ -121,6 +96,7 @@ mod tests {
         config
             .recent_registries
             .push("docker.io/myuser".to_string());
+        config.last_build_args.push("VERSION=1.2".to_string());
 
         let serialized = toml::to_string(&config).unwrap();
         let deserialized: BuilderConfig = toml::from_str(&serialized).unwrap();
@@ -128,5 +104,6 @@ mod tests {
         assert_eq!(deserialized.recent_images, vec!["myapp"]);
         assert_eq!(deserialized.recent_tags, vec!["latest"]);
         assert_eq!(deserialized.recent_registries, vec!["docker.io/myuser"]);
+        assert_eq!(deserialized.last_build_args, vec!["VERSION=1.2"]);
     }
 }
@@ -1,6 +1,6 @@
-use crate::core::Result;
+use crate::core::{OperationError, Result};
 use crate::ui::Console;
-use std::io::{BufRead, BufReader};
+use std::io::{BufRead, BufReader, Write};
 use std::process::{Command, Stdio};
 use std::thread;
 
@@ -19,6 +19,14 @@ pub trait BuildEngine {
 
     /// Push a container image to registry
     fn push(&self, context: &BuildContext) -> Result<BuildResult>;
+
+    /// Log in to a registry host; the password is passed on stdin
+    fn login(&self, registry: &str, username: &str, password: &str) -> Result<()>;
+
+    /// Whether `build` already pushes the image, making a separate push step unnecessary
+    fn pushes_on_build(&self) -> bool {
+        false
+    }
 }
 
 /// Docker build engine using docker buildx
@@ -40,39 +48,7 @@ impl BuildEngine for DockerEngine {
     }
 
     fn build(&self, context: &BuildContext) -> Result<BuildResult> {
-        let platforms: Vec<String> = context
-            .architecture
-            .iter()
-            .map(|a| a.platform().to_string())
-            .collect();
-
-        let mut args = vec![
-            "buildx".to_string(),
-            "build".to_string(),
-            "--platform".to_string(),
-            platforms.join(","),
-            "-f".to_string(),
-            context.dockerfile.display().to_string(),
-            "-t".to_string(),
-            context.local_image_ref(),
-        ];
-
-        // For Jetson Nano, add specific build args if needed
-        if context.architecture.iter().any(|a| a.is_jetson()) {
-            args.push("--build-arg".to_string());
-            args.push("TARGETPLATFORM=linux/arm64".to_string());
-        }
-
-        // Load the image to local docker (for single platform builds)
-        args.push("--load".to_string());
-
-        // Do not remove intermediate containers
-        args.push("--rm=false".to_string());
-
-        // Context directory
-        args.push(context.context_dir.display().to_string());
-
-        execute_command("docker", &args)
+        execute_command("docker", &docker_build_args(context))
     }
 
     fn push(&self, context: &BuildContext) -> Result<BuildResult> {
@@ -90,6 +66,49 @@ impl BuildEngine for DockerEngine {
         // Push
         execute_command("docker", &["push", &full_ref])
     }
+
+    fn login(&self, registry: &str, username: &str, password: &str) -> Result<()> {
+        execute_login("docker", registry, username, password)
+    }
+}
+
+/// Docker buildx engine producing a multi-platform manifest pushed straight to the registry
+pub struct BuildxEngine;
+
+impl BuildEngine for BuildxEngine {
+    fn name(&self) -> &'static str {
+        "Docker Buildx"
+    }
+
+    fn is_available(&self) -> bool {
+        Command::new("docker")
+            .args(["buildx", "version"])
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+            .map(|s| s.success())
+            .unwrap_or(false)
+    }
+
+    fn build(&self, context: &BuildContext) -> Result<BuildResult> {
+        execute_command("docker", &buildx_push_args(context))
+    }
+
+    fn push(&self, _context: &BuildContext) -> Result<BuildResult> {
+        // The manifest list is pushed by `build`
+        Ok(BuildResult {
+            success: true,
+            exit_code: Some(0),
+        })
+    }
+
+    fn login(&self, registry: &str, username: &str, password: &str) -> Result<()> {
+        execute_login("docker", registry, username, password)
+    }
+
+    fn pushes_on_build(&self) -> bool {
+        true
+    }
 }
 
 /// Buildah build engine
@@ -111,30 +130,7 @@ impl BuildEngine for BuildahEngine {
     }
 
     fn build(&self, context: &BuildContext) -> Result<BuildResult> {
-        let platforms: Vec<String> = context
-            .architecture
-            .iter()
-            .map(|a| a.platform().to_string())
-            .collect();
-
-        let mut args = vec![
-            "build".to_string(),
-            "--platform".to_string(),
-            platforms.join(","),
-            "--layers".to_string(),
-            "-f".to_string(),
-            context.dockerfile.display().to_string(),
-            "-t".to_string(),
-            context.local_image_ref(),
-        ];
-
-        // Do not remove intermediate containers
-        args.push("--rm=false".to_string());
-
-        // Context directory
-        args.push(context.context_dir.display().to_string());
-
-        execute_command("buildah", &args)
+        execute_command("buildah", &buildah_build_args(context))
     }
 
     fn push(&self, context: &BuildContext) -> Result<BuildResult> {
@@ -152,6 +148,134 @@ impl BuildEngine for BuildahEngine {
         // Push using buildah
         execute_command("buildah", &["push", &full_ref])
     }
+
+    fn login(&self, registry: &str, username: &str, password: &str) -> Result<()> {
+        execute_login("buildah", registry, username, password)
+    }
+}
+
+/// Arguments for `docker buildx build` loading the image into the local daemon
+fn docker_build_args(context: &BuildContext) -> Vec<String> {
+    buildx_args(context, context.local_image_ref(), "--load")
+}
+
+/// Arguments for `docker buildx build` pushing a multi-platform manifest
+fn buildx_push_args(context: &BuildContext) -> Vec<String> {
+    buildx_args(context, context.full_image_ref(), "--push")
+}
+
+fn buildx_args(context: &BuildContext, image_ref: String, output: &str) -> Vec<String> {
+    let mut args = vec![
+        "buildx".to_string(),
+        "build".to_string(),
+        "--platform".to_string(),
+        context.platforms().join(","),
+        "-f".to_string(),
+        context.dockerfile.display().to_string(),
+        "-t".to_string(),
+        image_ref,
+    ];
+
+    // For Jetson Nano, add specific build args if needed
+    if context.architecture.iter().any(|a| a.is_jetson()) {
+        args.push("--build-arg".to_string());
+        args.push("TARGETPLATFORM=linux/arm64".to_string());
+    }
+
+    push_key_value_flags(&mut args, "--build-arg", &context.build_args);
+    push_key_value_flags(&mut args, "--label", &context.labels);
+
+    // Load into the local daemon or push to the registry
+    args.push(output.to_string());
+
+    // Do not remove intermediate containers
+    args.push("--rm=false".to_string());
+
+    // Context directory
+    args.push(context.context_dir.display().to_string());
+
+    args
+}
+
+/// Arguments for `buildah build`
+fn buildah_build_args(context: &BuildContext) -> Vec<String> {
+    let mut args = vec![
+        "build".to_string(),
+        "--platform".to_string(),
+        context.platforms().join(","),
+        "--layers".to_string(),
+        "-f".to_string(),
+        context.dockerfile.display().to_string(),
+        "-t".to_string(),
+        context.local_image_ref(),
+    ];
+
+    push_key_value_flags(&mut args, "--build-arg", &context.build_args);
+    push_key_value_flags(&mut args, "--label", &context.labels);
+
+    // Do not remove intermediate containers
+    args.push("--rm=false".to_string());
+
+    // Context directory
+    args.push(context.context_dir.display().to_string());
+
+    args
+}
+
+/// Append `flag KEY=VALUE` for every pair
+fn push_key_value_flags(args: &mut Vec<String>, flag: &str, pairs: &[(String, String)]) {
+    for (key, value) in pairs {
+        args.push(flag.to_string());
+        args.push(format!("{}={}", key, value));
+    }
+}
+
+/// Run `<program> login` with the password on stdin so it never shows up in the process list
+fn execute_login(program: &str, registry: &str, username: &str, password: &str) -> Result<()> {
+    let mut command = Command::new(program);
+    command
+        .args([
+            "login",
+            "--username",
+            username,
+            "--password-stdin",
+            registry,
+        ])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    Console::new().command(&command);
+    let login_error = |message: String| OperationError::Command {
+        command: format!("{} login {}", program, registry),
+        message,
+    };
+
+    let mut child = command
+        .spawn()
+        .map_err(|err| login_error(err.to_string()))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin
+            .write_all(password.as_bytes())
+            .map_err(|err| login_error(err.to_string()))?;
+    }
+    let output = child
+        .wait_with_output()
+        .map_err(|err| login_error(err.to_string()))?;
+
+    if output.status.success() {
+        Ok(())
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        Err(login_error(
+            stderr
+                .lines()
+                .rev()
+                .find(|line| !line.trim().is_empty())
+                .unwrap_or_default()
+                .trim()
+                .to_string(),
+        ))
+    }
 }
 
 /// Execute a command and stream output in real-time
@@ -159,10 +283,13 @@ fn execute_command<S: AsRef<str>>(program: &str, args: &[S]) -> Result<BuildResu
     let args_str: Vec<&str> = args.iter().map(|s| s.as_ref()).collect();
     let console = Console::new();
 
-    let mut child = Command::new(program)
+    let mut command = Command::new(program);
+    command
         .args(&args_str)
         .stdout(Stdio::piped())
-        .stderr(Stdio::piped())
+        .stderr(Stdio::piped());
+    console.command(&command);
+    let mut child = command
         .spawn()
         .map_err(|err| crate::core::OperationError::Command {
             command: program.to_string(),
@@ -205,6 +332,32 @@ fn execute_command<S: AsRef<str>>(program: &str, args: &[S]) -> Result<BuildResu
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::features::container_builder::types::Architecture;
+    use std::path::PathBuf;
+
+    fn context_with_flags() -> BuildContext {
+        BuildContext {
+            dockerfile: PathBuf::from("Dockerfile"),
+            context_dir: PathBuf::from("."),
+            image_name: "myapp".to_string(),
+            tag: "v1.0".to_string(),
+            architecture: vec![Architecture::Amd64],
+            push: false,
+            registry: None,
+            build_args: vec![("VERSION".to_string(), "1.2".to_string())],
+            labels: vec![(
+                "org.opencontainers.image.source".to_string(),
+                "https://example.com/repo".to_string(),
+            )],
+        }
+    }
+
+    fn flag_values(args: &[String], flag: &str) -> Vec<String> {
+        args.windows(2)
+            .filter(|pair| pair[0] == flag)
+            .map(|pair| pair[1].clone())
+            .collect()
+    }
 
     #[test]
     fn test_docker_engine_name() {
@@ -217,4 +370,46 @@ mod tests {
         let engine = BuildahEngine;
         assert_eq!(engine.name(), "Buildah");
     }
+
+    #[test]
+    fn test_docker_build_args_include_build_args_and_labels() {
+        let args = docker_build_args(&context_with_flags());
+        assert_eq!(flag_values(&args, "--build-arg"), ["VERSION=1.2"]);
+        assert_eq!(
+            flag_values(&args, "--label"),
+            ["org.opencontainers.image.source=https://example.com/repo"]
+        );
+        assert_eq!(args.last().map(String::as_str), Some("."));
+    }
+
+    #[test]
+    fn test_buildx_push_args_target_registry_for_all_platforms() {
+        let context = BuildContext {
+            architecture: vec![Architecture::Amd64, Architecture::Arm64],
+            push: true,
+            registry: Some("ghcr.io/acme".to_string()),
+            ..context_with_flags()
+        };
+        let args = buildx_push_args(&context);
+        assert_eq!(
+            flag_values(&args, "--platform"),
+            ["linux/amd64,linux/arm64"]
+        );
+        assert_eq!(flag_values(&args, "-t"), ["ghcr.io/acme/myapp:v1.0"]);
+        assert!(args.contains(&"--push".to_string()));
+        assert!(!args.contains(&"--load".to_string()));
+        assert!(BuildxEngine.pushes_on_build());
+        assert!(!DockerEngine.pushes_on_build());
+    }
+
+    #[test]
+    fn test_buildah_build_args_include_build_args_and_labels() {
+        let args = buildah_build_args(&context_with_flags());
+        assert_eq!(flag_values(&args, "--build-arg"), ["VERSION=1.2"]);
+        assert_eq!(
+            flag_values(&args, "--label"),
+            ["org.opencontainers.image.source=https://example.com/repo"]
+        );
+        assert_eq!(args.last().map(String::as_str), Some("."));
+    }
 }
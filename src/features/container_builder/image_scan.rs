@@ -0,0 +1,206 @@
+use crate::core::{OperationError, Result};
+use serde::Deserialize;
+use std::process::{Command, Stdio};
+
+/// Vulnerability scanner used to check a freshly built image
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageScanner {
+    Trivy,
+    Grype,
+}
+
+impl ImageScanner {
+    pub fn name(&self) -> &'static str {
+        match self {
+            ImageScanner::Trivy => "trivy",
+            ImageScanner::Grype => "grype",
+        }
+    }
+
+    /// First scanner found on PATH, preferring trivy
+    pub fn detect() -> Option<ImageScanner> {
+        [ImageScanner::Trivy, ImageScanner::Grype]
+            .into_iter()
+            .find(|scanner| scanner.is_available())
+    }
+
+    fn is_available(&self) -> bool {
+        Command::new(self.name())
+            .arg("--version")
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+            .map(|s| s.success())
+            .unwrap_or(false)
+    }
+
+    fn args(&self, image_ref: &str) -> Vec<String> {
+        let args: &[&str] = match self {
+            ImageScanner::Trivy => &["image", "--quiet", "--format", "json", image_ref],
+            ImageScanner::Grype => &[image_ref, "--quiet", "--output", "json"],
+        };
+        args.iter().map(|s| s.to_string()).collect()
+    }
+
+    fn parse(&self, output: &str) -> std::result::Result<SeverityCounts, serde_json::Error> {
+        match self {
+            ImageScanner::Trivy => parse_trivy(output),
+            ImageScanner::Grype => parse_grype(output),
+        }
+    }
+}
+
+/// Number of findings per severity
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SeverityCounts {
+    pub critical: usize,
+    pub high: usize,
+    pub medium: usize,
+    pub low: usize,
+    pub other: usize,
+}
+
+impl SeverityCounts {
+    fn add(&mut self, severity: &str) {
+        match severity.to_ascii_lowercase().as_str() {
+            "critical" => self.critical += 1,
+            "high" => self.high += 1,
+            "medium" => self.medium += 1,
+            "low" => self.low += 1,
+            _ => self.other += 1,
+        }
+    }
+
+    pub fn total(&self) -> usize {
+        self.critical + self.high + self.medium + self.low + self.other
+    }
+}
+
+/// Scan a local image and count its findings by severity
+pub fn scan_image(scanner: ImageScanner, image_ref: &str) -> Result<SeverityCounts> {
+    let command_error = |message: String| OperationError::Command {
+        command: scanner.name().to_string(),
+        message,
+    };
+
+    let output = Command::new(scanner.name())
+        .args(scanner.args(image_ref))
+        .stdin(Stdio::null())
+        .output()
+        .map_err(|err| command_error(err.to_string()))?;
+
+    if !output.status.success() {
+        return Err(command_error(
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ));
+    }
+
+    scanner
+        .parse(&String::from_utf8_lossy(&output.stdout))
+        .map_err(|err| command_error(err.to_string()))
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct TrivyReport {
+    #[serde(default)]
+    results: Vec<TrivyResult>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct TrivyResult {
+    #[serde(default)]
+    vulnerabilities: Option<Vec<TrivyVulnerability>>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct TrivyVulnerability {
+    severity: String,
+}
+
+#[derive(Deserialize)]
+struct GrypeReport {
+    #[serde(default)]
+    matches: Vec<GrypeMatch>,
+}
+
+#[derive(Deserialize)]
+struct GrypeMatch {
+    vulnerability: GrypeVulnerability,
+}
+
+#[derive(Deserialize)]
+struct GrypeVulnerability {
+    severity: String,
+}
+
+fn parse_trivy(output: &str) -> std::result::Result<SeverityCounts, serde_json::Error> {
+    let report: TrivyReport = serde_json::from_str(output)?;
+    let mut counts = SeverityCounts::default();
+    report
+        .results
+        .iter()
+        .flat_map(|result| result.vulnerabilities.iter().flatten())
+        .for_each(|vuln| counts.add(&vuln.severity));
+    Ok(counts)
+}
+
+fn parse_grype(output: &str) -> std::result::Result<SeverityCounts, serde_json::Error> {
+    let report: GrypeReport = serde_json::from_str(output)?;
+    let mut counts = SeverityCounts::default();
+    report
+        .matches
+        .iter()
+        .for_each(|m| counts.add(&m.vulnerability.severity));
+    Ok(counts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_trivy_counts_severities() {
+        let output = r#"{
+            "Results": [
+                {"Target": "debian", "Vulnerabilities": [
+                    {"VulnerabilityID": "CVE-1", "Severity": "CRITICAL"},
+                    {"VulnerabilityID": "CVE-2", "Severity": "HIGH"},
+                    {"VulnerabilityID": "CVE-3", "Severity": "LOW"}
+                ]},
+                {"Target": "app", "Vulnerabilities": null},
+                {"Target": "go.mod"}
+            ]
+        }"#;
+        let counts = parse_trivy(output).unwrap();
+        assert_eq!(counts.critical, 1);
+        assert_eq!(counts.high, 1);
+        assert_eq!(counts.low, 1);
+        assert_eq!(counts.total(), 3);
+    }
+
+    #[test]
+    fn test_parse_grype_counts_severities() {
+        let output = r#"{
+            "matches": [
+                {"vulnerability": {"id": "CVE-1", "severity": "Critical"}},
+                {"vulnerability": {"id": "CVE-2", "severity": "Medium"}},
+                {"vulnerability": {"id": "CVE-3", "severity": "Negligible"}}
+            ]
+        }"#;
+        let counts = parse_grype(output).unwrap();
+        assert_eq!(counts.critical, 1);
+        assert_eq!(counts.medium, 1);
+        assert_eq!(counts.other, 1);
+        assert_eq!(counts.total(), 3);
+    }
+
+    #[test]
+    fn test_parse_empty_reports() {
+        assert_eq!(parse_trivy("{}").unwrap().total(), 0);
+        assert_eq!(parse_grype("{}").unwrap().total(), 0);
+        assert!(parse_trivy("not json").is_err());
+    }
+}
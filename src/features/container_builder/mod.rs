@@ -1,18 +1,37 @@
 mod config;
 mod engines;
+mod image_scan;
 mod scanner;
+mod tag_template;
 mod types;
 
+use crate::core::requirements::require_commands;
+use crate::core::{OperationError, Result};
 use crate::i18n::{self, keys};
 use crate::ui::{Console, Prompts};
 use config::{BuilderConfig, load_builder_config, save_builder_config};
-use engines::{BuildEngine, BuildahEngine, DockerEngine};
-use scanner::scan_dockerfiles;
-use std::path::PathBuf;
-use types::{Architecture, BuildContext, EngineType};
+use engines::{BuildEngine, BuildahEngine, BuildxEngine, DockerEngine};
+use image_scan::{ImageScanner, scan_image};
+use scanner::{display_label, scan_dockerfiles};
+use std::path::{Path, PathBuf};
+use types::{Architecture, BuildContext, EngineType, parse_key_value, registry_host};
+
+/// Options supplied up front (e.g. from the command line)
+#[derive(Clone, Debug, Default)]
+pub struct ContainerBuildOptions {
+    /// Build context directory; asked for (defaulting to the Dockerfile's directory) when None
+    pub context: Option<PathBuf>,
+    /// Push even when the image scan reports critical vulnerabilities; never assumed by `--yes`
+    pub push_vulnerable: bool,
+}
 
 /// Execute Container Builder
 pub fn run() {
+    run_with(ContainerBuildOptions::default());
+}
+
+/// Execute Container Builder with options supplied up front
+pub fn run_with(options: ContainerBuildOptions) {
     let console = Console::new();
     let prompts = Prompts::new();
 
@@ -44,9 +63,15 @@ pub fn run() {
     let engine: Box<dyn BuildEngine> = match engine_type {
         EngineType::Docker => Box::new(DockerEngine),
         EngineType::Buildah => Box::new(BuildahEngine),
+        EngineType::Buildx => Box::new(BuildxEngine),
     };
 
-    // Verify engine is available
+    // Verify engine is available: the binary first (with an install hint), then the engine
+    // itself (e.g. the Buildx plugin)
+    if let Err(err) = require_commands(&[engine_type.binary()]) {
+        console.error(&err.to_string());
+        return;
+    }
     if !engine.is_available() {
         console.error(&crate::tr!(
             keys::CONTAINER_BUILDER_ENGINE_NOT_FOUND,
@@ -64,12 +89,14 @@ pub fn run() {
     console.info(i18n::t(keys::CONTAINER_BUILDER_SCANNING_DOCKERFILES));
     let dockerfiles = scan_dockerfiles(&current_dir);
 
-    if dockerfiles.is_empty() {
-        console.error(i18n::t(keys::CONTAINER_BUILDER_NO_DOCKERFILE));
-        return;
-    }
+    let selection = if dockerfiles.is_empty() {
+        console.warning(i18n::t(keys::CONTAINER_BUILDER_NO_DOCKERFILE));
+        input_dockerfile_path(&console, &current_dir)
+    } else {
+        select_dockerfile(&prompts, &console, &current_dir, &dockerfiles)
+    };
 
-    let dockerfile = match select_dockerfile(&prompts, &console, &dockerfiles) {
+    let dockerfile = match selection {
         Some(path) => path,
         None => {
             console.warning(i18n::t(keys::CONTAINER_BUILDER_CANCELLED));
@@ -82,6 +109,27 @@ pub fn run() {
         path = dockerfile.display()
     ));
 
+    // Build context: defaults to the Dockerfile's directory (monorepos often use the repo root)
+    let context_dir = match options.context {
+        Some(context) => match validate_context(&current_dir.join(context), &dockerfile) {
+            Ok(dir) => dir,
+            Err(err) => {
+                console.error(&err.to_string());
+                return;
+            }
+        },
+        None => {
+            let default = dockerfile.parent().unwrap_or(&current_dir);
+            match input_context_dir(&console, &current_dir, default, &dockerfile) {
+                Some(dir) => dir,
+                None => {
+                    console.warning(i18n::t(keys::CONTAINER_BUILDER_CANCELLED));
+                    return;
+                }
+            }
+        }
+    };
+
     // Step 3: Select architecture
     let architectures = select_architecture(&prompts, &console);
     if architectures.is_empty() {
@@ -107,8 +155,55 @@ pub fn run() {
         }
     };
 
-    // Step 5: Ask about push
-    let push_config = ask_push_config(&prompts, &console, &mut builder_config);
+    // Expand `{git_sha}` / `{git_branch}` / `{date}`; the template itself is what gets remembered
+    let resolved = tag_template::resolve(&tag, &context_dir);
+    for placeholder in &resolved.unresolved {
+        console.warning(&crate::tr!(
+            keys::CONTAINER_BUILDER_TAG_UNRESOLVED,
+            placeholder = format!("{{{placeholder}}}")
+        ));
+    }
+    if resolved.tag != tag {
+        console.info(&crate::tr!(
+            keys::CONTAINER_BUILDER_TAG_RESOLVED,
+            template = &tag,
+            tag = &resolved.tag
+        ));
+    }
+    let tag = resolved.tag;
+
+    // Step 5: Build arguments and labels
+    let Some(build_args) = input_build_args(&prompts, &console, &mut builder_config) else {
+        console.warning(i18n::t(keys::CONTAINER_BUILDER_CANCELLED));
+        return;
+    };
+    let Some(labels) = input_key_values(&console, i18n::t(keys::CONTAINER_BUILDER_INPUT_LABEL))
+    else {
+        console.warning(i18n::t(keys::CONTAINER_BUILDER_CANCELLED));
+        return;
+    };
+
+    // Step 6: Ask about push (multi-arch manifests can only be pushed)
+    let push_config = if engine.pushes_on_build() {
+        console.info(i18n::t(keys::CONTAINER_BUILDER_BUILDX_PUSH_REQUIRED));
+        match input_registry(&prompts, &mut builder_config) {
+            Some(registry) => Some(registry),
+            None => {
+                console.warning(i18n::t(keys::CONTAINER_BUILDER_CANCELLED));
+                return;
+            }
+        }
+    } else {
+        ask_push_config(&prompts, &console, &mut builder_config)
+    };
+
+    // Optional registry login before anything is pushed
+    if let Some(registry) = &push_config
+        && !login_if_requested(&prompts, &console, engine.as_ref(), registry)
+    {
+        console.warning(i18n::t(keys::CONTAINER_BUILDER_CANCELLED));
+        return;
+    }
 
     // Save config for future use
     if let Err(err) = save_builder_config(&builder_config) {
@@ -116,7 +211,6 @@ pub fn run() {
     }
 
     // Build context
-    let context_dir = dockerfile.parent().unwrap_or(&current_dir).to_path_buf();
     let build_context = BuildContext {
         dockerfile: dockerfile.clone(),
         context_dir,
@@ -125,6 +219,8 @@ pub fn run() {
         architecture: architectures.clone(),
         push: push_config.is_some(),
         registry: push_config.clone(),
+        build_args,
+        labels,
     };
 
     // Confirm build
@@ -132,8 +228,16 @@ pub fn run() {
     console.info(i18n::t(keys::CONTAINER_BUILDER_BUILD_SUMMARY));
     console.list_item("Engine:", engine.name());
     console.list_item("Dockerfile:", &dockerfile.display().to_string());
+    console.list_item("Context:", &build_context.context_dir.display().to_string());
     console.list_item("Architectures:", &arch_names.join(", "));
+    console.list_item("Platforms:", &build_context.platforms().join(", "));
     console.list_item("Image:", &format!("{}:{}", image_name, tag));
+    if !build_context.build_args.is_empty() {
+        console.list_item("Build args:", &format_pairs(&build_context.build_args));
+    }
+    if !build_context.labels.is_empty() {
+        console.list_item("Labels:", &format_pairs(&build_context.labels));
+    }
     if let Some(ref registry) = push_config {
         console.list_item("Push to:", registry);
     }
@@ -153,8 +257,21 @@ pub fn run() {
             if result.success {
                 console.success(i18n::t(keys::CONTAINER_BUILDER_BUILD_SUCCESS));
 
+                // Optional vulnerability scan of the local image before pushing
+                let push_allowed = engine.pushes_on_build()
+                    || scan_built_image(
+                        &prompts,
+                        &console,
+                        &build_context,
+                        options.push_vulnerable,
+                    );
+
                 // Push if requested
-                if build_context.push {
+                if build_context.push && engine.pushes_on_build() {
+                    console.success(i18n::t(keys::CONTAINER_BUILDER_PUSH_SUCCESS));
+                } else if build_context.push && !push_allowed {
+                    console.warning(i18n::t(keys::CONTAINER_BUILDER_PUSH_ABORTED));
+                } else if build_context.push {
                     console.info(i18n::t(keys::CONTAINER_BUILDER_PUSHING));
                     match engine.push(&build_context) {
                         Ok(push_result) => {
@@ -185,30 +302,111 @@ pub fn run() {
     }
 }
 
+/// Offer a trivy/grype scan of the built image; returns false when the user
+/// declines to push an image with critical findings
+fn scan_built_image(
+    prompts: &Prompts,
+    console: &Console,
+    context: &BuildContext,
+    push_vulnerable: bool,
+) -> bool {
+    let Some(scanner) = ImageScanner::detect() else {
+        return true;
+    };
+
+    let image_ref = context.local_image_ref();
+    if !prompts.confirm_with_options(
+        &crate::tr!(
+            keys::CONTAINER_BUILDER_ASK_IMAGE_SCAN,
+            image = image_ref,
+            scanner = scanner.name()
+        ),
+        false,
+    ) {
+        return true;
+    }
+
+    console.info(&crate::tr!(
+        keys::CONTAINER_BUILDER_IMAGE_SCANNING,
+        image = image_ref,
+        scanner = scanner.name()
+    ));
+
+    let counts = match scan_image(scanner, &image_ref) {
+        Ok(counts) => counts,
+        Err(err) => {
+            console.warning(&crate::tr!(
+                keys::CONTAINER_BUILDER_IMAGE_SCAN_FAILED,
+                error = err
+            ));
+            return true;
+        }
+    };
+
+    if counts.total() == 0 {
+        console.success(i18n::t(keys::CONTAINER_BUILDER_IMAGE_SCAN_CLEAN));
+        return true;
+    }
+
+    console.warning(&crate::tr!(
+        keys::CONTAINER_BUILDER_IMAGE_SCAN_FINDINGS,
+        total = counts.total(),
+        critical = counts.critical,
+        high = counts.high,
+        medium = counts.medium,
+        low = counts.low
+    ));
+
+    if counts.critical == 0 || !context.push || push_vulnerable {
+        return true;
+    }
+
+    // Pushing known-critical images is a safety override: `--yes` answers No here, only
+    // `--push-vulnerable` skips the question
+
+    prompts.confirm_with_options(
+        &crate::tr!(
+            keys::CONTAINER_BUILDER_IMAGE_SCAN_PUSH_ANYWAY,
+            count = counts.critical
+        ),
+        false,
+    )
+}
+
 fn select_engine(prompts: &Prompts, _console: &Console) -> Option<EngineType> {
-    let options = [
-        format!(
-            "Docker — {}",
-            i18n::t(keys::CONTAINER_BUILDER_ENGINE_DOCKER_DESC)
+    let mut engines = vec![
+        (
+            EngineType::Docker,
+            i18n::t(keys::CONTAINER_BUILDER_ENGINE_DOCKER_DESC),
         ),
-        format!(
-            "Buildah — {}",
-            i18n::t(keys::CONTAINER_BUILDER_ENGINE_BUILDAH_DESC)
+        (
+            EngineType::Buildah,
+            i18n::t(keys::CONTAINER_BUILDER_ENGINE_BUILDAH_DESC),
         ),
     ];
+    // Only offer buildx when the plugin is installed
+    if BuildxEngine.is_available() {
+        engines.push((
+            EngineType::Buildx,
+            i18n::t(keys::CONTAINER_BUILDER_ENGINE_BUILDX_DESC),
+        ));
+    }
+
+    let options: Vec<String> = engines
+        .iter()
+        .map(|(engine, desc)| format!("{} — {}", engine.name(), desc))
+        .collect();
     let option_refs: Vec<&str> = options.iter().map(|s| s.as_str()).collect();
 
     prompts
         .select(i18n::t(keys::CONTAINER_BUILDER_SELECT_ENGINE), &option_refs)
-        .map(|idx| match idx {
-            0 => EngineType::Docker,
-            _ => EngineType::Buildah,
-        })
+        .map(|idx| engines[idx].0)
 }
 
 fn select_dockerfile(
     prompts: &Prompts,
     console: &Console,
+    root: &Path,
     dockerfiles: &[PathBuf],
 ) -> Option<PathBuf> {
     console.info(&crate::tr!(
@@ -216,18 +414,103 @@ fn select_dockerfile(
         count = dockerfiles.len()
     ));
 
-    let options: Vec<String> = dockerfiles
-        .iter()
-        .map(|p| p.display().to_string())
-        .collect();
+    let mut options: Vec<String> = dockerfiles.iter().map(|p| display_label(root, p)).collect();
+    options.push(i18n::t(keys::CONTAINER_BUILDER_ENTER_PATH_OPTION).to_string());
     let option_refs: Vec<&str> = options.iter().map(|s| s.as_str()).collect();
 
-    prompts
-        .select(
-            i18n::t(keys::CONTAINER_BUILDER_SELECT_DOCKERFILE),
-            &option_refs,
-        )
-        .map(|idx| dockerfiles[idx].clone())
+    let idx = prompts.select(
+        i18n::t(keys::CONTAINER_BUILDER_SELECT_DOCKERFILE),
+        &option_refs,
+    )?;
+
+    match dockerfiles.get(idx) {
+        Some(path) => Some(path.clone()),
+        None => input_dockerfile_path(console, root),
+    }
+}
+
+/// Ask for a build file the scanner missed; relative paths resolve against `root`
+fn input_dockerfile_path(console: &Console, root: &Path) -> Option<PathBuf> {
+    use dialoguer::{Input, theme::ColorfulTheme};
+
+    loop {
+        let input: String = Input::with_theme(&ColorfulTheme::default())
+            .with_prompt(i18n::t(keys::CONTAINER_BUILDER_INPUT_DOCKERFILE_PATH))
+            .allow_empty(true)
+            .interact_text()
+            .ok()?;
+        let input = input.trim();
+        if input.is_empty() {
+            return None;
+        }
+
+        let path = root.join(input);
+        if path.is_file() {
+            return Some(path);
+        }
+
+        console.warning(&crate::tr!(
+            keys::CONTAINER_BUILDER_PATH_NOT_FILE,
+            path = path.display()
+        ));
+    }
+}
+
+/// Ask for the build context directory; an empty answer keeps `default`.
+/// Relative paths resolve against `root`.
+fn input_context_dir(
+    console: &Console,
+    root: &Path,
+    default: &Path,
+    dockerfile: &Path,
+) -> Option<PathBuf> {
+    use dialoguer::{Input, theme::ColorfulTheme};
+
+    let theme = ColorfulTheme::default();
+    let default_display = default
+        .strip_prefix(root)
+        .ok()
+        .filter(|relative| !relative.as_os_str().is_empty())
+        .unwrap_or(default)
+        .display()
+        .to_string();
+    loop {
+        let input: String = Input::with_theme(&theme)
+            .with_prompt(i18n::t(keys::CONTAINER_BUILDER_INPUT_CONTEXT))
+            .default(default_display.clone())
+            .interact_text()
+            .ok()?;
+
+        match validate_context(&root.join(input.trim()), dockerfile) {
+            Ok(dir) => return Some(dir),
+            Err(err) => console.warning(&err.to_string()),
+        }
+    }
+}
+
+/// The context must be an existing directory that contains the Dockerfile
+fn validate_context(context: &Path, dockerfile: &Path) -> Result<PathBuf> {
+    let context = match context.canonicalize() {
+        Ok(dir) if dir.is_dir() => dir,
+        _ => {
+            return Err(OperationError::Validation(crate::tr!(
+                keys::CONTAINER_BUILDER_CONTEXT_NOT_DIR,
+                path = context.display()
+            )));
+        }
+    };
+
+    let reachable = dockerfile
+        .canonicalize()
+        .is_ok_and(|file| file.starts_with(&context));
+    if !reachable {
+        return Err(OperationError::Validation(crate::tr!(
+            keys::CONTAINER_BUILDER_CONTEXT_OUTSIDE,
+            dockerfile = dockerfile.display(),
+            path = context.display()
+        )));
+    }
+    Ok(context)
 }
 
 fn select_architecture(prompts: &Prompts, _console: &Console) -> Vec<Architecture> {
@@ -243,11 +526,13 @@ fn select_architecture(prompts: &Prompts, _console: &Console) -> Vec<Architectur
         .map(|arch| *arch == Architecture::Amd64)
         .collect();
 
-    let selections = prompts.multi_select(
-        i18n::t(keys::CONTAINER_BUILDER_SELECT_ARCH),
-        &options,
-        &defaults,
-    );
+    let selections = prompts
+        .multi_select(
+            i18n::t(keys::CONTAINER_BUILDER_SELECT_ARCH),
+            &options,
+            &defaults,
+        )
+        .unwrap_or_default();
 
     selections
         .iter()
@@ -333,17 +618,144 @@ fn input_image_info(
     Some((image_name, tag))
 }
 
+/// Offer the previous build args for reuse, then collect additional ones
+fn input_build_args(
+    prompts: &Prompts,
+    console: &Console,
+    config: &mut BuilderConfig,
+) -> Option<Vec<(String, String)>> {
+    let mut build_args = Vec::new();
+
+    if !config.last_build_args.is_empty() {
+        let defaults = vec![true; config.last_build_args.len()];
+        let selections = prompts
+            .multi_select(
+                i18n::t(keys::CONTAINER_BUILDER_SELECT_BUILD_ARGS),
+                &config.last_build_args,
+                &defaults,
+            )
+            .unwrap_or_default();
+        build_args.extend(
+            selections
+                .iter()
+                .filter_map(|&i| parse_key_value(&config.last_build_args[i])),
+        );
+    }
+
+    build_args.extend(input_key_values(
+        console,
+        i18n::t(keys::CONTAINER_BUILDER_INPUT_BUILD_ARG),
+    )?);
+
+    // Remember build args
+    config.last_build_args = build_args
+        .iter()
+        .map(|(key, value)| format!("{}={}", key, value))
+        .collect();
+
+    Some(build_args)
+}
+
+/// Repeatedly prompt for `KEY=VALUE` pairs until an empty line is entered
+fn input_key_values(console: &Console, prompt: &str) -> Option<Vec<(String, String)>> {
+    use dialoguer::{Input, theme::ColorfulTheme};
+
+    let mut pairs = Vec::new();
+    loop {
+        let input: String = Input::with_theme(&ColorfulTheme::default())
+            .with_prompt(prompt)
+            .allow_empty(true)
+            .interact_text()
+            .ok()?;
+        let input = input.trim();
+        if input.is_empty() {
+            return Some(pairs);
+        }
+
+        match parse_key_value(input) {
+            Some(pair) => pairs.push(pair),
+            None => console.warning(&crate::tr!(
+                keys::CONTAINER_BUILDER_INVALID_KEY_VALUE,
+                input = input
+            )),
+        }
+    }
+}
+
+fn format_pairs(pairs: &[(String, String)]) -> String {
+    pairs
+        .iter()
+        .map(|(key, value)| format!("{}={}", key, value))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
 fn ask_push_config(
     prompts: &Prompts,
     _console: &Console,
     config: &mut BuilderConfig,
 ) -> Option<String> {
-    use dialoguer::{Input, theme::ColorfulTheme};
-
-    if !prompts.confirm(i18n::t(keys::CONTAINER_BUILDER_ASK_PUSH)) {
+    if !prompts.confirm_with_options(i18n::t(keys::CONTAINER_BUILDER_ASK_PUSH), false) {
         return None;
     }
 
+    input_registry(prompts, config)
+}
+
+/// Offer to log in to the registry host; returns false when the login was cancelled or failed.
+/// Only the host is remembered (in `recent_registries`); the password is never stored.
+fn login_if_requested(
+    prompts: &Prompts,
+    console: &Console,
+    engine: &dyn BuildEngine,
+    registry: &str,
+) -> bool {
+    use dialoguer::{Input, Password, theme::ColorfulTheme};
+
+    let host = registry_host(registry);
+    if !prompts.confirm_with_options(
+        &crate::tr!(keys::CONTAINER_BUILDER_ASK_LOGIN, registry = host),
+        false,
+    ) {
+        return true;
+    }
+
+    let theme = ColorfulTheme::default();
+    let Ok(username) = Input::<String>::with_theme(&theme)
+        .with_prompt(i18n::t(keys::CONTAINER_BUILDER_LOGIN_USERNAME))
+        .interact_text()
+    else {
+        return false;
+    };
+    let Ok(password) = Password::with_theme(&theme)
+        .with_prompt(i18n::t(keys::CONTAINER_BUILDER_LOGIN_PASSWORD))
+        .interact()
+    else {
+        return false;
+    };
+
+    match engine.login(host, username.trim(), &password) {
+        Ok(()) => {
+            console.success(&crate::tr!(
+                keys::CONTAINER_BUILDER_LOGIN_SUCCESS,
+                registry = host
+            ));
+            true
+        }
+        Err(err) => {
+            console.error(&crate::tr!(
+                keys::CONTAINER_BUILDER_LOGIN_FAILED,
+                registry = host,
+                error = err
+            ));
+            false
+        }
+    }
+}
+
+fn input_registry(prompts: &Prompts, config: &mut BuilderConfig) -> Option<String> {
+    use dialoguer::{Input, theme::ColorfulTheme};
+
     let registry: String = if config.recent_registries.is_empty() {
         Input::with_theme(&ColorfulTheme::default())
             .with_prompt(i18n::t(keys::CONTAINER_BUILDER_INPUT_REGISTRY))
@@ -389,4 +801,23 @@ mod tests {
         let archs = Architecture::all();
         assert!(archs.len() >= 4);
     }
+
+    #[test]
+    fn test_validate_context() {
+        let repo = tempfile::tempdir().unwrap();
+        let service = repo.path().join("services/api");
+        std::fs::create_dir_all(&service).unwrap();
+        let dockerfile = service.join("Dockerfile");
+        std::fs::write(&dockerfile, "FROM scratch\n").unwrap();
+
+        let root = repo.path().canonicalize().unwrap();
+        assert_eq!(validate_context(repo.path(), &dockerfile).unwrap(), root);
+        assert!(validate_context(&service, &dockerfile).is_ok());
+        assert!(validate_context(&root.join("services/web"), &dockerfile).is_err());
+
+        let other = repo.path().join("other");
+        std::fs::create_dir(&other).unwrap();
+        assert!(validate_context(&other, &dockerfile).is_err());
+        assert!(validate_context(&dockerfile, &dockerfile).is_err());
+    }
 }
@@ -28,6 +28,22 @@ pub fn scan_dockerfiles(root: &Path) -> Vec<PathBuf> {
     dockerfiles
 }
 
+/// Selection label leading with the file name, followed by its directory relative to `root`
+pub fn display_label(root: &Path, path: &Path) -> String {
+    let file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_else(|| path.display().to_string());
+    let dir = path
+        .parent()
+        .map(|parent| parent.strip_prefix(root).unwrap_or(parent))
+        .filter(|dir| !dir.as_os_str().is_empty())
+        .map(|dir| dir.display().to_string())
+        .unwrap_or_else(|| ".".to_string());
+
+    format!("{}  ({})", file_name, dir)
+}
+
 /// Check if a path should be skipped during scanning
 fn should_skip_path(path: &Path) -> bool {
     let Some(file_name) = path.file_name().and_then(|n| n.to_str()) else {
@@ -111,6 +127,7 @@ mod tests {
         assert!(is_dockerfile_name("Dockerfile"));
         assert!(is_dockerfile_name("dockerfile"));
         assert!(is_dockerfile_name("Containerfile"));
+        assert!(is_dockerfile_name("Containerfile.buildah"));
         assert!(is_dockerfile_name("Dockerfile.dev"));
         assert!(is_dockerfile_name("Dockerfile.prod"));
         assert!(is_dockerfile_name("app.dockerfile"));
@@ -160,4 +177,17 @@ mod tests {
 
         assert_eq!(dockerfiles.len(), 1);
     }
+
+    #[test]
+    fn test_display_label_shows_file_name_and_relative_dir() {
+        let root = Path::new("/repo");
+        assert_eq!(
+            display_label(root, Path::new("/repo/Dockerfile.prod")),
+            "Dockerfile.prod  (.)"
+        );
+        assert_eq!(
+            display_label(root, Path::new("/repo/services/api/Containerfile")),
+            format!("Containerfile  ({})", Path::new("services/api").display())
+        );
+    }
 }
@@ -0,0 +1,101 @@
+//! Image tag templates
+//!
+//! Tags may contain `{git_sha}`, `{git_branch}` and `{date}`; they are resolved in the build
+//! context before building. Placeholders that cannot be resolved (e.g. outside a git repo) are
+//! kept as literal text.
+
+use crate::core::process;
+use chrono::Local;
+use std::path::Path;
+use std::process::Command;
+
+/// Placeholders understood in tag input
+pub const PLACEHOLDERS: [&str; 3] = ["git_sha", "git_branch", "date"];
+
+/// A tag with its placeholders expanded
+#[derive(Debug, PartialEq, Eq)]
+pub struct ResolvedTag {
+    pub tag: String,
+    /// Placeholders left as literal text because they could not be resolved
+    pub unresolved: Vec<&'static str>,
+}
+
+/// Expand the placeholders of `template` using git metadata from `dir`
+pub fn resolve(template: &str, dir: &Path) -> ResolvedTag {
+    expand(template, |name| match name {
+        "git_sha" => git_output(dir, &["rev-parse", "--short", "HEAD"]),
+        "git_branch" => git_output(dir, &["symbolic-ref", "--short", "-q", "HEAD"]),
+        "date" => Some(Local::now().format("%Y%m%d").to_string()),
+        _ => None,
+    })
+}
+
+fn expand(template: &str, lookup: impl Fn(&str) -> Option<String>) -> ResolvedTag {
+    let mut tag = template.to_string();
+    let mut unresolved = Vec::new();
+
+    for name in PLACEHOLDERS {
+        let placeholder = format!("{{{name}}}");
+        if !tag.contains(&placeholder) {
+            continue;
+        }
+        match lookup(name).map(|value| sanitize(&value)) {
+            Some(value) if !value.is_empty() => tag = tag.replace(&placeholder, &value),
+            _ => unresolved.push(name),
+        }
+    }
+
+    ResolvedTag { tag, unresolved }
+}
+
+/// Image tags only allow `[A-Za-z0-9_.-]`; branch names like `feature/x` become `feature-x`
+fn sanitize(value: &str) -> String {
+    value
+        .trim()
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-') {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect()
+}
+
+fn git_output(dir: &Path, args: &[&str]) -> Option<String> {
+    let mut command = Command::new("git");
+    command.arg("-C").arg(dir).args(args);
+    let output = process::run_with_timeout(&mut command).ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expand_placeholders() {
+        let resolved = expand("{git_branch}-{git_sha}-{date}", |name| match name {
+            "git_sha" => Some("abc1234".to_string()),
+            "git_branch" => Some("feature/login".to_string()),
+            "date" => Some("20260102".to_string()),
+            _ => None,
+        });
+        assert_eq!(resolved.tag, "feature-login-abc1234-20260102");
+        assert!(resolved.unresolved.is_empty());
+    }
+
+    #[test]
+    fn test_unresolved_placeholders_stay_literal() {
+        let resolved = expand("v1-{git_sha}", |_| None);
+        assert_eq!(resolved.tag, "v1-{git_sha}");
+        assert_eq!(resolved.unresolved, ["git_sha"]);
+
+        let plain = expand("latest", |_| panic!("no lookup expected"));
+        assert_eq!(plain.tag, "latest");
+    }
+}
@@ -6,14 +6,23 @@ use std::path::PathBuf;
 pub enum EngineType {
     Docker,
     Buildah,
+    Buildx,
 }
 
 impl EngineType {
-    #[allow(dead_code)]
     pub fn name(&self) -> &'static str {
         match self {
             EngineType::Docker => "Docker",
             EngineType::Buildah => "Buildah",
+            EngineType::Buildx => "Docker Buildx",
+        }
+    }
+
+    /// Executable the engine runs (Buildx is a Docker plugin)
+    pub fn binary(&self) -> &'static str {
+        match self {
+            EngineType::Docker | EngineType::Buildx => "docker",
+            EngineType::Buildah => "buildah",
         }
     }
 }
@@ -84,6 +93,10 @@ pub struct BuildContext {
     pub architecture: Vec<Architecture>,
     pub push: bool,
     pub registry: Option<String>,
+    /// `--build-arg` pairs passed to the engine
+    pub build_args: Vec<(String, String)>,
+    /// `--label` pairs attached to the image
+    pub labels: Vec<(String, String)>,
 }
 
 impl BuildContext {
@@ -95,12 +108,43 @@ impl BuildContext {
         }
     }
 
+    /// Distinct platform strings for the selected architectures, in selection order
+    pub fn platforms(&self) -> Vec<&'static str> {
+        let mut platforms: Vec<&'static str> = Vec::new();
+        for platform in self.architecture.iter().map(|a| a.platform()) {
+            if !platforms.contains(&platform) {
+                platforms.push(platform);
+            }
+        }
+        platforms
+    }
+
     /// Get local image reference (name:tag)
     pub fn local_image_ref(&self) -> String {
         format!("{}:{}", self.image_name, self.tag)
     }
 }
 
+/// Parse a `KEY=VALUE` pair; the value may be empty but the key may not
+pub fn parse_key_value(input: &str) -> Option<(String, String)> {
+    let (key, value) = input.split_once('=')?;
+    let key = key.trim();
+    if key.is_empty() || key.contains(char::is_whitespace) {
+        return None;
+    }
+    Some((key.to_string(), value.to_string()))
+}
+
+/// Registry host to log in to (`ghcr.io/org` → `ghcr.io`); a bare namespace means Docker Hub
+pub fn registry_host(registry: &str) -> &str {
+    let first = registry.split('/').next().unwrap_or_default();
+    if first.contains('.') || first.contains(':') || first == "localhost" {
+        first
+    } else {
+        "docker.io"
+    }
+}
+
 /// Result of a build or push operation
 #[derive(Debug)]
 pub struct BuildResult {
@@ -113,6 +157,18 @@ pub struct BuildResult {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_registry_host() {
+        assert_eq!(registry_host("ghcr.io/acme"), "ghcr.io");
+        assert_eq!(registry_host("localhost:5000"), "localhost:5000");
+        assert_eq!(
+            registry_host("registry.example.com:8443/team/app"),
+            "registry.example.com:8443"
+        );
+        assert_eq!(registry_host("docker.io/myuser"), "docker.io");
+        assert_eq!(registry_host("myuser"), "docker.io");
+    }
+
     #[test]
     fn test_architecture_platforms() {
         assert_eq!(Architecture::Amd64.platform(), "linux/amd64");
@@ -131,6 +187,8 @@ mod tests {
             architecture: vec![Architecture::Amd64],
             push: false,
             registry: None,
+            build_args: Vec::new(),
+            labels: Vec::new(),
         };
         assert_eq!(context.local_image_ref(), "myapp:v1.0");
         assert_eq!(context.full_image_ref(), "myapp:v1.0");
@@ -144,4 +202,43 @@ mod tests {
             "docker.io/myuser/myapp:v1.0"
         );
     }
+
+    #[test]
+    fn test_build_context_platforms_are_deduplicated() {
+        let context = BuildContext {
+            dockerfile: PathBuf::from("Dockerfile"),
+            context_dir: PathBuf::from("."),
+            image_name: "myapp".to_string(),
+            tag: "v1.0".to_string(),
+            architecture: vec![
+                Architecture::Amd64,
+                Architecture::Arm64,
+                Architecture::JetsonNano,
+            ],
+            push: true,
+            registry: None,
+            build_args: Vec::new(),
+            labels: Vec::new(),
+        };
+        assert_eq!(context.platforms(), ["linux/amd64", "linux/arm64"]);
+    }
+
+    #[test]
+    fn test_parse_key_value() {
+        assert_eq!(
+            parse_key_value("VERSION=1.2"),
+            Some(("VERSION".to_string(), "1.2".to_string()))
+        );
+        assert_eq!(
+            parse_key_value("URL=http://x?a=b"),
+            Some(("URL".to_string(), "http://x?a=b".to_string()))
+        );
+        assert_eq!(
+            parse_key_value("EMPTY="),
+            Some(("EMPTY".to_string(), String::new()))
+        );
+        assert_eq!(parse_key_value("NOVALUE"), None);
+        assert_eq!(parse_key_value("=value"), None);
+        assert_eq!(parse_key_value("TWO WORDS=x"), None);
+    }
 }
@@ -5,6 +5,7 @@
 
 use crate::core::{OperationError, Result};
 use crate::i18n::{self, keys};
+use crate::ui::Console;
 use std::path::{Path, PathBuf};
 use std::process::Command;
 use std::{env, fs};
@@ -18,20 +19,14 @@ use super::types::CudaPackageId;
 
 /// 確保快取目錄存在
 pub fn ensure_cache_dir(ctx: &BuildContext) -> Result<()> {
-    fs::create_dir_all(&ctx.wheels_dir).map_err(|err| OperationError::Io {
-        path: ctx.wheels_dir.display().to_string(),
-        source: err,
-    })?;
-    fs::create_dir_all(&ctx.sources_dir).map_err(|err| OperationError::Io {
-        path: ctx.sources_dir.display().to_string(),
-        source: err,
-    })?;
+    fs::create_dir_all(&ctx.wheels_dir)
+        .map_err(|err| OperationError::io(ctx.wheels_dir.display().to_string(), err))?;
+    fs::create_dir_all(&ctx.sources_dir)
+        .map_err(|err| OperationError::io(ctx.sources_dir.display().to_string(), err))?;
 
     if ctx.ccache_path.is_some() {
-        fs::create_dir_all(&ctx.ccache_dir).map_err(|err| OperationError::Io {
-            path: ctx.ccache_dir.display().to_string(),
-            source: err,
-        })?;
+        fs::create_dir_all(&ctx.ccache_dir)
+            .map_err(|err| OperationError::io(ctx.ccache_dir.display().to_string(), err))?;
     }
 
     Ok(())
@@ -63,10 +58,8 @@ pub fn clear_cached_artifacts(ctx: &BuildContext, package: CudaPackageId) -> Res
             continue;
         }
 
-        fs::remove_file(&path).map_err(|err| OperationError::Io {
-            path: path.display().to_string(),
-            source: err,
-        })?;
+        fs::remove_file(&path)
+            .map_err(|err| OperationError::io(path.display().to_string(), err))?;
     }
 
     Ok(())
@@ -75,10 +68,8 @@ pub fn clear_cached_artifacts(ctx: &BuildContext, package: CudaPackageId) -> Res
 /// 清理快取目錄
 pub fn clean_cache(ctx: &BuildContext) -> Result<()> {
     if ctx.wheels_dir.exists() {
-        fs::remove_dir_all(&ctx.wheels_dir).map_err(|err| OperationError::Io {
-            path: ctx.wheels_dir.display().to_string(),
-            source: err,
-        })?;
+        fs::remove_dir_all(&ctx.wheels_dir)
+            .map_err(|err| OperationError::io(ctx.wheels_dir.display().to_string(), err))?;
     }
     Ok(())
 }
@@ -94,10 +85,8 @@ pub fn ensure_venv(ctx: &BuildContext) -> Result<()> {
     }
 
     if ctx.venv_dir.exists() {
-        fs::remove_dir_all(&ctx.venv_dir).map_err(|err| OperationError::Io {
-            path: ctx.venv_dir.display().to_string(),
-            source: err,
-        })?;
+        fs::remove_dir_all(&ctx.venv_dir)
+            .map_err(|err| OperationError::io(ctx.venv_dir.display().to_string(), err))?;
     }
 
     let venv_str = ctx.venv_dir.display().to_string();
@@ -240,6 +229,7 @@ fn run_source_build(
         cmd.env("CMAKE_ARGS", cmake_args.join(" "));
     }
 
+    Console::new().command(&cmd);
     let status = cmd.status().map_err(|err| {
         command_error(
             &format!("pip wheel {}", package.pip_name()),
@@ -386,15 +376,11 @@ fn build_ctranslate2_from_repo(ctx: &BuildContext) -> Result<()> {
     let install_dir = source_dir.join("install");
 
     if build_dir.exists() {
-        fs::remove_dir_all(&build_dir).map_err(|err| OperationError::Io {
-            path: build_dir.display().to_string(),
-            source: err,
-        })?;
-    }
-    fs::create_dir_all(&build_dir).map_err(|err| OperationError::Io {
-        path: build_dir.display().to_string(),
-        source: err,
-    })?;
+        fs::remove_dir_all(&build_dir)
+            .map_err(|err| OperationError::io(build_dir.display().to_string(), err))?;
+    }
+    fs::create_dir_all(&build_dir)
+        .map_err(|err| OperationError::io(build_dir.display().to_string(), err))?;
 
     let mut configure = Command::new("cmake");
     configure.current_dir(&build_dir).args([
@@ -662,6 +648,7 @@ fn configure_base_build_command(
 }
 
 fn run_checked_command(mut cmd: Command, description: &str) -> Result<()> {
+    Console::new().command(&cmd);
     let status = cmd
         .status()
         .map_err(|err| command_error(description, &err.to_string()))?;
@@ -837,9 +824,10 @@ pub fn force_install_packages(ctx: &BuildContext, packages: &[CudaPackageId]) ->
 /// 使用 venv 的 python 執行指令（stdout/stderr 直接串流到終端）
 fn run_venv_python(ctx: &BuildContext, args: &[&str]) -> Result<()> {
     let cuda_home_str = ctx.cuda_home.display().to_string();
-    let status = Command::new(&ctx.venv_python)
-        .args(args)
-        .env("CUDA_HOME", &cuda_home_str)
+    let mut command = Command::new(&ctx.venv_python);
+    command.args(args).env("CUDA_HOME", &cuda_home_str);
+    Console::new().command(&command);
+    let status = command
         .status()
         .map_err(|err| command_error(&format!("python {}", args.join(" ")), &err.to_string()))?;
 
@@ -855,9 +843,10 @@ fn run_venv_python(ctx: &BuildContext, args: &[&str]) -> Result<()> {
 
 fn run_python(ctx: &BuildContext, args: &[&str]) -> Result<()> {
     let cuda_home_str = ctx.cuda_home.display().to_string();
-    let status = Command::new(&ctx.python_path)
-        .args(args)
-        .env("CUDA_HOME", &cuda_home_str)
+    let mut command = Command::new(&ctx.python_path);
+    command.args(args).env("CUDA_HOME", &cuda_home_str);
+    Console::new().command(&command);
+    let status = command
         .status()
         .map_err(|err| command_error(&format!("python {}", args.join(" ")), &err.to_string()))?;
 
@@ -883,9 +872,10 @@ fn uv_install_to_venv(ctx: &BuildContext, packages: &[&str]) -> Result<()> {
     let mut args = vec!["pip", "install"];
     args.extend_from_slice(packages);
 
-    let status = Command::new("uv")
-        .args(&args)
-        .env("VIRTUAL_ENV", &venv_str)
+    let mut command = Command::new("uv");
+    command.args(&args).env("VIRTUAL_ENV", &venv_str);
+    Console::new().command(&command);
+    let status = command
         .status()
         .map_err(|err| command_error("uv pip install", &err.to_string()))?;
 
@@ -901,7 +891,10 @@ fn uv_install_to_venv(ctx: &BuildContext, packages: &[&str]) -> Result<()> {
 
 /// 執行外部指令（stdout/stderr 直接串流到終端）
 fn run_streaming(program: &str, args: &[&str]) -> Result<()> {
-    let status = Command::new(program).args(args).status().map_err(|err| {
+    let mut command = Command::new(program);
+    command.args(args);
+    Console::new().command(&command);
+    let status = command.status().map_err(|err| {
         command_error(&format!("{} {}", program, args.join(" ")), &err.to_string())
     })?;
 
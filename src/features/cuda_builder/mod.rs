@@ -55,27 +55,54 @@ pub fn run() {
     }
     console.blank_line();
 
-    let options = vec![
-        i18n::t(keys::CUDA_BUILDER_MODE_BUILD),
-        i18n::t(keys::CUDA_BUILDER_MODE_INSTALL),
-        i18n::t(keys::CUDA_BUILDER_MODE_STATUS),
-        i18n::t(keys::CUDA_BUILDER_MODE_CLEAN),
-    ];
-
-    let Some(selection) = prompts.select(i18n::t(keys::CUDA_BUILDER_SELECT_MODE), &options) else {
+    let Some(selection) = prompts.select(i18n::t(keys::CUDA_BUILDER_SELECT_MODE), &mode_options())
+    else {
         console.warning(i18n::t(keys::CUDA_BUILDER_CANCELLED));
         return;
     };
 
-    match selection {
-        0 => run_build(&console, &prompts, &ctx),
-        1 => run_install(&console, &prompts, &ctx),
-        2 => run_status(&console, &ctx),
-        3 => run_clean(&console, &prompts, &ctx),
-        _ => unreachable!(),
+    // 選「返回」直接回主選單；Esc 取消才提示已取消
+    match Mode::ALL.get(selection) {
+        Some(Mode::Build) => run_build(&console, &prompts, &ctx),
+        Some(Mode::Install) => run_install(&console, &prompts, &ctx),
+        Some(Mode::Status) => run_status(&console, &ctx),
+        Some(Mode::Clean) => run_clean(&console, &prompts, &ctx),
+        None => {}
     }
 }
 
+/// 建構器模式
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Mode {
+    Build,
+    Install,
+    Status,
+    Clean,
+}
+
+impl Mode {
+    /// 選單順序
+    const ALL: [Mode; 4] = [Mode::Build, Mode::Install, Mode::Status, Mode::Clean];
+
+    fn label(self) -> &'static str {
+        match self {
+            Mode::Build => i18n::t(keys::CUDA_BUILDER_MODE_BUILD),
+            Mode::Install => i18n::t(keys::CUDA_BUILDER_MODE_INSTALL),
+            Mode::Status => i18n::t(keys::CUDA_BUILDER_MODE_STATUS),
+            Mode::Clean => i18n::t(keys::CUDA_BUILDER_MODE_CLEAN),
+        }
+    }
+}
+
+/// 模式選單的選項，依 [`Mode::ALL`] 排列，最後一項為「返回」
+fn mode_options() -> Vec<&'static str> {
+    Mode::ALL
+        .iter()
+        .map(|mode| mode.label())
+        .chain([i18n::t(keys::MENU_BACK)])
+        .collect()
+}
+
 /// 建構模式：自動建立 venv，並將選取套件從原始碼重建為 wheels
 fn run_build(console: &Console, prompts: &Prompts, ctx: &BuildContext) {
     let items: Vec<String> = ALL_PACKAGES
@@ -96,11 +123,13 @@ fn run_build(console: &Console, prompts: &Prompts, ctx: &BuildContext) {
         .map(|pkg| builder::scan_cached_wheels(ctx, *pkg).is_empty())
         .collect();
 
-    let selected = prompts.multi_select(
-        i18n::t(keys::CUDA_BUILDER_SELECT_PACKAGES),
-        &items,
-        &defaults,
-    );
+    let selected = prompts
+        .multi_select(
+            i18n::t(keys::CUDA_BUILDER_SELECT_PACKAGES),
+            &items,
+            &defaults,
+        )
+        .unwrap_or_default();
 
     if selected.is_empty() {
         console.info(i18n::t(keys::CUDA_BUILDER_NO_SELECTION));
@@ -305,11 +334,13 @@ fn run_install(console: &Console, prompts: &Prompts, ctx: &BuildContext) {
         .collect();
     let defaults = vec![true; items.len()];
 
-    let selected = prompts.multi_select(
-        i18n::t(keys::CUDA_BUILDER_SELECT_INSTALL),
-        &items,
-        &defaults,
-    );
+    let selected = prompts
+        .multi_select(
+            i18n::t(keys::CUDA_BUILDER_SELECT_INSTALL),
+            &items,
+            &defaults,
+        )
+        .unwrap_or_default();
 
     if selected.is_empty() {
         console.info(i18n::t(keys::CUDA_BUILDER_NO_SELECTION));
@@ -383,3 +414,19 @@ fn run_clean(console: &Console, prompts: &Prompts, ctx: &BuildContext) {
         Err(err) => console.error(&err.to_string()),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mode_menu_ends_with_back() {
+        let options = mode_options();
+        assert_eq!(options.len(), Mode::ALL.len() + 1);
+        for (index, mode) in Mode::ALL.iter().enumerate() {
+            assert_eq!(options[index], mode.label());
+        }
+        assert_eq!(options.last(), Some(&i18n::t(keys::MENU_BACK)));
+        assert_eq!(Mode::ALL.get(options.len() - 1), None);
+    }
+}
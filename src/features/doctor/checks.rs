@@ -0,0 +1,318 @@
+//! 環境檢查項目
+//!
+//! 列出各功能會呼叫的外部工具，以及每個功能需要哪些工具才能使用
+
+use crate::core::process;
+use crate::features::package_manager::is_command_available;
+use crate::features::security_scanner::scanner_tools;
+use crate::i18n::keys;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::thread;
+
+/// 要檢查的外部工具
+struct ToolSpec {
+    label: &'static str,
+    binary: &'static str,
+    /// 顯示版本的參數；空陣列表示不查詢版本
+    version_args: &'static [&'static str],
+}
+
+const TOOLS: &[ToolSpec] = &[
+    ToolSpec {
+        label: "Git",
+        binary: "git",
+        version_args: &["--version"],
+    },
+    ToolSpec {
+        label: "curl",
+        binary: "curl",
+        version_args: &["--version"],
+    },
+    ToolSpec {
+        label: "Docker",
+        binary: "docker",
+        version_args: &["--version"],
+    },
+    ToolSpec {
+        label: "Buildah",
+        binary: "buildah",
+        version_args: &["--version"],
+    },
+    ToolSpec {
+        label: "Cargo",
+        binary: "cargo",
+        version_args: &["--version"],
+    },
+    ToolSpec {
+        label: "rustup",
+        binary: "rustup",
+        version_args: &["--version"],
+    },
+    ToolSpec {
+        label: "cross",
+        binary: "cross",
+        version_args: &["--version"],
+    },
+    ToolSpec {
+        label: "kubectl",
+        binary: "kubectl",
+        version_args: &["version", "--client"],
+    },
+    ToolSpec {
+        label: "tmux",
+        binary: "tmux",
+        version_args: &["-V"],
+    },
+    ToolSpec {
+        label: "npm",
+        binary: "npm",
+        version_args: &["--version"],
+    },
+    ToolSpec {
+        label: "Claude Code",
+        binary: "claude",
+        version_args: &["--version"],
+    },
+    ToolSpec {
+        label: "Codex CLI",
+        binary: "codex",
+        version_args: &["--version"],
+    },
+    ToolSpec {
+        label: "Gemini CLI",
+        binary: "gemini",
+        version_args: &["--version"],
+    },
+    ToolSpec {
+        label: "Python",
+        binary: "python3",
+        version_args: &["--version"],
+    },
+    ToolSpec {
+        label: "CUDA (nvcc)",
+        binary: "nvcc",
+        version_args: &["--version"],
+    },
+];
+
+/// 掃描工具查詢版本的參數（git-secrets 沒有版本參數）
+fn scanner_version_args(binary: &str) -> &'static [&'static str] {
+    match binary {
+        "gitleaks" => &["version"],
+        "git-secrets" => &[],
+        _ => &["--version"],
+    }
+}
+
+/// 功能所需的工具：每一組中至少要有一個
+pub struct FeatureRequirement {
+    pub name_key: &'static str,
+    pub requires: &'static [&'static [&'static str]],
+}
+
+pub const FEATURES: &[FeatureRequirement] = &[
+    FeatureRequirement {
+        name_key: keys::MENU_TERRAFORM_CLEANER,
+        requires: &[],
+    },
+    FeatureRequirement {
+        name_key: keys::MENU_TOOL_UPGRADER,
+        requires: &[&["npm"], &["claude", "codex", "gemini"]],
+    },
+    FeatureRequirement {
+        name_key: keys::MENU_PACKAGE_MANAGER,
+        requires: &[&["curl"]],
+    },
+    FeatureRequirement {
+        name_key: keys::MENU_RUST_UPGRADER,
+        requires: &[&["rustup"], &["cargo"]],
+    },
+    FeatureRequirement {
+        name_key: keys::MENU_SECURITY_SCANNER,
+        requires: &[
+            &["git"],
+            &["gitleaks", "trufflehog", "git-secrets", "trivy", "semgrep"],
+        ],
+    },
+    FeatureRequirement {
+        name_key: keys::MENU_MCP_MANAGER,
+        requires: &[&["claude", "codex", "gemini"]],
+    },
+    FeatureRequirement {
+        name_key: keys::MENU_KUBECONFIG_MANAGER,
+        requires: &[&["kubectl"]],
+    },
+    FeatureRequirement {
+        name_key: keys::MENU_RUST_BUILDER,
+        requires: &[&["cargo"]],
+    },
+    FeatureRequirement {
+        name_key: keys::MENU_CONTAINER_BUILDER,
+        requires: &[&["docker", "buildah"]],
+    },
+    FeatureRequirement {
+        name_key: keys::MENU_SKILL_INSTALLER,
+        requires: &[&["git"], &["claude", "codex", "gemini"]],
+    },
+    FeatureRequirement {
+        name_key: keys::MENU_CUDA_BUILDER,
+        requires: &[&["git"], &["python3"], &["nvcc"]],
+    },
+    FeatureRequirement {
+        name_key: keys::MENU_SYSTEM_UPDATER,
+        requires: &[],
+    },
+    FeatureRequirement {
+        name_key: keys::MENU_SELF_UPDATE,
+        requires: &[&["curl"]],
+    },
+];
+
+/// 單一工具的檢查結果
+pub struct ToolStatus {
+    pub label: &'static str,
+    pub binary: &'static str,
+    pub path: Option<PathBuf>,
+    pub version: Option<String>,
+}
+
+/// 檢查所有工具（版本查詢平行執行）
+pub fn check_tools() -> Vec<ToolStatus> {
+    let mut candidates: Vec<(&'static str, &'static str, Option<PathBuf>, &[&str])> = TOOLS
+        .iter()
+        .map(|tool| {
+            (
+                tool.label,
+                tool.binary,
+                is_command_available(tool.binary),
+                tool.version_args,
+            )
+        })
+        .collect();
+    candidates.extend(
+        scanner_tools()
+            .into_iter()
+            .map(|(label, binary, path)| (label, binary, path, scanner_version_args(binary))),
+    );
+
+    thread::scope(|scope| {
+        let handles: Vec<_> = candidates
+            .into_iter()
+            .map(|(label, binary, path, args)| {
+                scope.spawn(move || {
+                    let version = path
+                        .as_ref()
+                        .filter(|_| !args.is_empty())
+                        .and_then(|path| tool_version(path, args));
+                    ToolStatus {
+                        label,
+                        binary,
+                        path,
+                        version,
+                    }
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .filter_map(|handle| handle.join().ok())
+            .collect()
+    })
+}
+
+fn tool_version(path: &Path, args: &[&str]) -> Option<String> {
+    let output = process::run_with_timeout(Command::new(path).args(args)).ok()?;
+    // 部分工具把版本印在 stderr
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    extract_version(&stdout).or_else(|| extract_version(&stderr))
+}
+
+/// 取出輸出中第一個像版本號的片段（例如 `git version 2.43.0`、`tmux 3.4`、`v1.30.2`）
+fn extract_version(output: &str) -> Option<String> {
+    output
+        .split(|c: char| c.is_whitespace() || matches!(c, ',' | '(' | ')' | '"' | ':'))
+        .map(|token| token.trim_start_matches(['v', 'V']))
+        .find(|token| {
+            token.starts_with(|c: char| c.is_ascii_digit())
+                && token.contains('.')
+                && token
+                    .split('.')
+                    .next()
+                    .is_some_and(|major| major.chars().all(|c| c.is_ascii_digit()))
+        })
+        .map(|token| token.trim_end_matches('.').to_string())
+}
+
+/// 功能缺少的工具組；每組以 ` / ` 連接表示任選其一，空陣列表示可以使用
+pub fn missing_requirements(
+    feature: &FeatureRequirement,
+    installed: impl Fn(&str) -> bool,
+) -> Vec<String> {
+    feature
+        .requires
+        .iter()
+        .filter(|group| !group.iter().any(|binary| installed(binary)))
+        .map(|group| group.join(" / "))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_version() {
+        assert_eq!(
+            extract_version("git version 2.43.0\n"),
+            Some("2.43.0".into())
+        );
+        assert_eq!(extract_version("tmux 3.4"), Some("3.4".into()));
+        assert_eq!(
+            extract_version("Client Version: v1.30.2\nKustomize Version: v5.0.4"),
+            Some("1.30.2".into())
+        );
+        assert_eq!(
+            extract_version("Docker version 27.0.3, build 7d4bcd8"),
+            Some("27.0.3".into())
+        );
+        assert_eq!(extract_version("usage: git-secrets"), None);
+    }
+
+    #[test]
+    fn test_missing_requirements() {
+        let installed = |binary: &str| matches!(binary, "git" | "codex");
+
+        let mcp = FEATURES
+            .iter()
+            .find(|feature| feature.name_key == keys::MENU_MCP_MANAGER)
+            .unwrap();
+        assert!(missing_requirements(mcp, installed).is_empty());
+
+        let container = FEATURES
+            .iter()
+            .find(|feature| feature.name_key == keys::MENU_CONTAINER_BUILDER)
+            .unwrap();
+        assert_eq!(
+            missing_requirements(container, installed),
+            ["docker / buildah"]
+        );
+
+        let cleaner = &FEATURES[0];
+        assert!(missing_requirements(cleaner, |_| false).is_empty());
+    }
+
+    #[test]
+    fn test_requirements_refer_to_checked_tools() {
+        let scanners = ["gitleaks", "trufflehog", "git-secrets", "trivy", "semgrep"];
+        for feature in FEATURES {
+            for binary in feature.requires.iter().flat_map(|group| group.iter()) {
+                assert!(
+                    TOOLS.iter().any(|tool| tool.binary == *binary) || scanners.contains(binary),
+                    "{binary} is not checked"
+                );
+            }
+        }
+    }
+}
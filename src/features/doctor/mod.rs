@@ -0,0 +1,74 @@
+mod checks;
+
+use crate::i18n::{self, keys};
+use crate::ui::Console;
+use checks::{FEATURES, check_tools, missing_requirements};
+use colored::Colorize;
+
+/// 執行環境檢查：列出外部工具的版本與路徑，以及目前可用的功能
+pub fn run() {
+    let console = Console::new();
+    console.header(i18n::t(keys::DOCTOR_HEADER));
+    console.info(i18n::t(keys::DOCTOR_CHECKING));
+
+    let tools = check_tools();
+
+    console.blank_line();
+    console.raw(&i18n::t(keys::DOCTOR_TOOLS_TITLE).bold().to_string());
+    for tool in &tools {
+        match &tool.path {
+            Some(path) => {
+                let name = match &tool.version {
+                    Some(version) => format!("{} {version}", tool.label),
+                    None => tool.label.to_string(),
+                };
+                console.list_item(
+                    &"✓".green().to_string(),
+                    &format!("{name} {}", format!("({})", path.display()).bright_black()),
+                );
+            }
+            None => console.list_item(
+                &"✗".red().to_string(),
+                &format!(
+                    "{} — {}",
+                    tool.label,
+                    crate::tr!(keys::DOCTOR_TOOL_MISSING, binary = tool.binary).bright_black()
+                ),
+            ),
+        }
+    }
+
+    let installed = |binary: &str| {
+        tools
+            .iter()
+            .any(|tool| tool.binary == binary && tool.path.is_some())
+    };
+    let mut ready = 0;
+
+    console.blank_line();
+    console.raw(&i18n::t(keys::DOCTOR_FEATURES_TITLE).bold().to_string());
+    for feature in FEATURES {
+        let missing = missing_requirements(feature, installed);
+        let name = i18n::t(feature.name_key);
+        if missing.is_empty() {
+            ready += 1;
+            console.list_item(&"✓".green().to_string(), name);
+        } else {
+            console.list_item(
+                &"✗".yellow().to_string(),
+                &format!(
+                    "{} — {}",
+                    name,
+                    crate::tr!(keys::DOCTOR_FEATURE_NEEDS, tools = missing.join(", ")).yellow()
+                ),
+            );
+        }
+    }
+
+    console.blank_line();
+    console.success(&crate::tr!(
+        keys::DOCTOR_SUMMARY,
+        ready = ready,
+        total = FEATURES.len()
+    ));
+}
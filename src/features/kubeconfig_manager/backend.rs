@@ -0,0 +1,280 @@
+use std::path::Path;
+use std::process::Command;
+
+/// 視窗隔離的後端（終端多工器或一般 shell）
+pub trait WindowBackend {
+    /// 顯示用名稱
+    fn name(&self) -> &'static str;
+
+    /// 取得目前視窗的唯一識別 ID
+    fn window_id(&self) -> Result<String, String>;
+
+    /// 讓此視窗之後開啟的 shell 繼承 KUBECONFIG；不支援時回傳 Ok(false)
+    fn set_env(&self, _window_id: &str, _config_path: &Path) -> Result<bool, String> {
+        Ok(false)
+    }
+
+    /// 移除視窗層級的 KUBECONFIG；不支援時回傳 Ok(false)
+    fn unset_env(&self, _window_id: &str) -> Result<bool, String> {
+        Ok(false)
+    }
+
+    /// 在目前 shell 輸入指令（例如 export KUBECONFIG=...）
+    fn send_command(&self, command: &str) -> Result<(), String>;
+}
+
+/// 偵測到的多工器種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BackendKind {
+    Tmux,
+    Zellij,
+    Screen,
+    Shell,
+}
+
+impl BackendKind {
+    /// 依環境變數判斷目前的多工器
+    pub fn detect(env: impl Fn(&str) -> Option<String>) -> Self {
+        if env("TMUX").is_some() {
+            Self::Tmux
+        } else if env("ZELLIJ").is_some() {
+            Self::Zellij
+        } else if env("STY").is_some() {
+            Self::Screen
+        } else {
+            Self::Shell
+        }
+    }
+}
+
+/// 選出目前環境對應的後端
+pub fn detect_backend() -> Box<dyn WindowBackend> {
+    let env = |key: &str| std::env::var(key).ok().filter(|value| !value.is_empty());
+    match BackendKind::detect(env) {
+        BackendKind::Tmux => Box::new(TmuxBackend),
+        BackendKind::Zellij => Box::new(ZellijBackend {
+            session: env("ZELLIJ_SESSION_NAME").unwrap_or_else(|| "default".to_string()),
+            pane: env("ZELLIJ_PANE_ID"),
+        }),
+        BackendKind::Screen => Box::new(ScreenBackend {
+            session: env("STY").unwrap_or_default(),
+            window: env("WINDOW").unwrap_or_else(|| "0".to_string()),
+        }),
+        BackendKind::Shell => Box::new(ShellBackend { pid: parent_pid() }),
+    }
+}
+
+fn run(program: &str, args: &[&str]) -> Result<String, String> {
+    let output = Command::new(program)
+        .args(args)
+        .output()
+        .map_err(|e| format!("Failed to execute {}: {}", program, e))?;
+
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).to_string());
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// tmux：以 session_name:window_index 識別視窗
+pub struct TmuxBackend;
+
+impl TmuxBackend {
+    fn target(window_id: &str) -> Result<String, String> {
+        let parts: Vec<&str> = window_id.split(':').collect();
+        if parts.len() != 2 {
+            return Err(format!("Invalid window ID format: {}", window_id));
+        }
+        Ok(format!("{}:{}", parts[0], parts[1]))
+    }
+}
+
+impl WindowBackend for TmuxBackend {
+    fn name(&self) -> &'static str {
+        "tmux"
+    }
+
+    fn window_id(&self) -> Result<String, String> {
+        run(
+            "tmux",
+            &["display-message", "-p", "#{session_name}:#{window_index}"],
+        )
+    }
+
+    fn set_env(&self, window_id: &str, config_path: &Path) -> Result<bool, String> {
+        let target = Self::target(window_id)?;
+        run(
+            "tmux",
+            &[
+                "set-environment",
+                "-t",
+                &target,
+                "KUBECONFIG",
+                &config_path.display().to_string(),
+            ],
+        )?;
+        Ok(true)
+    }
+
+    fn unset_env(&self, window_id: &str) -> Result<bool, String> {
+        let target = Self::target(window_id)?;
+        match run(
+            "tmux",
+            &["set-environment", "-t", &target, "-u", "KUBECONFIG"],
+        ) {
+            Ok(_) => Ok(true),
+            // tmux 可能會因為變數不存在而失敗，這不是嚴重錯誤
+            Err(stderr) if stderr.contains("unknown variable") => Ok(true),
+            Err(stderr) => Err(stderr),
+        }
+    }
+
+    fn send_command(&self, command: &str) -> Result<(), String> {
+        run("tmux", &["send-keys", command, "Enter"]).map(|_| ())
+    }
+}
+
+/// zellij：以 session 與 pane ID 識別（zellij 沒有可查詢的目前分頁索引）
+pub struct ZellijBackend {
+    session: String,
+    pane: Option<String>,
+}
+
+impl WindowBackend for ZellijBackend {
+    fn name(&self) -> &'static str {
+        "zellij"
+    }
+
+    fn window_id(&self) -> Result<String, String> {
+        let pane = self.pane.as_deref().ok_or("ZELLIJ_PANE_ID is not set")?;
+        Ok(format!("zellij:{}:pane{}", self.session, pane))
+    }
+
+    fn send_command(&self, command: &str) -> Result<(), String> {
+        run(
+            "zellij",
+            &["action", "write-chars", &format!("{command}\n")],
+        )
+        .map(|_| ())
+    }
+}
+
+/// GNU screen：以 STY 與 WINDOW 識別
+pub struct ScreenBackend {
+    session: String,
+    window: String,
+}
+
+impl WindowBackend for ScreenBackend {
+    fn name(&self) -> &'static str {
+        "screen"
+    }
+
+    fn window_id(&self) -> Result<String, String> {
+        Ok(format!("screen:{}:{}", self.session, self.window))
+    }
+
+    fn send_command(&self, command: &str) -> Result<(), String> {
+        run(
+            "screen",
+            &[
+                "-S",
+                &self.session,
+                "-p",
+                &self.window,
+                "-X",
+                "stuff",
+                &format!("{command}\n"),
+            ],
+        )
+        .map(|_| ())
+    }
+}
+
+/// 沒有多工器時以呼叫者 shell 的 PID 識別，無法自動套用到 shell
+pub struct ShellBackend {
+    pub pid: u32,
+}
+
+impl WindowBackend for ShellBackend {
+    fn name(&self) -> &'static str {
+        "shell"
+    }
+
+    fn window_id(&self) -> Result<String, String> {
+        Ok(format!("shell:{}", self.pid))
+    }
+
+    fn send_command(&self, _command: &str) -> Result<(), String> {
+        Err("No terminal multiplexer to send the command to".to_string())
+    }
+}
+
+#[cfg(unix)]
+fn parent_pid() -> u32 {
+    std::os::unix::process::parent_id()
+}
+
+#[cfg(not(unix))]
+fn parent_pid() -> u32 {
+    std::process::id()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn env_with(vars: &'static [(&'static str, &'static str)]) -> impl Fn(&str) -> Option<String> {
+        move |key| {
+            vars.iter()
+                .find(|(name, _)| *name == key)
+                .map(|(_, value)| value.to_string())
+        }
+    }
+
+    #[test]
+    fn test_detect_prefers_tmux() {
+        let env = env_with(&[("TMUX", "/tmp/tmux-1000/default"), ("STY", "123.pts")]);
+        assert_eq!(BackendKind::detect(env), BackendKind::Tmux);
+    }
+
+    #[test]
+    fn test_detect_other_multiplexers() {
+        assert_eq!(
+            BackendKind::detect(env_with(&[("ZELLIJ", "0")])),
+            BackendKind::Zellij
+        );
+        assert_eq!(
+            BackendKind::detect(env_with(&[("STY", "123.pts-0.host")])),
+            BackendKind::Screen
+        );
+        assert_eq!(BackendKind::detect(env_with(&[])), BackendKind::Shell);
+    }
+
+    #[test]
+    fn test_window_ids() {
+        let zellij = ZellijBackend {
+            session: "work".to_string(),
+            pane: Some("3".to_string()),
+        };
+        assert_eq!(zellij.window_id().unwrap(), "zellij:work:pane3");
+
+        let screen = ScreenBackend {
+            session: "123.pts-0.host".to_string(),
+            window: "2".to_string(),
+        };
+        assert_eq!(screen.window_id().unwrap(), "screen:123.pts-0.host:2");
+
+        let shell = ShellBackend { pid: 4242 };
+        assert_eq!(shell.window_id().unwrap(), "shell:4242");
+        assert!(shell.send_command("true").is_err());
+        assert_eq!(shell.set_env("shell:4242", Path::new("x")), Ok(false));
+    }
+
+    #[test]
+    fn test_tmux_target_validation() {
+        assert_eq!(TmuxBackend::target("dev:1").unwrap(), "dev:1");
+        assert!(TmuxBackend::target("zellij:work:pane3").is_err());
+    }
+}
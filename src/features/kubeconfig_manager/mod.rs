@@ -1,8 +1,12 @@
+mod backend;
 mod service;
 
+use crate::core::dry_run;
+use crate::core::requirements::require_commands;
 use crate::i18n::{self, keys};
 use crate::ui::{Console, Prompts};
 use service::KubeconfigService;
+use std::path::{Path, PathBuf};
 
 /// 執行 Kubeconfig 視窗隔離管理功能
 pub fn run() {
@@ -21,12 +25,17 @@ pub fn run() {
 
     let options = vec![
         i18n::t(keys::KUBECONFIG_ACTION_SETUP),
+        i18n::t(keys::KUBECONFIG_ACTION_MERGE),
+        i18n::t(keys::KUBECONFIG_ACTION_SWITCH_CONTEXT),
         i18n::t(keys::KUBECONFIG_ACTION_CLEANUP),
         i18n::t(keys::KUBECONFIG_ACTION_LIST),
         i18n::t(keys::KUBECONFIG_ACTION_CLEANUP_ALL),
+        i18n::t(keys::MENU_BACK),
     ];
 
+    // 選「返回」直接回主選單；Esc 取消才提示已取消
     let selection = match prompts.select(i18n::t(keys::KUBECONFIG_SELECT_ACTION), &options) {
+        Some(idx) if idx == options.len() - 1 => return,
         Some(idx) => idx,
         None => {
             console.warning(i18n::t(keys::KUBECONFIG_CANCELLED));
@@ -36,31 +45,20 @@ pub fn run() {
 
     match selection {
         0 => execute_setup(&service, &console),
-        1 => execute_cleanup(&service, &console, &prompts),
-        2 => execute_list(&service, &console),
-        3 => execute_cleanup_all(&service, &console, &prompts),
+        1 => execute_merge(&service, &console, &prompts),
+        2 => execute_switch_context(&service, &console, &prompts),
+        3 => execute_cleanup(&service, &console, &prompts),
+        4 => execute_list(&service, &console),
+        5 => execute_cleanup_all(&service, &console, &prompts),
         _ => unreachable!(),
     }
 }
 
 fn execute_setup(service: &KubeconfigService, console: &Console) {
-    // 檢查是否在 tmux 中
-    if !service.is_in_tmux() {
-        console.error(i18n::t(keys::KUBECONFIG_NOT_IN_TMUX));
+    let Some(window_id) = current_window_id(service, console) else {
         return;
-    }
-
-    // 取得 tmux 視窗 ID
-    let window_id = match service.get_tmux_window_id() {
-        Ok(id) => id,
-        Err(err) => {
-            console.error(&crate::tr!(keys::KUBECONFIG_WINDOW_ID_FAILED, error = err));
-            return;
-        }
     };
 
-    console.info(&crate::tr!(keys::KUBECONFIG_WINDOW_ID, id = &window_id));
-
     // 建立視窗專屬的 kubeconfig
     match service.setup_window_kubeconfig(&window_id) {
         Ok(config_path) => {
@@ -68,51 +66,261 @@ fn execute_setup(service: &KubeconfigService, console: &Console) {
                 keys::KUBECONFIG_SETUP_SUCCESS,
                 path = config_path.display()
             ));
+            activate_window_config(service, console, &window_id, &config_path);
+        }
+        Err(err) => {
+            console.error(&crate::tr!(keys::KUBECONFIG_SETUP_FAILED, error = err));
+        }
+    }
+}
 
-            // 設定 tmux 環境變數
-            if let Err(err) = service.set_tmux_env(&window_id, &config_path) {
-                console.warning(&crate::tr!(keys::KUBECONFIG_TMUX_ENV_FAILED, error = err));
-            } else {
-                console.success(i18n::t(keys::KUBECONFIG_TMUX_ENV_SET));
-            }
+fn execute_merge(service: &KubeconfigService, console: &Console, prompts: &Prompts) {
+    if let Err(err) = require_commands(&["kubectl"]) {
+        console.error(&err.to_string());
+        return;
+    }
 
-            // 自動在當前 shell 執行 export 指令
-            console.blank_line();
-            if let Err(err) = service.apply_shell_env(&config_path) {
-                console.warning(&crate::tr!(
-                    keys::KUBECONFIG_SHELL_APPLY_FAILED,
-                    error = err
-                ));
-                console.info(i18n::t(keys::KUBECONFIG_SHELL_HINT));
-                console.raw(&format!(
-                    "\n  export KUBECONFIG=\"{}\"\n\n",
-                    config_path.display()
-                ));
-            } else {
-                console.success(i18n::t(keys::KUBECONFIG_SHELL_APPLIED));
+    let Some(window_id) = current_window_id(service, console) else {
+        return;
+    };
+
+    let Some(sources) = select_sources(service, console, prompts, &window_id) else {
+        console.warning(i18n::t(keys::KUBECONFIG_CANCELLED));
+        return;
+    };
+
+    // 先逐一驗證，避免 kubectl 合併時略過無法解析的檔案
+    let mut valid = Vec::new();
+    for source in sources {
+        match service::read_kubeconfig(&source) {
+            Ok(view) => {
+                let contexts: Vec<&str> = view.contexts.iter().map(|c| c.name.as_str()).collect();
+                console.list_item(
+                    "📄",
+                    &format!("{} ({})", source.display(), contexts.join(", ")),
+                );
+                valid.push(source);
             }
+            Err(err) => console.error_item(
+                &crate::tr!(keys::KUBECONFIG_INVALID_SOURCE, path = source.display()),
+                &err,
+            ),
+        }
+    }
+
+    if valid.is_empty() {
+        console.warning(i18n::t(keys::KUBECONFIG_NO_SOURCE_SELECTED));
+        return;
+    }
+
+    match service.merge_window_kubeconfig(&window_id, &valid) {
+        Ok(config_path) => {
+            console.success(&crate::tr_plural!(
+                keys::KUBECONFIG_MERGE_SUCCESS,
+                count = valid.len(),
+                path = config_path.display()
+            ));
+            activate_window_config(service, console, &window_id, &config_path);
         }
         Err(err) => {
-            console.error(&crate::tr!(keys::KUBECONFIG_SETUP_FAILED, error = err));
+            console.error(&crate::tr!(keys::KUBECONFIG_MERGE_FAILED, error = err));
         }
     }
 }
 
-fn execute_cleanup(service: &KubeconfigService, console: &Console, prompts: &Prompts) {
-    // 檢查是否在 tmux 中
-    if !service.is_in_tmux() {
-        console.error(i18n::t(keys::KUBECONFIG_NOT_IN_TMUX));
+fn execute_switch_context(service: &KubeconfigService, console: &Console, prompts: &Prompts) {
+    use dialoguer::{Input, theme::ColorfulTheme};
+
+    if let Err(err) = require_commands(&["kubectl"]) {
+        console.error(&err.to_string());
+        return;
+    }
+
+    let Some(window_id) = current_window_id(service, console) else {
+        return;
+    };
+
+    let config_path = service.get_window_kubeconfig_path(&window_id);
+    if !config_path.exists() {
+        console.warning(&crate::tr!(keys::KUBECONFIG_NO_CONFIG, id = &window_id));
         return;
     }
 
-    // 取得 tmux 視窗 ID
-    let window_id = match service.get_tmux_window_id() {
-        Ok(id) => id,
+    let view = match service::read_kubeconfig(&config_path) {
+        Ok(view) => view,
         Err(err) => {
-            console.error(&crate::tr!(keys::KUBECONFIG_WINDOW_ID_FAILED, error = err));
+            console.error(&crate::tr!(keys::KUBECONFIG_READ_FAILED, error = err));
             return;
         }
     };
+    if view.contexts.is_empty() {
+        console.warning(&crate::tr!(
+            keys::KUBECONFIG_NO_CONTEXTS,
+            path = config_path.display()
+        ));
+        return;
+    }
+
+    let items: Vec<String> = view
+        .contexts
+        .iter()
+        .map(|ctx| {
+            let marker = if ctx.name == view.current_context {
+                "*"
+            } else {
+                " "
+            };
+            format!(
+                "{} {} ({}, {})",
+                marker,
+                ctx.name,
+                ctx.context.cluster,
+                ctx.namespace()
+            )
+        })
+        .collect();
+    let item_refs: Vec<&str> = items.iter().map(String::as_str).collect();
+
+    let prompt = crate::tr!(
+        keys::KUBECONFIG_SELECT_CONTEXT,
+        current = &view.current_context
+    );
+    let Some(selection) = prompts.select(&prompt, &item_refs) else {
+        console.warning(i18n::t(keys::KUBECONFIG_CANCELLED));
+        return;
+    };
+    let context = &view.contexts[selection];
+
+    let Ok(namespace) = Input::<String>::with_theme(&ColorfulTheme::default())
+        .with_prompt(crate::tr!(
+            keys::KUBECONFIG_INPUT_NAMESPACE,
+            context = &context.name
+        ))
+        .default(context.namespace().to_string())
+        .interact_text()
+    else {
+        console.warning(i18n::t(keys::KUBECONFIG_CANCELLED));
+        return;
+    };
+    let namespace = namespace.trim();
+    let changed_namespace = (namespace != context.namespace()).then_some(namespace);
+
+    match service.switch_context(&window_id, &context.name, changed_namespace) {
+        Ok(()) => console.success(&crate::tr!(
+            keys::KUBECONFIG_CONTEXT_SWITCHED,
+            context = &context.name,
+            namespace = namespace
+        )),
+        Err(err) => console.error(&crate::tr!(keys::KUBECONFIG_SWITCH_FAILED, error = err)),
+    }
+}
+
+/// 選擇要合併的 kubeconfig（目錄中的檔案加上手動輸入的路徑）；取消時回傳 None
+fn select_sources(
+    service: &KubeconfigService,
+    console: &Console,
+    prompts: &Prompts,
+    window_id: &str,
+) -> Option<Vec<PathBuf>> {
+    use dialoguer::{Input, theme::ColorfulTheme};
+
+    let candidates = service.merge_candidates(window_id);
+    let mut sources = if candidates.is_empty() {
+        console.warning(&crate::tr!(
+            keys::KUBECONFIG_NO_SOURCES,
+            path = service.kube_dir().display()
+        ));
+        Vec::new()
+    } else {
+        let window_config = service.get_window_kubeconfig_path(window_id);
+        let items: Vec<String> = candidates
+            .iter()
+            .map(|path| path.display().to_string())
+            .collect();
+        let defaults: Vec<bool> = candidates
+            .iter()
+            .map(|path| *path == window_config)
+            .collect();
+        let chosen =
+            prompts.multi_select(i18n::t(keys::KUBECONFIG_SELECT_SOURCES), &items, &defaults)?;
+        chosen
+            .into_iter()
+            .map(|idx| candidates[idx].clone())
+            .collect()
+    };
+
+    let extra: String = Input::with_theme(&ColorfulTheme::default())
+        .with_prompt(i18n::t(keys::KUBECONFIG_INPUT_EXTRA_SOURCES))
+        .allow_empty(true)
+        .interact_text()
+        .ok()?;
+    for path in extra.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+        let path = service::expand_home(path);
+        if !sources.contains(&path) {
+            sources.push(path);
+        }
+    }
+
+    Some(sources)
+}
+
+/// 將視窗專屬的 kubeconfig 套用到多工器視窗與目前 shell
+fn activate_window_config(
+    service: &KubeconfigService,
+    console: &Console,
+    window_id: &str,
+    config_path: &Path,
+) {
+    // 設定視窗層級的環境變數（目前僅 tmux 支援）
+    match service.set_window_env(window_id, config_path) {
+        Ok(true) => console.success(i18n::t(keys::KUBECONFIG_TMUX_ENV_SET)),
+        Ok(false) => {}
+        Err(err) => console.warning(&crate::tr!(keys::KUBECONFIG_TMUX_ENV_FAILED, error = err)),
+    }
+
+    // 自動在當前 shell 執行 export 指令
+    console.blank_line();
+    if let Err(err) = service.apply_shell_env(config_path) {
+        console.warning(&crate::tr!(
+            keys::KUBECONFIG_SHELL_APPLY_FAILED,
+            error = err
+        ));
+        console.info(i18n::t(keys::KUBECONFIG_SHELL_HINT));
+        console.raw(&format!(
+            "\n  export KUBECONFIG=\"{}\"\n\n",
+            config_path.display()
+        ));
+    } else {
+        console.success(i18n::t(keys::KUBECONFIG_SHELL_APPLIED));
+    }
+}
+
+/// 取得目前視窗 ID 並顯示使用的隔離後端
+fn current_window_id(service: &KubeconfigService, console: &Console) -> Option<String> {
+    match service.get_window_id() {
+        Ok(id) => {
+            console.info(&crate::tr!(
+                keys::KUBECONFIG_WINDOW_ID,
+                backend = service.backend_name(),
+                id = &id
+            ));
+            Some(id)
+        }
+        Err(err) => {
+            console.error(&crate::tr!(
+                keys::KUBECONFIG_WINDOW_ID_FAILED,
+                backend = service.backend_name(),
+                error = err
+            ));
+            None
+        }
+    }
+}
+
+fn execute_cleanup(service: &KubeconfigService, console: &Console, prompts: &Prompts) {
+    let Some(window_id) = current_window_id(service, console) else {
+        return;
+    };
 
     // 檢查是否有對應的 kubeconfig
     let config_path = service.get_window_kubeconfig_path(&window_id);
@@ -126,7 +334,12 @@ fn execute_cleanup(service: &KubeconfigService, console: &Console, prompts: &Pro
         path = config_path.display()
     ));
 
-    if !prompts.confirm_with_options(i18n::t(keys::KUBECONFIG_CONFIRM_CLEANUP), false) {
+    if dry_run::is_enabled() {
+        preview_deletion(console, &[config_path]);
+        return;
+    }
+
+    if !prompts.confirm(i18n::t(keys::KUBECONFIG_CONFIRM_CLEANUP)) {
         console.warning(i18n::t(keys::KUBECONFIG_CANCELLED));
         return;
     }
@@ -139,8 +352,8 @@ fn execute_cleanup(service: &KubeconfigService, console: &Console, prompts: &Pro
                 path = config_path.display()
             ));
 
-            // 移除 tmux 環境變數
-            if let Err(err) = service.unset_tmux_env(&window_id) {
+            // 移除視窗層級的環境變數
+            if let Err(err) = service.unset_window_env(&window_id) {
                 console.warning(&crate::tr!(
                     keys::KUBECONFIG_TMUX_ENV_UNSET_FAILED,
                     error = err
@@ -184,6 +397,9 @@ fn execute_list(service: &KubeconfigService, console: &Console) {
     }
 }
 
+/// 清理全部時需要輸入的確認字（也可輸入數量）
+const CLEANUP_ALL_CONFIRM_WORD: &str = "delete";
+
 fn execute_cleanup_all(service: &KubeconfigService, console: &Console, prompts: &Prompts) {
     let configs = service.list_window_kubeconfigs();
 
@@ -201,7 +417,25 @@ fn execute_cleanup_all(service: &KubeconfigService, console: &Console, prompts:
         console.list_item("📄", &config.display().to_string());
     }
 
-    if !prompts.confirm_with_options(i18n::t(keys::KUBECONFIG_CONFIRM_CLEANUP_ALL), false) {
+    if dry_run::is_enabled() {
+        preview_deletion(console, &configs);
+        return;
+    }
+
+    // 其他視窗可能正在使用這些設定，要求輸入確認字或數量，避免誤刪
+    let count = configs.len().to_string();
+    console.warning(&crate::tr!(
+        keys::KUBECONFIG_CLEANUP_ALL_WARNING,
+        count = &count
+    ));
+    if !prompts.confirm_typed(
+        &crate::tr!(
+            keys::KUBECONFIG_CONFIRM_CLEANUP_ALL,
+            word = CLEANUP_ALL_CONFIRM_WORD,
+            count = &count
+        ),
+        &[CLEANUP_ALL_CONFIRM_WORD, &count],
+    ) {
         console.warning(i18n::t(keys::KUBECONFIG_CANCELLED));
         return;
     }
@@ -215,6 +449,17 @@ fn execute_cleanup_all(service: &KubeconfigService, console: &Console, prompts:
     );
 }
 
+/// 模擬執行：列出將被刪除的 kubeconfig，不實際刪除
+fn preview_deletion(console: &Console, paths: &[PathBuf]) {
+    for path in paths {
+        console.list_item(
+            "🔍",
+            &crate::tr!(keys::CONSOLE_DRY_RUN_WOULD_DELETE, path = path.display()),
+        );
+    }
+    console.show_dry_run_summary(paths.len());
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -1,5 +1,88 @@
+use super::backend::{WindowBackend, detect_backend};
+use crate::i18n::{self, keys};
+use crate::ui::Console;
+use serde::Deserialize;
 use std::path::{Path, PathBuf};
-use std::process::Command;
+use std::process::{Command, Stdio};
+
+/// `kubectl config view -o json` 中用到的欄位
+#[derive(Debug, Default, Deserialize)]
+pub struct KubeconfigView {
+    #[serde(default)]
+    pub contexts: Vec<NamedContext>,
+    #[serde(rename = "current-context", default)]
+    pub current_context: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NamedContext {
+    pub name: String,
+    #[serde(default)]
+    pub context: ContextDetails,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct ContextDetails {
+    #[serde(default)]
+    pub cluster: String,
+    #[serde(default)]
+    pub namespace: Option<String>,
+}
+
+impl NamedContext {
+    /// 未設定 namespace 時 kubectl 使用 `default`
+    pub fn namespace(&self) -> &str {
+        self.context.namespace.as_deref().unwrap_or("default")
+    }
+}
+
+impl KubeconfigView {
+    fn parse(raw: &str) -> Result<Self, String> {
+        serde_json::from_str(raw).map_err(|e| e.to_string())
+    }
+}
+
+/// 執行 kubectl 並回傳 stdout
+fn run_kubectl(args: &[&str], kubeconfig: &std::ffi::OsStr) -> Result<String, String> {
+    let mut command = Command::new("kubectl");
+    command
+        .args(args)
+        .env("KUBECONFIG", kubeconfig)
+        .stdin(Stdio::null());
+    Console::new().command(&command);
+    let output = command
+        .output()
+        .map_err(|e| format!("Failed to execute kubectl: {}", e))?;
+
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+/// 以 kubectl 讀取 kubeconfig，確認內容可被解析
+///
+/// kubectl 遇到不存在的檔案會回傳空設定，因此先確認檔案存在
+pub fn read_kubeconfig(path: &Path) -> Result<KubeconfigView, String> {
+    if !path.is_file() {
+        return Err(i18n::t(keys::KUBECONFIG_SOURCE_NOT_FOUND).to_string());
+    }
+    let raw = run_kubectl(&["config", "view", "-o", "json"], path.as_os_str())?;
+    KubeconfigView::parse(&raw)
+}
+
+/// 展開使用者輸入路徑開頭的 `~`
+pub fn expand_home(path: &str) -> PathBuf {
+    let home = || dirs::home_dir().unwrap_or_default();
+    if path == "~" {
+        home()
+    } else if let Some(rest) = path.strip_prefix("~/") {
+        home().join(rest)
+    } else {
+        PathBuf::from(path)
+    }
+}
 
 /// Kubeconfig 視窗隔離服務
 pub struct KubeconfigService {
@@ -7,10 +90,12 @@ pub struct KubeconfigService {
     base_kubeconfig: PathBuf,
     /// 視窗專屬 kubeconfig 的目錄
     configs_dir: PathBuf,
+    /// 依目前終端多工器選出的視窗隔離後端
+    backend: Box<dyn WindowBackend>,
 }
 
 impl KubeconfigService {
-    /// 建立新的 KubeconfigService 實例
+    /// 建立新的 KubeconfigService 實例，並偵測目前的終端多工器
     pub fn new() -> Result<Self, String> {
         let home = dirs::home_dir().ok_or("Unable to determine home directory")?;
         let base_kubeconfig = home.join(".kube").join("config");
@@ -19,27 +104,18 @@ impl KubeconfigService {
         Ok(Self {
             base_kubeconfig,
             configs_dir,
+            backend: detect_backend(),
         })
     }
 
-    /// 檢查是否在 tmux 環境中
-    pub fn is_in_tmux(&self) -> bool {
-        std::env::var("TMUX").is_ok()
+    /// 目前使用的視窗隔離後端名稱
+    pub fn backend_name(&self) -> &'static str {
+        self.backend.name()
     }
 
-    /// 取得目前 tmux 視窗的唯一識別 ID
-    /// 格式: session_name:window_index
-    pub fn get_tmux_window_id(&self) -> Result<String, String> {
-        let output = Command::new("tmux")
-            .args(["display-message", "-p", "#{session_name}:#{window_index}"])
-            .output()
-            .map_err(|e| format!("Failed to execute tmux: {}", e))?;
-
-        if !output.status.success() {
-            return Err(String::from_utf8_lossy(&output.stderr).to_string());
-        }
-
-        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    /// 取得目前視窗的唯一識別 ID
+    pub fn get_window_id(&self) -> Result<String, String> {
+        self.backend.window_id()
     }
 
     /// 取得視窗專屬 kubeconfig 的路徑
@@ -78,86 +154,97 @@ impl KubeconfigService {
         Ok(config_path)
     }
 
-    /// 設定 tmux 視窗的環境變數
-    pub fn set_tmux_env(&self, window_id: &str, config_path: &Path) -> Result<(), String> {
-        // 取得 session 名稱
-        let parts: Vec<&str> = window_id.split(':').collect();
-        if parts.len() != 2 {
-            return Err(format!("Invalid window ID format: {}", window_id));
-        }
-
-        let target = format!("{}:{}", parts[0], parts[1]);
-
-        let output = Command::new("tmux")
-            .args([
-                "set-environment",
-                "-t",
-                &target,
-                "KUBECONFIG",
-                &config_path.display().to_string(),
-            ])
-            .output()
-            .map_err(|e| format!("Failed to execute tmux: {}", e))?;
-
-        if !output.status.success() {
-            return Err(String::from_utf8_lossy(&output.stderr).to_string());
-        }
-
-        Ok(())
+    /// 設定視窗層級的環境變數；後端不支援時回傳 Ok(false)
+    pub fn set_window_env(&self, window_id: &str, config_path: &Path) -> Result<bool, String> {
+        self.backend.set_env(window_id, config_path)
     }
 
-    /// 透過 tmux send-keys 在當前 shell 自動執行 export 指令
+    /// 在當前 shell 自動執行 export 指令
     pub fn apply_shell_env(&self, config_path: &Path) -> Result<(), String> {
-        let export_cmd = format!("export KUBECONFIG=\"{}\"", config_path.display());
+        self.backend
+            .send_command(&format!("export KUBECONFIG=\"{}\"", config_path.display()))
+    }
 
-        let output = Command::new("tmux")
-            .args(["send-keys", &export_cmd, "Enter"])
-            .output()
-            .map_err(|e| format!("Failed to execute tmux send-keys: {}", e))?;
+    /// 在當前 shell 自動執行 unset 指令
+    pub fn unapply_shell_env(&self) -> Result<(), String> {
+        self.backend.send_command("unset KUBECONFIG")
+    }
 
-        if !output.status.success() {
-            return Err(String::from_utf8_lossy(&output.stderr).to_string());
-        }
+    /// 移除視窗層級的環境變數；後端不支援時回傳 Ok(false)
+    pub fn unset_window_env(&self, window_id: &str) -> Result<bool, String> {
+        self.backend.unset_env(window_id)
+    }
 
-        Ok(())
+    /// 基礎 kubeconfig 所在目錄（通常為 ~/.kube）
+    pub fn kube_dir(&self) -> &Path {
+        self.base_kubeconfig.parent().unwrap_or(Path::new("."))
     }
 
-    /// 透過 tmux send-keys 在當前 shell 自動執行 unset 指令
-    pub fn unapply_shell_env(&self) -> Result<(), String> {
-        let output = Command::new("tmux")
-            .args(["send-keys", "unset KUBECONFIG", "Enter"])
-            .output()
-            .map_err(|e| format!("Failed to execute tmux send-keys: {}", e))?;
+    /// 可合併的 kubeconfig 檔案：~/.kube 下的一般檔案與目前視窗的設定
+    pub fn merge_candidates(&self, window_id: &str) -> Vec<PathBuf> {
+        let mut candidates: Vec<PathBuf> = std::fs::read_dir(self.kube_dir())
+            .ok()
+            .map(|entries| {
+                entries
+                    .filter_map(|entry| entry.ok())
+                    .map(|entry| entry.path())
+                    .filter(|path| path.is_file())
+                    .filter(|path| {
+                        path.file_name()
+                            .is_some_and(|name| !name.to_string_lossy().starts_with('.'))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        candidates.sort();
 
-        if !output.status.success() {
-            return Err(String::from_utf8_lossy(&output.stderr).to_string());
+        let window_config = self.get_window_kubeconfig_path(window_id);
+        if window_config.is_file() {
+            candidates.insert(0, window_config);
         }
-
-        Ok(())
+        candidates
     }
 
-    /// 移除 tmux 視窗的環境變數
-    pub fn unset_tmux_env(&self, window_id: &str) -> Result<(), String> {
-        let parts: Vec<&str> = window_id.split(':').collect();
-        if parts.len() != 2 {
-            return Err(format!("Invalid window ID format: {}", window_id));
-        }
+    /// 以 `kubectl config view --flatten` 合併多個 kubeconfig 成為視窗專屬設定
+    pub fn merge_window_kubeconfig(
+        &self,
+        window_id: &str,
+        sources: &[PathBuf],
+    ) -> Result<PathBuf, String> {
+        let joined =
+            std::env::join_paths(sources).map_err(|e| format!("Invalid kubeconfig path: {}", e))?;
+        let merged = run_kubectl(&["config", "view", "--flatten"], &joined)?;
 
-        let target = format!("{}:{}", parts[0], parts[1]);
+        if !self.configs_dir.exists() {
+            std::fs::create_dir_all(&self.configs_dir)
+                .map_err(|e| format!("Failed to create configs directory: {}", e))?;
+        }
 
-        let output = Command::new("tmux")
-            .args(["set-environment", "-t", &target, "-u", "KUBECONFIG"])
-            .output()
-            .map_err(|e| format!("Failed to execute tmux: {}", e))?;
+        let config_path = self.get_window_kubeconfig_path(window_id);
+        std::fs::write(&config_path, merged)
+            .map_err(|e| format!("Failed to write kubeconfig: {}", e))?;
+        Ok(config_path)
+    }
 
-        if !output.status.success() {
-            // tmux 可能會因為變數不存在而失敗，這不是嚴重錯誤
-            let stderr = String::from_utf8_lossy(&output.stderr);
-            if !stderr.contains("unknown variable") {
-                return Err(stderr.to_string());
-            }
+    /// 在視窗專屬的 kubeconfig 中切換 context，並可一併設定 namespace
+    ///
+    /// 只寫回該視窗的檔案，不影響其他視窗或 ~/.kube/config
+    pub fn switch_context(
+        &self,
+        window_id: &str,
+        context: &str,
+        namespace: Option<&str>,
+    ) -> Result<(), String> {
+        let config_path = self.get_window_kubeconfig_path(window_id);
+        let kubeconfig = config_path.as_os_str();
+        run_kubectl(&["config", "use-context", context], kubeconfig)?;
+        if let Some(namespace) = namespace {
+            let namespace = format!("--namespace={namespace}");
+            run_kubectl(
+                &["config", "set-context", "--current", &namespace],
+                kubeconfig,
+            )?;
         }
-
         Ok(())
     }
 
@@ -213,8 +300,25 @@ impl KubeconfigService {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::features::kubeconfig_manager::backend::ShellBackend;
     use tempfile::TempDir;
 
+    #[test]
+    fn test_expand_home() {
+        let home = dirs::home_dir().unwrap_or_default();
+        assert_eq!(expand_home("~"), home);
+        assert_eq!(expand_home("~/.kube/dev"), home.join(".kube/dev"));
+        assert_eq!(expand_home("/etc/kube"), PathBuf::from("/etc/kube"));
+        assert_eq!(expand_home("~other/x"), PathBuf::from("~other/x"));
+    }
+
+    #[test]
+    fn test_read_kubeconfig_reports_missing_file() {
+        let dir = TempDir::new().unwrap();
+        let err = read_kubeconfig(&dir.path().join("missing")).unwrap_err();
+        assert_eq!(err, i18n::t(keys::KUBECONFIG_SOURCE_NOT_FOUND));
+    }
+
     struct TestService {
         service: KubeconfigService,
         _temp_dir: TempDir,
@@ -233,6 +337,7 @@ mod tests {
             let service = KubeconfigService {
                 base_kubeconfig,
                 configs_dir,
+                backend: Box::new(ShellBackend { pid: 4242 }),
             };
 
             Self {
@@ -255,6 +360,16 @@ mod tests {
         assert!(path.to_string_lossy().contains("mysession-1.yaml"));
     }
 
+    #[test]
+    fn test_shell_backend_window_path() {
+        let test = TestService::new();
+        assert_eq!(test.service.backend_name(), "shell");
+
+        let window_id = test.service.get_window_id().unwrap();
+        let path = test.service.get_window_kubeconfig_path(&window_id);
+        assert!(path.to_string_lossy().ends_with("shell-4242.yaml"));
+    }
+
     #[test]
     fn test_setup_window_kubeconfig() {
         let test = TestService::new();
@@ -265,6 +380,45 @@ mod tests {
         assert!(config_path.exists());
     }
 
+    #[test]
+    fn test_merge_candidates() {
+        let test = TestService::new();
+        let kube_dir = test.service.kube_dir().to_path_buf();
+        std::fs::write(kube_dir.join("staging.yaml"), "apiVersion: v1\n").unwrap();
+        std::fs::write(kube_dir.join(".hidden"), "").unwrap();
+
+        assert_eq!(
+            test.service.merge_candidates("test:0"),
+            vec![kube_dir.join("config"), kube_dir.join("staging.yaml")]
+        );
+
+        let window_config = test.service.setup_window_kubeconfig("test:0").unwrap();
+        assert_eq!(test.service.merge_candidates("test:0")[0], window_config);
+    }
+
+    #[test]
+    fn test_parse_kubeconfig_view() {
+        let view = KubeconfigView::parse(
+            r#"{
+                "kind": "Config",
+                "current-context": "prod",
+                "contexts": [
+                    {"name": "prod", "context": {"cluster": "prod-cluster", "user": "admin", "namespace": "apps"}},
+                    {"name": "dev", "context": {"cluster": "dev-cluster", "user": "dev"}}
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let names: Vec<&str> = view.contexts.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, ["prod", "dev"]);
+        assert_eq!(view.current_context, "prod");
+        assert_eq!(view.contexts[0].namespace(), "apps");
+        assert_eq!(view.contexts[1].namespace(), "default");
+        assert_eq!(view.contexts[1].context.cluster, "dev-cluster");
+        assert!(KubeconfigView::parse("not json").is_err());
+    }
+
     #[test]
     fn test_cleanup_window_kubeconfig() {
         let test = TestService::new();
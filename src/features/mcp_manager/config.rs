@@ -1,61 +1,90 @@
-/// 編譯時環境變數
+use std::sync::LazyLock;
+
+/// GitHub MCP 需要的 Token 環境變數
+pub const GITHUB_TOKEN_VAR: &str = "GITHUB_PERSONAL_ACCESS_TOKEN";
+/// Context7 API Key 環境變數（選填）
+pub const CONTEXT7_API_KEY_VAR: &str = "CONTEXT7_API_KEY";
+
+/// MCP 相關環境變數
+///
+/// 執行期讀取；未設定或為空時退回編譯時 `.env` 的值。
 pub struct EnvConfig {
-    pub github_token: Option<&'static str>,
-    pub github_host: Option<&'static str>,
-    pub github_toolsets: Option<&'static str>,
-    pub github_mcp_mode: Option<&'static str>,
-    pub context7_api_key: Option<&'static str>,
-    pub enable_cloudflare_mcp_raw: Option<&'static str>,
+    pub github_token: Option<String>,
+    pub github_host: Option<String>,
+    pub github_toolsets: Option<String>,
+    pub github_mcp_mode: Option<String>,
+    pub context7_api_key: Option<String>,
+    pub enable_cloudflare_mcp_raw: Option<String>,
 }
 
 impl EnvConfig {
-    pub const fn new() -> Self {
+    pub fn from_env() -> Self {
         Self {
-            github_token: option_env!("GITHUB_PERSONAL_ACCESS_TOKEN"),
-            github_host: option_env!("GITHUB_HOST"),
-            github_toolsets: option_env!("GITHUB_TOOLSETS"),
-            github_mcp_mode: option_env!("GITHUB_MCP_MODE"),
-            context7_api_key: option_env!("CONTEXT7_API_KEY"),
-            enable_cloudflare_mcp_raw: first_env(
-                option_env!("enable_cloudflare_mcp"),
-                option_env!("ENABLE_CLOUDFLARE_MCP"),
+            github_token: lookup(
+                GITHUB_TOKEN_VAR,
+                option_env!("GITHUB_PERSONAL_ACCESS_TOKEN"),
             ),
+            github_host: lookup("GITHUB_HOST", option_env!("GITHUB_HOST")),
+            github_toolsets: lookup("GITHUB_TOOLSETS", option_env!("GITHUB_TOOLSETS")),
+            github_mcp_mode: lookup("GITHUB_MCP_MODE", option_env!("GITHUB_MCP_MODE")),
+            context7_api_key: lookup(CONTEXT7_API_KEY_VAR, option_env!("CONTEXT7_API_KEY")),
+            enable_cloudflare_mcp_raw: lookup(
+                "enable_cloudflare_mcp",
+                option_env!("enable_cloudflare_mcp"),
+            )
+            .or_else(|| {
+                lookup(
+                    "ENABLE_CLOUDFLARE_MCP",
+                    option_env!("ENABLE_CLOUDFLARE_MCP"),
+                )
+            }),
         }
     }
 
     /// 取得 GitHub MCP 模式（remote 或 docker）
     /// 預設為 docker，因為 remote 模式需要 GitHub Copilot 訂閱
     pub fn github_mcp_mode_value(&self) -> &'static str {
-        match self.github_mcp_mode {
+        match self.github_mcp_mode.as_deref() {
             Some(mode) if mode.eq_ignore_ascii_case("remote") => "remote",
             _ => "docker",
         }
     }
 
+    pub fn github_host_value(&self) -> &str {
+        self.github_host.as_deref().unwrap_or("github.com")
+    }
+
     pub fn enable_cloudflare_mcp(&self) -> bool {
-        parse_bool_env(self.enable_cloudflare_mcp_raw)
+        parse_bool_env(self.enable_cloudflare_mcp_raw.as_deref())
     }
 }
 
 impl Default for EnvConfig {
     fn default() -> Self {
-        Self::new()
+        Self::from_env()
     }
 }
 
 /// 全域配置實例
-pub static ENV_CONFIG: EnvConfig = EnvConfig::new();
-
-const fn first_env(
-    primary: Option<&'static str>,
-    fallback: Option<&'static str>,
-) -> Option<&'static str> {
-    match primary {
-        Some(value) => Some(value),
-        None => fallback,
+pub static ENV_CONFIG: LazyLock<EnvConfig> = LazyLock::new(EnvConfig::from_env);
+
+/// 環境變數是否已設定且非空（已知變數也接受編譯時的值）
+pub fn is_env_set(name: &str) -> bool {
+    match name {
+        GITHUB_TOKEN_VAR => ENV_CONFIG.github_token.is_some(),
+        CONTEXT7_API_KEY_VAR => ENV_CONFIG.context7_api_key.is_some(),
+        _ => non_empty(std::env::var(name).ok()).is_some(),
     }
 }
 
+fn lookup(name: &str, build_time: Option<&'static str>) -> Option<String> {
+    non_empty(std::env::var(name).ok()).or_else(|| non_empty(build_time.map(str::to_string)))
+}
+
+fn non_empty(value: Option<String>) -> Option<String> {
+    value.filter(|value| !value.trim().is_empty())
+}
+
 fn parse_bool_env(value: Option<&str>) -> bool {
     let Some(raw) = value else {
         return false;
@@ -63,3 +92,31 @@ fn parse_bool_env(value: Option<&str>) -> bool {
     let trimmed = raw.trim();
     trimmed == "1" || trimmed.eq_ignore_ascii_case("true") || trimmed.eq_ignore_ascii_case("yes")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_non_empty_rejects_blank_values() {
+        assert_eq!(non_empty(Some("  ".to_string())), None);
+        assert_eq!(non_empty(None), None);
+        assert_eq!(
+            non_empty(Some("token".to_string())).as_deref(),
+            Some("token")
+        );
+    }
+
+    #[test]
+    fn test_lookup_falls_back_to_build_time_value() {
+        let name = "OPS_TOOLS_TEST_UNSET_MCP_VAR";
+        assert_eq!(lookup(name, Some("baked")).as_deref(), Some("baked"));
+        assert_eq!(lookup(name, Some("")), None);
+        assert_eq!(lookup(name, None), None);
+    }
+
+    #[test]
+    fn test_unknown_unset_var_is_missing() {
+        assert!(!is_env_set("OPS_TOOLS_TEST_UNSET_MCP_VAR"));
+    }
+}
@@ -0,0 +1,215 @@
+use super::tools::{CliType, McpTool};
+use crate::core::config::config_dir;
+use crate::i18n::keys;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// 使用者自訂 MCP 定義檔名（位於設定目錄）
+pub const CUSTOM_FILE_NAME: &str = "mcp-custom.toml";
+
+#[derive(Debug, Default, Deserialize)]
+struct CustomFile {
+    #[serde(default)]
+    server: Vec<CustomEntry>,
+}
+
+/// 單一自訂 MCP 伺服器定義（匯出檔也使用相同格式）
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub(super) struct CustomEntry {
+    #[serde(default)]
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    /// 支援的 CLI（claude / codex）；留空表示全部
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub supported_cli: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub install_args: Vec<String>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub requires_interactive: bool,
+    /// 安裝前必須設定的環境變數
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub required_env: Vec<String>,
+}
+
+/// 自訂 MCP 載入結果
+#[derive(Default)]
+pub struct CustomTools {
+    pub path: Option<PathBuf>,
+    pub tools: Vec<McpTool>,
+    /// 無法解析或被略過的項目說明
+    pub warnings: Vec<String>,
+}
+
+/// 自訂 MCP 定義檔路徑
+pub fn custom_file_path() -> Option<PathBuf> {
+    config_dir().map(|dir| dir.join(CUSTOM_FILE_NAME))
+}
+
+/// 讀取自訂 MCP，略過與內建工具同名或不完整的項目
+pub fn load_custom_tools(cli: CliType, builtin: &[McpTool]) -> CustomTools {
+    let Some(path) = custom_file_path().filter(|path| path.is_file()) else {
+        return CustomTools::default();
+    };
+    let mut result = load_from_path(&path, cli, builtin);
+    result.path = Some(path);
+    result
+}
+
+fn load_from_path(path: &Path, cli: CliType, builtin: &[McpTool]) -> CustomTools {
+    let content = match std::fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(err) => return parse_failure(path, &err.to_string()),
+    };
+    match toml::from_str::<CustomFile>(&content) {
+        Ok(file) => convert_entries(file.server, cli, builtin),
+        Err(err) => parse_failure(path, &err.to_string()),
+    }
+}
+
+fn parse_failure(path: &Path, error: &str) -> CustomTools {
+    CustomTools {
+        warnings: vec![crate::tr!(
+            keys::MCP_MANAGER_CUSTOM_PARSE_FAILED,
+            path = path.display(),
+            error = error
+        )],
+        ..CustomTools::default()
+    }
+}
+
+pub(super) fn convert_entries(
+    entries: Vec<CustomEntry>,
+    cli: CliType,
+    builtin: &[McpTool],
+) -> CustomTools {
+    let mut result = CustomTools::default();
+
+    for (index, entry) in entries.into_iter().enumerate() {
+        let name = entry.name.trim().to_string();
+        let reason = if name.is_empty() {
+            Some(crate::tr!(keys::MCP_MANAGER_CUSTOM_MISSING_NAME))
+        } else if entry.install_args.iter().all(|arg| arg.trim().is_empty()) {
+            Some(crate::tr!(keys::MCP_MANAGER_CUSTOM_MISSING_ARGS))
+        } else if builtin
+            .iter()
+            .chain(result.tools.iter())
+            .any(|tool| tool.name == name)
+        {
+            Some(crate::tr!(keys::MCP_MANAGER_CUSTOM_DUPLICATE, name = &name))
+        } else {
+            None
+        };
+
+        if let Some(reason) = reason {
+            result.warnings.push(crate::tr!(
+                keys::MCP_MANAGER_CUSTOM_SKIPPED,
+                index = index + 1,
+                reason = reason
+            ));
+            continue;
+        }
+
+        let supported = entry.supported_cli.is_empty()
+            || entry
+                .supported_cli
+                .iter()
+                .any(|value| value.trim().eq_ignore_ascii_case(cli.command()));
+        if !supported {
+            continue;
+        }
+
+        result.tools.push(McpTool {
+            display_name_key: keys::MCP_TOOL_CUSTOM,
+            custom_display_name: Some(entry.display_name.unwrap_or_else(|| name.clone())),
+            name,
+            install_args: entry.install_args,
+            requires_interactive: entry.requires_interactive,
+            has_options: false,
+            required_env: entry.required_env,
+        });
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::mcp_manager::tools::get_available_tools;
+
+    fn parse(content: &str, cli: CliType) -> CustomTools {
+        let file: CustomFile = toml::from_str(content).unwrap();
+        convert_entries(file.server, cli, &get_available_tools(cli))
+    }
+
+    #[test]
+    fn test_custom_entry_becomes_tool() {
+        let result = parse(
+            r#"
+[[server]]
+name = "my-server"
+display_name = "My Server"
+supported_cli = ["claude"]
+install_args = ["my-server", "--", "npx", "-y", "my-mcp"]
+"#,
+            CliType::Claude,
+        );
+
+        assert!(result.warnings.is_empty());
+        assert_eq!(result.tools.len(), 1);
+        let tool = &result.tools[0];
+        assert_eq!(tool.name, "my-server");
+        assert_eq!(tool.display_name(), "My Server");
+        assert_eq!(tool.install_args.len(), 5);
+    }
+
+    #[test]
+    fn test_unsupported_cli_is_filtered() {
+        let result = parse(
+            r#"
+[[server]]
+name = "claude-only"
+supported_cli = ["claude"]
+install_args = ["claude-only", "--", "npx", "claude-only-mcp"]
+"#,
+            CliType::Codex,
+        );
+
+        assert!(result.tools.is_empty());
+        assert!(result.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_invalid_entries_are_reported() {
+        let result = parse(
+            r#"
+[[server]]
+name = ""
+install_args = ["x"]
+
+[[server]]
+name = "no-args"
+
+[[server]]
+name = "playwright"
+install_args = ["playwright", "--", "npx", "other"]
+"#,
+            CliType::Claude,
+        );
+
+        assert!(result.tools.is_empty());
+        assert_eq!(result.warnings.len(), 3);
+    }
+
+    #[test]
+    fn test_parse_error_is_reported() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(CUSTOM_FILE_NAME);
+        std::fs::write(&path, "[[server]\nname = ").unwrap();
+
+        let result = load_from_path(&path, CliType::Claude, &[]);
+        assert!(result.tools.is_empty());
+        assert_eq!(result.warnings.len(), 1);
+    }
+}
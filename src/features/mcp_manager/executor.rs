@@ -1,12 +1,26 @@
 use super::config::ENV_CONFIG;
 use super::tools::{CliType, McpTool, McpToolOptions};
-use crate::core::{OperationError, Result};
+use crate::core::{OperationError, Result, process};
 use crate::i18n::{self, keys};
+use crate::ui::suspend_progress;
+use serde_json::Value as JsonValue;
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
 use toml::Value as TomlValue;
 
+/// 重新安裝前保存的既有定義，安裝失敗時用來還原
+#[derive(Debug, Clone, PartialEq)]
+pub enum McpSnapshot {
+    /// `~/.claude.json` 中的定義與其範圍（`local` / `user`）
+    Claude {
+        scope: &'static str,
+        definition: JsonValue,
+    },
+    /// `~/.codex/config.toml` 的 `[mcp_servers.<name>]`
+    Codex(TomlValue),
+}
+
 /// MCP CLI 執行器
 pub struct McpExecutor {
     cli: CliType,
@@ -17,16 +31,15 @@ impl McpExecutor {
         Self { cli }
     }
 
+    pub fn cli(&self) -> CliType {
+        self.cli
+    }
+
     /// 取得已安裝的 MCP 清單
     pub fn list_installed(&self) -> Result<Vec<String>> {
         self.maybe_migrate_cli_settings()?;
-        let output = Command::new(self.cli.command())
-            .args(["mcp", "list"])
-            .output()
-            .map_err(|e| OperationError::Command {
-                command: self.cli.command().to_string(),
-                message: crate::tr!(keys::ERROR_UNABLE_TO_EXECUTE, error = e),
-            })?;
+        let output =
+            process::run_with_timeout(Command::new(self.cli.command()).args(["mcp", "list"]))?;
 
         if output.status.success() {
             let stdout = String::from_utf8_lossy(&output.stdout);
@@ -38,6 +51,12 @@ impl McpExecutor {
 
     /// 安裝 MCP
     pub fn install(&self, tool: &McpTool, options: &McpToolOptions) -> Result<()> {
+        if let Some(var) = tool.missing_env().first() {
+            return Err(OperationError::Config {
+                key: var.to_string(),
+                message: i18n::t(keys::MCP_EXECUTOR_ENV_MISSING).to_string(),
+            });
+        }
         self.maybe_migrate_cli_settings()?;
         let mut args: Vec<&str> = vec!["mcp", "add"];
         let install_args = tool.get_install_args_with_options(options);
@@ -45,16 +64,13 @@ impl McpExecutor {
         args.extend(string_refs);
 
         if tool.requires_interactive {
-            let status = Command::new(self.cli.command())
+            let mut command = Command::new(self.cli.command());
+            command
                 .args(&args)
                 .stdin(Stdio::inherit())
                 .stdout(Stdio::inherit())
-                .stderr(Stdio::inherit())
-                .status()
-                .map_err(|e| OperationError::Command {
-                    command: self.cli.command().to_string(),
-                    message: crate::tr!(keys::ERROR_UNABLE_TO_EXECUTE, error = e),
-                })?;
+                .stderr(Stdio::inherit());
+            let status = suspend_progress(|| process::status_with_timeout(&mut command))?;
 
             if status.success() {
                 self.maybe_migrate_cli_settings()?;
@@ -66,13 +82,7 @@ impl McpExecutor {
                 })
             }
         } else {
-            let output = Command::new(self.cli.command())
-                .args(&args)
-                .output()
-                .map_err(|e| OperationError::Command {
-                    command: self.cli.command().to_string(),
-                    message: crate::tr!(keys::ERROR_UNABLE_TO_EXECUTE, error = e),
-                })?;
+            let output = process::run_with_timeout(Command::new(self.cli.command()).args(&args))?;
 
             if output.status.success() {
                 self.maybe_migrate_cli_settings()?;
@@ -94,13 +104,9 @@ impl McpExecutor {
     /// 移除 MCP
     pub fn remove(&self, name: &str) -> Result<()> {
         self.maybe_migrate_cli_settings()?;
-        let output = Command::new(self.cli.command())
-            .args(["mcp", "remove", name])
-            .output()
-            .map_err(|e| OperationError::Command {
-                command: self.cli.command().to_string(),
-                message: crate::tr!(keys::ERROR_UNABLE_TO_EXECUTE, error = e),
-            })?;
+        let output = process::run_with_timeout(
+            Command::new(self.cli.command()).args(["mcp", "remove", name]),
+        )?;
 
         if output.status.success() {
             Ok(())
@@ -117,6 +123,81 @@ impl McpExecutor {
         }
     }
 
+    /// 讀取已安裝 MCP 的既有定義；找不到時回傳 None
+    pub fn snapshot(&self, name: &str) -> Result<Option<McpSnapshot>> {
+        match self.cli {
+            CliType::Claude => {
+                let Some(path) = claude_config_path().filter(|path| path.exists()) else {
+                    return Ok(None);
+                };
+                let root: JsonValue =
+                    serde_json::from_str(&read_config(&path)?).map_err(|err| {
+                        OperationError::Config {
+                            key: path.display().to_string(),
+                            message: crate::tr!(
+                                keys::MCP_EXECUTOR_CONFIG_PARSE_FAILED,
+                                error = err
+                            ),
+                        }
+                    })?;
+                let project = std::env::current_dir()
+                    .map(|dir| dir.display().to_string())
+                    .unwrap_or_default();
+                Ok(find_claude_server(&root, &project, name)
+                    .map(|(scope, definition)| McpSnapshot::Claude { scope, definition }))
+            }
+            CliType::Codex => {
+                let Some(path) = codex_config_path().filter(|path| path.exists()) else {
+                    return Ok(None);
+                };
+                let root = parse_codex_config(&path)?;
+                Ok(root
+                    .get("mcp_servers")
+                    .and_then(|servers| servers.get(name))
+                    .cloned()
+                    .map(McpSnapshot::Codex))
+            }
+        }
+    }
+
+    /// 還原 [`snapshot`](Self::snapshot) 保存的定義
+    pub fn restore(&self, name: &str, snapshot: &McpSnapshot) -> Result<()> {
+        match snapshot {
+            McpSnapshot::Claude { scope, definition } => {
+                let output = process::run_with_timeout(Command::new(self.cli.command()).args([
+                    "mcp",
+                    "add-json",
+                    "--scope",
+                    scope,
+                    name,
+                    &definition.to_string(),
+                ]))?;
+                if output.status.success() {
+                    Ok(())
+                } else {
+                    let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+                    Err(OperationError::Command {
+                        command: format!("{} mcp add-json", self.cli.command()),
+                        message: stderr
+                            .lines()
+                            .next()
+                            .unwrap_or(i18n::t(keys::ERROR_UNKNOWN))
+                            .to_string(),
+                    })
+                }
+            }
+            McpSnapshot::Codex(definition) => {
+                let Some(path) = codex_config_path() else {
+                    return Err(OperationError::Config {
+                        key: "HOME".to_string(),
+                        message: i18n::t(keys::ERROR_UNKNOWN).to_string(),
+                    });
+                };
+                restore_codex_server(&path, name, definition)
+            }
+        }
+    }
+
     fn maybe_migrate_cli_settings(&self) -> Result<()> {
         self.maybe_configure_codex_context7_headers()?;
         self.maybe_configure_codex_github_env()?;
@@ -128,7 +209,7 @@ impl McpExecutor {
             return Ok(());
         }
 
-        let Some(key) = ENV_CONFIG.context7_api_key else {
+        let Some(key) = ENV_CONFIG.context7_api_key.as_deref() else {
             return Ok(());
         };
 
@@ -149,10 +230,10 @@ impl McpExecutor {
             return Ok(());
         }
 
-        let Some(token) = ENV_CONFIG.github_token else {
+        let Some(token) = ENV_CONFIG.github_token.as_deref() else {
             return Ok(());
         };
-        let host = ENV_CONFIG.github_host.unwrap_or("github.com");
+        let host = ENV_CONFIG.github_host_value();
 
         let Some(path) = codex_config_path() else {
             return Ok(());
@@ -217,11 +298,76 @@ fn codex_config_path() -> Option<PathBuf> {
     Some(PathBuf::from(home).join(".codex").join("config.toml"))
 }
 
-fn update_codex_context7_config(path: &Path, api_key: &str) -> Result<bool> {
-    let raw = fs::read_to_string(path).map_err(|err| OperationError::Io {
-        path: path.display().to_string(),
-        source: err,
+fn claude_config_path() -> Option<PathBuf> {
+    let home = std::env::var("HOME").ok()?;
+    Some(PathBuf::from(home).join(".claude.json"))
+}
+
+fn read_config(path: &Path) -> Result<String> {
+    fs::read_to_string(path).map_err(|err| OperationError::io(path.display().to_string(), err))
+}
+
+fn parse_codex_config(path: &Path) -> Result<toml::Table> {
+    toml::from_str(&read_config(path)?).map_err(|err| OperationError::Config {
+        key: path.display().to_string(),
+        message: crate::tr!(keys::MCP_EXECUTOR_CONFIG_PARSE_FAILED, error = err),
+    })
+}
+
+/// 依 Claude CLI 的優先順序尋找定義：目前專案（local）優先於使用者層級（user）
+fn find_claude_server(
+    root: &JsonValue,
+    project: &str,
+    name: &str,
+) -> Option<(&'static str, JsonValue)> {
+    let local = root
+        .get("projects")
+        .and_then(|projects| projects.get(project))
+        .and_then(|project| project.get("mcpServers"))
+        .and_then(|servers| servers.get(name));
+    if let Some(definition) = local {
+        return Some(("local", definition.clone()));
+    }
+    root.get("mcpServers")
+        .and_then(|servers| servers.get(name))
+        .map(|definition| ("user", definition.clone()))
+}
+
+fn restore_codex_server(path: &Path, name: &str, definition: &TomlValue) -> Result<()> {
+    let mut root = if path.exists() {
+        parse_codex_config(path)?
+    } else {
+        toml::Table::new()
+    };
+    let servers = root
+        .entry("mcp_servers")
+        .or_insert_with(|| TomlValue::Table(toml::Table::new()));
+    let Some(servers) = servers.as_table_mut() else {
+        return Err(OperationError::Config {
+            key: path.display().to_string(),
+            message: crate::tr!(
+                keys::MCP_EXECUTOR_CONFIG_PARSE_FAILED,
+                error = "mcp_servers"
+            ),
+        });
+    };
+    servers.insert(name.to_string(), definition.clone());
+
+    let formatted = toml::to_string(&root).map_err(|err| OperationError::Config {
+        key: path.display().to_string(),
+        message: crate::tr!(keys::MCP_EXECUTOR_CONFIG_SERIALIZE_FAILED, error = err),
     })?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|err| OperationError::io(parent.display().to_string(), err))?;
+    }
+    fs::write(path, format!("{}\n", formatted))
+        .map_err(|err| OperationError::io(path.display().to_string(), err))
+}
+
+fn update_codex_context7_config(path: &Path, api_key: &str) -> Result<bool> {
+    let raw = fs::read_to_string(path)
+        .map_err(|err| OperationError::io(path.display().to_string(), err))?;
 
     let mut root: toml::Table = toml::from_str(&raw).map_err(|err| OperationError::Config {
         key: path.display().to_string(),
@@ -286,20 +432,16 @@ fn update_codex_context7_config(path: &Path, api_key: &str) -> Result<bool> {
             key: path.display().to_string(),
             message: crate::tr!(keys::MCP_EXECUTOR_CONFIG_SERIALIZE_FAILED, error = err),
         })?;
-        fs::write(path, format!("{}\n", formatted)).map_err(|err| OperationError::Io {
-            path: path.display().to_string(),
-            source: err,
-        })?;
+        fs::write(path, format!("{}\n", formatted))
+            .map_err(|err| OperationError::io(path.display().to_string(), err))?;
     }
 
     Ok(changed)
 }
 
 fn update_codex_github_config(path: &Path, token: &str, host: &str) -> Result<bool> {
-    let raw = fs::read_to_string(path).map_err(|err| OperationError::Io {
-        path: path.display().to_string(),
-        source: err,
-    })?;
+    let raw = fs::read_to_string(path)
+        .map_err(|err| OperationError::io(path.display().to_string(), err))?;
 
     let mut root: toml::Table = toml::from_str(&raw).map_err(|err| OperationError::Config {
         key: path.display().to_string(),
@@ -367,10 +509,8 @@ fn update_codex_github_config(path: &Path, token: &str, host: &str) -> Result<bo
             key: path.display().to_string(),
             message: crate::tr!(keys::MCP_EXECUTOR_CONFIG_SERIALIZE_FAILED, error = err),
         })?;
-        fs::write(path, format!("{}\n", formatted)).map_err(|err| OperationError::Io {
-            path: path.display().to_string(),
-            source: err,
-        })?;
+        fs::write(path, format!("{}\n", formatted))
+            .map_err(|err| OperationError::io(path.display().to_string(), err))?;
     }
 
     Ok(changed)
@@ -453,6 +593,54 @@ mod tests {
         assert_eq!(result, vec!["sequential-thinking".to_string()]);
     }
 
+    #[test]
+    fn test_find_claude_server_prefers_project_scope() {
+        let root = serde_json::json!({
+            "mcpServers": {
+                "context7": {"type": "http", "url": "https://user.example"}
+            },
+            "projects": {
+                "/work": {
+                    "mcpServers": {
+                        "context7": {"type": "http", "url": "https://local.example"}
+                    }
+                }
+            }
+        });
+
+        let (scope, definition) = find_claude_server(&root, "/work", "context7").unwrap();
+        assert_eq!(scope, "local");
+        assert_eq!(definition["url"], "https://local.example");
+
+        let (scope, definition) = find_claude_server(&root, "/other", "context7").unwrap();
+        assert_eq!(scope, "user");
+        assert_eq!(definition["url"], "https://user.example");
+
+        assert!(find_claude_server(&root, "/work", "github").is_none());
+    }
+
+    #[test]
+    fn test_restore_codex_server_reinserts_definition() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        let content = r#"model = "o3"
+
+[mcp_servers.context7]
+url = "https://mcp.context7.com/mcp"
+"#;
+        fs::write(&path, content).unwrap();
+
+        let root = parse_codex_config(&path).unwrap();
+        let definition = root["mcp_servers"]["context7"].clone();
+
+        fs::write(&path, "model = \"o3\"\n").unwrap();
+        restore_codex_server(&path, "context7", &definition).unwrap();
+
+        let restored = parse_codex_config(&path).unwrap();
+        assert_eq!(restored["model"].as_str(), Some("o3"));
+        assert_eq!(restored["mcp_servers"]["context7"], definition);
+    }
+
     #[test]
     fn test_update_codex_context7_config_sets_http_headers() {
         let dir = tempfile::tempdir().unwrap();
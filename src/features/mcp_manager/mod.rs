@@ -1,11 +1,14 @@
 mod config;
+mod custom;
 mod executor;
+mod profile;
 mod tools;
 
+use crate::core::{AppConfig, dry_run, load_config, save_config};
 use crate::i18n::{self, keys};
-use crate::ui::{Console, Prompts};
+use crate::ui::{Console, PromptError, Prompts};
 use executor::McpExecutor;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use tools::{CliType, McpTool, McpToolOptions, get_available_tools};
 
 /// 執行 MCP 管理功能
@@ -16,13 +19,15 @@ pub fn run() {
     console.header(i18n::t(keys::MCP_MANAGER_HEADER));
 
     // 選擇 CLI 類型
-    let cli_options = ["Anthropic Claude", "OpenAI Codex"];
+    let cli_options = ["Anthropic Claude", "OpenAI Codex", i18n::t(keys::MENU_BACK)];
     let cli_selection = prompts.select(i18n::t(keys::MCP_MANAGER_SELECT_CLI), &cli_options);
 
+    // 選「返回」直接回主選單；Esc 取消才提示已取消
     let cli = match cli_selection {
         Some(0) => CliType::Claude,
         Some(1) => CliType::Codex,
-        _ => {
+        Some(_) => return,
+        None => {
             console.warning(i18n::t(keys::MCP_MANAGER_CANCELLED));
             return;
         }
@@ -56,14 +61,91 @@ pub fn run() {
     console.separator();
 
     // 顯示可用工具
-    let available_tools = get_available_tools(cli);
+    let mut available_tools = get_available_tools(cli);
+    let custom = custom::load_custom_tools(cli, &available_tools);
+    for warning in &custom.warnings {
+        console.warning(warning);
+    }
+    if let Some(path) = &custom.path
+        && !custom.tools.is_empty()
+    {
+        console.info(&crate::tr_plural!(
+            keys::MCP_MANAGER_CUSTOM_LOADED,
+            count = custom.tools.len(),
+            path = path.display()
+        ));
+    }
+    available_tools.extend(custom.tools);
+
+    let app_config = load_config().ok().flatten().unwrap_or_default();
+
+    console.blank_line();
+    let actions = [
+        i18n::t(keys::MCP_MANAGER_ACTION_MANAGE),
+        i18n::t(keys::MCP_MANAGER_ACTION_EXPORT),
+        i18n::t(keys::MCP_MANAGER_ACTION_APPLY),
+        i18n::t(keys::MENU_BACK),
+    ];
+    match prompts.select(i18n::t(keys::MCP_MANAGER_ACTION_PROMPT), &actions) {
+        Some(0) => run_manage(
+            &console,
+            &prompts,
+            &executor,
+            &installed,
+            &available_tools,
+            &app_config,
+        ),
+        Some(1) => export_profile(&console, &prompts, cli, &installed, &available_tools),
+        Some(2) => apply_profile(
+            &console,
+            &prompts,
+            &executor,
+            &installed,
+            &available_tools,
+            &app_config,
+        ),
+        Some(_) => {}
+        None => console.warning(i18n::t(keys::MCP_MANAGER_CANCELLED)),
+    }
+}
+
+/// 勾選要安裝/移除的工具
+fn run_manage(
+    console: &Console,
+    prompts: &Prompts,
+    executor: &McpExecutor,
+    installed: &[String],
+    available_tools: &[McpTool],
+    app_config: &AppConfig,
+) {
+    let cli = executor.cli();
+
+    // 已安裝且定義與安裝時不同（例如 Token 已更換）或沒有記錄的工具
+    let states: HashMap<&str, DefinitionState> = available_tools
+        .iter()
+        .filter(|mcp| installed.contains(&mcp.name))
+        .map(|mcp| (mcp.name.as_str(), definition_state(app_config, cli, mcp)))
+        .collect();
+
     let items: Vec<String> = available_tools
         .iter()
         .map(|mcp| {
-            let status = if installed.contains(&mcp.name.to_string()) {
-                i18n::t(keys::MCP_MANAGER_STATUS_INSTALLED)
-            } else {
-                i18n::t(keys::MCP_MANAGER_STATUS_MISSING)
+            let missing_env = mcp.missing_env();
+            let status = match states.get(mcp.name.as_str()) {
+                Some(DefinitionState::Changed) => {
+                    i18n::t(keys::MCP_MANAGER_STATUS_CHANGED).to_string()
+                }
+                Some(DefinitionState::Untracked) => {
+                    i18n::t(keys::MCP_MANAGER_STATUS_UNTRACKED).to_string()
+                }
+                Some(DefinitionState::Current) => {
+                    i18n::t(keys::MCP_MANAGER_STATUS_INSTALLED).to_string()
+                }
+                None if !missing_env.is_empty() => crate::tr!(
+                    keys::MCP_MANAGER_STATUS_NEEDS_ENV,
+                    vars = missing_env.join(", ")
+                ),
+                None => i18n::t(keys::MCP_MANAGER_STATUS_MISSING).to_string(),
             };
             format!("{} {}", status, mcp.display_name())
         })
@@ -71,33 +153,96 @@ pub fn run() {
 
     let defaults: Vec<bool> = available_tools
         .iter()
-        .map(|mcp| installed.contains(&mcp.name.to_string()))
+        .map(|mcp| installed.contains(&mcp.name))
         .collect();
 
+    console.blank_line();
+    let Some(visible) = ask_filter(console, &items) else {
+        console.warning(i18n::t(keys::MCP_MANAGER_CANCELLED));
+        return;
+    };
+
     console.blank_line();
     console.info(i18n::t(keys::MCP_MANAGER_SELECT_INSTALL));
     console.info(i18n::t(keys::MCP_MANAGER_SELECT_HELP));
     console.blank_line();
 
-    let selections =
-        prompts.multi_select(i18n::t(keys::MCP_MANAGER_SELECT_PROMPT), &items, &defaults);
+    let visible_items: Vec<String> = visible.iter().map(|&i| items[i].clone()).collect();
+    let visible_defaults: Vec<bool> = visible.iter().map(|&i| defaults[i]).collect();
+    let Some(chosen) = prompts.multi_select(
+        i18n::t(keys::MCP_MANAGER_SELECT_PROMPT),
+        &visible_items,
+        &visible_defaults,
+    ) else {
+        console.warning(i18n::t(keys::MCP_MANAGER_CANCELLED));
+        return;
+    };
+
+    // 篩選後看不到的工具維持原狀，差異一律以完整清單計算
+    let mut selections: HashSet<usize> = (0..available_tools.len())
+        .filter(|&i| defaults[i])
+        .collect();
+    for (view_index, &i) in visible.iter().enumerate() {
+        if chosen.contains(&view_index) {
+            selections.insert(i);
+        } else {
+            selections.remove(&i);
+        }
+    }
 
     // 計算需要安裝和移除的項目
     let mut to_install: Vec<&McpTool> = Vec::new();
     let mut to_remove: Vec<&McpTool> = Vec::new();
+    let mut outdated: Vec<&McpTool> = Vec::new();
 
     for (i, mcp) in available_tools.iter().enumerate() {
         let is_selected = selections.contains(&i);
-        let is_installed = installed.contains(&mcp.name.to_string());
+        let is_installed = installed.contains(&mcp.name);
 
         if is_selected && !is_installed {
             to_install.push(mcp);
         } else if !is_selected && is_installed {
             to_remove.push(mcp);
+        } else if is_selected
+            && states
+                .get(mcp.name.as_str())
+                .is_some_and(|state| *state != DefinitionState::Current)
+        {
+            outdated.push(mcp);
         }
     }
 
-    if to_install.is_empty() && to_remove.is_empty() {
+    // 缺少必要環境變數的工具不安裝，提示使用者需設定的變數
+    to_install.retain(|mcp| warn_if_missing_env(mcp, console));
+    outdated.retain(|mcp| warn_if_missing_env(mcp, console));
+
+    let Some(to_reinstall) = select_reinstall(console, prompts, app_config, cli, &outdated) else {
+        console.warning(i18n::t(keys::MCP_MANAGER_CANCELLED));
+        return;
+    };
+
+    apply_changes(
+        console,
+        prompts,
+        executor,
+        &to_install,
+        &to_remove,
+        &to_reinstall,
+    );
+}
+
+/// 顯示變更摘要，確認後執行安裝、移除與重新安裝
+fn apply_changes(
+    console: &Console,
+    prompts: &Prompts,
+    executor: &McpExecutor,
+    to_install: &[&McpTool],
+    to_remove: &[&McpTool],
+    to_reinstall: &[&McpTool],
+) {
+    let cli = executor.cli();
+
+    if to_install.is_empty() && to_remove.is_empty() && to_reinstall.is_empty() {
         console.blank_line();
         console.success(i18n::t(keys::MCP_MANAGER_NO_CHANGES));
         return;
@@ -110,19 +255,30 @@ pub fn run() {
 
     if !to_install.is_empty() {
         console.success(i18n::t(keys::MCP_MANAGER_WILL_INSTALL));
-        for mcp in &to_install {
+        for mcp in to_install {
             console.list_item("➕", mcp.display_name());
         }
     }
 
     if !to_remove.is_empty() {
         console.warning(i18n::t(keys::MCP_MANAGER_WILL_REMOVE));
-        for mcp in &to_remove {
+        for mcp in to_remove {
             console.list_item("➖", mcp.display_name());
         }
     }
 
+    if !to_reinstall.is_empty() {
+        console.info(i18n::t(keys::MCP_MANAGER_WILL_REINSTALL));
+        for mcp in to_reinstall {
+            console.list_item("🔄", mcp.display_name());
+        }
+    }
+
     console.blank_line();
+    if dry_run::is_enabled() {
+        console.info(i18n::t(keys::MCP_MANAGER_DRY_RUN));
+        return;
+    }
     if !prompts.confirm(i18n::t(keys::MCP_MANAGER_CONFIRM_CHANGES)) {
         console.warning(i18n::t(keys::MCP_MANAGER_CANCELLED));
         return;
@@ -132,7 +288,7 @@ pub fn run() {
 
     // 為有選項的工具收集配置
     let mut tool_options: HashMap<&str, McpToolOptions> = HashMap::new();
-    for mcp in &to_install {
+    for mcp in to_install.iter().chain(to_reinstall.iter()) {
         if mcp.has_options && mcp.name == "chrome-devtools" {
             console.info(&crate::tr!(
                 keys::MCP_MANAGER_CONFIGURE_TOOL,
@@ -152,7 +308,7 @@ pub fn run() {
                 _ => true, // 預設使用 headless
             };
             tool_options.insert(
-                mcp.name,
+                mcp.name.as_str(),
                 McpToolOptions {
                     headless: Some(headless),
                 },
@@ -161,7 +317,11 @@ pub fn run() {
         }
     }
 
-    if to_install.iter().any(|mcp| mcp.requires_interactive) {
+    if to_install
+        .iter()
+        .chain(to_reinstall.iter())
+        .any(|mcp| mcp.requires_interactive)
+    {
         console.info(i18n::t(keys::MCP_MANAGER_OAUTH_HINT));
         console.info(i18n::t(keys::MCP_MANAGER_WSL_HINT));
         console.blank_line();
@@ -170,22 +330,27 @@ pub fn run() {
     // 執行安裝和移除
     let mut success_count = 0;
     let mut failed_count = 0;
-    let total_operations = to_install.len() + to_remove.len();
-
-    for (i, mcp) in to_install.iter().enumerate() {
-        console.show_progress(
-            i + 1,
-            total_operations,
-            &crate::tr!(keys::MCP_MANAGER_INSTALLING, tool = mcp.display_name()),
-        );
+    let total_operations = to_install.len() + to_remove.len() + to_reinstall.len();
+    let mut fingerprints: Vec<(&str, Option<String>)> = Vec::new();
+    let mut progress = console.progress_bar(total_operations);
+
+    for mcp in to_install.iter() {
+        progress.tick(&crate::tr!(
+            keys::MCP_MANAGER_INSTALLING,
+            tool = mcp.display_name()
+        ));
 
-        let options = tool_options.get(mcp.name).cloned().unwrap_or_default();
+        let options = tool_options
+            .get(mcp.name.as_str())
+            .cloned()
+            .unwrap_or_default();
         match executor.install(mcp, &options) {
             Ok(()) => {
                 console.success_item(&crate::tr!(
                     keys::MCP_MANAGER_INSTALL_SUCCESS,
                     tool = mcp.display_name()
                 ));
+                fingerprints.push((mcp.name.as_str(), Some(mcp.fingerprint())));
                 success_count += 1;
             }
             Err(err) => {
@@ -198,19 +363,19 @@ pub fn run() {
         }
     }
 
-    for (i, mcp) in to_remove.iter().enumerate() {
-        console.show_progress(
-            to_install.len() + i + 1,
-            total_operations,
-            &crate::tr!(keys::MCP_MANAGER_REMOVING, tool = mcp.display_name()),
-        );
+    for mcp in to_remove.iter() {
+        progress.tick(&crate::tr!(
+            keys::MCP_MANAGER_REMOVING,
+            tool = mcp.display_name()
+        ));
 
-        match executor.remove(mcp.name) {
+        match executor.remove(&mcp.name) {
             Ok(()) => {
                 console.success_item(&crate::tr!(
                     keys::MCP_MANAGER_REMOVE_SUCCESS,
                     tool = mcp.display_name()
                 ));
+                fingerprints.push((mcp.name.as_str(), None));
                 success_count += 1;
             }
             Err(err) => {
@@ -223,6 +388,70 @@ pub fn run() {
         }
     }
 
+    for mcp in to_reinstall.iter() {
+        progress.tick(&crate::tr!(
+            keys::MCP_MANAGER_REINSTALLING,
+            tool = mcp.display_name()
+        ));
+
+        let options = tool_options
+            .get(mcp.name.as_str())
+            .cloned()
+            .unwrap_or_default();
+        // 移除前保存既有定義，新定義安裝失敗時還原，避免工具就此消失
+        let previous = executor.snapshot(&mcp.name).ok().flatten();
+        let removed = executor.remove(&mcp.name);
+        match removed.and_then(|()| executor.install(mcp, &options)) {
+            Ok(()) => {
+                console.success_item(&crate::tr!(
+                    keys::MCP_MANAGER_REINSTALL_SUCCESS,
+                    tool = mcp.display_name()
+                ));
+                fingerprints.push((mcp.name.as_str(), Some(mcp.fingerprint())));
+                success_count += 1;
+            }
+            Err(err) => {
+                console.error_item(
+                    &crate::tr!(
+                        keys::MCP_MANAGER_REINSTALL_FAILED,
+                        tool = mcp.display_name()
+                    ),
+                    &err.to_string(),
+                );
+                failed_count += 1;
+                if executor
+                    .list_installed()
+                    .is_ok_and(|now| now.contains(&mcp.name))
+                {
+                    continue;
+                }
+                match previous.map(|snapshot| executor.restore(&mcp.name, &snapshot)) {
+                    Some(Ok(())) => console.warning(&crate::tr!(
+                        keys::MCP_MANAGER_REINSTALL_RESTORED,
+                        tool = mcp.display_name()
+                    )),
+                    restore => {
+                        console.error_item(
+                            &crate::tr!(
+                                keys::MCP_MANAGER_REINSTALL_LOST,
+                                tool = mcp.display_name()
+                            ),
+                            &restore
+                                .and_then(|result| result.err())
+                                .map(|err| err.to_string())
+                                .unwrap_or_default(),
+                        );
+                        fingerprints.push((mcp.name.as_str(), None));
+                    }
+                }
+            }
+        }
+    }
+
+    progress.finish();
+
+    record_fingerprints(cli, &fingerprints, console);
+
     console.show_summary(
         i18n::t(keys::MCP_MANAGER_SUMMARY),
         success_count,
@@ -230,6 +459,249 @@ pub fn run() {
     );
 }
 
+/// 將已安裝的 MCP 匯出成可分享的檔案
+fn export_profile(
+    console: &Console,
+    prompts: &Prompts,
+    cli: CliType,
+    installed: &[String],
+    available: &[McpTool],
+) {
+    let default = format!("mcp-{}.toml", cli.command());
+    let Some(path) = prompts.input_path(
+        i18n::t(keys::MCP_MANAGER_EXPORT_PATH_PROMPT),
+        Some(&default),
+    ) else {
+        console.warning(i18n::t(keys::MCP_MANAGER_CANCELLED));
+        return;
+    };
+
+    match profile::export(&path, cli, installed, available) {
+        Ok(result) => {
+            for name in &result.skipped {
+                console.warning(&crate::tr!(keys::MCP_MANAGER_EXPORT_SKIPPED, name = name));
+            }
+            console.success(&crate::tr_plural!(
+                keys::MCP_MANAGER_EXPORT_DONE,
+                count = result.count,
+                path = path.display()
+            ));
+        }
+        Err(err) => console.error(&crate::tr!(keys::MCP_MANAGER_EXPORT_FAILED, error = err)),
+    }
+}
+
+/// 依匯出檔安裝缺少的工具並移除檔案中沒有的工具
+fn apply_profile(
+    console: &Console,
+    prompts: &Prompts,
+    executor: &McpExecutor,
+    installed: &[String],
+    available: &[McpTool],
+    app_config: &AppConfig,
+) {
+    let cli = executor.cli();
+    let Some(path) = prompts.input_path(i18n::t(keys::MCP_MANAGER_APPLY_PATH_PROMPT), None) else {
+        console.warning(i18n::t(keys::MCP_MANAGER_CANCELLED));
+        return;
+    };
+
+    let profile = match profile::load(&path, cli, available) {
+        Ok(profile) => profile,
+        Err(err) => {
+            console.error(&crate::tr!(keys::MCP_MANAGER_APPLY_FAILED, error = err));
+            return;
+        }
+    };
+    for warning in &profile.warnings {
+        console.warning(warning);
+    }
+
+    // 未由本工具管理的伺服器只有名稱，僅在使用者同意時移除
+    let mut unmanaged: Vec<McpTool> = installed
+        .iter()
+        .filter(|name| !available.iter().any(|mcp| &mcp.name == *name))
+        .filter(|name| !profile.tools.iter().any(|wanted| &wanted.name == *name))
+        .map(|name| McpTool::unmanaged(name))
+        .collect();
+    if !unmanaged.is_empty() {
+        let names: Vec<&str> = unmanaged.iter().map(|mcp| mcp.display_name()).collect();
+        if !prompts.confirm_with_options(
+            &crate::tr!(keys::MCP_MANAGER_APPLY_UNMANAGED, names = names.join(", ")),
+            false,
+        ) {
+            unmanaged.clear();
+        }
+    }
+
+    let to_install: Vec<&McpTool> = profile
+        .tools
+        .iter()
+        .filter(|mcp| !installed.contains(&mcp.name))
+        .filter(|mcp| warn_if_missing_env(mcp, console))
+        .collect();
+    let to_remove: Vec<&McpTool> = available
+        .iter()
+        .chain(unmanaged.iter())
+        .filter(|mcp| installed.contains(&mcp.name))
+        .filter(|mcp| !profile.tools.iter().any(|wanted| wanted.name == mcp.name))
+        .collect();
+    let outdated: Vec<&McpTool> = profile
+        .tools
+        .iter()
+        .filter(|mcp| installed.contains(&mcp.name))
+        .filter(|mcp| definition_state(app_config, cli, mcp) != DefinitionState::Current)
+        .filter(|mcp| warn_if_missing_env(mcp, console))
+        .collect();
+    let Some(to_reinstall) = select_reinstall(console, prompts, app_config, cli, &outdated) else {
+        console.warning(i18n::t(keys::MCP_MANAGER_CANCELLED));
+        return;
+    };
+
+    apply_changes(
+        console,
+        prompts,
+        executor,
+        &to_install,
+        &to_remove,
+        &to_reinstall,
+    );
+}
+
+/// 已安裝工具的定義與安裝時記錄的雜湊比較結果
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum DefinitionState {
+    Current,
+    Changed,
+    /// 沒有記錄（在本工具之外安裝，或早於雜湊記錄的版本），無法判斷是否變更
+    Untracked,
+}
+
+fn definition_state(config: &AppConfig, cli: CliType, mcp: &McpTool) -> DefinitionState {
+    match config.mcp_fingerprint(cli.command(), &mcp.name) {
+        None => DefinitionState::Untracked,
+        Some(recorded) if recorded != mcp.fingerprint() => DefinitionState::Changed,
+        Some(_) => DefinitionState::Current,
+    }
+}
+
+/// 詢問要重新安裝（先移除再新增）哪些工具；只預設勾選確定已變更的，沒有記錄的需自行勾選
+///
+/// 取消時回傳 None
+fn select_reinstall<'a>(
+    console: &Console,
+    prompts: &Prompts,
+    config: &AppConfig,
+    cli: CliType,
+    outdated: &[&'a McpTool],
+) -> Option<Vec<&'a McpTool>> {
+    if outdated.is_empty() {
+        return Some(Vec::new());
+    }
+    let states: Vec<DefinitionState> = outdated
+        .iter()
+        .map(|mcp| definition_state(config, cli, mcp))
+        .collect();
+    let items: Vec<String> = outdated
+        .iter()
+        .zip(&states)
+        .map(|(mcp, state)| match state {
+            DefinitionState::Untracked => crate::tr!(
+                keys::MCP_MANAGER_REINSTALL_UNTRACKED,
+                tool = mcp.display_name()
+            ),
+            _ => mcp.display_name().to_string(),
+        })
+        .collect();
+    let defaults: Vec<bool> = states
+        .iter()
+        .map(|state| *state == DefinitionState::Changed)
+        .collect();
+
+    console.blank_line();
+    let prompt = i18n::t(keys::MCP_MANAGER_SELECT_REINSTALL);
+    let chosen = match prompts.try_multi_select(prompt, &items, &defaults) {
+        Ok(chosen) => chosen,
+        // `--yes` 且沒有預設勾選的項目：不重新安裝任何工具，其餘變更照常進行
+        Err(PromptError::NoDefault) => Vec::new(),
+        Err(PromptError::Cancelled) => return None,
+        Err(err) => {
+            console.error(&format!("{prompt} — {err}"));
+            return None;
+        }
+    };
+    Some(chosen.into_iter().map(|index| outdated[index]).collect())
+}
+
+/// 詢問篩選字串，回傳符合的工具索引（空白表示全部）；取消時回傳 None
+fn ask_filter(console: &Console, items: &[String]) -> Option<Vec<usize>> {
+    use dialoguer::{Input, theme::ColorfulTheme};
+
+    loop {
+        let query: String = Input::with_theme(&ColorfulTheme::default())
+            .with_prompt(i18n::t(keys::MCP_MANAGER_FILTER_PROMPT))
+            .allow_empty(true)
+            .interact_text()
+            .ok()?;
+
+        let visible = filter_items(items, query.trim());
+        if !visible.is_empty() {
+            return Some(visible);
+        }
+        console.warning(&crate::tr!(
+            keys::MCP_MANAGER_FILTER_NO_MATCH,
+            query = query.trim()
+        ));
+    }
+}
+
+/// 依模糊比對（字元依序出現，不分大小寫）篩選項目
+fn filter_items(items: &[String], query: &str) -> Vec<usize> {
+    items
+        .iter()
+        .enumerate()
+        .filter(|(_, item)| fuzzy_matches(item, query))
+        .map(|(i, _)| i)
+        .collect()
+}
+
+fn fuzzy_matches(text: &str, query: &str) -> bool {
+    let mut text = text.chars().flat_map(char::to_lowercase);
+    query
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .all(|wanted| text.any(|c| c == wanted))
+}
+
+/// 若缺少必要環境變數則顯示警告並回傳 false
+fn warn_if_missing_env(mcp: &McpTool, console: &Console) -> bool {
+    let missing = mcp.missing_env();
+    if missing.is_empty() {
+        return true;
+    }
+    console.warning(&crate::tr!(
+        keys::MCP_MANAGER_SKIP_MISSING_ENV,
+        tool = mcp.display_name(),
+        vars = missing.join(", ")
+    ));
+    false
+}
+
+/// 保存安裝時的定義雜湊，供下次偵測定義變更
+fn record_fingerprints(cli: CliType, updates: &[(&str, Option<String>)], console: &Console) {
+    if updates.is_empty() {
+        return;
+    }
+    let mut config = load_config().ok().flatten().unwrap_or_default();
+    for (name, fingerprint) in updates {
+        config.set_mcp_fingerprint(cli.command(), name, fingerprint.clone());
+    }
+    if let Err(err) = save_config(&config) {
+        console.warning(&crate::tr!(keys::CONFIG_SAVE_FAILED, error = err));
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::tools::{CliType, get_available_tools};
@@ -239,4 +711,44 @@ mod tests {
         let tools = get_available_tools(CliType::Claude);
         assert!(!tools.is_empty());
     }
+
+    #[test]
+    fn test_fuzzy_filter() {
+        let items = [
+            "[installed] GitHub".to_string(),
+            "[missing] Chrome DevTools".to_string(),
+            "[missing] Context7".to_string(),
+        ];
+
+        assert_eq!(super::filter_items(&items, ""), [0, 1, 2]);
+        assert_eq!(super::filter_items(&items, "git"), [0]);
+        assert_eq!(super::filter_items(&items, "cdt"), [1]);
+        assert_eq!(super::filter_items(&items, "CONTEXT 7"), [2]);
+        assert_eq!(super::filter_items(&items, "installed"), [0]);
+        assert!(super::filter_items(&items, "xyz").is_empty());
+    }
+
+    #[test]
+    fn test_definition_state() {
+        use super::{DefinitionState, definition_state};
+
+        let mcp = get_available_tools(CliType::Claude).remove(0);
+        let mut config = crate::core::AppConfig::default();
+        assert_eq!(
+            definition_state(&config, CliType::Claude, &mcp),
+            DefinitionState::Untracked
+        );
+
+        config.set_mcp_fingerprint("claude", &mcp.name, Some(mcp.fingerprint()));
+        assert_eq!(
+            definition_state(&config, CliType::Claude, &mcp),
+            DefinitionState::Current
+        );
+
+        config.set_mcp_fingerprint("claude", &mcp.name, Some("stale".to_string()));
+        assert_eq!(
+            definition_state(&config, CliType::Claude, &mcp),
+            DefinitionState::Changed
+        );
+    }
 }
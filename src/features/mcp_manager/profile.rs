@@ -0,0 +1,215 @@
+//! MCP 設定檔匯出/套用
+//!
+//! 將目前安裝的 MCP 伺服器寫成可版控的 TOML，內建工具只記錄名稱（Token 於套用時從環境變數取得），
+//! 自訂工具則保留完整定義
+
+use super::custom::{self, CustomEntry};
+use super::tools::{CliType, McpTool};
+use crate::core::{OperationError, Result};
+use crate::i18n::keys;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+/// 匯出檔內容
+#[derive(Debug, Default, Deserialize, Serialize)]
+struct ProfileFile {
+    /// 匯出時使用的 CLI（claude / codex）
+    cli: String,
+    #[serde(default)]
+    server: Vec<CustomEntry>,
+}
+
+/// 匯出結果
+pub struct ExportResult {
+    pub count: usize,
+    /// 找不到定義而未匯出的伺服器名稱
+    pub skipped: Vec<String>,
+}
+
+/// 套用檔案後解析出的目標工具
+#[derive(Default)]
+pub struct Profile {
+    pub tools: Vec<McpTool>,
+    /// 無法解析或被略過的項目說明
+    pub warnings: Vec<String>,
+}
+
+/// 將已安裝的 MCP 寫入檔案
+pub fn export(
+    path: &Path,
+    cli: CliType,
+    installed: &[String],
+    available: &[McpTool],
+) -> Result<ExportResult> {
+    let (file, skipped) = build_profile(cli, installed, available);
+    let content = toml::to_string_pretty(&file).map_err(|err| OperationError::Config {
+        key: path.display().to_string(),
+        message: err.to_string(),
+    })?;
+    fs::write(path, content).map_err(|err| OperationError::io(path.display().to_string(), err))?;
+    Ok(ExportResult {
+        count: file.server.len(),
+        skipped,
+    })
+}
+
+fn build_profile(
+    cli: CliType,
+    installed: &[String],
+    available: &[McpTool],
+) -> (ProfileFile, Vec<String>) {
+    let mut server = Vec::new();
+    let mut skipped = Vec::new();
+
+    for name in installed {
+        match available.iter().find(|tool| &tool.name == name) {
+            Some(tool) if tool.custom_display_name.is_some() => server.push(CustomEntry {
+                name: tool.name.clone(),
+                display_name: tool.custom_display_name.clone(),
+                supported_cli: vec![cli.command().to_string()],
+                install_args: tool.install_args.clone(),
+                requires_interactive: tool.requires_interactive,
+                required_env: tool.required_env.clone(),
+            }),
+            Some(tool) => server.push(CustomEntry {
+                name: tool.name.clone(),
+                ..CustomEntry::default()
+            }),
+            None => skipped.push(name.clone()),
+        }
+    }
+
+    let file = ProfileFile {
+        cli: cli.command().to_string(),
+        server,
+    };
+    (file, skipped)
+}
+
+/// 讀取匯出檔並解析出要安裝的工具；本機已有的定義優先
+pub fn load(path: &Path, cli: CliType, available: &[McpTool]) -> Result<Profile> {
+    let content = fs::read_to_string(path)
+        .map_err(|err| OperationError::io(path.display().to_string(), err))?;
+    let file: ProfileFile = toml::from_str(&content).map_err(|err| OperationError::Config {
+        key: path.display().to_string(),
+        message: err.to_string(),
+    })?;
+
+    if !file.cli.eq_ignore_ascii_case(cli.command()) {
+        return Err(OperationError::Validation(crate::tr!(
+            keys::MCP_MANAGER_PROFILE_CLI_MISMATCH,
+            path = path.display(),
+            expected = &file.cli,
+            cli = cli.command()
+        )));
+    }
+
+    Ok(resolve(file.server, cli, available))
+}
+
+fn resolve(entries: Vec<CustomEntry>, cli: CliType, available: &[McpTool]) -> Profile {
+    let mut profile = Profile::default();
+    let mut definitions = Vec::new();
+
+    for entry in entries {
+        let name = entry.name.trim();
+        if let Some(tool) = available.iter().find(|tool| tool.name == name) {
+            if !profile.tools.iter().any(|existing| existing.name == name) {
+                profile.tools.push(tool.clone());
+            }
+        } else if entry.install_args.is_empty() {
+            profile
+                .warnings
+                .push(crate::tr!(keys::MCP_MANAGER_PROFILE_UNKNOWN, name = name));
+        } else {
+            definitions.push(entry);
+        }
+    }
+
+    let custom = custom::convert_entries(definitions, cli, &profile.tools);
+    profile.tools.extend(custom.tools);
+    profile.warnings.extend(custom.warnings);
+    profile
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::mcp_manager::tools::get_available_tools;
+
+    fn custom_tool(name: &str) -> McpTool {
+        McpTool {
+            name: name.to_string(),
+            display_name_key: keys::MCP_TOOL_CUSTOM,
+            custom_display_name: Some("Internal Docs".to_string()),
+            install_args: vec!["add".to_string(), name.to_string()],
+            requires_interactive: false,
+            has_options: false,
+            required_env: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_export_keeps_only_names_for_builtin_tools() {
+        let mut available = get_available_tools(CliType::Claude);
+        let builtin = available[0].name.clone();
+        available.push(custom_tool("internal-docs"));
+        let installed = vec![
+            builtin.clone(),
+            "internal-docs".to_string(),
+            "hand-added".to_string(),
+        ];
+
+        let (file, skipped) = build_profile(CliType::Claude, &installed, &available);
+
+        assert_eq!(file.cli, "claude");
+        assert_eq!(skipped, ["hand-added"]);
+        assert_eq!(file.server[0].name, builtin);
+        assert!(file.server[0].install_args.is_empty());
+        assert_eq!(file.server[1].install_args, ["add", "internal-docs"]);
+
+        let content = toml::to_string_pretty(&file).unwrap();
+        let parsed: ProfileFile = toml::from_str(&content).unwrap();
+        assert_eq!(parsed.server.len(), 2);
+    }
+
+    #[test]
+    fn test_resolve_prefers_local_definitions() {
+        let available = get_available_tools(CliType::Claude);
+        let builtin = available[0].name.clone();
+        let file: ProfileFile = toml::from_str(&format!(
+            r#"
+cli = "claude"
+
+[[server]]
+name = "{builtin}"
+
+[[server]]
+name = "internal-docs"
+install_args = ["add", "internal-docs"]
+
+[[server]]
+name = "missing"
+"#
+        ))
+        .unwrap();
+
+        let profile = resolve(file.server, CliType::Claude, &available);
+
+        let names: Vec<&str> = profile.tools.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, [builtin.as_str(), "internal-docs"]);
+        assert_eq!(profile.tools[0].install_args, available[0].install_args);
+        assert_eq!(profile.warnings.len(), 1);
+    }
+
+    #[test]
+    fn test_load_rejects_other_cli() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("mcp.toml");
+        fs::write(&path, "cli = \"codex\"\n").unwrap();
+
+        let result = load(&path, CliType::Claude, &[]);
+        assert!(matches!(result, Err(OperationError::Validation(_))));
+    }
+}
@@ -1,5 +1,6 @@
-use super::config::ENV_CONFIG;
+use super::config::{ENV_CONFIG, GITHUB_TOKEN_VAR, is_env_set};
 use crate::i18n::{self, keys};
+use sha2::{Digest, Sha256};
 
 /// MCP 工具配置選項
 #[derive(Clone, Default)]
@@ -11,12 +12,16 @@ pub struct McpToolOptions {
 /// MCP 工具定義
 #[derive(Clone)]
 pub struct McpTool {
-    pub name: &'static str,
+    pub name: String,
     pub display_name_key: &'static str,
+    /// 使用者自訂工具的顯示名稱（內建工具使用 `display_name_key`）
+    pub custom_display_name: Option<String>,
     pub install_args: Vec<String>,
     pub requires_interactive: bool,
     /// 工具是否有可配置選項（如 Chrome DevTools 的 headless 模式）
     pub has_options: bool,
+    /// 安裝前必須設定的環境變數
+    pub required_env: Vec<String>,
 }
 
 impl McpTool {
@@ -37,8 +42,47 @@ impl McpTool {
 }
 
 impl McpTool {
-    pub fn display_name(&self) -> &'static str {
-        i18n::t(self.display_name_key)
+    /// 已安裝但沒有定義的伺服器（僅能移除）
+    pub fn unmanaged(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            display_name_key: keys::MCP_TOOL_CUSTOM,
+            custom_display_name: Some(name.to_string()),
+            install_args: Vec::new(),
+            requires_interactive: false,
+            has_options: false,
+            required_env: Vec::new(),
+        }
+    }
+
+    /// 安裝定義的雜湊（不保存明文參數，避免寫入 Token）
+    pub fn fingerprint(&self) -> String {
+        let mut hasher = Sha256::new();
+        for arg in &self.install_args {
+            hasher.update(arg.as_bytes());
+            hasher.update([0]);
+        }
+        hasher.update([u8::from(self.requires_interactive)]);
+        hasher
+            .finalize()
+            .iter()
+            .map(|b| format!("{b:02x}"))
+            .collect()
+    }
+
+    /// 尚未設定的必要環境變數
+    pub fn missing_env(&self) -> Vec<&str> {
+        self.required_env
+            .iter()
+            .map(String::as_str)
+            .filter(|name| !is_env_set(name))
+            .collect()
+    }
+
+    pub fn display_name(&self) -> &str {
+        self.custom_display_name
+            .as_deref()
+            .unwrap_or_else(|| i18n::t(self.display_name_key))
     }
 }
 
@@ -157,8 +201,9 @@ pub fn get_available_tools(cli_type: CliType) -> Vec<McpTool> {
 
     let mut tools = vec![
         McpTool {
-            name: "sequential-thinking",
+            name: "sequential-thinking".to_string(),
             display_name_key: keys::MCP_TOOL_SEQUENTIAL_THINKING,
+            custom_display_name: None,
             install_args: {
                 let mut args = vec!["sequential-thinking".to_string()];
                 if let Some(sep) = separator {
@@ -173,10 +218,12 @@ pub fn get_available_tools(cli_type: CliType) -> Vec<McpTool> {
             },
             requires_interactive: false,
             has_options: false,
+            required_env: Vec::new(),
         },
         McpTool {
-            name: "chrome-devtools",
+            name: "chrome-devtools".to_string(),
             display_name_key: keys::MCP_TOOL_CHROME_DEVTOOLS,
+            custom_display_name: None,
             install_args: {
                 let mut args = vec!["chrome-devtools".to_string()];
                 if let Some(sep) = separator {
@@ -192,10 +239,12 @@ pub fn get_available_tools(cli_type: CliType) -> Vec<McpTool> {
             },
             requires_interactive: false,
             has_options: true,
+            required_env: Vec::new(),
         },
         McpTool {
-            name: "playwright",
+            name: "playwright".to_string(),
             display_name_key: keys::MCP_TOOL_PLAYWRIGHT,
+            custom_display_name: None,
             install_args: {
                 let mut args = vec!["playwright".to_string()];
                 if let Some(sep) = separator {
@@ -209,6 +258,7 @@ pub fn get_available_tools(cli_type: CliType) -> Vec<McpTool> {
             },
             requires_interactive: false,
             has_options: false,
+            required_env: Vec::new(),
         },
     ];
 
@@ -221,16 +271,18 @@ pub fn get_available_tools(cli_type: CliType) -> Vec<McpTool> {
         "-y".to_string(),
         "@upstash/context7-mcp".to_string(),
     ]);
-    if let Some(key) = ENV_CONFIG.context7_api_key {
+    if let Some(key) = ENV_CONFIG.context7_api_key.as_deref() {
         context7_args.push("--api-key".to_string());
         context7_args.push(key.to_string());
     }
     tools.push(McpTool {
-        name: "context7",
+        name: "context7".to_string(),
         display_name_key: keys::MCP_TOOL_CONTEXT7,
+        custom_display_name: None,
         install_args: context7_args,
         requires_interactive: false,
         has_options: false,
+        required_env: Vec::new(),
     });
 
     if ENV_CONFIG.enable_cloudflare_mcp() {
@@ -249,18 +301,22 @@ pub fn get_available_tools(cli_type: CliType) -> Vec<McpTool> {
                 ],
             };
             tools.push(McpTool {
-                name: tool.name,
+                name: tool.name.to_string(),
                 display_name_key: tool.display_name_key,
+                custom_display_name: None,
                 install_args: args,
                 requires_interactive: true,
                 has_options: false,
+                required_env: Vec::new(),
             });
         }
     }
 
-    if let Some(token) = ENV_CONFIG.github_token {
+    // 未設定 Token 時仍列出，但安裝前會提示需要設定的環境變數
+    {
+        let token = ENV_CONFIG.github_token.as_deref().unwrap_or_default();
         let mode = ENV_CONFIG.github_mcp_mode_value();
-        let host = ENV_CONFIG.github_host.unwrap_or("github.com");
+        let host = ENV_CONFIG.github_host_value();
 
         let install_args = if mode == "remote" {
             // 遠端模式：使用 GitHub 託管的 MCP 伺服器（官方推薦）
@@ -302,7 +358,7 @@ pub fn get_available_tools(cli_type: CliType) -> Vec<McpTool> {
                 args.push(format!("GITHUB_HOST=https://{}", host));
             }
             // 加入 GITHUB_TOOLSETS（功能集）
-            if let Some(toolsets) = ENV_CONFIG.github_toolsets {
+            if let Some(toolsets) = ENV_CONFIG.github_toolsets.as_deref() {
                 args.push("--env".to_string());
                 args.push(format!("GITHUB_TOOLSETS={}", toolsets));
             }
@@ -330,11 +386,13 @@ pub fn get_available_tools(cli_type: CliType) -> Vec<McpTool> {
         };
 
         tools.push(McpTool {
-            name: "github",
+            name: "github".to_string(),
             display_name_key: keys::MCP_TOOL_GITHUB,
+            custom_display_name: None,
             install_args,
             requires_interactive: mode == "remote",
             has_options: false,
+            required_env: vec![GITHUB_TOKEN_VAR.to_string()],
         });
     }
 
@@ -402,6 +460,41 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_fingerprint_tracks_install_args() {
+        let tool = get_available_tools(CliType::Claude)
+            .into_iter()
+            .find(|tool| tool.name == "playwright")
+            .expect("Missing playwright tool");
+        let mut changed = tool.clone();
+        changed.install_args.push("--token=rotated".to_string());
+
+        assert_eq!(tool.fingerprint(), tool.clone().fingerprint());
+        assert_ne!(tool.fingerprint(), changed.fingerprint());
+        assert!(!tool.fingerprint().contains("playwright"));
+    }
+
+    #[test]
+    fn test_github_requires_token_env() {
+        let github = get_available_tools(CliType::Claude)
+            .into_iter()
+            .find(|tool| tool.name == "github")
+            .expect("Missing github tool");
+        assert_eq!(github.required_env, [GITHUB_TOKEN_VAR]);
+    }
+
+    #[test]
+    fn test_missing_env_lists_unset_vars() {
+        let mut tool = get_available_tools(CliType::Claude)
+            .into_iter()
+            .find(|tool| tool.name == "playwright")
+            .expect("Missing playwright tool");
+        assert!(tool.missing_env().is_empty());
+
+        tool.required_env = vec!["OPS_TOOLS_TEST_UNSET_MCP_VAR".to_string()];
+        assert_eq!(tool.missing_env(), ["OPS_TOOLS_TEST_UNSET_MCP_VAR"]);
+    }
+
     #[test]
     fn test_display_name_uses_locale() {
         let _guard = i18n::test_lock();
@@ -1,5 +1,6 @@
 pub mod container_builder;
 pub mod cuda_builder;
+pub mod doctor;
 pub mod kubeconfig_manager;
 pub mod mcp_manager;
 pub mod package_manager;
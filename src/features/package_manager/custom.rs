@@ -0,0 +1,337 @@
+//! 使用者自訂套件
+//!
+//! 從設定目錄的 `packages-custom.toml` 讀取額外套件，依套件管理器指定安裝/更新/移除命令
+
+use super::types::{PackageAction, PackageDefinition, PackageManager};
+use crate::core::config::config_dir;
+use crate::i18n::keys;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// 使用者自訂套件定義檔名（位於設定目錄）
+pub const CUSTOM_FILE_NAME: &str = "packages-custom.toml";
+
+/// 未指定套件管理器時使用的命令鍵
+const DEFAULT_COMMAND_KEY: &str = "default";
+
+/// 命令表可用的鍵
+const COMMAND_KEYS: [&str; 9] = [
+    DEFAULT_COMMAND_KEY,
+    "brew",
+    "apt",
+    "dnf",
+    "yum",
+    "pacman",
+    "zypper",
+    "apk",
+    "pkg",
+];
+
+#[derive(Debug, Default, Deserialize)]
+struct CustomFile {
+    #[serde(default)]
+    package: Vec<CustomEntry>,
+}
+
+/// 單一自訂套件定義
+#[derive(Debug, Deserialize)]
+struct CustomEntry {
+    #[serde(default)]
+    name: String,
+    /// 用來判斷是否已安裝的命令（預設與 name 相同）
+    #[serde(default)]
+    binary: Option<String>,
+    /// 是否以 sudo 執行命令
+    #[serde(default)]
+    sudo: bool,
+    #[serde(default)]
+    install: HashMap<String, String>,
+    #[serde(default)]
+    update: HashMap<String, String>,
+    #[serde(default)]
+    remove: HashMap<String, String>,
+}
+
+/// 已驗證的自訂套件
+#[derive(Clone, Debug)]
+pub struct CustomPackage {
+    pub name: String,
+    pub binary: String,
+    pub sudo: bool,
+    install: HashMap<String, String>,
+    update: HashMap<String, String>,
+    remove: HashMap<String, String>,
+}
+
+impl CustomPackage {
+    /// 取得目前套件管理器對應的命令，沒有時改用 `default`；更新未設定時沿用安裝命令
+    pub fn command(&self, action: PackageAction, manager: Option<PackageManager>) -> Option<&str> {
+        match action {
+            PackageAction::Install => lookup(&self.install, manager),
+            PackageAction::Update => {
+                lookup(&self.update, manager).or_else(|| lookup(&self.install, manager))
+            }
+            PackageAction::Remove => lookup(&self.remove, manager),
+        }
+    }
+}
+
+fn lookup(commands: &HashMap<String, String>, manager: Option<PackageManager>) -> Option<&str> {
+    manager
+        .and_then(|manager| commands.get(manager.label()))
+        .or_else(|| commands.get(DEFAULT_COMMAND_KEY))
+        .map(String::as_str)
+}
+
+/// 自訂套件載入結果
+#[derive(Default)]
+pub struct CustomPackages {
+    pub path: Option<PathBuf>,
+    pub packages: Vec<CustomPackage>,
+    /// 無法解析或被略過的項目說明
+    pub warnings: Vec<String>,
+}
+
+/// 自訂套件定義檔路徑
+pub fn custom_file_path() -> Option<PathBuf> {
+    config_dir().map(|dir| dir.join(CUSTOM_FILE_NAME))
+}
+
+/// 讀取自訂套件，略過與內建套件同名或不完整的項目
+pub fn load_custom_packages(builtin: &[PackageDefinition]) -> CustomPackages {
+    let Some(path) = custom_file_path().filter(|path| path.is_file()) else {
+        return CustomPackages::default();
+    };
+    let mut result = load_from_path(&path, builtin);
+    result.path = Some(path);
+    result
+}
+
+fn load_from_path(path: &Path, builtin: &[PackageDefinition]) -> CustomPackages {
+    let content = match std::fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(err) => return parse_failure(path, &err.to_string()),
+    };
+    match toml::from_str::<CustomFile>(&content) {
+        Ok(file) => convert_entries(file.package, builtin),
+        Err(err) => parse_failure(path, &err.to_string()),
+    }
+}
+
+fn parse_failure(path: &Path, error: &str) -> CustomPackages {
+    CustomPackages {
+        warnings: vec![crate::tr!(
+            keys::PACKAGE_MANAGER_CUSTOM_PARSE_FAILED,
+            path = path.display(),
+            error = error
+        )],
+        ..CustomPackages::default()
+    }
+}
+
+/// 移除空白命令，回傳第一個未知的套件管理器鍵
+fn clean_commands(commands: HashMap<String, String>) -> Result<HashMap<String, String>, String> {
+    let mut cleaned = HashMap::new();
+    for (key, command) in commands {
+        let key = key.trim().to_ascii_lowercase();
+        if !COMMAND_KEYS.contains(&key.as_str()) {
+            return Err(key);
+        }
+        let command = command.trim();
+        if !command.is_empty() {
+            cleaned.insert(key, command.to_string());
+        }
+    }
+    Ok(cleaned)
+}
+
+fn convert_entries(entries: Vec<CustomEntry>, builtin: &[PackageDefinition]) -> CustomPackages {
+    let mut result = CustomPackages::default();
+
+    for (index, entry) in entries.into_iter().enumerate() {
+        let name = entry.name.trim().to_string();
+        let commands = clean_commands(entry.install).and_then(|install| {
+            Ok((
+                install,
+                clean_commands(entry.update)?,
+                clean_commands(entry.remove)?,
+            ))
+        });
+
+        let reason = if name.is_empty() {
+            Some(crate::tr!(keys::PACKAGE_MANAGER_CUSTOM_MISSING_NAME))
+        } else if builtin
+            .iter()
+            .map(|pkg| pkg.name.as_str())
+            .chain(result.packages.iter().map(|pkg| pkg.name.as_str()))
+            .any(|existing| existing.eq_ignore_ascii_case(&name))
+        {
+            Some(crate::tr!(
+                keys::PACKAGE_MANAGER_CUSTOM_DUPLICATE,
+                name = &name
+            ))
+        } else {
+            match &commands {
+                Err(manager) => Some(crate::tr!(
+                    keys::PACKAGE_MANAGER_CUSTOM_UNKNOWN_MANAGER,
+                    manager = manager
+                )),
+                Ok((install, _, _)) if install.is_empty() => {
+                    Some(crate::tr!(keys::PACKAGE_MANAGER_CUSTOM_MISSING_INSTALL))
+                }
+                Ok(_) => None,
+            }
+        };
+
+        let (install, update, remove) = match (reason, commands) {
+            (None, Ok(commands)) => commands,
+            (reason, _) => {
+                result.warnings.push(crate::tr!(
+                    keys::PACKAGE_MANAGER_CUSTOM_SKIPPED,
+                    index = index + 1,
+                    reason = reason.unwrap_or_default()
+                ));
+                continue;
+            }
+        };
+
+        let binary = entry
+            .binary
+            .map(|binary| binary.trim().to_string())
+            .filter(|binary| !binary.is_empty())
+            .unwrap_or_else(|| name.clone());
+
+        result.packages.push(CustomPackage {
+            name,
+            binary,
+            sudo: entry.sudo,
+            install,
+            update,
+            remove,
+        });
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::package_manager::types::package_definitions;
+
+    fn parse(content: &str) -> CustomPackages {
+        let file: CustomFile = toml::from_str(content).unwrap();
+        convert_entries(file.package, &package_definitions())
+    }
+
+    #[test]
+    fn test_custom_entry_becomes_package() {
+        let result = parse(
+            r#"
+[[package]]
+name = "jq"
+sudo = true
+
+[package.install]
+brew = "brew install jq"
+apt = "apt-get install -y jq"
+default = "echo unsupported && exit 1"
+
+[package.remove]
+APT = "apt-get remove -y jq"
+"#,
+        );
+
+        assert!(result.warnings.is_empty());
+        assert_eq!(result.packages.len(), 1);
+        let pkg = &result.packages[0];
+        assert_eq!(pkg.name, "jq");
+        assert_eq!(pkg.binary, "jq");
+        assert!(pkg.sudo);
+        assert_eq!(
+            pkg.command(PackageAction::Install, Some(PackageManager::Apt)),
+            Some("apt-get install -y jq")
+        );
+        assert_eq!(
+            pkg.command(PackageAction::Install, Some(PackageManager::Pacman)),
+            Some("echo unsupported && exit 1")
+        );
+        assert_eq!(
+            pkg.command(PackageAction::Update, Some(PackageManager::Brew)),
+            Some("brew install jq")
+        );
+        assert_eq!(
+            pkg.command(PackageAction::Remove, Some(PackageManager::Apt)),
+            Some("apt-get remove -y jq")
+        );
+        assert_eq!(
+            pkg.command(PackageAction::Remove, Some(PackageManager::Brew)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_binary_overrides_name() {
+        let result = parse(
+            r#"
+[[package]]
+name = "ripgrep"
+binary = "rg"
+install = { default = "cargo install ripgrep" }
+"#,
+        );
+
+        assert_eq!(result.packages[0].binary, "rg");
+        assert_eq!(
+            result.packages[0].command(PackageAction::Install, None),
+            Some("cargo install ripgrep")
+        );
+    }
+
+    #[test]
+    fn test_invalid_entries_are_reported() {
+        let result = parse(
+            r#"
+[[package]]
+name = ""
+install = { default = "true" }
+
+[[package]]
+name = "no-install"
+remove = { default = "true" }
+
+[[package]]
+name = "Terraform"
+install = { default = "true" }
+
+[[package]]
+name = "unknown-manager"
+install = { choco = "choco install x" }
+
+[[package]]
+name = "helm"
+install = { default = "true" }
+
+[[package]]
+name = "HELM"
+install = { default = "true" }
+"#,
+        );
+
+        assert_eq!(result.packages.len(), 1);
+        assert_eq!(result.packages[0].name, "helm");
+        assert_eq!(result.warnings.len(), 5);
+    }
+
+    #[test]
+    fn test_parse_error_is_reported() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(CUSTOM_FILE_NAME);
+        std::fs::write(&path, "[[package]\nname = ").unwrap();
+
+        let result = load_from_path(&path, &[]);
+        assert!(result.packages.is_empty());
+        assert_eq!(result.warnings.len(), 1);
+    }
+}
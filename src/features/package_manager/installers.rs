@@ -5,19 +5,21 @@
 use crate::core::{OperationError, Result};
 use crate::i18n::{self, keys};
 use std::fs;
+use std::path::PathBuf;
 
 use super::config_content::{
     BUN_INSTALL_SCRIPT, FFMPEG_BUILD_SCRIPT, NVM_INSTALL_SCRIPT, PNPM_INSTALL_SCRIPT,
     RUSTUP_INSTALL_SCRIPT, TMUX_CONF_CONTENT, UV_INSTALL_SCRIPT, VIMRC_CONTENT,
 };
 use super::shell::{
-    create_symlink, create_temp_dir, download_file, ensure_hashicorp_repo, ensure_profile_line,
-    extract_tar, fetch_text, find_binary, go_arch, install_binary, install_with_manager,
-    is_command_available, latest_github_asset, latest_go_download, nvm_dir, remove_binary,
-    remove_file, remove_home_binary, remove_with_manager, run_command, run_command_path, run_shell,
-    rustup_path, update_with_manager, uv_path, verify_checksum, write_config_with_backup,
+    checksum_for, create_symlink, create_temp_dir, download_file, ensure_hashicorp_repo,
+    ensure_profile_line, extract_tar, fetch_text, find_binary, go_arch, go_download,
+    install_binary, install_with_manager, is_command_available, latest_github_asset, nvm_dir,
+    remove_binary, remove_file, remove_home_binary, remove_with_manager, require_package_manager,
+    run_command, run_command_path, run_shell, rustup_path, update_with_manager, uv_path,
+    verify_checksum, write_config_with_backup,
 };
-use super::types::{ActionContext, PackageId, SupportedOs};
+use super::types::{ActionContext, PackageAction, PackageId, SupportedOs};
 
 // ============================================================================
 // 公開 API
@@ -40,6 +42,9 @@ pub fn is_installed(package: PackageId, ctx: &ActionContext) -> bool {
         PackageId::Tmux => is_command_available("tmux").is_some(),
         PackageId::Vim => is_command_available("vim").is_some(),
         PackageId::Ffmpeg => is_command_available("ffmpeg").is_some(),
+        PackageId::Custom(index) => ctx
+            .custom_package(index)
+            .is_some_and(|pkg| is_command_available(&pkg.binary).is_some()),
     }
 }
 
@@ -60,6 +65,7 @@ pub fn install_package(package: PackageId, ctx: &mut ActionContext) -> Result<()
         PackageId::Tmux => install_tmux(ctx),
         PackageId::Vim => install_vim(ctx),
         PackageId::Ffmpeg => install_ffmpeg(ctx),
+        PackageId::Custom(index) => run_custom(ctx, index, PackageAction::Install),
     }
 }
 
@@ -72,7 +78,7 @@ pub fn update_package(package: PackageId, ctx: &mut ActionContext) -> Result<()>
         PackageId::Rust => update_rust(ctx),
         PackageId::Go => install_go(ctx),
         PackageId::Terraform => update_terraform(ctx),
-        PackageId::Kubectl => install_kubectl(ctx),
+        PackageId::Kubectl => update_kubectl(ctx),
         PackageId::Kubectx => update_kubectx(ctx),
         PackageId::K9s => update_k9s(ctx),
         PackageId::Git => update_git(ctx),
@@ -80,6 +86,7 @@ pub fn update_package(package: PackageId, ctx: &mut ActionContext) -> Result<()>
         PackageId::Tmux => update_tmux(ctx),
         PackageId::Vim => update_vim(ctx),
         PackageId::Ffmpeg => update_ffmpeg(ctx),
+        PackageId::Custom(index) => run_custom(ctx, index, PackageAction::Update),
     }
 }
 
@@ -92,7 +99,7 @@ pub fn remove_package(package: PackageId, ctx: &mut ActionContext) -> Result<()>
         PackageId::Rust => remove_rust(ctx),
         PackageId::Go => remove_go(ctx),
         PackageId::Terraform => remove_terraform(ctx),
-        PackageId::Kubectl => remove_binary(ctx, "kubectl"),
+        PackageId::Kubectl => remove_kubectl(ctx),
         PackageId::Kubectx => remove_kubectx(ctx),
         PackageId::K9s => remove_k9s(ctx),
         PackageId::Git => remove_git(ctx),
@@ -100,9 +107,135 @@ pub fn remove_package(package: PackageId, ctx: &mut ActionContext) -> Result<()>
         PackageId::Tmux => remove_tmux(ctx),
         PackageId::Vim => remove_vim(ctx),
         PackageId::Ffmpeg => remove_ffmpeg(ctx),
+        PackageId::Custom(index) => run_custom(ctx, index, PackageAction::Remove),
+    }
+}
+
+/// 移除套件時會刪除的目錄與執行檔（只列出存在的路徑；交給套件管理器移除的套件為空）
+pub fn removal_targets(package: PackageId, ctx: &ActionContext) -> Vec<PathBuf> {
+    let home = &ctx.home_dir;
+    let local_bin = |name: &str| home.join(".local/bin").join(name);
+    let linux_only = |paths: Vec<PathBuf>| match ctx.os {
+        SupportedOs::Linux => paths,
+        SupportedOs::Macos | SupportedOs::FreeBsd => Vec::new(),
+    };
+    let candidates = match package {
+        PackageId::Nvm => vec![nvm_dir(ctx)],
+        PackageId::Pnpm => vec![
+            home.join(".local/share/pnpm"),
+            home.join(".local/share/pnpm-global"),
+            local_bin("pnpm"),
+            local_bin("pnpx"),
+        ],
+        PackageId::Bun => vec![home.join(".bun"), local_bin("bun"), local_bin("bunx")],
+        PackageId::Rust => vec![home.join(".rustup"), home.join(".cargo")],
+        PackageId::Go => vec![PathBuf::from("/usr/local/go")],
+        PackageId::Kubectl => match ctx.os {
+            SupportedOs::FreeBsd => Vec::new(),
+            SupportedOs::Linux | SupportedOs::Macos => {
+                is_command_available("kubectl").into_iter().collect()
+            }
+        },
+        PackageId::Kubectx => linux_only(vec![home.join(".kubectx"), local_bin("kubectx")]),
+        PackageId::K9s => linux_only(is_command_available("k9s").into_iter().collect()),
+        PackageId::Uv => uv_path(ctx)
+            .into_iter()
+            .chain([home.join(".local/share/uv")])
+            .collect(),
+        PackageId::Ffmpeg => linux_only(vec![
+            home.join(".ffbuild"),
+            local_bin("ffmpeg"),
+            local_bin("ffprobe"),
+        ]),
+        PackageId::Terraform
+        | PackageId::Git
+        | PackageId::Tmux
+        | PackageId::Vim
+        | PackageId::Custom(_) => Vec::new(),
+    };
+    candidates
+        .into_iter()
+        .filter(|path| path.exists())
+        .collect()
+}
+
+/// rustup 自帶的執行檔，移除 Rust 時不算使用者安裝的工具
+const RUSTUP_PROXIES: [&str; 12] = [
+    "cargo",
+    "cargo-clippy",
+    "cargo-fmt",
+    "cargo-miri",
+    "clippy-driver",
+    "rls",
+    "rust-analyzer",
+    "rust-gdb",
+    "rust-lldb",
+    "rustc",
+    "rustdoc",
+    "rustfmt",
+];
+
+/// `~/.cargo/bin` 中以 `cargo install` 安裝的工具（移除 Rust 時會一併刪除）
+pub fn cargo_installed_binaries(ctx: &ActionContext) -> Vec<String> {
+    let Ok(entries) = fs::read_dir(ctx.home_dir.join(".cargo/bin")) else {
+        return Vec::new();
+    };
+    let names = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.file_name().to_string_lossy().to_string());
+    user_cargo_binaries(names)
+}
+
+fn user_cargo_binaries(names: impl IntoIterator<Item = String>) -> Vec<String> {
+    let mut binaries: Vec<String> = names
+        .into_iter()
+        .filter(|name| {
+            let stem = name.strip_suffix(".exe").unwrap_or(name);
+            stem != "rustup" && !RUSTUP_PROXIES.contains(&stem)
+        })
+        .collect();
+    binaries.sort();
+    binaries
+}
+
+/// 指定版本的操作失敗時，轉為清楚的「版本不可用」錯誤
+fn pinned<T>(result: Result<T>, package: &str, version: Option<&str>) -> Result<T> {
+    match version {
+        Some(version) => result.map_err(|err| {
+            OperationError::Validation(crate::tr!(
+                keys::PACKAGE_MANAGER_VERSION_UNAVAILABLE,
+                package = package,
+                version = version,
+                error = err
+            ))
+        }),
+        None => result,
     }
 }
 
+// ============================================================================
+// 自訂套件
+// ============================================================================
+
+/// 執行自訂套件在目前套件管理器下的命令
+fn run_custom(ctx: &ActionContext, index: usize, action: PackageAction) -> Result<()> {
+    let pkg = ctx
+        .custom_package(index)
+        .ok_or_else(|| OperationError::Validation(format!("custom package #{index}")))?;
+    let command = pkg.command(action, ctx.package_manager).ok_or_else(|| {
+        OperationError::Validation(crate::tr!(
+            keys::PACKAGE_MANAGER_CUSTOM_NO_COMMAND,
+            package = &pkg.name,
+            action = action.label(),
+            manager = ctx
+                .package_manager
+                .map_or("default", |manager| manager.label())
+        ))
+    })?;
+    run_shell(ctx, command, pkg.sudo)?;
+    Ok(())
+}
+
 // ============================================================================
 // NVM
 // ============================================================================
@@ -125,10 +258,8 @@ fn update_nvm(ctx: &mut ActionContext) -> Result<()> {
 fn remove_nvm(ctx: &mut ActionContext) -> Result<()> {
     let dir = nvm_dir(ctx);
     if dir.exists() {
-        fs::remove_dir_all(&dir).map_err(|err| OperationError::Io {
-            path: dir.display().to_string(),
-            source: err,
-        })?;
+        fs::remove_dir_all(&dir)
+            .map_err(|err| OperationError::io(dir.display().to_string(), err))?;
     }
     Ok(())
 }
@@ -154,16 +285,12 @@ fn remove_pnpm(ctx: &mut ActionContext) -> Result<()> {
     let pnpm_home = ctx.home_dir.join(".local/share/pnpm");
     let pnpm_global = ctx.home_dir.join(".local/share/pnpm-global");
     if pnpm_home.exists() {
-        fs::remove_dir_all(&pnpm_home).map_err(|err| OperationError::Io {
-            path: pnpm_home.display().to_string(),
-            source: err,
-        })?;
+        fs::remove_dir_all(&pnpm_home)
+            .map_err(|err| OperationError::io(pnpm_home.display().to_string(), err))?;
     }
     if pnpm_global.exists() {
-        fs::remove_dir_all(&pnpm_global).map_err(|err| OperationError::Io {
-            path: pnpm_global.display().to_string(),
-            source: err,
-        })?;
+        fs::remove_dir_all(&pnpm_global)
+            .map_err(|err| OperationError::io(pnpm_global.display().to_string(), err))?;
     }
     remove_home_binary(ctx, "pnpm")?;
     remove_home_binary(ctx, "pnpx")?;
@@ -196,10 +323,8 @@ fn update_bun(ctx: &mut ActionContext) -> Result<()> {
 fn remove_bun(ctx: &mut ActionContext) -> Result<()> {
     let bun_dir = ctx.home_dir.join(".bun");
     if bun_dir.exists() {
-        fs::remove_dir_all(&bun_dir).map_err(|err| OperationError::Io {
-            path: bun_dir.display().to_string(),
-            source: err,
-        })?;
+        fs::remove_dir_all(&bun_dir)
+            .map_err(|err| OperationError::io(bun_dir.display().to_string(), err))?;
     }
     remove_home_binary(ctx, "bun")?;
     remove_home_binary(ctx, "bunx")?;
@@ -211,11 +336,19 @@ fn remove_bun(ctx: &mut ActionContext) -> Result<()> {
 // ============================================================================
 
 fn install_rust(ctx: &mut ActionContext) -> Result<()> {
-    run_shell(
+    let version = ctx.requested_version(PackageId::Rust).map(str::to_string);
+    let toolchain = version
+        .as_deref()
+        .map(|v| format!(" --default-toolchain {v}"))
+        .unwrap_or_default();
+    let result = run_shell(
         ctx,
-        &format!("curl --proto '=https' --tlsv1.2 -sSf {RUSTUP_INSTALL_SCRIPT} | sh -s -- -y"),
+        &format!(
+            "curl --proto '=https' --tlsv1.2 -sSf {RUSTUP_INSTALL_SCRIPT} | sh -s -- -y{toolchain}"
+        ),
         false,
-    )?;
+    );
+    pinned(result, "Rust", version.as_deref())?;
     Ok(())
 }
 
@@ -249,13 +382,19 @@ fn remove_rust(ctx: &mut ActionContext) -> Result<()> {
 // ============================================================================
 
 fn install_go(ctx: &mut ActionContext) -> Result<()> {
-    let download = latest_go_download(ctx)?;
+    let version = ctx.requested_version(PackageId::Go);
+    let download = pinned(go_download(ctx, version), "Go", version)?;
     let temp_dir = create_temp_dir(ctx, "go-download")?;
     let archive_path = temp_dir.join(&download.filename);
+    let checksum = match &download.sha256 {
+        Some(sha256) => sha256.clone(),
+        None => fetch_text(ctx, &format!("{}.sha256", download.url), &[])?,
+    };
     download_file(ctx, &download.url, &archive_path)?;
+    verify_checksum(&archive_path, &checksum)?;
 
     match ctx.os {
-        SupportedOs::Linux => {
+        SupportedOs::Linux | SupportedOs::FreeBsd => {
             run_command(ctx, "rm", &["-rf", "/usr/local/go"], ctx.sudo_available)?;
             run_command(
                 ctx,
@@ -297,15 +436,35 @@ fn remove_go(ctx: &mut ActionContext) -> Result<()> {
 // ============================================================================
 
 fn install_terraform(ctx: &mut ActionContext) -> Result<()> {
-    match ctx.os {
-        SupportedOs::Macos => install_with_manager(ctx, "terraform"),
-        SupportedOs::Linux => install_terraform_linux(ctx),
-    }
+    let version = ctx
+        .requested_version(PackageId::Terraform)
+        .map(str::to_string);
+    let package = match version.as_deref() {
+        Some(version) => {
+            let manager = require_package_manager(ctx)?;
+            manager
+                .versioned_package("terraform", version)
+                .ok_or_else(|| {
+                    OperationError::Validation(crate::tr!(
+                        keys::PACKAGE_MANAGER_VERSION_PIN_UNSUPPORTED,
+                        manager = manager.label(),
+                        package = "Terraform"
+                    ))
+                })?
+        }
+        None => "terraform".to_string(),
+    };
+
+    let result = match ctx.os {
+        SupportedOs::Macos | SupportedOs::FreeBsd => install_with_manager(ctx, &package),
+        SupportedOs::Linux => install_terraform_linux(ctx, &package),
+    };
+    pinned(result, "Terraform", version.as_deref())
 }
 
 fn update_terraform(ctx: &mut ActionContext) -> Result<()> {
     match ctx.os {
-        SupportedOs::Macos => update_with_manager(ctx, "terraform"),
+        SupportedOs::Macos | SupportedOs::FreeBsd => update_with_manager(ctx, "terraform"),
         SupportedOs::Linux => update_terraform_linux(ctx),
     }
 }
@@ -314,9 +473,9 @@ fn remove_terraform(ctx: &mut ActionContext) -> Result<()> {
     remove_with_manager(ctx, "terraform")
 }
 
-fn install_terraform_linux(ctx: &mut ActionContext) -> Result<()> {
+fn install_terraform_linux(ctx: &mut ActionContext, package: &str) -> Result<()> {
     ensure_hashicorp_repo(ctx)?;
-    install_with_manager(ctx, "terraform")
+    install_with_manager(ctx, package)
 }
 
 fn update_terraform_linux(ctx: &mut ActionContext) -> Result<()> {
@@ -329,12 +488,30 @@ fn update_terraform_linux(ctx: &mut ActionContext) -> Result<()> {
 // ============================================================================
 
 fn install_kubectl(ctx: &mut ActionContext) -> Result<()> {
-    let version = fetch_text(
-        ctx,
-        "https://dl.k8s.io/release/stable.txt",
-        &["-H", "User-Agent: ops-tools"],
-    )?;
-    let version = version.trim();
+    let requested = ctx
+        .requested_version(PackageId::Kubectl)
+        .map(str::to_string);
+    // dl.k8s.io 沒有 FreeBSD 版本，改用 pkg（不支援指定版本）
+    if ctx.os == SupportedOs::FreeBsd {
+        if requested.is_some() {
+            return Err(OperationError::Validation(crate::tr!(
+                keys::PACKAGE_MANAGER_VERSION_PIN_UNSUPPORTED,
+                manager = "pkg",
+                package = "kubectl"
+            )));
+        }
+        return install_with_manager(ctx, "kubectl");
+    }
+    let version = match requested.as_deref() {
+        Some(version) => format!("v{version}"),
+        None => fetch_text(
+            ctx,
+            "https://dl.k8s.io/release/stable.txt",
+            &["-H", "User-Agent: ops-tools"],
+        )?
+        .trim()
+        .to_string(),
+    };
     let arch = go_arch()?;
     let os = ctx.os.kubectl_os();
     let url = format!(
@@ -343,38 +520,57 @@ fn install_kubectl(ctx: &mut ActionContext) -> Result<()> {
     );
     let checksum_url = format!("{}.sha256", url);
 
+    // 先取得 checksum，指定的版本不存在時可在下載前回報
+    let checksum = pinned(
+        fetch_text(ctx, &checksum_url, &["-H", "User-Agent: ops-tools"]),
+        "kubectl",
+        requested.as_deref(),
+    )?;
+
     let temp_dir = create_temp_dir(ctx, "kubectl")?;
     let bin_path = temp_dir.join("kubectl");
     download_file(ctx, &url, &bin_path)?;
-
-    let checksum = fetch_text(ctx, &checksum_url, &["-H", "User-Agent: ops-tools"])?;
-    verify_checksum(ctx, &bin_path, checksum.trim())?;
+    verify_checksum(&bin_path, &checksum)?;
 
     install_binary(ctx, &bin_path, "kubectl")?;
     Ok(())
 }
 
+fn update_kubectl(ctx: &mut ActionContext) -> Result<()> {
+    match ctx.os {
+        SupportedOs::FreeBsd => update_with_manager(ctx, "kubectl"),
+        SupportedOs::Linux | SupportedOs::Macos => install_kubectl(ctx),
+    }
+}
+
+fn remove_kubectl(ctx: &mut ActionContext) -> Result<()> {
+    match ctx.os {
+        SupportedOs::FreeBsd => remove_with_manager(ctx, "kubectl"),
+        SupportedOs::Linux | SupportedOs::Macos => remove_binary(ctx, "kubectl"),
+    }
+}
+
 // ============================================================================
 // Kubectx
 // ============================================================================
 
 fn install_kubectx(ctx: &mut ActionContext) -> Result<()> {
     match ctx.os {
-        SupportedOs::Macos => install_with_manager(ctx, "kubectx"),
+        SupportedOs::Macos | SupportedOs::FreeBsd => install_with_manager(ctx, "kubectx"),
         SupportedOs::Linux => install_kubectx_linux(ctx),
     }
 }
 
 fn update_kubectx(ctx: &mut ActionContext) -> Result<()> {
     match ctx.os {
-        SupportedOs::Macos => update_with_manager(ctx, "kubectx"),
+        SupportedOs::Macos | SupportedOs::FreeBsd => update_with_manager(ctx, "kubectx"),
         SupportedOs::Linux => update_kubectx_linux(ctx),
     }
 }
 
 fn remove_kubectx(ctx: &mut ActionContext) -> Result<()> {
     match ctx.os {
-        SupportedOs::Macos => remove_with_manager(ctx, "kubectx"),
+        SupportedOs::Macos | SupportedOs::FreeBsd => remove_with_manager(ctx, "kubectx"),
         SupportedOs::Linux => remove_kubectx_linux(ctx),
     }
 }
@@ -414,10 +610,8 @@ fn install_kubectx_linux(ctx: &mut ActionContext) -> Result<()> {
     }
 
     let bin_dir = ctx.home_dir.join(".local/bin");
-    fs::create_dir_all(&bin_dir).map_err(|err| OperationError::Io {
-        path: bin_dir.display().to_string(),
-        source: err,
-    })?;
+    fs::create_dir_all(&bin_dir)
+        .map_err(|err| OperationError::io(bin_dir.display().to_string(), err))?;
     let link_path = bin_dir.join("kubectx");
     let target = repo_dir.join("kubectx");
     create_symlink(&target, &link_path)?;
@@ -443,21 +637,21 @@ fn remove_kubectx_linux(ctx: &mut ActionContext) -> Result<()> {
 
 fn install_k9s(ctx: &mut ActionContext) -> Result<()> {
     match ctx.os {
-        SupportedOs::Macos => install_with_manager(ctx, "k9s"),
+        SupportedOs::Macos | SupportedOs::FreeBsd => install_with_manager(ctx, "k9s"),
         SupportedOs::Linux => install_k9s_linux(ctx),
     }
 }
 
 fn update_k9s(ctx: &mut ActionContext) -> Result<()> {
     match ctx.os {
-        SupportedOs::Macos => update_with_manager(ctx, "k9s"),
+        SupportedOs::Macos | SupportedOs::FreeBsd => update_with_manager(ctx, "k9s"),
         SupportedOs::Linux => install_k9s_linux(ctx),
     }
 }
 
 fn remove_k9s(ctx: &mut ActionContext) -> Result<()> {
     match ctx.os {
-        SupportedOs::Macos => remove_with_manager(ctx, "k9s"),
+        SupportedOs::Macos | SupportedOs::FreeBsd => remove_with_manager(ctx, "k9s"),
         SupportedOs::Linux => remove_binary(ctx, "k9s"),
     }
 }
@@ -466,7 +660,15 @@ fn install_k9s_linux(ctx: &mut ActionContext) -> Result<()> {
     let asset = latest_github_asset("derailed/k9s", ctx, "k9s_", ".tar.gz")?;
     let temp_dir = create_temp_dir(ctx, "k9s")?;
     let archive = temp_dir.join(&asset.name);
+    let checksums = match &asset.checksums_url {
+        Some(url) => fetch_text(ctx, url, &["-H", "User-Agent: ops-tools"])?,
+        None => String::new(),
+    };
     download_file(ctx, &asset.url, &archive)?;
+    verify_checksum(
+        &archive,
+        checksum_for(&checksums, &asset.name).unwrap_or_default(),
+    )?;
     extract_tar(ctx, &archive, &temp_dir)?;
     let binary = find_binary(&temp_dir, "k9s").ok_or_else(|| OperationError::Command {
         command: "k9s".to_string(),
@@ -481,10 +683,7 @@ fn install_k9s_linux(ctx: &mut ActionContext) -> Result<()> {
 // ============================================================================
 
 fn install_git(ctx: &mut ActionContext) -> Result<()> {
-    match ctx.os {
-        SupportedOs::Macos => install_with_manager(ctx, "git"),
-        SupportedOs::Linux => install_with_manager(ctx, "git"),
-    }
+    install_with_manager(ctx, "git")
 }
 
 fn update_git(ctx: &mut ActionContext) -> Result<()> {
@@ -559,10 +758,8 @@ fn setup_tmux_config(ctx: &mut ActionContext) -> Result<()> {
 
     let plugins_dir = ctx.home_dir.join(".tmux/plugins");
     let tpm_dir = plugins_dir.join("tpm");
-    fs::create_dir_all(&plugins_dir).map_err(|err| OperationError::Io {
-        path: plugins_dir.display().to_string(),
-        source: err,
-    })?;
+    fs::create_dir_all(&plugins_dir)
+        .map_err(|err| OperationError::io(plugins_dir.display().to_string(), err))?;
 
     if tpm_dir.exists() {
         run_command(
@@ -629,10 +826,8 @@ fn setup_vim_config(ctx: &mut ActionContext) -> Result<()> {
     )?;
 
     let colors_dir = ctx.home_dir.join(".vim/colors");
-    fs::create_dir_all(&colors_dir).map_err(|err| OperationError::Io {
-        path: colors_dir.display().to_string(),
-        source: err,
-    })?;
+    fs::create_dir_all(&colors_dir)
+        .map_err(|err| OperationError::io(colors_dir.display().to_string(), err))?;
     download_file(
         ctx,
         "https://raw.githubusercontent.com/tomasr/molokai/master/colors/molokai.vim",
@@ -649,7 +844,7 @@ fn setup_vim_config(ctx: &mut ActionContext) -> Result<()> {
 
 fn install_ffmpeg(ctx: &mut ActionContext) -> Result<()> {
     match ctx.os {
-        SupportedOs::Macos => install_with_manager(ctx, "ffmpeg"),
+        SupportedOs::Macos | SupportedOs::FreeBsd => install_with_manager(ctx, "ffmpeg"),
         SupportedOs::Linux => run_ffmpeg_build(ctx),
     }
 }
@@ -660,7 +855,7 @@ fn update_ffmpeg(ctx: &mut ActionContext) -> Result<()> {
 
 fn remove_ffmpeg(ctx: &mut ActionContext) -> Result<()> {
     match ctx.os {
-        SupportedOs::Macos => remove_with_manager(ctx, "ffmpeg"),
+        SupportedOs::Macos | SupportedOs::FreeBsd => remove_with_manager(ctx, "ffmpeg"),
         SupportedOs::Linux => {
             let prefix = ctx.home_dir.join(".ffbuild");
             if prefix.exists() {
@@ -676,10 +871,8 @@ fn remove_ffmpeg(ctx: &mut ActionContext) -> Result<()> {
 fn run_ffmpeg_build(ctx: &mut ActionContext) -> Result<()> {
     let temp_dir = create_temp_dir(ctx, "ffmpeg-build")?;
     let script_path = temp_dir.join("build_ffmpeg.sh");
-    fs::write(&script_path, FFMPEG_BUILD_SCRIPT).map_err(|err| OperationError::Io {
-        path: script_path.display().to_string(),
-        source: err,
-    })?;
+    fs::write(&script_path, FFMPEG_BUILD_SCRIPT)
+        .map_err(|err| OperationError::io(script_path.display().to_string(), err))?;
     run_command(
         ctx,
         "bash",
@@ -688,3 +881,24 @@ fn run_ffmpeg_build(ctx: &mut ActionContext) -> Result<()> {
     )?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_user_cargo_binaries_skip_rustup_proxies() {
+        let names = [
+            "rustc",
+            "cargo",
+            "ripgrep",
+            "rustup",
+            "cargo-watch",
+            "rustfmt.exe",
+        ];
+        assert_eq!(
+            user_cargo_binaries(names.into_iter().map(String::from)),
+            ["cargo-watch", "ripgrep"]
+        );
+    }
+}
@@ -1,16 +1,22 @@
 mod config_content;
+mod custom;
 mod installers;
 mod operations;
+pub mod self_update;
 mod shell;
 mod types;
 
+use crate::core::{dry_run, load_config, save_config};
 use crate::i18n::{self, keys};
 use crate::ui::{Console, Prompts};
 use operations::{
-    ActionContext, PackageAction, SupportedOs, ensure_curl, package_definitions, update_curl,
+    ActionContext, PackageAction, SupportedOs, ensure_curl, normalize_version, package_definitions,
+    update_curl,
 };
 use std::collections::HashSet;
 
+pub use operations::is_command_available;
+
 pub fn run() {
     let console = Console::new();
     let prompts = Prompts::new();
@@ -23,39 +29,87 @@ pub fn run() {
     };
 
     let mut ctx = ActionContext::new(os);
+    load_custom_packages(&console, &mut ctx);
+    let dry_run = dry_run::is_enabled();
 
-    let options = vec![
-        i18n::t(keys::PACKAGE_MANAGER_MODE_INSTALL),
-        i18n::t(keys::PACKAGE_MANAGER_MODE_UPDATE),
-    ];
-
-    let Some(selection) = prompts.select(i18n::t(keys::PACKAGE_MANAGER_MODE_PROMPT), &options)
+    let Some(selection) =
+        prompts.select(i18n::t(keys::PACKAGE_MANAGER_MODE_PROMPT), &mode_options())
     else {
         console.warning(i18n::t(keys::PACKAGE_MANAGER_CANCELLED));
         return;
     };
 
-    match selection {
-        0 => run_install(&console, &prompts, &mut ctx),
-        1 => run_update(&console, &prompts, &mut ctx),
-        _ => unreachable!(),
+    // 選「返回」直接回主選單；Esc 取消才提示已取消
+    match Mode::ALL.get(selection) {
+        Some(Mode::Install) => run_install(&console, &prompts, &mut ctx, dry_run),
+        Some(Mode::Update) => run_update(&console, &prompts, &mut ctx, dry_run),
+        None => {}
+    }
+}
+
+/// 套件管理模式
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Mode {
+    Install,
+    Update,
+}
+
+impl Mode {
+    /// 選單順序
+    const ALL: [Mode; 2] = [Mode::Install, Mode::Update];
+
+    fn label(self) -> &'static str {
+        match self {
+            Mode::Install => i18n::t(keys::PACKAGE_MANAGER_MODE_INSTALL),
+            Mode::Update => i18n::t(keys::PACKAGE_MANAGER_MODE_UPDATE),
+        }
+    }
+}
+
+/// 模式選單的選項，依 [`Mode::ALL`] 排列，最後一項為「返回」
+fn mode_options() -> Vec<&'static str> {
+    Mode::ALL
+        .iter()
+        .map(|mode| mode.label())
+        .chain([i18n::t(keys::MENU_BACK)])
+        .collect()
+}
+
+/// 載入使用者自訂套件並顯示略過的項目
+fn load_custom_packages(console: &Console, ctx: &mut ActionContext) {
+    let custom = custom::load_custom_packages(&package_definitions());
+    for warning in &custom.warnings {
+        console.warning(warning);
     }
+    if let Some(path) = &custom.path
+        && !custom.packages.is_empty()
+    {
+        console.info(&crate::tr_plural!(
+            keys::PACKAGE_MANAGER_CUSTOM_LOADED,
+            count = custom.packages.len(),
+            path = path.display()
+        ));
+    }
+    ctx.set_custom_packages(custom.packages);
 }
 
-fn run_install(console: &Console, prompts: &Prompts, ctx: &mut ActionContext) {
-    let packages = package_definitions();
+fn run_install(console: &Console, prompts: &Prompts, ctx: &mut ActionContext, dry_run: bool) {
+    let packages = ctx.definitions();
     let defaults: Vec<bool> = packages
         .iter()
         .map(|pkg| operations::is_installed(pkg.id, ctx))
         .collect();
 
-    let items: Vec<String> = packages.iter().map(|pkg| pkg.name.to_string()).collect();
+    let items: Vec<String> = packages.iter().map(|pkg| pkg.name.clone()).collect();
 
-    let selected = prompts.multi_select(
+    let Some(selected) = prompts.multi_select(
         i18n::t(keys::PACKAGE_MANAGER_INSTALL_PROMPT),
         &items,
         &defaults,
-    );
+    ) else {
+        console.warning(i18n::t(keys::PACKAGE_MANAGER_CANCELLED));
+        return;
+    };
 
     if selected.is_empty() && defaults.iter().all(|installed| *installed) {
         console.info(i18n::t(keys::PACKAGE_MANAGER_NO_CHANGES));
@@ -68,12 +122,15 @@ fn run_install(console: &Console, prompts: &Prompts, ctx: &mut ActionContext) {
         let installed = defaults[idx];
         let selected = selected_set.contains(&idx);
         if !installed && selected {
-            actions.push((PackageAction::Install, *pkg));
+            actions.push((PackageAction::Install, pkg.clone()));
         } else if installed && !selected {
-            actions.push((PackageAction::Remove, *pkg));
+            actions.push((PackageAction::Remove, pkg.clone()));
         }
     }
 
+    actions.retain(|(action, pkg)| {
+        *action != PackageAction::Remove || confirm_removal(console, prompts, ctx, pkg, dry_run)
+    });
     if actions.is_empty() {
         console.info(i18n::t(keys::PACKAGE_MANAGER_NO_CHANGES));
         return;
@@ -87,16 +144,123 @@ fn run_install(console: &Console, prompts: &Prompts, ctx: &mut ActionContext) {
         }
     });
 
-    if let Err(err) = ensure_curl(ctx) {
+    if ask_versions(console, ctx, &actions, dry_run).is_none() {
+        console.warning(i18n::t(keys::PACKAGE_MANAGER_CANCELLED));
+        return;
+    }
+
+    if !dry_run && let Err(err) = ensure_curl(ctx) {
         console.error(&err.to_string());
         return;
     }
 
-    run_actions(console, ctx, &actions);
+    run_actions(console, ctx, &actions, dry_run);
+}
+
+/// 列出移除時會刪除的目錄與執行檔並逐一確認；dry-run 只列出不詢問
+fn confirm_removal(
+    console: &Console,
+    prompts: &Prompts,
+    ctx: &ActionContext,
+    pkg: &operations::PackageDefinition,
+    dry_run: bool,
+) -> bool {
+    let targets = operations::removal_targets(pkg.id, ctx);
+    if targets.is_empty() {
+        return true;
+    }
+
+    console.warning(&crate::tr!(
+        keys::PACKAGE_MANAGER_REMOVE_TARGETS,
+        package = &pkg.name
+    ));
+    for path in &targets {
+        console.list_item("🗑", &path.display().to_string());
+    }
+    if pkg.id == operations::PackageId::Rust {
+        let tools = operations::cargo_installed_binaries(ctx);
+        if tools.is_empty() {
+            console.warning(i18n::t(keys::PACKAGE_MANAGER_REMOVE_CARGO_WARNING));
+        } else {
+            console.warning(&crate::tr!(
+                keys::PACKAGE_MANAGER_REMOVE_CARGO_TOOLS,
+                tools = tools.join(", ")
+            ));
+        }
+    }
+    if dry_run {
+        return true;
+    }
+
+    let confirmed = prompts.confirm(&crate::tr!(
+        keys::PACKAGE_MANAGER_REMOVE_CONFIRM,
+        package = &pkg.name
+    ));
+    if !confirmed {
+        console.info(&crate::tr!(
+            keys::PACKAGE_MANAGER_REMOVE_KEPT,
+            package = &pkg.name
+        ));
+    }
+    confirmed
+}
+
+/// 詢問可鎖定版本的套件要安裝的版本，並記住上次的選擇
+fn ask_versions(
+    console: &Console,
+    ctx: &mut ActionContext,
+    actions: &[(PackageAction, operations::PackageDefinition)],
+    dry_run: bool,
+) -> Option<()> {
+    use dialoguer::{Input, theme::ColorfulTheme};
+
+    let pinnable: Vec<_> = actions
+        .iter()
+        .filter(|(action, pkg)| *action == PackageAction::Install && pkg.pinnable)
+        .map(|(_, pkg)| pkg)
+        .collect();
+    if pinnable.is_empty() {
+        return Some(());
+    }
+
+    let mut config = load_config().ok().flatten().unwrap_or_default();
+    for pkg in pinnable {
+        let default = config
+            .package_version(&pkg.name)
+            .unwrap_or("latest")
+            .to_string();
+        let input: String = Input::with_theme(&ColorfulTheme::default())
+            .with_prompt(crate::tr!(
+                keys::PACKAGE_MANAGER_VERSION_PROMPT,
+                package = &pkg.name
+            ))
+            .default(default)
+            .interact_text()
+            .ok()?;
+
+        let version = normalize_version(&input);
+        config.set_package_version(&pkg.name, version.clone());
+        ctx.set_requested_version(pkg.id, version);
+    }
+
+    // dry-run 不寫入設定檔，只套用到本次預覽
+    if !dry_run && let Err(err) = save_config(&config) {
+        console.warning(&crate::tr!(keys::CONFIG_SAVE_FAILED, error = err));
+    }
+    Some(())
+}
+
+/// 顯示用的套件名稱，含指定的版本
+fn package_label(pkg: &operations::PackageDefinition, ctx: &ActionContext) -> String {
+    match ctx.requested_version(pkg.id) {
+        Some(version) => format!("{} {}", pkg.name, version),
+        None => pkg.name.clone(),
+    }
 }
 
-fn run_update(console: &Console, prompts: &Prompts, ctx: &mut ActionContext) {
-    let installed_packages: Vec<_> = package_definitions()
+fn run_update(console: &Console, prompts: &Prompts, ctx: &mut ActionContext, dry_run: bool) {
+    let installed_packages: Vec<_> = ctx
+        .definitions()
         .into_iter()
         .filter(|pkg| operations::is_installed(pkg.id, ctx))
         .collect();
@@ -108,38 +272,43 @@ fn run_update(console: &Console, prompts: &Prompts, ctx: &mut ActionContext) {
 
     let items: Vec<String> = installed_packages
         .iter()
-        .map(|pkg| pkg.name.to_string())
+        .map(|pkg| pkg.name.clone())
         .collect();
     let defaults = vec![true; items.len()];
 
-    let selected = prompts.multi_select(
+    let Some(selected) = prompts.multi_select(
         i18n::t(keys::PACKAGE_MANAGER_UPDATE_PROMPT),
         &items,
         &defaults,
-    );
+    ) else {
+        console.warning(i18n::t(keys::PACKAGE_MANAGER_CANCELLED));
+        return;
+    };
 
     if selected.is_empty() {
         console.info(i18n::t(keys::PACKAGE_MANAGER_NO_CHANGES));
         return;
     }
 
-    if let Err(err) = ensure_curl(ctx) {
-        console.error(&err.to_string());
-        return;
-    }
+    if !dry_run {
+        if let Err(err) = ensure_curl(ctx) {
+            console.error(&err.to_string());
+            return;
+        }
 
-    if let Err(err) = update_curl(ctx) {
-        console.warning(&crate::tr!(
-            keys::PACKAGE_MANAGER_CURL_UPDATE_FAILED,
-            error = err
-        ));
+        if let Err(err) = update_curl(ctx) {
+            console.warning(&crate::tr!(
+                keys::PACKAGE_MANAGER_CURL_UPDATE_FAILED,
+                error = err
+            ));
+        }
     }
 
     let selected_set: HashSet<usize> = selected.into_iter().collect();
     let mut actions = Vec::new();
     for (idx, pkg) in installed_packages.iter().enumerate() {
         if selected_set.contains(&idx) {
-            actions.push((PackageAction::Update, *pkg));
+            actions.push((PackageAction::Update, pkg.clone()));
         }
     }
 
@@ -148,34 +317,47 @@ fn run_update(console: &Console, prompts: &Prompts, ctx: &mut ActionContext) {
         return;
     }
 
-    run_actions(console, ctx, &actions);
+    run_actions(console, ctx, &actions, dry_run);
 }
 
 fn run_actions(
     console: &Console,
     ctx: &mut ActionContext,
     actions: &[(PackageAction, operations::PackageDefinition)],
+    dry_run: bool,
 ) {
     let mut success_count = 0;
     let mut failed_count = 0;
+    let mut removal_count = 0;
+
+    let mut progress = console.progress_bar(actions.len());
+    for (action, pkg) in actions {
+        let label = package_label(pkg, ctx);
+        progress.tick(&crate::tr!(
+            keys::PACKAGE_MANAGER_ACTION_RUNNING,
+            action = action.label(),
+            package = label
+        ));
 
-    for (idx, (action, pkg)) in actions.iter().enumerate() {
-        console.show_progress(
-            idx + 1,
-            actions.len(),
-            &crate::tr!(
-                keys::PACKAGE_MANAGER_ACTION_RUNNING,
-                action = action.label(),
-                package = pkg.name
-            ),
-        );
-
-        match operations::apply_action(*action, pkg.id, ctx) {
+        match operations::apply_action(*action, pkg.id, ctx, dry_run) {
+            Ok(()) if dry_run => {
+                console.list_item(
+                    "🔍",
+                    &crate::tr!(
+                        keys::CONSOLE_DRY_RUN_WOULD_APPLY,
+                        action = action.label(),
+                        package = label
+                    ),
+                );
+                if *action == PackageAction::Remove {
+                    removal_count += 1;
+                }
+            }
             Ok(()) => {
                 console.success_item(&crate::tr!(
                     keys::PACKAGE_MANAGER_ACTION_SUCCESS,
                     action = action.label(),
-                    package = pkg.name
+                    package = label
                 ));
                 if pkg.id == operations::PackageId::Vim
                     && matches!(action, PackageAction::Install | PackageAction::Update)
@@ -189,7 +371,7 @@ fn run_actions(
                     &crate::tr!(
                         keys::PACKAGE_MANAGER_ACTION_FAILED,
                         action = action.label(),
-                        package = pkg.name
+                        package = label
                     ),
                     &err.to_string(),
                 );
@@ -199,6 +381,12 @@ fn run_actions(
 
         console.blank_line();
     }
+    progress.finish();
+
+    if dry_run {
+        console.show_dry_run_summary(removal_count);
+        return;
+    }
 
     console.show_summary(
         i18n::t(keys::PACKAGE_MANAGER_SUMMARY),
@@ -206,3 +394,19 @@ fn run_actions(
         failed_count,
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mode_menu_ends_with_back() {
+        let options = mode_options();
+        assert_eq!(options.len(), Mode::ALL.len() + 1);
+        for (index, mode) in Mode::ALL.iter().enumerate() {
+            assert_eq!(options[index], mode.label());
+        }
+        assert_eq!(options.last(), Some(&i18n::t(keys::MENU_BACK)));
+        assert_eq!(Mode::ALL.get(options.len() - 1), None);
+    }
+}
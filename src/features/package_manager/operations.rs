@@ -11,7 +11,8 @@ use super::types;
 
 // 重新匯出公開型別
 pub use types::{
-    ActionContext, PackageAction, PackageDefinition, PackageId, SupportedOs, package_definitions,
+    ActionContext, PackageAction, PackageDefinition, PackageId, SupportedOs, normalize_version,
+    package_definitions,
 };
 
 // 重新匯出 shell 工具
@@ -42,12 +43,26 @@ pub fn is_installed(package: PackageId, ctx: &ActionContext) -> bool {
     installers::is_installed(package, ctx)
 }
 
-/// 執行套件操作（安裝/更新/移除）
+/// 移除套件時會刪除的路徑
+pub fn removal_targets(package: PackageId, ctx: &ActionContext) -> Vec<std::path::PathBuf> {
+    installers::removal_targets(package, ctx)
+}
+
+/// 以 `cargo install` 安裝、移除 Rust 時會遺失的工具
+pub fn cargo_installed_binaries(ctx: &ActionContext) -> Vec<String> {
+    installers::cargo_installed_binaries(ctx)
+}
+
+/// 執行套件操作（安裝/更新/移除）；`dry_run` 時不做任何變更
 pub fn apply_action(
     action: PackageAction,
     package: PackageId,
     ctx: &mut ActionContext,
+    dry_run: bool,
 ) -> Result<()> {
+    if dry_run {
+        return Ok(());
+    }
     match action {
         PackageAction::Install => installers::install_package(package, ctx),
         PackageAction::Update => installers::update_package(package, ctx),
@@ -0,0 +1,219 @@
+//! ops-tools 自我更新
+//!
+//! 從本專案的 GitHub release 下載對應平台的壓縮檔，驗證 SHA-256 後原子替換目前的執行檔
+
+use crate::core::{OperationError, Result, dry_run};
+use crate::features::tool_upgrader::compare_versions;
+use crate::i18n::{self, keys};
+use crate::ui::{Console, Prompts};
+use std::cmp::Ordering;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::shell::{
+    checksum_for, create_temp_dir, download_file, extract_tar, fetch_text, find_binary,
+    find_github_asset, verify_checksum,
+};
+use super::types::{ActionContext, SupportedOs, normalize_version};
+
+/// 發佈 release 的 repo
+const REPO: &str = "DennySORA/Ops-Tools";
+
+/// 壓縮檔內的執行檔名稱（與 release workflow 一致）
+const BINARY_NAME: &str = "ops-tools";
+
+/// 編譯時的版本
+const CURRENT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+pub fn run() {
+    let console = Console::new();
+    let prompts = Prompts::new();
+
+    console.header(i18n::t(keys::SELF_UPDATE_HEADER));
+
+    let Some(os) = SupportedOs::detect() else {
+        console.warning(i18n::t(keys::PACKAGE_MANAGER_UNSUPPORTED_OS));
+        return;
+    };
+    let Some(asset_name) = release_asset_name(os, env::consts::ARCH) else {
+        console.error(i18n::t(keys::PACKAGE_MANAGER_ARCH_UNSUPPORTED));
+        return;
+    };
+
+    let ctx = ActionContext::new(os);
+    let asset = match find_github_asset(REPO, &ctx, |name| name == asset_name) {
+        Ok(asset) => asset,
+        Err(err) => {
+            console.error(&crate::tr!(keys::SELF_UPDATE_CHECK_FAILED, error = err));
+            return;
+        }
+    };
+
+    let latest = normalize_version(&asset.tag).unwrap_or_default();
+    if !is_newer(CURRENT_VERSION, &latest) {
+        console.success(&crate::tr!(
+            keys::SELF_UPDATE_UP_TO_DATE,
+            version = CURRENT_VERSION
+        ));
+        return;
+    }
+
+    console.info(&crate::tr!(
+        keys::SELF_UPDATE_AVAILABLE,
+        current = CURRENT_VERSION,
+        latest = &latest
+    ));
+    if dry_run::is_enabled() {
+        console.info(&crate::tr!(
+            keys::SELF_UPDATE_DRY_RUN,
+            current = CURRENT_VERSION,
+            latest = &latest
+        ));
+        return;
+    }
+    if !prompts.confirm(&crate::tr!(
+        keys::SELF_UPDATE_CONFIRM,
+        current = CURRENT_VERSION,
+        latest = &latest
+    )) {
+        console.warning(i18n::t(keys::SELF_UPDATE_CANCELLED));
+        return;
+    }
+
+    match install_release(
+        &ctx,
+        &asset.name,
+        &asset.url,
+        asset.checksums_url.as_deref(),
+    ) {
+        Ok(path) => console.success(&crate::tr!(
+            keys::SELF_UPDATE_DONE,
+            current = CURRENT_VERSION,
+            latest = &latest,
+            path = path.display()
+        )),
+        Err(err) => console.error(&crate::tr!(keys::SELF_UPDATE_FAILED, error = err)),
+    }
+}
+
+/// 下載、驗證並替換目前的執行檔，回傳被替換的路徑
+fn install_release(
+    ctx: &ActionContext,
+    name: &str,
+    url: &str,
+    checksums_url: Option<&str>,
+) -> Result<PathBuf> {
+    let current_exe = env::current_exe()
+        .and_then(fs::canonicalize)
+        .map_err(|err| OperationError::io("current_exe".to_string(), err))?;
+
+    let temp_dir = create_temp_dir(ctx, "self-update")?;
+    let archive = temp_dir.join(name);
+    // 未提供 checksum 清單時 verify_checksum 會拒絕，不替換未驗證的執行檔
+    let checksums = match checksums_url {
+        Some(url) => fetch_text(ctx, url, &["-H", "User-Agent: ops-tools"])?,
+        None => String::new(),
+    };
+    download_file(ctx, url, &archive)?;
+    verify_checksum(&archive, checksum_for(&checksums, name).unwrap_or_default())?;
+    extract_tar(ctx, &archive, &temp_dir)?;
+
+    let binary = find_binary(&temp_dir, BINARY_NAME).ok_or_else(|| OperationError::Command {
+        command: BINARY_NAME.to_string(),
+        message: i18n::t(keys::PACKAGE_MANAGER_BINARY_NOT_FOUND).to_string(),
+    })?;
+    replace_executable(&binary, &current_exe)?;
+    Ok(current_exe)
+}
+
+/// 先複製到目標目錄中的暫存檔，再以 rename 原子替換（同一檔案系統）
+fn replace_executable(source: &Path, target: &Path) -> Result<()> {
+    let file_name = target
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_else(|| BINARY_NAME.to_string());
+    let staged = target.with_file_name(format!(".{file_name}.new-{}", std::process::id()));
+    let io_error = |path: &Path, err| OperationError::io(path.display().to_string(), err);
+
+    fs::copy(source, &staged).map_err(|err| io_error(&staged, err))?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(&staged, fs::Permissions::from_mode(0o755))
+            .map_err(|err| io_error(&staged, err))?;
+    }
+    fs::rename(&staged, target).map_err(|err| {
+        let _ = fs::remove_file(&staged);
+        io_error(target, err)
+    })
+}
+
+/// 對應平台的 release asset 名稱（見 `.github/workflows/release.yml`）
+fn release_asset_name(os: SupportedOs, arch: &str) -> Option<String> {
+    let os = match os {
+        SupportedOs::Linux => "linux",
+        SupportedOs::Macos => "macos",
+        SupportedOs::FreeBsd => return None,
+    };
+    let arch = match arch {
+        "x86_64" => "x86_64",
+        // release workflow 只發佈 macOS 的 arm64 壓縮檔
+        "aarch64" if os == "macos" => "arm64",
+        _ => return None,
+    };
+    Some(format!("{BINARY_NAME}-{os}-{arch}.tar.gz"))
+}
+
+/// `latest` 是否比 `current` 新；無法解析時視為不需更新
+fn is_newer(current: &str, latest: &str) -> bool {
+    compare_versions(current, latest) == Some(Ordering::Less)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_release_asset_name() {
+        assert_eq!(
+            release_asset_name(SupportedOs::Linux, "x86_64").as_deref(),
+            Some("ops-tools-linux-x86_64.tar.gz")
+        );
+        assert_eq!(
+            release_asset_name(SupportedOs::Macos, "aarch64").as_deref(),
+            Some("ops-tools-macos-arm64.tar.gz")
+        );
+        assert_eq!(release_asset_name(SupportedOs::Linux, "aarch64"), None);
+        assert_eq!(release_asset_name(SupportedOs::Linux, "riscv64"), None);
+        assert_eq!(release_asset_name(SupportedOs::FreeBsd, "x86_64"), None);
+    }
+
+    #[test]
+    fn test_is_newer() {
+        assert!(is_newer("0.1.0", "0.2.0"));
+        assert!(is_newer("0.1.9", "0.1.10"));
+        assert!(!is_newer("0.2.0", "0.2"));
+        assert!(!is_newer("0.2.0", "0.1.5"));
+        assert!(!is_newer("0.1.0", ""));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_replace_executable() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("new");
+        let target = dir.path().join("ops-tools");
+        fs::write(&source, "new").unwrap();
+        fs::write(&target, "old").unwrap();
+
+        replace_executable(&source, &target).unwrap();
+
+        assert_eq!(fs::read_to_string(&target).unwrap(), "new");
+        let mode = fs::metadata(&target).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o755);
+        assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 2);
+    }
+}
@@ -2,16 +2,22 @@
 //!
 //! 提供指令執行、檔案下載、壓縮解壓等底層操作
 
-use crate::core::{OperationError, Result};
+pub use crate::core::requirements::is_command_available;
+use crate::core::{OperationError, Result, TempDir, checksum, process};
 use crate::i18n::{self, keys};
+use crate::ui::{Console, Prompts, suspend_progress};
+use colored::Colorize;
+use dialoguer::{Input, theme::ColorfulTheme};
 use serde::Deserialize;
+use similar::TextDiff;
+use std::collections::HashMap;
 use std::env;
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::process::Command;
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::{Duration, SystemTime};
 
-use super::types::{ActionContext, PackageManager, SupportedOs};
+use super::types::{ActionContext, PackageAction, PackageManager, SupportedOs};
 
 // ============================================================================
 // 指令執行
@@ -32,14 +38,10 @@ pub fn run_command(
         program = "sudo".to_string();
     }
 
-    let status = Command::new(&program)
-        .args(&args_vec)
-        .stdin(std::process::Stdio::null())
-        .status()
-        .map_err(|err| OperationError::Command {
-            command: program.clone(),
-            message: crate::tr!(keys::ERROR_UNABLE_TO_EXECUTE, error = err),
-        })?;
+    let mut command = Command::new(&program);
+    command.args(&args_vec).stdin(std::process::Stdio::null());
+    Console::new().command(&command);
+    let status = suspend_progress(|| process::status_with_timeout(&mut command))?;
 
     if status.success() {
         Ok(format!("{program} completed"))
@@ -66,13 +68,10 @@ pub fn capture_command(
         program = "sudo".to_string();
     }
 
-    let output = Command::new(&program)
-        .args(&args_vec)
-        .output()
-        .map_err(|err| OperationError::Command {
-            command: program.clone(),
-            message: crate::tr!(keys::ERROR_UNABLE_TO_EXECUTE, error = err),
-        })?;
+    let mut command = Command::new(&program);
+    command.args(&args_vec);
+    Console::new().command(&command);
+    let output = process::run_with_timeout(&mut command)?;
 
     if output.status.success() {
         Ok(String::from_utf8_lossy(&output.stdout).to_string())
@@ -115,38 +114,6 @@ pub fn run_shell(ctx: &ActionContext, command: &str, use_sudo: bool) -> Result<S
     }
 }
 
-/// 檢查指令是否可用
-pub fn is_command_available(command: &str) -> Option<PathBuf> {
-    let path = Path::new(command);
-    if path.is_absolute() || command.contains(std::path::MAIN_SEPARATOR) {
-        if path.is_file() {
-            return Some(path.to_path_buf());
-        }
-        return None;
-    }
-
-    let path_var = env::var_os("PATH")?;
-    for dir in env::split_paths(&path_var) {
-        let candidate = dir.join(command);
-        if candidate.is_file() {
-            return Some(candidate);
-        }
-
-        #[cfg(windows)]
-        {
-            let extensions = ["exe", "cmd", "bat"];
-            for ext in extensions {
-                let candidate = dir.join(format!("{}.{}", command, ext));
-                if candidate.is_file() {
-                    return Some(candidate);
-                }
-            }
-        }
-    }
-
-    None
-}
-
 // ============================================================================
 // 檔案下載
 // ============================================================================
@@ -154,10 +121,8 @@ pub fn is_command_available(command: &str) -> Option<PathBuf> {
 /// 下載檔案到指定路徑
 pub fn download_file(ctx: &ActionContext, url: &str, dest: &Path) -> Result<()> {
     if let Some(parent) = dest.parent() {
-        fs::create_dir_all(parent).map_err(|err| OperationError::Io {
-            path: parent.display().to_string(),
-            source: err,
-        })?;
+        fs::create_dir_all(parent)
+            .map_err(|err| OperationError::io(parent.display().to_string(), err))?;
     }
 
     run_command(
@@ -181,23 +146,9 @@ pub fn fetch_text(ctx: &ActionContext, url: &str, extra_args: &[&str]) -> Result
 // 檔案系統操作
 // ============================================================================
 
-/// 建立暫存目錄
-pub fn create_temp_dir(ctx: &ActionContext, prefix: &str) -> Result<PathBuf> {
-    let timestamp = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .map_err(|err| OperationError::Command {
-            command: "time".to_string(),
-            message: err.to_string(),
-        })?
-        .as_millis();
-    let dir = ctx
-        .temp_dir
-        .join(format!("ops-tools-{}-{}", prefix, timestamp));
-    fs::create_dir_all(&dir).map_err(|err| OperationError::Io {
-        path: dir.display().to_string(),
-        source: err,
-    })?;
-    Ok(dir)
+/// 建立暫存目錄，回傳的 [`TempDir`] 離開作用域時自動刪除
+pub fn create_temp_dir(ctx: &ActionContext, prefix: &str) -> Result<TempDir> {
+    TempDir::new_in(&ctx.temp_dir, prefix)
 }
 
 /// 安裝執行檔到系統
@@ -219,15 +170,11 @@ pub fn install_binary(ctx: &ActionContext, source: &Path, name: &str) -> Result<
     }
 
     let local_dir = ctx.home_dir.join(".local/bin");
-    fs::create_dir_all(&local_dir).map_err(|err| OperationError::Io {
-        path: local_dir.display().to_string(),
-        source: err,
-    })?;
+    fs::create_dir_all(&local_dir)
+        .map_err(|err| OperationError::io(local_dir.display().to_string(), err))?;
     let target = local_dir.join(name);
-    fs::copy(source, &target).map_err(|err| OperationError::Io {
-        path: target.display().to_string(),
-        source: err,
-    })?;
+    fs::copy(source, &target)
+        .map_err(|err| OperationError::io(target.display().to_string(), err))?;
     set_executable(&target)?;
     Ok(target)
 }
@@ -244,10 +191,8 @@ pub fn remove_binary(ctx: &ActionContext, name: &str) -> Result<()> {
 pub fn remove_home_binary(ctx: &ActionContext, name: &str) -> Result<()> {
     let local_bin = ctx.home_dir.join(".local/bin").join(name);
     if local_bin.exists() {
-        fs::remove_file(&local_bin).map_err(|err| OperationError::Io {
-            path: local_bin.display().to_string(),
-            source: err,
-        })?;
+        fs::remove_file(&local_bin)
+            .map_err(|err| OperationError::io(local_bin.display().to_string(), err))?;
     }
     Ok(())
 }
@@ -258,10 +203,8 @@ pub fn remove_file(ctx: &ActionContext, path: &Path) -> Result<()> {
         if path.starts_with("/usr/local") && ctx.sudo_available {
             run_command(ctx, "rm", &["-f", path.to_str().unwrap_or_default()], true)?;
         } else {
-            fs::remove_file(path).map_err(|err| OperationError::Io {
-                path: path.display().to_string(),
-                source: err,
-            })?;
+            fs::remove_file(path)
+                .map_err(|err| OperationError::io(path.display().to_string(), err))?;
         }
     }
     Ok(())
@@ -273,16 +216,11 @@ pub fn set_executable(path: &Path) -> Result<()> {
     {
         use std::os::unix::fs::PermissionsExt;
         let mut perms = fs::metadata(path)
-            .map_err(|err| OperationError::Io {
-                path: path.display().to_string(),
-                source: err,
-            })?
+            .map_err(|err| OperationError::io(path.display().to_string(), err))?
             .permissions();
         perms.set_mode(0o755);
-        fs::set_permissions(path, perms).map_err(|err| OperationError::Io {
-            path: path.display().to_string(),
-            source: err,
-        })?;
+        fs::set_permissions(path, perms)
+            .map_err(|err| OperationError::io(path.display().to_string(), err))?;
     }
     Ok(())
 }
@@ -304,34 +242,75 @@ pub fn ensure_profile_line(ctx: &ActionContext, line: &str) -> Result<()> {
         }
         content.push_str(line);
         content.push('\n');
-        fs::write(&profile, content).map_err(|err| OperationError::Io {
-            path: profile.display().to_string(),
-            source: err,
-        })?;
+        fs::write(&profile, content)
+            .map_err(|err| OperationError::io(profile.display().to_string(), err))?;
     }
     Ok(())
 }
 
 /// 寫入設定檔（含備份）
+///
+/// 既有檔案內容不同時先顯示 unified diff，使用者確認後才備份並覆寫
 pub fn write_config_with_backup(path: &Path, content: &str) -> Result<()> {
     if let Ok(existing) = fs::read_to_string(path) {
         if existing == content {
             return Ok(());
         }
         let backup = backup_path(path);
-        fs::copy(path, &backup).map_err(|err| OperationError::Io {
-            path: backup.display().to_string(),
-            source: err,
-        })?;
+        if !suspend_progress(|| confirm_overwrite(path, &backup, &existing, content)) {
+            Console::new().info(&crate::tr!(
+                keys::PACKAGE_MANAGER_CONFIG_KEPT,
+                path = path.display()
+            ));
+            return Ok(());
+        }
+        fs::copy(path, &backup)
+            .map_err(|err| OperationError::io(backup.display().to_string(), err))?;
     }
 
-    fs::write(path, content).map_err(|err| OperationError::Io {
-        path: path.display().to_string(),
-        source: err,
-    })?;
+    fs::write(path, content).map_err(|err| OperationError::io(path.display().to_string(), err))?;
     Ok(())
 }
 
+/// 顯示既有設定與新內容的差異並詢問是否覆寫
+fn confirm_overwrite(path: &Path, backup: &Path, existing: &str, content: &str) -> bool {
+    let console = Console::new();
+    console.warning(&crate::tr!(
+        keys::PACKAGE_MANAGER_CONFIG_DIFF_HEADER,
+        path = path.display()
+    ));
+    for line in config_diff(path, existing, content).lines() {
+        let styled = if line.starts_with("+++") || line.starts_with("---") {
+            line.bold()
+        } else if line.starts_with('+') {
+            line.green()
+        } else if line.starts_with('-') {
+            line.red()
+        } else if line.starts_with("@@") {
+            line.cyan()
+        } else {
+            line.normal()
+        };
+        console.raw(&styled.to_string());
+    }
+
+    Prompts::new().confirm(&crate::tr!(
+        keys::PACKAGE_MANAGER_CONFIG_OVERWRITE_CONFIRM,
+        path = path.display(),
+        backup = backup.display()
+    ))
+}
+
+/// 既有檔案（`a/`）與新內容（`b/`）的 unified diff
+fn config_diff(path: &Path, existing: &str, content: &str) -> String {
+    let name = path.display().to_string();
+    TextDiff::from_lines(existing, content)
+        .unified_diff()
+        .context_radius(3)
+        .header(&format!("a/{name}"), &format!("b/{name}"))
+        .to_string()
+}
+
 /// 產生備份檔案路徑
 fn backup_path(path: &Path) -> PathBuf {
     let name = path
@@ -364,37 +343,33 @@ pub fn create_symlink(target: &Path, link: &Path) -> Result<()> {
     }
     #[cfg(unix)]
     {
-        std::os::unix::fs::symlink(target, link).map_err(|err| OperationError::Io {
-            path: link.display().to_string(),
-            source: err,
-        })?;
+        std::os::unix::fs::symlink(target, link)
+            .map_err(|err| OperationError::io(link.display().to_string(), err))?;
     }
     Ok(())
 }
 
-/// 驗證檔案 checksum
-pub fn verify_checksum(ctx: &ActionContext, path: &Path, checksum: &str) -> Result<()> {
-    if is_command_available("sha256sum").is_some() {
-        let command = format!(
-            "echo \"{}  {}\" | sha256sum --check",
-            checksum,
-            path.to_str().unwrap_or_default()
-        );
-        run_shell(ctx, &command, false)?;
-        return Ok(());
-    }
-
-    if is_command_available("shasum").is_some() {
-        let command = format!(
-            "echo \"{}  {}\" | shasum -a 256 -c -",
-            checksum,
-            path.to_str().unwrap_or_default()
-        );
-        run_shell(ctx, &command, false)?;
-        return Ok(());
-    }
+/// 驗證檔案的 SHA-256（以 Rust 計算，不依賴 sha256sum/shasum）
+///
+/// `checksum` 可為單純的雜湊或 `<hash>  <file>` 格式；沒有可用的雜湊時視為無法驗證並回傳錯誤
+pub fn verify_checksum(path: &Path, checksum: &str) -> Result<()> {
+    let Some(expected) = checksum.split_whitespace().next() else {
+        return Err(OperationError::Validation(crate::tr!(
+            keys::PACKAGE_MANAGER_CHECKSUM_MISSING,
+            file = path.display()
+        )));
+    };
+    checksum::verify_sha256(path, expected)
+}
 
-    Ok(())
+/// 從 `<hash>  <file>` 格式的清單（例如 checksums.sha256）找出指定檔案的雜湊
+pub fn checksum_for<'a>(listing: &'a str, filename: &str) -> Option<&'a str> {
+    listing.lines().find_map(|line| {
+        let mut parts = line.split_whitespace();
+        let hash = parts.next()?;
+        let name = parts.next()?.trim_start_matches('*');
+        (name == filename).then_some(hash)
+    })
 }
 
 /// 在目錄中尋找執行檔
@@ -509,40 +484,132 @@ pub fn require_package_manager(ctx: &ActionContext) -> Result<PackageManager> {
     })
 }
 
-/// 偵測 apt codename
+/// HashiCorp apt repo 提供的上游 codename（Debian 與 Ubuntu）
+const APT_UPSTREAM_CODENAMES: [&str; 9] = [
+    "buster", "bullseye", "bookworm", "trixie", "focal", "jammy", "noble", "oracular", "plucky",
+];
+
+/// 衍生版 codename 對應的 Debian codename（os-release 沒有提供上游 codename 的發行版）
+const APT_DERIVATIVE_CODENAMES: [(&str, &str); 6] = [
+    ("beowulf", "buster"),
+    ("chimaera", "bullseye"),
+    ("daedalus", "bookworm"),
+    ("excalibur", "trixie"),
+    ("byzantium", "bullseye"),
+    ("crimson", "bookworm"),
+];
+
+/// apt codename 偵測結果
+#[derive(Debug, PartialEq, Eq)]
+enum AptCodename {
+    /// 可直接用於 HashiCorp repo 的上游 codename
+    Known(String),
+    /// 無法確定，附上最可能的猜測
+    Ambiguous(Option<String>),
+}
+
+/// 偵測 apt codename，並顯示結果讓使用者在加入 repo 前確認
 pub fn detect_apt_codename(ctx: &ActionContext) -> Result<String> {
-    if let Some(value) = read_os_release_value("VERSION_CODENAME")
-        .or_else(|| read_os_release_value("UBUNTU_CODENAME"))
-    {
-        return Ok(value);
-    }
+    let os_release = fs::read_to_string("/etc/os-release").unwrap_or_default();
+    let mut detected = parse_apt_codename(&os_release);
 
-    if is_command_available("lsb_release").is_some() {
+    if detected == AptCodename::Ambiguous(None) && is_command_available("lsb_release").is_some() {
         let output = capture_command(ctx, "lsb_release", &["-cs"], false)?;
         let code = output.trim();
         if !code.is_empty() {
-            return Ok(code.to_string());
+            detected = classify_apt_codename(&os_release_values(&os_release), code);
         }
     }
 
-    Err(OperationError::Command {
+    let codename = match detected {
+        AptCodename::Known(codename) => codename,
+        AptCodename::Ambiguous(guess) => suspend_progress(|| prompt_apt_codename(guess))?,
+    };
+    Console::new().info(&crate::tr!(
+        keys::PACKAGE_MANAGER_APT_CODENAME,
+        codename = &codename
+    ));
+    Ok(codename)
+}
+
+/// 從 os-release 內容判斷上游 codename
+///
+/// Mint、Pop!_OS、LMDE 等衍生版的 `VERSION_CODENAME` 是自己的名稱，
+/// 因此優先使用 `UBUNTU_CODENAME` / `DEBIAN_CODENAME`
+fn parse_apt_codename(os_release: &str) -> AptCodename {
+    let values = os_release_values(os_release);
+    let upstream = ["UBUNTU_CODENAME", "DEBIAN_CODENAME"]
+        .iter()
+        .find_map(|key| values.get(key).filter(|value| !value.is_empty()));
+    if let Some(codename) = upstream {
+        return AptCodename::Known(codename.to_string());
+    }
+    match values
+        .get("VERSION_CODENAME")
+        .filter(|value| !value.is_empty())
+    {
+        Some(codename) => classify_apt_codename(&values, codename),
+        None => AptCodename::Ambiguous(None),
+    }
+}
+
+/// Debian/Ubuntu 本身或已知 codename 視為確定；其餘以 `ID_LIKE` 為底的衍生版名稱只當作猜測
+fn classify_apt_codename(values: &HashMap<&str, &str>, codename: &str) -> AptCodename {
+    let id = values.get("ID").copied().unwrap_or_default();
+    if matches!(id, "debian" | "ubuntu") || APT_UPSTREAM_CODENAMES.contains(&codename) {
+        return AptCodename::Known(codename.to_string());
+    }
+    let based_on_apt = values.get("ID_LIKE").is_some_and(|like| {
+        like.split_whitespace()
+            .any(|base| matches!(base, "debian" | "ubuntu"))
+    });
+    match APT_DERIVATIVE_CODENAMES
+        .iter()
+        .find(|(derivative, _)| *derivative == codename)
+    {
+        Some((_, upstream)) if based_on_apt => AptCodename::Known(upstream.to_string()),
+        _ => AptCodename::Ambiguous(Some(codename.to_string())),
+    }
+}
+
+/// 無法確定 codename 時請使用者輸入（預設為猜測值）；非互動模式直接使用猜測值
+fn prompt_apt_codename(guess: Option<String>) -> Result<String> {
+    let missing = || OperationError::Command {
         command: "lsb_release".to_string(),
         message: i18n::t(keys::PACKAGE_MANAGER_CODENAME_MISSING).to_string(),
-    })
-}
+    };
+    let console = Console::new();
+    console.warning(&crate::tr!(
+        keys::PACKAGE_MANAGER_CODENAME_AMBIGUOUS,
+        guess = guess.as_deref().unwrap_or("-"),
+        known = APT_UPSTREAM_CODENAMES.join(", ")
+    ));
+    if !Prompts::is_interactive() {
+        return guess.ok_or_else(missing);
+    }
 
-/// 讀取 /etc/os-release 的值
-fn read_os_release_value(key: &str) -> Option<String> {
-    let content = fs::read_to_string("/etc/os-release").ok()?;
-    for line in content.lines() {
-        let mut parts = line.splitn(2, '=');
-        let k = parts.next()?.trim();
-        let v = parts.next()?.trim().trim_matches('"');
-        if k == key {
-            return Some(v.to_string());
-        }
+    let theme = ColorfulTheme::default();
+    let mut input = Input::<String>::with_theme(&theme)
+        .with_prompt(i18n::t(keys::PACKAGE_MANAGER_CODENAME_PROMPT))
+        .allow_empty(guess.is_some());
+    if let Some(guess) = &guess {
+        input = input.default(guess.clone());
     }
-    None
+    let codename = input.interact_text().map_err(|_| missing())?;
+    let codename = codename.trim();
+    if codename.is_empty() {
+        return Err(missing());
+    }
+    Ok(codename.to_string())
+}
+
+/// 解析 os-release 的 `KEY=value` 行
+fn os_release_values(content: &str) -> HashMap<&str, &str> {
+    content
+        .lines()
+        .filter_map(|line| line.split_once('='))
+        .map(|(key, value)| (key.trim(), value.trim().trim_matches('"')))
+        .collect()
 }
 
 /// 使用系統套件管理器安裝
@@ -550,7 +617,13 @@ pub fn install_with_manager(ctx: &mut ActionContext, package: &str) -> Result<()
     let manager = require_package_manager(ctx)?;
     match manager {
         PackageManager::Brew => {
-            run_command(ctx, "brew", &["install", package], false)?;
+            let cask = ctx.is_brew_cask(package);
+            run_command(
+                ctx,
+                "brew",
+                &brew_args(PackageAction::Install, package, cask),
+                false,
+            )?;
         }
         PackageManager::Apt => {
             ensure_apt_updated(ctx)?;
@@ -572,6 +645,9 @@ pub fn install_with_manager(ctx: &mut ActionContext, package: &str) -> Result<()
         PackageManager::Apk => {
             run_command(ctx, "apk", &["add", package], true)?;
         }
+        PackageManager::Pkg => {
+            run_command(ctx, "pkg", &["install", "-y", package], true)?;
+        }
     }
     Ok(())
 }
@@ -581,7 +657,13 @@ pub fn update_with_manager(ctx: &mut ActionContext, package: &str) -> Result<()>
     let manager = require_package_manager(ctx)?;
     match manager {
         PackageManager::Brew => {
-            run_command(ctx, "brew", &["upgrade", package], false)?;
+            let cask = ctx.is_brew_cask(package);
+            run_command(
+                ctx,
+                "brew",
+                &brew_args(PackageAction::Update, package, cask),
+                false,
+            )?;
         }
         PackageManager::Apt => {
             ensure_apt_updated(ctx)?;
@@ -608,16 +690,40 @@ pub fn update_with_manager(ctx: &mut ActionContext, package: &str) -> Result<()>
         PackageManager::Apk => {
             run_command(ctx, "apk", &["upgrade", package], true)?;
         }
+        PackageManager::Pkg => {
+            run_command(ctx, "pkg", &["upgrade", "-y", package], true)?;
+        }
     }
     Ok(())
 }
 
+/// brew 的參數；cask 套件加上 `--cask`，其餘維持 formula 的用法
+fn brew_args(action: PackageAction, package: &str, cask: bool) -> Vec<&str> {
+    let verb = match action {
+        PackageAction::Install => "install",
+        PackageAction::Update => "upgrade",
+        PackageAction::Remove => "uninstall",
+    };
+    let mut args = vec![verb];
+    if cask {
+        args.push("--cask");
+    }
+    args.push(package);
+    args
+}
+
 /// 使用系統套件管理器移除
 pub fn remove_with_manager(ctx: &mut ActionContext, package: &str) -> Result<()> {
     let manager = require_package_manager(ctx)?;
     match manager {
         PackageManager::Brew => {
-            run_command(ctx, "brew", &["uninstall", package], false)?;
+            let cask = ctx.is_brew_cask(package);
+            run_command(
+                ctx,
+                "brew",
+                &brew_args(PackageAction::Remove, package, cask),
+                false,
+            )?;
         }
         PackageManager::Apt => {
             run_command(ctx, "apt-get", &["remove", "-y", package], true)?;
@@ -637,6 +743,9 @@ pub fn remove_with_manager(ctx: &mut ActionContext, package: &str) -> Result<()>
         PackageManager::Apk => {
             run_command(ctx, "apk", &["del", package], true)?;
         }
+        PackageManager::Pkg => {
+            run_command(ctx, "pkg", &["delete", "-y", package], true)?;
+        }
     }
     Ok(())
 }
@@ -659,6 +768,7 @@ pub fn go_arch() -> Result<&'static str> {
 
 #[derive(Deserialize)]
 struct GoRelease {
+    version: String,
     stable: bool,
     files: Vec<GoFile>,
 }
@@ -669,34 +779,56 @@ struct GoFile {
     os: String,
     arch: String,
     kind: String,
+    #[serde(default)]
+    sha256: String,
 }
 
 /// Go 下載資訊
 pub struct GoDownload {
     pub filename: String,
     pub url: String,
+    /// 發佈清單中的 SHA-256（缺少時改讀 `<url>.sha256`）
+    pub sha256: Option<String>,
 }
 
-/// 取得最新 Go 下載連結
-pub fn latest_go_download(ctx: &ActionContext) -> Result<GoDownload> {
-    let json = fetch_text(ctx, "https://go.dev/dl/?mode=json", &[])?;
+/// 取得 Go 下載連結（未指定版本時為最新穩定版）
+pub fn go_download(ctx: &ActionContext, version: Option<&str>) -> Result<GoDownload> {
+    let url = match version {
+        Some(_) => "https://go.dev/dl/?mode=json&include=all",
+        None => "https://go.dev/dl/?mode=json",
+    };
+    let json = fetch_text(ctx, url, &[])?;
     let releases: Vec<GoRelease> =
         serde_json::from_str(&json).map_err(|err| OperationError::Command {
             command: "go release".to_string(),
             message: err.to_string(),
         })?;
     let release =
-        releases
-            .into_iter()
-            .find(|rel| rel.stable)
-            .ok_or_else(|| OperationError::Command {
-                command: "go release".to_string(),
-                message: i18n::t(keys::PACKAGE_MANAGER_GO_VERSION_MISSING).to_string(),
-            })?;
+        match version {
+            Some(version) => {
+                let wanted = format!("go{version}");
+                releases
+                    .into_iter()
+                    .find(|rel| rel.version == wanted)
+                    .ok_or_else(|| OperationError::Command {
+                        command: "go release".to_string(),
+                        message: crate::tr!(
+                            keys::PACKAGE_MANAGER_GO_RELEASE_NOT_FOUND,
+                            version = version
+                        ),
+                    })?
+            }
+            None => releases.into_iter().find(|rel| rel.stable).ok_or_else(|| {
+                OperationError::Command {
+                    command: "go release".to_string(),
+                    message: i18n::t(keys::PACKAGE_MANAGER_GO_VERSION_MISSING).to_string(),
+                }
+            })?,
+        };
 
     let arch = go_arch()?;
     let desired_kind = match ctx.os {
-        SupportedOs::Linux => "archive",
+        SupportedOs::Linux | SupportedOs::FreeBsd => "archive",
         SupportedOs::Macos => "installer",
     };
     let file = release
@@ -709,19 +841,26 @@ pub fn latest_go_download(ctx: &ActionContext) -> Result<GoDownload> {
         })?;
 
     Ok(GoDownload {
-        filename: file.filename.clone(),
         url: format!("https://go.dev/dl/{}", file.filename),
+        sha256: Some(file.sha256).filter(|sha256| !sha256.is_empty()),
+        filename: file.filename,
     })
 }
 
 /// GitHub Release Asset
 pub struct GithubAsset {
+    /// release 的 tag（例如 `v1.2.0`）
+    pub tag: String,
     pub name: String,
     pub url: String,
+    /// 同一 release 中的 checksum 清單（例如 `checksums.sha256`）
+    pub checksums_url: Option<String>,
 }
 
 #[derive(Deserialize)]
 struct GithubRelease {
+    #[serde(default)]
+    tag_name: String,
     assets: Vec<GithubReleaseAsset>,
 }
 
@@ -731,44 +870,157 @@ struct GithubReleaseAsset {
     browser_download_url: String,
 }
 
-/// 取得最新 GitHub release asset
-pub fn latest_github_asset(
-    repo: &str,
-    ctx: &ActionContext,
-    prefix: &str,
-    suffix: &str,
-) -> Result<GithubAsset> {
+/// 選用的 GitHub token，可提高 API 速率上限
+const GITHUB_TOKEN_ENV: &str = "GITHUB_TOKEN";
+
+/// release 查詢結果的快取有效時間
+const GITHUB_RELEASE_CACHE_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// release JSON 的快取檔（依 repo 區分）
+fn github_release_cache_path(repo: &str) -> Option<PathBuf> {
+    dirs::cache_dir().map(|dir| {
+        dir.join("ops-tools")
+            .join("github-releases")
+            .join(format!("{}.json", repo.replace('/', "__")))
+    })
+}
+
+/// 快取是否仍在有效時間內
+fn is_cache_fresh(modified: SystemTime, now: SystemTime, ttl: Duration) -> bool {
+    now.duration_since(modified)
+        .map(|age| age < ttl)
+        .unwrap_or(true)
+}
+
+fn read_fresh_cache(path: &Path) -> Option<String> {
+    let modified = fs::metadata(path).ok()?.modified().ok()?;
+    if !is_cache_fresh(modified, SystemTime::now(), GITHUB_RELEASE_CACHE_TTL) {
+        return None;
+    }
+    fs::read_to_string(path).ok()
+}
+
+/// 查詢最新 release；有 `GITHUB_TOKEN` 時附上授權，並快取一小時
+fn fetch_latest_release(ctx: &ActionContext, repo: &str) -> Result<GithubRelease> {
+    let cache_path = github_release_cache_path(repo);
+    if let Some(release) = cache_path
+        .as_deref()
+        .and_then(read_fresh_cache)
+        .and_then(|json| serde_json::from_str(&json).ok())
+    {
+        return Ok(release);
+    }
+
     let url = format!("https://api.github.com/repos/{repo}/releases/latest");
-    let json = fetch_text(ctx, &url, &["-H", "User-Agent: ops-tools"])?;
+    // token 透過暫存的 header 檔傳給 curl，避免出現在命令列（--verbose 會顯示命令）
+    let token = env::var(GITHUB_TOKEN_ENV)
+        .ok()
+        .filter(|token| !token.trim().is_empty());
+    let header_file = match token {
+        Some(token) => {
+            let file = tempfile::NamedTempFile::new()
+                .map_err(|err| OperationError::io("tempfile".to_string(), err))?;
+            fs::write(
+                file.path(),
+                format!("Authorization: Bearer {}\n", token.trim()),
+            )
+            .map_err(|err| OperationError::io(file.path().display().to_string(), err))?;
+            Some(file)
+        }
+        None => None,
+    };
+    let auth_header = header_file
+        .as_ref()
+        .map(|file| format!("@{}", file.path().display()));
+    let mut extra_args = vec!["-H", "User-Agent: ops-tools"];
+    if let Some(header) = &auth_header {
+        extra_args.extend(["-H", header.as_str()]);
+    }
+
+    let json = fetch_text(ctx, &url, &extra_args).map_err(|err| match err {
+        OperationError::Command { message, .. } if is_rate_limited(&message) => {
+            OperationError::Command {
+                command: "github release".to_string(),
+                message: crate::tr!(keys::PACKAGE_MANAGER_GITHUB_RATE_LIMITED, repo = repo),
+            }
+        }
+        err => err,
+    })?;
     let release: GithubRelease =
         serde_json::from_str(&json).map_err(|err| OperationError::Command {
             command: "github release".to_string(),
             message: err.to_string(),
         })?;
 
+    if let Some(path) = cache_path {
+        // 快取失敗不影響安裝
+        let _ = path
+            .parent()
+            .map(fs::create_dir_all)
+            .transpose()
+            .and_then(|_| fs::write(&path, &json));
+    }
+    Ok(release)
+}
+
+/// curl 的錯誤訊息是否為 GitHub API 速率限制（403/429）
+fn is_rate_limited(message: &str) -> bool {
+    message.contains("error: 403") || message.contains("error: 429")
+}
+
+/// 取得最新 GitHub release asset
+pub fn latest_github_asset(
+    repo: &str,
+    ctx: &ActionContext,
+    prefix: &str,
+    suffix: &str,
+) -> Result<GithubAsset> {
     let os_token = match ctx.os {
         SupportedOs::Linux => "Linux",
         SupportedOs::Macos => "Darwin",
+        SupportedOs::FreeBsd => "Freebsd",
     };
     let arch_token = go_arch()?;
 
-    let asset = release
+    find_github_asset(repo, ctx, |name| {
+        name.contains(prefix)
+            && name.contains(os_token)
+            && name.contains(arch_token)
+            && name.ends_with(suffix)
+    })
+}
+
+/// 在最新 GitHub release 中找出第一個名稱符合條件的 asset
+pub fn find_github_asset(
+    repo: &str,
+    ctx: &ActionContext,
+    matches: impl Fn(&str) -> bool,
+) -> Result<GithubAsset> {
+    let release = fetch_latest_release(ctx, repo)?;
+
+    let checksums_url = release
         .assets
-        .into_iter()
+        .iter()
         .find(|asset| {
-            asset.name.contains(prefix)
-                && asset.name.contains(os_token)
-                && asset.name.contains(arch_token)
-                && asset.name.ends_with(suffix)
+            let name = asset.name.to_ascii_lowercase();
+            name.contains("checksums") || name.ends_with(".sha256")
         })
+        .map(|asset| asset.browser_download_url.clone());
+
+    let asset = release
+        .assets
+        .into_iter()
+        .find(|asset| matches(&asset.name))
         .ok_or_else(|| OperationError::Command {
             command: "github release".to_string(),
             message: i18n::t(keys::PACKAGE_MANAGER_RELEASE_ASSET_MISSING).to_string(),
         })?;
 
     Ok(GithubAsset {
+        tag: release.tag_name,
         name: asset.name,
         url: asset.browser_download_url,
+        checksums_url,
     })
 }
 
@@ -809,3 +1061,138 @@ pub fn uv_path(ctx: &ActionContext) -> Option<PathBuf> {
     ];
     candidates.into_iter().find(|path| path.is_file())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apt_codename_prefers_upstream() {
+        let mint = "ID=linuxmint\nID_LIKE=\"ubuntu debian\"\nVERSION_CODENAME=wilma\nUBUNTU_CODENAME=noble\n";
+        assert_eq!(parse_apt_codename(mint), AptCodename::Known("noble".into()));
+
+        let lmde =
+            "ID=linuxmint\nID_LIKE=debian\nVERSION_CODENAME=faye\nDEBIAN_CODENAME=bookworm\n";
+        assert_eq!(
+            parse_apt_codename(lmde),
+            AptCodename::Known("bookworm".into())
+        );
+
+        let debian = "ID=debian\nVERSION_CODENAME=forky\n";
+        assert_eq!(
+            parse_apt_codename(debian),
+            AptCodename::Known("forky".into())
+        );
+    }
+
+    #[test]
+    fn test_apt_codename_maps_derivatives() {
+        let devuan = "ID=devuan\nID_LIKE=debian\nVERSION_CODENAME=daedalus\n";
+        assert_eq!(
+            parse_apt_codename(devuan),
+            AptCodename::Known("bookworm".into())
+        );
+
+        let raspbian = "ID=raspbian\nID_LIKE=debian\nVERSION_CODENAME=bookworm\n";
+        assert_eq!(
+            parse_apt_codename(raspbian),
+            AptCodename::Known("bookworm".into())
+        );
+
+        let kali = "ID=kali\nID_LIKE=debian\nVERSION_CODENAME=kali-rolling\n";
+        assert_eq!(
+            parse_apt_codename(kali),
+            AptCodename::Ambiguous(Some("kali-rolling".into()))
+        );
+        assert_eq!(parse_apt_codename(""), AptCodename::Ambiguous(None));
+    }
+
+    #[test]
+    fn test_brew_args_add_cask_only_for_casks() {
+        assert_eq!(
+            brew_args(PackageAction::Install, "ffmpeg", false),
+            ["install", "ffmpeg"]
+        );
+        assert_eq!(
+            brew_args(PackageAction::Install, "docker", true),
+            ["install", "--cask", "docker"]
+        );
+        assert_eq!(
+            brew_args(PackageAction::Update, "docker", true),
+            ["upgrade", "--cask", "docker"]
+        );
+        assert_eq!(
+            brew_args(PackageAction::Remove, "vim", false),
+            ["uninstall", "vim"]
+        );
+    }
+
+    #[test]
+    fn test_config_diff_is_unified() {
+        let diff = config_diff(
+            Path::new(".vimrc"),
+            "set number\nset mouse=a\n",
+            "set number\nset mouse=\nsyntax on\n",
+        );
+        assert!(diff.starts_with("--- a/.vimrc\n+++ b/.vimrc\n@@"));
+        assert!(diff.contains("\n-set mouse=a\n"));
+        assert!(diff.contains("\n+set mouse=\n+syntax on\n"));
+        assert!(diff.contains("\n set number\n"));
+    }
+
+    #[test]
+    fn test_checksum_for_listing() {
+        let listing = "\
+aaa111  k9s_Darwin_arm64.tar.gz
+bbb222  k9s_Linux_amd64.tar.gz
+ccc333 *k9s_Linux_arm64.tar.gz
+";
+        assert_eq!(
+            checksum_for(listing, "k9s_Linux_amd64.tar.gz"),
+            Some("bbb222")
+        );
+        assert_eq!(
+            checksum_for(listing, "k9s_Linux_arm64.tar.gz"),
+            Some("ccc333")
+        );
+        assert_eq!(checksum_for(listing, "k9s_Windows_amd64.zip"), None);
+    }
+
+    #[test]
+    fn test_github_release_cache_freshness() {
+        let now = SystemTime::now();
+        let ttl = Duration::from_secs(3600);
+        assert!(is_cache_fresh(now - Duration::from_secs(60), now, ttl));
+        assert!(!is_cache_fresh(now - Duration::from_secs(7200), now, ttl));
+        // 時鐘回撥時視為新的快取
+        assert!(is_cache_fresh(now + Duration::from_secs(60), now, ttl));
+
+        let path = github_release_cache_path("derailed/k9s").unwrap();
+        assert!(path.ends_with("github-releases/derailed__k9s.json"));
+    }
+
+    #[test]
+    fn test_rate_limit_detection() {
+        assert!(is_rate_limited(
+            "curl: (22) The requested URL returned error: 403"
+        ));
+        assert!(is_rate_limited(
+            "curl: (22) The requested URL returned error: 429"
+        ));
+        assert!(!is_rate_limited(
+            "curl: (22) The requested URL returned error: 404"
+        ));
+    }
+
+    #[test]
+    fn test_verify_checksum_requires_hash() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("kubectl");
+        fs::write(&path, b"hello").unwrap();
+
+        let hello = "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824";
+        assert!(verify_checksum(&path, &format!("{hello}  kubectl\n")).is_ok());
+        assert!(verify_checksum(&path, "  ").is_err());
+        assert!(verify_checksum(&path, &"0".repeat(64)).is_err());
+    }
+}
@@ -3,9 +3,11 @@
 //! 包含 PackageAction、PackageId、SupportedOs 等核心型別
 
 use crate::i18n::{self, keys};
+use std::collections::HashMap;
 use std::env;
 use std::path::PathBuf;
 
+use super::custom::CustomPackage;
 use super::shell::is_command_available;
 
 // ============================================================================
@@ -13,10 +15,11 @@ use super::shell::is_command_available;
 // ============================================================================
 
 /// 支援的作業系統
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum SupportedOs {
     Linux,
     Macos,
+    FreeBsd,
 }
 
 impl SupportedOs {
@@ -25,6 +28,7 @@ impl SupportedOs {
         match env::consts::OS {
             "linux" => Some(Self::Linux),
             "macos" => Some(Self::Macos),
+            "freebsd" => Some(Self::FreeBsd),
             _ => None,
         }
     }
@@ -34,6 +38,7 @@ impl SupportedOs {
         match self {
             Self::Linux => "Linux",
             Self::Macos => "macOS",
+            Self::FreeBsd => "FreeBSD",
         }
     }
 
@@ -42,6 +47,7 @@ impl SupportedOs {
         match self {
             Self::Linux => "linux",
             Self::Macos => "darwin",
+            Self::FreeBsd => "freebsd",
         }
     }
 
@@ -61,9 +67,35 @@ pub enum PackageManager {
     Pacman,
     Zypper,
     Apk,
+    /// FreeBSD pkg
+    Pkg,
 }
 
 impl PackageManager {
+    /// 取得顯示用名稱
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Brew => "brew",
+            Self::Apt => "apt",
+            Self::Dnf => "dnf",
+            Self::Yum => "yum",
+            Self::Pacman => "pacman",
+            Self::Zypper => "zypper",
+            Self::Apk => "apk",
+            Self::Pkg => "pkg",
+        }
+    }
+
+    /// 組合指定版本的套件名稱（不支援版本鎖定時回傳 None）
+    pub fn versioned_package(self, package: &str, version: &str) -> Option<String> {
+        match self {
+            Self::Apt | Self::Zypper | Self::Apk => Some(format!("{package}={version}")),
+            Self::Dnf | Self::Yum => Some(format!("{package}-{version}")),
+            Self::Brew => Some(format!("{package}@{version}")),
+            Self::Pacman | Self::Pkg => None,
+        }
+    }
+
     /// 偵測系統套件管理器
     pub fn detect(os: SupportedOs) -> Option<Self> {
         match os {
@@ -91,6 +123,7 @@ impl PackageManager {
                     None
                 }
             }
+            SupportedOs::FreeBsd => is_command_available("pkg").map(|_| Self::Pkg),
         }
     }
 }
@@ -119,7 +152,7 @@ impl PackageAction {
 }
 
 /// 套件識別碼
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum PackageId {
     Nvm,
     Pnpm,
@@ -135,13 +168,26 @@ pub enum PackageId {
     Tmux,
     Vim,
     Ffmpeg,
+    /// 使用者自訂套件（ActionContext 中的索引）
+    Custom(usize),
+}
+
+impl PackageId {
+    /// 是否可在該作業系統上安裝；pnpm、bun、uv 的安裝腳本沒有 FreeBSD 版本
+    pub fn supports(self, os: SupportedOs) -> bool {
+        !(os == SupportedOs::FreeBsd && matches!(self, Self::Pnpm | Self::Bun | Self::Uv))
+    }
 }
 
 /// 套件定義
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Debug)]
 pub struct PackageDefinition {
     pub id: PackageId,
-    pub name: &'static str,
+    pub name: String,
+    /// 安裝時可指定版本
+    pub pinnable: bool,
+    /// macOS 上以 Homebrew cask（`brew install --cask`）安裝
+    pub brew_cask: bool,
 }
 
 /// 取得所有套件定義
@@ -149,59 +195,87 @@ pub fn package_definitions() -> Vec<PackageDefinition> {
     vec![
         PackageDefinition {
             id: PackageId::Nvm,
-            name: "nvm",
+            name: "nvm".to_string(),
+            pinnable: false,
+            brew_cask: false,
         },
         PackageDefinition {
             id: PackageId::Pnpm,
-            name: "pnpm",
+            name: "pnpm".to_string(),
+            pinnable: false,
+            brew_cask: false,
         },
         PackageDefinition {
             id: PackageId::Bun,
-            name: "bun",
+            name: "bun".to_string(),
+            pinnable: false,
+            brew_cask: false,
         },
         PackageDefinition {
             id: PackageId::Rust,
-            name: "Rust",
+            name: "Rust".to_string(),
+            pinnable: true,
+            brew_cask: false,
         },
         PackageDefinition {
             id: PackageId::Go,
-            name: "Go",
+            name: "Go".to_string(),
+            pinnable: true,
+            brew_cask: false,
         },
         PackageDefinition {
             id: PackageId::Terraform,
-            name: "Terraform",
+            name: "Terraform".to_string(),
+            pinnable: true,
+            brew_cask: false,
         },
         PackageDefinition {
             id: PackageId::Kubectl,
-            name: "kubectl",
+            name: "kubectl".to_string(),
+            pinnable: true,
+            brew_cask: false,
         },
         PackageDefinition {
             id: PackageId::Kubectx,
-            name: "kubectx",
+            name: "kubectx".to_string(),
+            pinnable: false,
+            brew_cask: false,
         },
         PackageDefinition {
             id: PackageId::K9s,
-            name: "k9s",
+            name: "k9s".to_string(),
+            pinnable: false,
+            brew_cask: false,
         },
         PackageDefinition {
             id: PackageId::Git,
-            name: "git",
+            name: "git".to_string(),
+            pinnable: false,
+            brew_cask: false,
         },
         PackageDefinition {
             id: PackageId::Uv,
-            name: "uv",
+            name: "uv".to_string(),
+            pinnable: false,
+            brew_cask: false,
         },
         PackageDefinition {
             id: PackageId::Tmux,
-            name: "tmux",
+            name: "tmux".to_string(),
+            pinnable: false,
+            brew_cask: false,
         },
         PackageDefinition {
             id: PackageId::Vim,
-            name: "vim",
+            name: "vim".to_string(),
+            pinnable: false,
+            brew_cask: false,
         },
         PackageDefinition {
             id: PackageId::Ffmpeg,
-            name: "ffmpeg",
+            name: "ffmpeg".to_string(),
+            pinnable: false,
+            brew_cask: false,
         },
     ]
 }
@@ -220,6 +294,10 @@ pub struct ActionContext {
     pub(crate) apt_updated: bool,
     pub(crate) pacman_synced: bool,
     pub(crate) hashicorp_repo_ready: bool,
+    /// 安裝時要求的版本（未指定則為最新版）
+    pub(crate) versions: HashMap<PackageId, String>,
+    /// 從設定檔載入的自訂套件
+    pub(crate) custom_packages: Vec<CustomPackage>,
 }
 
 impl ActionContext {
@@ -241,6 +319,61 @@ impl ActionContext {
             apt_updated: false,
             pacman_synced: false,
             hashicorp_repo_ready: false,
+            versions: HashMap::new(),
+            custom_packages: Vec::new(),
+        }
+    }
+
+    /// 設定自訂套件
+    pub fn set_custom_packages(&mut self, packages: Vec<CustomPackage>) {
+        self.custom_packages = packages;
+    }
+
+    /// 取得自訂套件
+    pub fn custom_package(&self, index: usize) -> Option<&CustomPackage> {
+        self.custom_packages.get(index)
+    }
+
+    /// 套件是否為 Homebrew cask（依套件定義名稱比對）
+    pub fn is_brew_cask(&self, package: &str) -> bool {
+        self.definitions()
+            .iter()
+            .any(|definition| definition.brew_cask && definition.name.eq_ignore_ascii_case(package))
+    }
+
+    /// 取得內建與自訂套件定義
+    pub fn definitions(&self) -> Vec<PackageDefinition> {
+        let custom =
+            self.custom_packages
+                .iter()
+                .enumerate()
+                .map(|(index, pkg)| PackageDefinition {
+                    id: PackageId::Custom(index),
+                    name: pkg.name.clone(),
+                    pinnable: false,
+                    brew_cask: false,
+                });
+        package_definitions()
+            .into_iter()
+            .filter(|definition| definition.id.supports(self.os))
+            .chain(custom)
+            .collect()
+    }
+
+    /// 取得套件要求的版本
+    pub fn requested_version(&self, package: PackageId) -> Option<&str> {
+        self.versions.get(&package).map(String::as_str)
+    }
+
+    /// 設定套件要求的版本（None 表示最新版）
+    pub fn set_requested_version(&mut self, package: PackageId, version: Option<String>) {
+        match version {
+            Some(version) => {
+                self.versions.insert(package, version);
+            }
+            None => {
+                self.versions.remove(&package);
+            }
         }
     }
 
@@ -268,3 +401,76 @@ impl ActionContext {
         self.sudo_available
     }
 }
+
+/// 正規化使用者輸入的版本；空白或 "latest" 表示最新版，並移除開頭的 `v`
+pub fn normalize_version(input: &str) -> Option<String> {
+    let version = input.trim();
+    if version.is_empty() || version.eq_ignore_ascii_case("latest") {
+        return None;
+    }
+    let version = version
+        .strip_prefix('v')
+        .or_else(|| version.strip_prefix('V'))
+        .unwrap_or(version);
+    Some(version.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_version() {
+        assert_eq!(normalize_version(""), None);
+        assert_eq!(normalize_version(" latest "), None);
+        assert_eq!(normalize_version("LATEST"), None);
+        assert_eq!(normalize_version("v1.29.3"), Some("1.29.3".to_string()));
+        assert_eq!(normalize_version("1.22.1"), Some("1.22.1".to_string()));
+    }
+
+    #[test]
+    fn test_versioned_package() {
+        assert_eq!(
+            PackageManager::Apt.versioned_package("terraform", "1.7.5-1"),
+            Some("terraform=1.7.5-1".to_string())
+        );
+        assert_eq!(
+            PackageManager::Dnf.versioned_package("terraform", "1.7.5"),
+            Some("terraform-1.7.5".to_string())
+        );
+        assert_eq!(
+            PackageManager::Pacman.versioned_package("terraform", "1.7.5"),
+            None
+        );
+        assert_eq!(
+            PackageManager::Pkg.versioned_package("terraform", "1.7.5"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_freebsd_skips_script_only_packages() {
+        let unsupported: Vec<String> = package_definitions()
+            .iter()
+            .filter(|pkg| !pkg.id.supports(SupportedOs::FreeBsd))
+            .map(|pkg| pkg.name.clone())
+            .collect();
+        assert_eq!(unsupported, ["pnpm", "bun", "uv"]);
+        assert!(
+            package_definitions()
+                .iter()
+                .all(|pkg| pkg.id.supports(SupportedOs::Linux)
+                    && pkg.id.supports(SupportedOs::Macos))
+        );
+    }
+
+    #[test]
+    fn test_pinnable_packages() {
+        let pinnable: Vec<String> = package_definitions()
+            .iter()
+            .filter(|pkg| pkg.pinnable)
+            .map(|pkg| pkg.name.clone())
+            .collect();
+        assert_eq!(pinnable, ["Rust", "Go", "Terraform", "kubectl"]);
+    }
+}
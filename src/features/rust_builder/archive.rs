@@ -0,0 +1,154 @@
+use super::artifacts::{Artifact, artifact_file_name};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Files from the project root shipped next to the binaries, when present
+const DOC_PREFIXES: [&str; 3] = ["README", "LICENSE", "COPYING"];
+
+/// Archive type used for a target: zip for Windows, tar.gz everywhere else
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ArchiveFormat {
+    TarGz,
+    Zip,
+}
+
+impl ArchiveFormat {
+    pub fn for_target(triple: &str) -> Self {
+        if triple.contains("windows") {
+            Self::Zip
+        } else {
+            Self::TarGz
+        }
+    }
+
+    /// Host tool that creates the archive
+    pub fn tool(self) -> &'static str {
+        match self {
+            Self::TarGz => "tar",
+            Self::Zip => "zip",
+        }
+    }
+
+    fn extension(self) -> &'static str {
+        match self {
+            Self::TarGz => "tar.gz",
+            Self::Zip => "zip",
+        }
+    }
+}
+
+/// Base name of a release archive: `<crate>-<version>-<triple>`
+pub fn archive_stem(name: &str, version: &str, triple: &str) -> String {
+    format!("{name}-{version}-{triple}")
+}
+
+/// README / LICENSE files in the project root, sorted by name
+pub fn project_docs(project_dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(project_dir) else {
+        return Vec::new();
+    };
+    let mut docs: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file())
+        .filter(|path| {
+            path.file_name()
+                .map(|name| name.to_string_lossy().to_ascii_uppercase())
+                .is_some_and(|name| DOC_PREFIXES.iter().any(|prefix| name.starts_with(prefix)))
+        })
+        .collect();
+    docs.sort();
+    docs
+}
+
+/// Pack the artifacts (and docs) into `<dist_dir>/<stem>.<ext>` under a `<stem>/` folder,
+/// returning the archive path
+pub fn package(
+    artifacts: &[Artifact],
+    docs: &[PathBuf],
+    dist_dir: &Path,
+    stem: &str,
+    triple: &str,
+) -> Result<PathBuf, String> {
+    let format = ArchiveFormat::for_target(triple);
+    let staging = tempfile::tempdir().map_err(|e| e.to_string())?;
+    let content_dir = staging.path().join(stem);
+    fs::create_dir_all(&content_dir).map_err(|e| e.to_string())?;
+
+    for artifact in artifacts {
+        let name = artifact_file_name(&artifact.binary, triple);
+        fs::copy(&artifact.path, content_dir.join(name)).map_err(|e| e.to_string())?;
+    }
+    for doc in docs {
+        if let Some(name) = doc.file_name() {
+            fs::copy(doc, content_dir.join(name)).map_err(|e| e.to_string())?;
+        }
+    }
+
+    fs::create_dir_all(dist_dir).map_err(|e| e.to_string())?;
+    let dist_dir = fs::canonicalize(dist_dir).map_err(|e| e.to_string())?;
+    let archive = dist_dir.join(format!("{stem}.{}", format.extension()));
+    if archive.exists() {
+        fs::remove_file(&archive).map_err(|e| e.to_string())?;
+    }
+
+    let flags = match format {
+        ArchiveFormat::TarGz => "-czf",
+        ArchiveFormat::Zip => "-rq",
+    };
+    let mut command = Command::new(format.tool());
+    command
+        .arg(flags)
+        .arg(&archive)
+        .arg(stem)
+        .current_dir(staging.path());
+
+    let output = command.output().map_err(|e| e.to_string())?;
+    if output.status.success() {
+        Ok(archive)
+    } else {
+        Err(format!(
+            "{}: {}",
+            format.tool(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn archive_names_follow_target_platform() {
+        assert_eq!(
+            archive_stem("app", "1.2.0", "x86_64-unknown-linux-musl"),
+            "app-1.2.0-x86_64-unknown-linux-musl"
+        );
+        assert_eq!(
+            ArchiveFormat::for_target("x86_64-pc-windows-gnu"),
+            ArchiveFormat::Zip
+        );
+        assert_eq!(
+            ArchiveFormat::for_target("aarch64-apple-darwin"),
+            ArchiveFormat::TarGz
+        );
+    }
+
+    #[test]
+    fn project_docs_finds_readme_and_license() {
+        let temp = tempdir().unwrap();
+        for name in ["README.md", "LICENSE-MIT", "Cargo.toml", "license.txt"] {
+            fs::write(temp.path().join(name), b"text").unwrap();
+        }
+        fs::create_dir(temp.path().join("README.d")).unwrap();
+
+        let names: Vec<String> = project_docs(temp.path())
+            .iter()
+            .map(|path| path.file_name().unwrap().to_string_lossy().to_string())
+            .collect();
+        assert_eq!(names, ["LICENSE-MIT", "README.md", "license.txt"]);
+    }
+}
@@ -0,0 +1,172 @@
+use crate::core::path_utils::format_size;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// A binary produced by a build
+#[derive(Clone, Debug)]
+pub struct Artifact {
+    pub binary: String,
+    pub path: PathBuf,
+    pub size: u64,
+    /// Size before strip/upx, when post-processing changed the file
+    pub size_before: Option<u64>,
+}
+
+/// File name cargo gives a binary for the target (`.exe` on Windows, `.wasm` on wasm)
+pub fn artifact_file_name(binary: &str, triple: &str) -> String {
+    format!("{}{}", binary, executable_suffix(triple))
+}
+
+/// Name used when copying into `dist/`: `<binary>-<triple>` plus the platform suffix
+pub fn dist_file_name(binary: &str, triple: &str) -> String {
+    format!("{}-{}{}", binary, triple, executable_suffix(triple))
+}
+
+fn executable_suffix(triple: &str) -> &'static str {
+    if triple.contains("windows") {
+        ".exe"
+    } else if triple.starts_with("wasm") {
+        ".wasm"
+    } else {
+        ""
+    }
+}
+
+/// Find the built binaries in `profile_dir`, skipping any that were not produced
+pub fn collect(profile_dir: &Path, binaries: &[String], triple: &str) -> Vec<Artifact> {
+    binaries
+        .iter()
+        .filter_map(|binary| {
+            let path = profile_dir.join(artifact_file_name(binary, triple));
+            let size = fs::metadata(&path).ok().filter(|m| m.is_file())?.len();
+            Some(Artifact {
+                binary: binary.clone(),
+                path,
+                size,
+                size_before: None,
+            })
+        })
+        .collect()
+}
+
+/// Copy an artifact into `dist_dir`, returning the destination path
+pub fn copy_to_dist(
+    artifact: &Artifact,
+    dist_dir: &Path,
+    triple: &str,
+) -> std::io::Result<PathBuf> {
+    fs::create_dir_all(dist_dir)?;
+    let destination = dist_dir.join(dist_file_name(&artifact.binary, triple));
+    fs::copy(&artifact.path, &destination)?;
+    Ok(destination)
+}
+
+/// Run `strip` and/or `upx` on the artifact in place and record the new size
+pub fn post_process(artifact: &mut Artifact, strip: bool, upx: bool) -> Result<(), String> {
+    let original = artifact.size;
+
+    if strip {
+        run_tool("strip", &[], &artifact.path)?;
+    }
+    if upx {
+        run_tool("upx", &["-q"], &artifact.path)?;
+    }
+
+    if strip || upx {
+        artifact.size = fs::metadata(&artifact.path)
+            .map_err(|e| e.to_string())?
+            .len();
+        artifact.size_before = Some(original);
+    }
+    Ok(())
+}
+
+fn run_tool(program: &str, args: &[&str], path: &Path) -> Result<(), String> {
+    let output = Command::new(program)
+        .args(args)
+        .arg(path)
+        .output()
+        .map_err(|e| e.to_string())?;
+
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(format!(
+            "{}: {}",
+            program,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ))
+    }
+}
+
+/// Size shown next to an artifact, including the before/after delta when post-processed
+pub fn describe_size(artifact: &Artifact) -> String {
+    match artifact.size_before {
+        Some(before) if before > 0 => {
+            let change = (artifact.size as f64 - before as f64) / before as f64 * 100.0;
+            format!(
+                "{} → {}, {:+.1}%",
+                format_size(before),
+                format_size(artifact.size),
+                change
+            )
+        }
+        _ => format_size(artifact.size),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn file_names_follow_target_platform() {
+        assert_eq!(artifact_file_name("app", "x86_64-unknown-linux-gnu"), "app");
+        assert_eq!(
+            artifact_file_name("app", "x86_64-pc-windows-gnu"),
+            "app.exe"
+        );
+        assert_eq!(
+            dist_file_name("app", "x86_64-pc-windows-gnu"),
+            "app-x86_64-pc-windows-gnu.exe"
+        );
+        assert_eq!(
+            dist_file_name("app", "wasm32-unknown-unknown"),
+            "app-wasm32-unknown-unknown.wasm"
+        );
+    }
+
+    #[test]
+    fn collect_and_copy_artifacts() {
+        let temp = tempdir().unwrap();
+        let profile_dir = temp.path().join("target/aarch64-unknown-linux-gnu/release");
+        fs::create_dir_all(&profile_dir).unwrap();
+        fs::write(profile_dir.join("app"), b"binary").unwrap();
+
+        let binaries = vec!["app".to_string(), "missing".to_string()];
+        let artifacts = collect(&profile_dir, &binaries, "aarch64-unknown-linux-gnu");
+        assert_eq!(artifacts.len(), 1);
+        assert_eq!(artifacts[0].size, 6);
+
+        let dist = temp.path().join("dist");
+        let copied = copy_to_dist(&artifacts[0], &dist, "aarch64-unknown-linux-gnu").unwrap();
+        assert_eq!(copied, dist.join("app-aarch64-unknown-linux-gnu"));
+        assert_eq!(fs::read(copied).unwrap(), b"binary");
+    }
+
+    #[test]
+    fn describe_size_reports_delta() {
+        let mut artifact = Artifact {
+            binary: "app".to_string(),
+            path: PathBuf::from("app"),
+            size: 1024,
+            size_before: None,
+        };
+        assert_eq!(describe_size(&artifact), "1.0 KiB");
+
+        artifact.size_before = Some(4096);
+        assert_eq!(describe_size(&artifact), "4.0 KiB → 1.0 KiB, -75.0%");
+    }
+}
@@ -0,0 +1,146 @@
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Image repository cross uses when `Cross.toml` does not configure one
+const DEFAULT_IMAGE_REPO: &str = "ghcr.io/cross-rs";
+
+/// Subset of `Cross.toml` used to show which image builds each target
+#[derive(Debug, Default, Deserialize)]
+pub struct CrossConfig {
+    #[serde(default)]
+    target: HashMap<String, TargetConfig>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct TargetConfig {
+    image: Option<ImageConfig>,
+    dockerfile: Option<DockerfileConfig>,
+}
+
+/// `image = "name"` or `image = { name = "...", toolchain = [...] }`
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum ImageConfig {
+    Name(String),
+    Table { name: String },
+}
+
+/// `dockerfile = "path"` or `dockerfile = { file = "...", context = "..." }`
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum DockerfileConfig {
+    File(String),
+    Table { file: String },
+}
+
+/// Where cross gets the build environment for a target
+#[derive(Debug, PartialEq, Eq)]
+pub enum CrossImage {
+    /// Image configured in `Cross.toml`
+    Configured(String),
+    /// Image built from a Dockerfile configured in `Cross.toml`
+    Dockerfile(String),
+    /// The stock cross-rs image for the target
+    Default(String),
+}
+
+impl CrossConfig {
+    /// Image used for `triple`, falling back to the stock cross-rs image
+    pub fn image_for(&self, triple: &str) -> CrossImage {
+        let target = self.target.get(triple);
+        if let Some(image) = target.and_then(|target| target.image.as_ref()) {
+            let (ImageConfig::Name(name) | ImageConfig::Table { name }) = image;
+            return CrossImage::Configured(name.clone());
+        }
+        if let Some(dockerfile) = target.and_then(|target| target.dockerfile.as_ref()) {
+            let (DockerfileConfig::File(file) | DockerfileConfig::Table { file }) = dockerfile;
+            return CrossImage::Dockerfile(file.clone());
+        }
+        CrossImage::Default(format!("{DEFAULT_IMAGE_REPO}/{triple}"))
+    }
+}
+
+/// Path of the project's `Cross.toml` when it exists
+pub fn config_path(project_dir: &Path) -> Option<PathBuf> {
+    let path = project_dir.join("Cross.toml");
+    path.is_file().then_some(path)
+}
+
+/// Read and parse a `Cross.toml`
+pub fn load(path: &Path) -> Result<CrossConfig, String> {
+    let contents = fs::read_to_string(path).map_err(|err| err.to_string())?;
+    toml::from_str(&contents).map_err(|err| err.to_string())
+}
+
+/// Whether a target usually needs cross's Docker toolchain rather than plain cargo:
+/// Linux targets for another architecture or libc than the host (e.g. musl, riscv64)
+pub fn recommended_for(triple: &str, host: Option<&str>) -> bool {
+    if !triple.contains("-linux-") || Some(triple) == host {
+        return false;
+    }
+    let Some(host) = host.filter(|host| host.contains("-linux-")) else {
+        // Building Linux binaries from macOS or Windows needs a Linux toolchain
+        return true;
+    };
+    let arch = |triple: &str| triple.split('-').next().unwrap_or_default().to_string();
+    arch(triple) != arch(host) || (triple.contains("musl") && !host.contains("musl"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn image_for_reads_cross_toml() {
+        let config: CrossConfig = toml::from_str(
+            r#"
+            [build]
+            pre-build = ["apt-get update"]
+
+            [target.aarch64-unknown-linux-gnu]
+            image = "example/aarch64:1.0"
+
+            [target.riscv64gc-unknown-linux-gnu]
+            image = { name = "example/riscv:edge", toolchain = ["x86_64-unknown-linux-gnu"] }
+
+            [target.x86_64-unknown-linux-musl]
+            dockerfile = { file = "./docker/musl.Dockerfile", context = "." }
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.image_for("aarch64-unknown-linux-gnu"),
+            CrossImage::Configured("example/aarch64:1.0".into())
+        );
+        assert_eq!(
+            config.image_for("riscv64gc-unknown-linux-gnu"),
+            CrossImage::Configured("example/riscv:edge".into())
+        );
+        assert_eq!(
+            config.image_for("x86_64-unknown-linux-musl"),
+            CrossImage::Dockerfile("./docker/musl.Dockerfile".into())
+        );
+        assert_eq!(
+            config.image_for("i686-unknown-linux-gnu"),
+            CrossImage::Default("ghcr.io/cross-rs/i686-unknown-linux-gnu".into())
+        );
+    }
+
+    #[test]
+    fn recommended_for_foreign_linux_targets() {
+        let host = Some("x86_64-unknown-linux-gnu");
+        assert!(recommended_for("x86_64-unknown-linux-musl", host));
+        assert!(recommended_for("riscv64gc-unknown-linux-gnu", host));
+        assert!(recommended_for("aarch64-unknown-linux-gnu", host));
+        assert!(!recommended_for("x86_64-unknown-linux-gnu", host));
+        assert!(!recommended_for("x86_64-pc-windows-gnu", host));
+        assert!(!recommended_for("wasm32-unknown-unknown", host));
+
+        let mac = Some("aarch64-apple-darwin");
+        assert!(recommended_for("aarch64-unknown-linux-gnu", mac));
+        assert!(!recommended_for("x86_64-apple-darwin", mac));
+    }
+}
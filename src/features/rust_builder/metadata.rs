@@ -0,0 +1,157 @@
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Subset of `cargo metadata --no-deps --format-version 1` used by the builder
+#[derive(Debug, Deserialize)]
+pub struct CargoMetadata {
+    packages: Vec<Package>,
+    #[serde(default)]
+    workspace_members: Vec<String>,
+    pub target_directory: PathBuf,
+}
+
+#[derive(Debug, Deserialize)]
+struct Package {
+    id: String,
+    name: String,
+    version: String,
+    targets: Vec<CargoTarget>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CargoTarget {
+    name: String,
+    kind: Vec<String>,
+}
+
+impl CargoMetadata {
+    /// Names of the workspace member packages, in metadata order
+    pub fn workspace_packages(&self) -> Vec<String> {
+        self.packages
+            .iter()
+            .filter(|package| self.workspace_members.contains(&package.id))
+            .map(|package| package.name.clone())
+            .collect()
+    }
+
+    /// Name and version used for release archives: the first selected workspace member
+    /// (any member when `packages` is empty) that has a binary target
+    pub fn release_name(&self, packages: &[String]) -> Option<(String, String)> {
+        self.packages
+            .iter()
+            .filter(|package| self.workspace_members.contains(&package.id))
+            .filter(|package| packages.is_empty() || packages.contains(&package.name))
+            .find(|package| {
+                package
+                    .targets
+                    .iter()
+                    .any(|target| target.kind.iter().any(|kind| kind == "bin"))
+            })
+            .map(|package| (package.name.clone(), package.version.clone()))
+    }
+
+    /// Names of the binary targets in `packages` (all packages when empty)
+    pub fn binary_names(&self, packages: &[String]) -> Vec<String> {
+        let mut names: Vec<String> = self
+            .packages
+            .iter()
+            .filter(|package| packages.is_empty() || packages.contains(&package.name))
+            .flat_map(|package| &package.targets)
+            .filter(|target| target.kind.iter().any(|kind| kind == "bin"))
+            .map(|target| target.name.clone())
+            .collect();
+        names.sort();
+        names.dedup();
+        names
+    }
+}
+
+/// Run `cargo metadata` in the project directory
+pub fn load(project_dir: &Path) -> Result<CargoMetadata, String> {
+    let output = Command::new("cargo")
+        .args(["metadata", "--no-deps", "--format-version", "1"])
+        .current_dir(project_dir)
+        .output()
+        .map_err(|e| e.to_string())?;
+
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+
+    parse(&String::from_utf8_lossy(&output.stdout))
+}
+
+fn parse(raw: &str) -> Result<CargoMetadata, String> {
+    serde_json::from_str(raw).map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r#"{
+        "packages": [
+            {
+                "name": "tools",
+                "version": "0.1.0",
+                "id": "path+file:///repo#tools@0.1.0",
+                "targets": [
+                    {"name": "ops-tools", "kind": ["bin"]},
+                    {"name": "tools", "kind": ["lib"]}
+                ]
+            },
+            {
+                "name": "helper",
+                "version": "0.2.0",
+                "id": "path+file:///repo/helper#helper@0.1.0",
+                "targets": [
+                    {"name": "helper-cli", "kind": ["bin"]},
+                    {"name": "bench", "kind": ["bench"]}
+                ]
+            }
+        ],
+        "workspace_members": [
+            "path+file:///repo#tools@0.1.0",
+            "path+file:///repo/helper#helper@0.1.0"
+        ],
+        "target_directory": "/repo/target",
+        "version": 1
+    }"#;
+
+    #[test]
+    fn binary_names_only_lists_bin_targets() {
+        let metadata = parse(SAMPLE).unwrap();
+        assert_eq!(metadata.binary_names(&[]), ["helper-cli", "ops-tools"]);
+        assert_eq!(
+            metadata.binary_names(&["helper".to_string()]),
+            ["helper-cli"]
+        );
+        assert_eq!(metadata.target_directory, PathBuf::from("/repo/target"));
+    }
+
+    #[test]
+    fn workspace_packages_lists_members() {
+        let metadata = parse(SAMPLE).unwrap();
+        assert_eq!(metadata.workspace_packages(), ["tools", "helper"]);
+    }
+
+    #[test]
+    fn release_name_uses_selected_package() {
+        let metadata = parse(SAMPLE).unwrap();
+        assert_eq!(
+            metadata.release_name(&[]),
+            Some(("tools".to_string(), "0.1.0".to_string()))
+        );
+        assert_eq!(
+            metadata.release_name(&["helper".to_string()]),
+            Some(("helper".to_string(), "0.2.0".to_string()))
+        );
+        assert_eq!(metadata.release_name(&["missing".to_string()]), None);
+    }
+
+    #[test]
+    fn parse_rejects_invalid_json() {
+        assert!(parse("not json").is_err());
+    }
+}
@@ -1,28 +1,101 @@
+mod archive;
+mod artifacts;
+mod cross;
+mod metadata;
+
+use crate::core::requirements::{is_command_available, require_commands};
 use crate::i18n::{self, keys};
-use crate::ui::{Console, Prompts};
+use crate::ui::{Console, Prompts, suspend_progress};
+use archive::ArchiveFormat;
+use artifacts::Artifact;
+use cross::CrossImage;
 use std::collections::HashSet;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
 
-#[derive(Clone, Copy)]
-enum Builder {
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Builder {
     Cargo,
     Cross,
 }
 
-#[derive(Clone)]
+/// Pre-selected build options; any field left unset falls back to an interactive prompt
+#[derive(Clone, Debug, Default)]
+pub struct BuildOptions {
+    pub builder: Option<Builder>,
+    pub release: Option<bool>,
+    pub targets: Vec<String>,
+    /// Cargo features to enable; `None` prompts together with `no_default_features`
+    pub features: Option<Vec<String>>,
+    pub no_default_features: Option<bool>,
+    /// Copy built binaries into `dist/`
+    pub dist: Option<bool>,
+    /// Strip symbols from built binaries
+    pub strip: Option<bool>,
+    /// Compress built binaries with upx
+    pub upx: Option<bool>,
+    /// Package each target's binaries into a release archive under `dist/`
+    pub archive: Option<bool>,
+    /// Workspace packages to build; empty prompts in a workspace
+    pub packages: Vec<String>,
+    /// Only list the known targets and their install status
+    pub list_targets: bool,
+}
+
+/// Settings shared by every target build
+#[derive(Clone, Debug)]
+struct BuildSettings {
+    builder: Builder,
+    release: bool,
+    features: Vec<String>,
+    no_default_features: bool,
+    strip: bool,
+    upx: bool,
+    packages: Vec<String>,
+}
+
+/// Where the build writes its output and which binaries it produces
+#[derive(Clone, Debug)]
+struct ProjectLayout {
+    target_dir: PathBuf,
+    binaries: Vec<String>,
+    /// Crate name and version used to name release archives
+    release_name: Option<(String, String)>,
+}
+
+/// Output of a successful target build
+#[derive(Debug)]
+struct BuildOutput {
+    profile_dir: PathBuf,
+    artifacts: Vec<Artifact>,
+    /// Post-processing failures that did not fail the build
+    warnings: Vec<String>,
+}
+
+#[derive(Clone, Debug)]
 struct Target {
-    triple: &'static str,
+    triple: String,
     name_key: &'static str,
 }
 
 /// Entry point for Rust multi-platform builder
 pub fn run() {
+    run_with(BuildOptions::default());
+}
+
+/// Run the builder with options supplied up front (e.g. from the command line)
+pub fn run_with(options: BuildOptions) {
     let console = Console::new();
     let prompts = Prompts::new();
 
     console.header(i18n::t(keys::RUST_BUILDER_HEADER));
 
+    if options.list_targets {
+        list_targets(&console);
+        return;
+    }
+
     // Ensure Cargo project exists
     let project_dir = match std::env::current_dir() {
         Ok(dir) => dir,
@@ -37,20 +110,20 @@ pub fn run() {
         return;
     }
 
-    if !command_available("cargo") {
-        console.error(&crate::tr!(
-            keys::ERROR_COMMAND_NOT_FOUND,
-            command = "cargo"
-        ));
+    if let Err(err) = require_commands(&["cargo", "rustup"]) {
+        console.error(&err.to_string());
         return;
     }
 
-    if !command_available("rustup") {
-        console.error(i18n::t(keys::RUST_BUILDER_RUSTUP_MISSING));
-        return;
-    }
+    let metadata = match metadata::load(&project_dir) {
+        Ok(metadata) => Some(metadata),
+        Err(err) => {
+            console.warning(&crate::tr!(keys::RUST_BUILDER_METADATA_FAILED, error = err));
+            None
+        }
+    };
 
-    let builder = match select_builder(&prompts) {
+    let builder = match options.builder.or_else(|| select_builder(&prompts)) {
         Some(b) => b,
         None => {
             console.warning(i18n::t(keys::RUST_BUILDER_CANCELLED));
@@ -58,7 +131,7 @@ pub fn run() {
         }
     };
 
-    let release = match select_profile(&prompts) {
+    let release = match options.release.or_else(|| select_profile(&prompts)) {
         Some(p) => p,
         None => {
             console.warning(i18n::t(keys::RUST_BUILDER_CANCELLED));
@@ -66,14 +139,81 @@ pub fn run() {
         }
     };
 
-    let targets = match select_targets(&prompts) {
-        Some(t) if !t.is_empty() => t,
-        _ => {
-            console.warning(i18n::t(keys::RUST_BUILDER_NO_TARGET_SELECTED));
-            return;
+    let strip = options.strip.unwrap_or_else(|| {
+        prompts.confirm_with_options(i18n::t(keys::RUST_BUILDER_CONFIRM_STRIP), false)
+    });
+    let upx = options.upx.unwrap_or_else(|| {
+        prompts.confirm_with_options(i18n::t(keys::RUST_BUILDER_CONFIRM_UPX), false)
+    });
+    let strip = strip && tool_available(&console, "strip");
+    let upx = upx && tool_available(&console, "upx");
+
+    let (features, no_default_features) = match (options.features, options.no_default_features) {
+        (None, None) => match input_features(&prompts) {
+            Some(selection) => selection,
+            None => {
+                console.warning(i18n::t(keys::RUST_BUILDER_CANCELLED));
+                return;
+            }
+        },
+        (features, no_default) => (features.unwrap_or_default(), no_default.unwrap_or(false)),
+    };
+
+    let packages = if !options.packages.is_empty() {
+        options.packages.clone()
+    } else if let Some(metadata) = &metadata {
+        match select_packages(&prompts, metadata) {
+            Some(packages) => packages,
+            None => {
+                console.warning(i18n::t(keys::RUST_BUILDER_NO_PACKAGE_SELECTED));
+                return;
+            }
+        }
+    } else {
+        Vec::new()
+    };
+
+    let mut settings = BuildSettings {
+        builder,
+        release,
+        features,
+        no_default_features,
+        strip,
+        upx,
+        packages,
+    };
+
+    let preset_targets = !options.targets.is_empty();
+    let targets = if preset_targets {
+        match resolve_targets(&options.targets) {
+            Ok(t) => t,
+            Err(unknown) => {
+                console.error(&crate::tr!(
+                    keys::RUST_BUILDER_INVALID_TRIPLE,
+                    target = unknown
+                ));
+                return;
+            }
+        }
+    } else {
+        match select_targets(&prompts, &console) {
+            Some(t) if !t.is_empty() => t,
+            _ => {
+                console.warning(i18n::t(keys::RUST_BUILDER_NO_TARGET_SELECTED));
+                return;
+            }
         }
     };
 
+    review_cross(
+        &console,
+        &prompts,
+        &project_dir,
+        &targets,
+        &mut settings,
+        options.builder.is_none(),
+    );
+
     // Install missing targets
     let installed = match installed_targets() {
         Ok(list) => list,
@@ -85,7 +225,7 @@ pub fn run() {
 
     let missing: Vec<&Target> = targets
         .iter()
-        .filter(|t| !installed.contains(t.triple))
+        .filter(|t| !installed.contains(&t.triple))
         .collect();
 
     let mut install_failures = HashSet::new();
@@ -95,15 +235,16 @@ pub fn run() {
             count = missing.len()
         ));
 
-        if prompts.confirm(i18n::t(keys::RUST_BUILDER_CONFIRM_INSTALL_TARGETS)) {
-            for (idx, target) in missing.iter().enumerate() {
-                console.show_progress(
-                    idx + 1,
-                    missing.len(),
-                    &crate::tr!(keys::RUST_BUILDER_INSTALLING_TARGET, target = target.triple),
-                );
+        // Targets named explicitly on the command line are installed without asking
+        if preset_targets || prompts.confirm(i18n::t(keys::RUST_BUILDER_CONFIRM_INSTALL_TARGETS)) {
+            let mut progress = console.progress_bar(missing.len());
+            for target in &missing {
+                progress.tick(&crate::tr!(
+                    keys::RUST_BUILDER_INSTALLING_TARGET,
+                    target = target.triple
+                ));
 
-                match install_target(target.triple) {
+                match install_target(&target.triple) {
                     Ok(_) => console.success_item(&crate::tr!(
                         keys::RUST_BUILDER_INSTALL_SUCCESS,
                         target = target.triple
@@ -113,10 +254,11 @@ pub fn run() {
                             &crate::tr!(keys::RUST_BUILDER_INSTALL_FAILED, target = target.triple),
                             &err,
                         );
-                        install_failures.insert(target.triple);
+                        install_failures.insert(target.triple.as_str());
                     }
                 }
             }
+            progress.finish();
             console.separator();
         } else {
             console.warning(i18n::t(keys::RUST_BUILDER_SKIP_INSTALL));
@@ -124,29 +266,88 @@ pub fn run() {
         }
     }
 
+    let layout = match &metadata {
+        Some(metadata) => ProjectLayout {
+            binaries: metadata.binary_names(&settings.packages),
+            target_dir: metadata.target_directory.clone(),
+            release_name: metadata.release_name(&settings.packages),
+        },
+        None => ProjectLayout {
+            target_dir: project_dir.join("target"),
+            binaries: Vec::new(),
+            release_name: None,
+        },
+    };
+
+    let copy_to_dist = !layout.binaries.is_empty()
+        && options.dist.unwrap_or_else(|| {
+            prompts.confirm_with_options(i18n::t(keys::RUST_BUILDER_CONFIRM_COPY_DIST), false)
+        });
+    let package_archives = layout.release_name.is_some()
+        && !layout.binaries.is_empty()
+        && options.archive.unwrap_or_else(|| {
+            prompts.confirm_with_options(i18n::t(keys::RUST_BUILDER_CONFIRM_ARCHIVE), false)
+        });
+    let dist_dir = project_dir.join("dist");
+
     // Build selected targets
     let mut success = 0;
     let mut failed = 0;
 
-    for (idx, target) in targets.iter().enumerate() {
-        if install_failures.contains(target.triple) {
-            failed += 1;
-            continue;
-        }
+    // Targets whose installation failed count as failed builds
+    let buildable: Vec<&Target> = targets
+        .iter()
+        .filter(|t| !install_failures.contains(t.triple.as_str()))
+        .collect();
+    failed += targets.len() - buildable.len();
 
-        console.show_progress(
-            idx + 1,
-            targets.len(),
-            &crate::tr!(keys::RUST_BUILDER_BUILDING, target = target.triple),
-        );
+    // Artifacts of successful targets, packaged after all builds finish
+    let mut built: Vec<(&str, Vec<Artifact>)> = Vec::new();
+    // Per-target build time and whether it succeeded
+    let mut timings: Vec<(&str, Duration, bool)> = Vec::new();
+    let build_started = Instant::now();
 
-        match build_target(&project_dir, target.triple, builder, release) {
-            Ok(binary_dir) => {
+    let mut progress = console.progress_bar(buildable.len());
+    for target in buildable {
+        progress.tick(&crate::tr!(
+            keys::RUST_BUILDER_BUILDING,
+            target = target.triple
+        ));
+
+        let started = Instant::now();
+        let result = build_target(&project_dir, &target.triple, &settings, &layout);
+        let elapsed = started.elapsed();
+        timings.push((&target.triple, elapsed, result.is_ok()));
+
+        match result {
+            Ok(output) => {
                 console.success_item(&crate::tr!(
                     keys::RUST_BUILDER_BUILD_SUCCESS,
-                    target = target.triple
+                    target = target.triple,
+                    elapsed = format_elapsed(elapsed)
                 ));
-                console.list_item(" ", &binary_dir.display().to_string());
+                for warning in &output.warnings {
+                    console.warning(warning);
+                }
+                if output.artifacts.is_empty() {
+                    console.list_item(" ", &output.profile_dir.display().to_string());
+                }
+                for artifact in &output.artifacts {
+                    console.list_item(
+                        " ",
+                        &format!(
+                            "{} ({})",
+                            artifact.path.display(),
+                            artifacts::describe_size(artifact)
+                        ),
+                    );
+                    if copy_to_dist {
+                        report_dist_copy(&console, artifact, &dist_dir, &target.triple);
+                    }
+                }
+                if package_archives && !output.artifacts.is_empty() {
+                    built.push((&target.triple, output.artifacts));
+                }
                 success += 1;
             }
             Err(err) => {
@@ -160,8 +361,238 @@ pub fn run() {
 
         console.blank_line();
     }
+    progress.finish();
+
+    if let Some((name, version)) = &layout.release_name
+        && !built.is_empty()
+    {
+        package_artifacts(&console, &project_dir, &dist_dir, name, version, &built);
+    }
 
     console.show_summary(i18n::t(keys::RUST_BUILDER_SUMMARY_TITLE), success, failed);
+    show_timings(&console, timings, build_started.elapsed());
+}
+
+/// Print the total build time and, for several targets, each target slowest first
+fn show_timings(console: &Console, mut timings: Vec<(&str, Duration, bool)>, total: Duration) {
+    if timings.is_empty() {
+        return;
+    }
+
+    console.info(&crate::tr!(
+        keys::RUST_BUILDER_TIMING_TOTAL,
+        elapsed = format_elapsed(total)
+    ));
+    if timings.len() < 2 {
+        return;
+    }
+
+    timings.sort_by_key(|&(_, elapsed, _)| std::cmp::Reverse(elapsed));
+    for (triple, elapsed, ok) in timings {
+        let icon = if ok { "✓" } else { "✗" };
+        console.list_item(icon, &format!("{triple}: {}", format_elapsed(elapsed)));
+    }
+}
+
+/// Human-readable duration, e.g. `8.4s` or `2m 05s`
+fn format_elapsed(elapsed: Duration) -> String {
+    let secs = elapsed.as_secs();
+    if secs < 60 {
+        format!("{:.1}s", elapsed.as_secs_f64())
+    } else {
+        format!("{}m {:02}s", secs / 60, secs % 60)
+    }
+}
+
+/// Create one release archive per built target, skipping formats whose tool is missing
+fn package_artifacts(
+    console: &Console,
+    project_dir: &Path,
+    dist_dir: &Path,
+    name: &str,
+    version: &str,
+    built: &[(&str, Vec<Artifact>)],
+) {
+    let docs = archive::project_docs(project_dir);
+    let mut missing_tools = HashSet::new();
+
+    for (triple, artifacts) in built {
+        let tool = ArchiveFormat::for_target(triple).tool();
+        if missing_tools.contains(tool) {
+            continue;
+        }
+        if is_command_available(tool).is_none() {
+            console.warning(&crate::tr!(
+                keys::RUST_BUILDER_TOOL_MISSING_SKIP,
+                tool = tool
+            ));
+            missing_tools.insert(tool);
+            continue;
+        }
+
+        let stem = archive::archive_stem(name, version, triple);
+        match archive::package(artifacts, &docs, dist_dir, &stem, triple) {
+            Ok(path) => console.success_item(&crate::tr!(
+                keys::RUST_BUILDER_ARCHIVE_CREATED,
+                path = path.display()
+            )),
+            Err(err) => console.error_item(
+                &crate::tr!(keys::RUST_BUILDER_ARCHIVE_FAILED, target = triple),
+                &err,
+            ),
+        }
+    }
+    console.blank_line();
+}
+
+fn report_dist_copy(console: &Console, artifact: &Artifact, dist_dir: &Path, triple: &str) {
+    match artifacts::copy_to_dist(artifact, dist_dir, triple) {
+        Ok(destination) => console.list_item(
+            " ",
+            &crate::tr!(
+                keys::RUST_BUILDER_COPIED_TO_DIST,
+                path = destination.display()
+            ),
+        ),
+        Err(err) => console.error_item(
+            &crate::tr!(
+                keys::RUST_BUILDER_COPY_DIST_FAILED,
+                path = artifact.path.display()
+            ),
+            &err.to_string(),
+        ),
+    }
+}
+
+/// Print every known target with its install status, marking the host and cross recommendations
+fn list_targets(console: &Console) {
+    if let Err(err) = require_commands(&["rustup"]) {
+        console.error(&err.to_string());
+        return;
+    }
+    let installed = match installed_targets() {
+        Ok(list) => list,
+        Err(err) => {
+            console.error(&err);
+            return;
+        }
+    };
+    let host = host_triple();
+    let targets = available_targets();
+
+    for target in &targets {
+        let mut notes = Vec::new();
+        if host.as_deref() == Some(target.triple.as_str()) {
+            notes.push(i18n::t(keys::RUST_BUILDER_LIST_HOST));
+        }
+        if cross::recommended_for(&target.triple, host.as_deref()) {
+            notes.push(i18n::t(keys::RUST_BUILDER_LIST_CROSS));
+        }
+        let (icon, status) = if installed.contains(&target.triple) {
+            ("✓", i18n::t(keys::RUST_BUILDER_LIST_INSTALLED))
+        } else {
+            ("✗", i18n::t(keys::RUST_BUILDER_LIST_MISSING))
+        };
+
+        let mut line = format!(
+            "{} — {} [{status}]",
+            target.triple,
+            i18n::t(target.name_key)
+        );
+        if !notes.is_empty() {
+            line.push_str(&format!(" ({})", notes.join(", ")));
+        }
+        console.list_item(icon, &line);
+    }
+
+    let installed_count = targets
+        .iter()
+        .filter(|target| installed.contains(&target.triple))
+        .count();
+    console.blank_line();
+    console.info(&crate::tr!(
+        keys::RUST_BUILDER_LIST_SUMMARY,
+        installed = installed_count,
+        total = targets.len()
+    ));
+}
+
+/// Warn about targets that usually need cross and offer to switch to it; when building
+/// with cross, show which image each target uses
+fn review_cross(
+    console: &Console,
+    prompts: &Prompts,
+    project_dir: &Path,
+    targets: &[Target],
+    settings: &mut BuildSettings,
+    can_switch: bool,
+) {
+    if settings.builder == Builder::Cargo {
+        let host = host_triple();
+        let recommended: Vec<&str> = targets
+            .iter()
+            .filter(|t| cross::recommended_for(&t.triple, host.as_deref()))
+            .map(|t| t.triple.as_str())
+            .collect();
+        if recommended.is_empty() {
+            return;
+        }
+
+        console.warning(&crate::tr!(
+            keys::RUST_BUILDER_CROSS_RECOMMENDED,
+            targets = recommended.join(", ")
+        ));
+        if !command_available("cross") {
+            console.info(i18n::t(keys::RUST_BUILDER_CROSS_INSTALL_HINT));
+            return;
+        }
+        if !can_switch
+            || !prompts
+                .confirm_with_options(i18n::t(keys::RUST_BUILDER_CONFIRM_SWITCH_CROSS), false)
+        {
+            return;
+        }
+        settings.builder = Builder::Cross;
+    }
+
+    show_cross_images(console, project_dir, targets);
+}
+
+fn show_cross_images(console: &Console, project_dir: &Path, targets: &[Target]) {
+    let config = match cross::config_path(project_dir) {
+        Some(path) => match cross::load(&path) {
+            Ok(config) => {
+                console.info(&crate::tr!(
+                    keys::RUST_BUILDER_CROSS_CONFIG_FOUND,
+                    path = path.display()
+                ));
+                config
+            }
+            Err(err) => {
+                console.warning(&crate::tr!(
+                    keys::RUST_BUILDER_CROSS_CONFIG_INVALID,
+                    path = path.display(),
+                    error = err
+                ));
+                cross::CrossConfig::default()
+            }
+        },
+        None => cross::CrossConfig::default(),
+    };
+
+    for target in targets {
+        let image = match config.image_for(&target.triple) {
+            CrossImage::Configured(image) => image,
+            CrossImage::Dockerfile(path) => {
+                crate::tr!(keys::RUST_BUILDER_CROSS_DOCKERFILE, path = path)
+            }
+            CrossImage::Default(image) => {
+                crate::tr!(keys::RUST_BUILDER_CROSS_IMAGE_DEFAULT, image = image)
+            }
+        };
+        console.list_item("🐳", &format!("{}: {image}", target.triple));
+    }
+    console.blank_line();
 }
 
 fn select_builder(prompts: &Prompts) -> Option<Builder> {
@@ -194,97 +625,215 @@ fn select_profile(prompts: &Prompts) -> Option<bool> {
         .map(|idx| idx == 0)
 }
 
-fn select_targets(prompts: &Prompts) -> Option<Vec<Target>> {
+/// Let the user pick workspace members; returns an empty list (build everything)
+/// for single-crate projects or when every member is selected
+fn select_packages(prompts: &Prompts, metadata: &metadata::CargoMetadata) -> Option<Vec<String>> {
+    let members = metadata.workspace_packages();
+    if members.len() <= 1 {
+        return Some(Vec::new());
+    }
+
+    let defaults = vec![true; members.len()];
+    let selections = prompts.multi_select(
+        i18n::t(keys::RUST_BUILDER_SELECT_PACKAGES),
+        &members,
+        &defaults,
+    )?;
+
+    match selections.len() {
+        0 => None,
+        n if n == members.len() => Some(Vec::new()),
+        _ => Some(selections.into_iter().map(|i| members[i].clone()).collect()),
+    }
+}
+
+fn select_targets(prompts: &Prompts, console: &Console) -> Option<Vec<Target>> {
     let targets = available_targets();
     let host = host_triple();
 
-    let items: Vec<String> = targets
+    let mut items: Vec<String> = targets
         .iter()
         .map(|t| format!("{} — {}", i18n::t(t.name_key), t.triple))
         .collect();
+    items.push(i18n::t(keys::RUST_BUILDER_CUSTOM_TARGET_OPTION).to_string());
 
-    let defaults: Vec<bool> = targets
+    let mut defaults: Vec<bool> = targets
         .iter()
-        .map(|t| host.as_deref() == Some(t.triple))
+        .map(|t| host.as_deref() == Some(t.triple.as_str()))
         .collect();
+    defaults.push(false);
 
     let selections = prompts.multi_select(
         i18n::t(keys::RUST_BUILDER_SELECT_TARGETS),
         &items,
         &defaults,
-    );
+    )?;
 
     if selections.is_empty() {
         return None;
     }
 
-    let chosen = selections
-        .into_iter()
-        .map(|idx| targets[idx].clone())
-        .collect();
+    let mut chosen: Vec<Target> = Vec::new();
+    for idx in selections {
+        match targets.get(idx) {
+            Some(target) => chosen.push(target.clone()),
+            None => {
+                for triple in input_custom_targets(console)? {
+                    if !chosen.iter().any(|t| t.triple == triple) {
+                        chosen.push(Target {
+                            triple,
+                            name_key: keys::RUST_BUILDER_TARGET_CUSTOM,
+                        });
+                    }
+                }
+            }
+        }
+    }
     Some(chosen)
 }
 
+/// Prompt for one or more custom triples until every entry looks valid
+fn input_custom_targets(console: &Console) -> Option<Vec<String>> {
+    use dialoguer::{Input, theme::ColorfulTheme};
+
+    loop {
+        let input: String = Input::with_theme(&ColorfulTheme::default())
+            .with_prompt(i18n::t(keys::RUST_BUILDER_INPUT_CUSTOM_TARGETS))
+            .allow_empty(true)
+            .interact_text()
+            .ok()?;
+
+        let triples = split_list(&input);
+        match triples.iter().find(|t| !looks_like_triple(t)) {
+            Some(invalid) => console.warning(&crate::tr!(
+                keys::RUST_BUILDER_INVALID_TRIPLE,
+                target = invalid
+            )),
+            None => return Some(triples),
+        }
+    }
+}
+
+/// Ask for cargo features and whether to disable the default ones
+fn input_features(prompts: &Prompts) -> Option<(Vec<String>, bool)> {
+    use dialoguer::{Input, theme::ColorfulTheme};
+
+    let input: String = Input::with_theme(&ColorfulTheme::default())
+        .with_prompt(i18n::t(keys::RUST_BUILDER_INPUT_FEATURES))
+        .allow_empty(true)
+        .interact_text()
+        .ok()?;
+    let no_default_features = prompts.confirm_with_options(
+        i18n::t(keys::RUST_BUILDER_CONFIRM_NO_DEFAULT_FEATURES),
+        false,
+    );
+
+    Some((split_list(&input), no_default_features))
+}
+
+/// Split a comma- or whitespace-separated list, dropping empty entries
+fn split_list(input: &str) -> Vec<String> {
+    input
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|item| !item.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Check that a string has the shape of a target triple (`arch-vendor-os[-env]`)
+fn looks_like_triple(triple: &str) -> bool {
+    let parts: Vec<&str> = triple.split('-').collect();
+    (2..=4).contains(&parts.len())
+        && parts.iter().all(|part| {
+            !part.is_empty()
+                && part
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.')
+        })
+        && parts[0].starts_with(|c: char| c.is_ascii_alphabetic())
+}
+
+/// Map requested triples onto the known target list, keeping well-formed unknown triples as
+/// custom targets; returns the first triple that does not look like one
+fn resolve_targets(triples: &[String]) -> Result<Vec<Target>, String> {
+    let available = available_targets();
+    triples
+        .iter()
+        .map(|triple| {
+            if let Some(known) = available.iter().find(|t| &t.triple == triple) {
+                Ok(known.clone())
+            } else if looks_like_triple(triple) {
+                Ok(Target {
+                    triple: triple.clone(),
+                    name_key: keys::RUST_BUILDER_TARGET_CUSTOM,
+                })
+            } else {
+                Err(triple.clone())
+            }
+        })
+        .collect()
+}
+
 fn available_targets() -> Vec<Target> {
     vec![
         Target {
-            triple: "x86_64-unknown-linux-gnu",
+            triple: "x86_64-unknown-linux-gnu".to_string(),
             name_key: keys::RUST_BUILDER_TARGET_LINUX_X86_64_GNU,
         },
         Target {
-            triple: "aarch64-unknown-linux-gnu",
+            triple: "aarch64-unknown-linux-gnu".to_string(),
             name_key: keys::RUST_BUILDER_TARGET_LINUX_ARM64_GNU,
         },
         Target {
-            triple: "i686-unknown-linux-gnu",
+            triple: "i686-unknown-linux-gnu".to_string(),
             name_key: keys::RUST_BUILDER_TARGET_LINUX_I686_GNU,
         },
         Target {
-            triple: "armv7-unknown-linux-gnueabihf",
+            triple: "armv7-unknown-linux-gnueabihf".to_string(),
             name_key: keys::RUST_BUILDER_TARGET_LINUX_ARMV7_GNU,
         },
         Target {
-            triple: "riscv64gc-unknown-linux-gnu",
+            triple: "riscv64gc-unknown-linux-gnu".to_string(),
             name_key: keys::RUST_BUILDER_TARGET_LINUX_RISCV64_GNU,
         },
         Target {
-            triple: "powerpc64le-unknown-linux-gnu",
+            triple: "powerpc64le-unknown-linux-gnu".to_string(),
             name_key: keys::RUST_BUILDER_TARGET_LINUX_PPC64LE_GNU,
         },
         Target {
-            triple: "x86_64-unknown-linux-musl",
+            triple: "x86_64-unknown-linux-musl".to_string(),
             name_key: keys::RUST_BUILDER_TARGET_LINUX_X86_64_MUSL,
         },
         Target {
-            triple: "aarch64-unknown-linux-musl",
+            triple: "aarch64-unknown-linux-musl".to_string(),
             name_key: keys::RUST_BUILDER_TARGET_LINUX_ARM64_MUSL,
         },
         Target {
-            triple: "i686-unknown-linux-musl",
+            triple: "i686-unknown-linux-musl".to_string(),
             name_key: keys::RUST_BUILDER_TARGET_LINUX_I686_MUSL,
         },
         Target {
-            triple: "armv7-unknown-linux-musleabihf",
+            triple: "armv7-unknown-linux-musleabihf".to_string(),
             name_key: keys::RUST_BUILDER_TARGET_LINUX_ARMV7_MUSL,
         },
         Target {
-            triple: "x86_64-apple-darwin",
+            triple: "x86_64-apple-darwin".to_string(),
             name_key: keys::RUST_BUILDER_TARGET_MACOS_X86_64,
         },
         Target {
-            triple: "aarch64-apple-darwin",
+            triple: "aarch64-apple-darwin".to_string(),
             name_key: keys::RUST_BUILDER_TARGET_MACOS_ARM64,
         },
         Target {
-            triple: "x86_64-pc-windows-gnu",
+            triple: "x86_64-pc-windows-gnu".to_string(),
             name_key: keys::RUST_BUILDER_TARGET_WINDOWS_X86_64,
         },
         Target {
-            triple: "aarch64-pc-windows-msvc",
+            triple: "aarch64-pc-windows-msvc".to_string(),
             name_key: keys::RUST_BUILDER_TARGET_WINDOWS_ARM64,
         },
         Target {
-            triple: "wasm32-unknown-unknown",
+            triple: "wasm32-unknown-unknown".to_string(),
             name_key: keys::RUST_BUILDER_TARGET_WASM32_UNKNOWN,
         },
     ]
@@ -309,12 +858,13 @@ fn installed_targets() -> Result<HashSet<String>, String> {
 }
 
 fn install_target(target: &str) -> Result<(), String> {
-    let status = Command::new("rustup")
+    let mut command = Command::new("rustup");
+    command
         .args(["target", "add", target])
         .stdout(Stdio::inherit())
-        .stderr(Stdio::inherit())
-        .status()
-        .map_err(|e| e.to_string())?;
+        .stderr(Stdio::inherit());
+    Console::new().command(&command);
+    let status = suspend_progress(|| command.status()).map_err(|e| e.to_string())?;
 
     if status.success() {
         Ok(())
@@ -326,35 +876,87 @@ fn install_target(target: &str) -> Result<(), String> {
 fn build_target(
     project_dir: &PathBuf,
     target: &str,
-    builder: Builder,
-    release: bool,
-) -> Result<PathBuf, String> {
-    let mut args = vec!["build", "--target", target];
-    if release {
-        args.push("--release");
-    }
-
-    let program = match builder {
+    settings: &BuildSettings,
+    layout: &ProjectLayout,
+) -> Result<BuildOutput, String> {
+    let program = match settings.builder {
         Builder::Cargo => "cargo",
         Builder::Cross => "cross",
     };
 
-    let status = Command::new(program)
-        .args(&args)
+    let mut command = Command::new(program);
+    command
+        .args(build_args(target, settings))
         .current_dir(project_dir)
         .stdout(Stdio::inherit())
-        .stderr(Stdio::inherit())
-        .status()
-        .map_err(|e| e.to_string())?;
+        .stderr(Stdio::inherit());
+    Console::new().command(&command);
+    let status = suspend_progress(|| command.status()).map_err(|e| e.to_string())?;
 
     if status.success() {
-        let profile_dir = if release { "release" } else { "debug" };
-        Ok(project_dir.join("target").join(target).join(profile_dir))
+        let profile = if settings.release { "release" } else { "debug" };
+        let profile_dir = layout.target_dir.join(target).join(profile);
+        let mut artifacts = artifacts::collect(&profile_dir, &layout.binaries, target);
+        let warnings = artifacts
+            .iter_mut()
+            .filter_map(|artifact| {
+                artifacts::post_process(artifact, settings.strip, settings.upx)
+                    .err()
+                    .map(|error| {
+                        crate::tr!(
+                            keys::RUST_BUILDER_POST_PROCESS_FAILED,
+                            path = artifact.path.display(),
+                            error = error
+                        )
+                    })
+            })
+            .collect();
+        Ok(BuildOutput {
+            profile_dir,
+            artifacts,
+            warnings,
+        })
     } else {
         Err(format!("{} build failed", program))
     }
 }
 
+/// Arguments for `cargo build` / `cross build`
+fn build_args(target: &str, settings: &BuildSettings) -> Vec<String> {
+    let mut args = vec![
+        "build".to_string(),
+        "--target".to_string(),
+        target.to_string(),
+    ];
+    if settings.release {
+        args.push("--release".to_string());
+    }
+    if !settings.features.is_empty() {
+        args.push("--features".to_string());
+        args.push(settings.features.join(","));
+    }
+    if settings.no_default_features {
+        args.push("--no-default-features".to_string());
+    }
+    for package in &settings.packages {
+        args.push("-p".to_string());
+        args.push(package.clone());
+    }
+    args
+}
+
+/// Check an optional post-processing tool, telling the user when it is skipped
+fn tool_available(console: &Console, tool: &str) -> bool {
+    let available = command_available(tool);
+    if !available {
+        console.warning(&crate::tr!(
+            keys::RUST_BUILDER_TOOL_MISSING_SKIP,
+            tool = tool
+        ));
+    }
+    available
+}
+
 fn command_available(cmd: &str) -> bool {
     Command::new(cmd)
         .arg("--version")
@@ -389,4 +991,86 @@ mod tests {
         let list = available_targets();
         assert!(!list.is_empty());
     }
+
+    #[test]
+    fn resolve_targets_accepts_known_triples() {
+        let resolved = resolve_targets(&["x86_64-unknown-linux-gnu".to_string()]).unwrap();
+        assert_eq!(resolved.len(), 1);
+        assert_eq!(resolved[0].triple, "x86_64-unknown-linux-gnu");
+    }
+
+    #[test]
+    fn resolve_targets_reports_unknown_triple() {
+        let resolved = resolve_targets(&["thumbv7em-none-eabihf".to_string()]).unwrap();
+        assert_eq!(resolved[0].triple, "thumbv7em-none-eabihf");
+        assert_eq!(resolved[0].name_key, keys::RUST_BUILDER_TARGET_CUSTOM);
+
+        let err = resolve_targets(&["not a target".to_string()]).unwrap_err();
+        assert_eq!(err, "not a target");
+    }
+
+    #[test]
+    fn looks_like_triple_accepts_common_shapes() {
+        assert!(looks_like_triple("thumbv7em-none-eabihf"));
+        assert!(looks_like_triple("x86_64-unknown-linux-gnu"));
+        assert!(looks_like_triple("wasm32-wasip1"));
+        assert!(looks_like_triple("armv7-unknown-linux-gnueabihf"));
+        assert!(!looks_like_triple("linux"));
+        assert!(!looks_like_triple("x86_64--linux"));
+        assert!(!looks_like_triple("a-b-c-d-e"));
+        assert!(!looks_like_triple("64bit-linux"));
+        assert!(!looks_like_triple("x86 64-linux"));
+    }
+
+    #[test]
+    fn format_elapsed_switches_to_minutes() {
+        assert_eq!(format_elapsed(Duration::from_millis(8_420)), "8.4s");
+        assert_eq!(format_elapsed(Duration::from_secs(125)), "2m 05s");
+        assert_eq!(format_elapsed(Duration::from_secs(3_600)), "60m 00s");
+    }
+
+    #[test]
+    fn split_list_handles_commas_and_spaces() {
+        assert_eq!(split_list("foo, bar baz,,"), ["foo", "bar", "baz"]);
+        assert!(split_list("  ").is_empty());
+    }
+
+    #[test]
+    fn build_args_include_features() {
+        let settings = BuildSettings {
+            builder: Builder::Cross,
+            release: true,
+            features: vec!["foo".to_string(), "bar".to_string()],
+            no_default_features: true,
+            strip: false,
+            upx: false,
+            packages: vec!["core".to_string()],
+        };
+        assert_eq!(
+            build_args("thumbv7em-none-eabihf", &settings),
+            [
+                "build",
+                "--target",
+                "thumbv7em-none-eabihf",
+                "--release",
+                "--features",
+                "foo,bar",
+                "--no-default-features",
+                "-p",
+                "core",
+            ]
+        );
+
+        let plain = BuildSettings {
+            release: false,
+            features: Vec::new(),
+            no_default_features: false,
+            packages: Vec::new(),
+            ..settings
+        };
+        assert_eq!(
+            build_args("x86_64-unknown-linux-gnu", &plain),
+            ["build", "--target", "x86_64-unknown-linux-gnu"]
+        );
+    }
 }
@@ -4,7 +4,7 @@ mod upgrader;
 use crate::core::OperationError;
 use crate::i18n::{self, keys};
 use crate::ui::{Console, Prompts};
-use tools::{REQUIRED_CARGO_TOOLS, UPGRADE_STEPS};
+use tools::{REQUIRED_CARGO_TOOLS, UPGRADE_STEPS, UpgradeStep};
 use upgrader::RustUpgrader;
 
 /// 執行 Rust 專案升級功能
@@ -54,7 +54,7 @@ pub fn run() {
 
     // 步驟 3: 安裝缺少的工具
     if !missing_tools.is_empty() {
-        console.warning(&crate::tr!(
+        console.warning(&crate::tr_plural!(
             keys::RUST_UPGRADER_MISSING_TOOLS,
             count = missing_tools.len()
         ));
@@ -120,7 +120,26 @@ pub fn run() {
 
     console.separator();
 
-    if !prompts.confirm(i18n::t(keys::RUST_UPGRADER_CONFIRM_UPGRADE)) {
+    let step_items: Vec<String> = UPGRADE_STEPS
+        .iter()
+        .map(|step| step.name.to_string())
+        .collect();
+    let defaults: Vec<bool> = UPGRADE_STEPS
+        .iter()
+        .map(|step| step.selected_by_default)
+        .collect();
+    let selected_steps: Vec<&UpgradeStep> = prompts
+        .multi_select(
+            i18n::t(keys::RUST_UPGRADER_SELECT_STEPS),
+            &step_items,
+            &defaults,
+        )
+        .unwrap_or_default()
+        .into_iter()
+        .map(|idx| &UPGRADE_STEPS[idx])
+        .collect();
+
+    if selected_steps.is_empty() {
         console.warning(i18n::t(keys::RUST_UPGRADER_CANCELLED));
         return;
     }
@@ -131,11 +150,12 @@ pub fn run() {
     let mut success_count = 0;
     let mut failed_count = 0;
     let mut skipped_count = 0;
+    let mut verifications: Vec<(&str, bool)> = Vec::new();
 
-    for (i, step) in UPGRADE_STEPS.iter().enumerate() {
+    for (i, step) in selected_steps.iter().enumerate() {
         console.show_progress(
             i + 1,
-            UPGRADE_STEPS.len(),
+            selected_steps.len(),
             &crate::tr!(keys::RUST_UPGRADER_RUNNING_STEP, step = step.name),
         );
 
@@ -144,6 +164,9 @@ pub fn run() {
                 console.success_item(&crate::tr!(keys::RUST_UPGRADER_STEP_DONE, step = step.name));
                 display_output(&console, &output);
                 success_count += 1;
+                if step.verification {
+                    verifications.push((step.name, true));
+                }
             }
             Err(OperationError::MissingCargoToml) => {
                 console.warning(&crate::tr!(
@@ -158,6 +181,9 @@ pub fn run() {
                     &err.to_string(),
                 );
                 failed_count += 1;
+                if step.verification {
+                    verifications.push((step.name, false));
+                }
             }
         }
         console.blank_line();
@@ -175,6 +201,20 @@ pub fn run() {
             count = skipped_count
         ));
     }
+    for (step, passed) in verifications {
+        if passed {
+            console.success(&crate::tr!(keys::RUST_UPGRADER_VERIFY_PASSED, step = step));
+        } else {
+            console.warning(&crate::tr!(keys::RUST_UPGRADER_VERIFY_FAILED, step = step));
+        }
+    }
+    let deselected_count = UPGRADE_STEPS.len() - selected_steps.len();
+    if deselected_count > 0 {
+        console.info(&crate::tr_plural!(
+            keys::RUST_UPGRADER_STEPS_DESELECTED,
+            count = deselected_count
+        ));
+    }
 }
 
 /// 顯示命令輸出（限制行數）
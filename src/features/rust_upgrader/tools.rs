@@ -38,6 +38,9 @@ pub struct UpgradeStep {
     pub args: &'static [&'static str],
     pub description_key: &'static str,
     pub requires_project: bool,
+    /// Checks the project after upgrading rather than changing anything
+    pub verification: bool,
+    pub selected_by_default: bool,
 }
 
 impl UpgradeStep {
@@ -54,8 +57,22 @@ impl UpgradeStep {
             args,
             description_key,
             requires_project,
+            verification: false,
+            selected_by_default: true,
         }
     }
+
+    /// Mark the step as a post-upgrade verification
+    pub const fn verification(mut self) -> Self {
+        self.verification = true;
+        self
+    }
+
+    /// Leave the step unchecked in the step selection
+    pub const fn unselected(mut self) -> Self {
+        self.selected_by_default = false;
+        self
+    }
 }
 
 /// 升級步驟清單
@@ -102,6 +119,23 @@ pub const UPGRADE_STEPS: &[UpgradeStep] = &[
         keys::RUST_UPGRADER_STEP_DESC_CARGO_AUDIT,
         true,
     ),
+    UpgradeStep::new(
+        "Cargo Build",
+        "cargo",
+        &["build"],
+        keys::RUST_UPGRADER_STEP_DESC_CARGO_BUILD,
+        true,
+    )
+    .verification(),
+    UpgradeStep::new(
+        "Cargo Test",
+        "cargo",
+        &["test"],
+        keys::RUST_UPGRADER_STEP_DESC_CARGO_TEST,
+        true,
+    )
+    .verification()
+    .unselected(),
 ];
 
 #[cfg(test)]
@@ -135,4 +169,27 @@ mod tests {
         assert!(!step.command.is_empty());
         assert!(!step.description_key.is_empty());
     }
+
+    #[test]
+    fn test_verification_steps_run_after_upgrade() {
+        let upgrade = UPGRADE_STEPS
+            .iter()
+            .position(|step| step.name == "Cargo Upgrade")
+            .unwrap();
+        let verifications: Vec<usize> = UPGRADE_STEPS
+            .iter()
+            .enumerate()
+            .filter(|(_, step)| step.verification)
+            .map(|(idx, _)| idx)
+            .collect();
+
+        assert!(!verifications.is_empty());
+        assert!(verifications.iter().all(|&idx| idx > upgrade));
+        assert!(
+            UPGRADE_STEPS
+                .iter()
+                .filter(|step| step.verification)
+                .all(|step| step.requires_project)
+        );
+    }
 }
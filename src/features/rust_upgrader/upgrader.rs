@@ -1,5 +1,6 @@
 use crate::core::{OperationError, Result};
 use crate::i18n::{self, keys};
+use crate::ui::Console;
 use std::path::Path;
 use std::process::Command;
 
@@ -76,14 +77,15 @@ impl RustUpgrader {
 
     /// 安裝 cargo 工具
     pub fn install_tool(&self, tool: &CargoTool) -> Result<String> {
-        let status = Command::new("cargo")
+        let mut command = Command::new("cargo");
+        command
             .args(["install", tool.crate_name])
-            .stdin(std::process::Stdio::null())
-            .status()
-            .map_err(|e| OperationError::Command {
-                command: "cargo install".to_string(),
-                message: crate::tr!(keys::ERROR_UNABLE_TO_EXECUTE, error = e),
-            })?;
+            .stdin(std::process::Stdio::null());
+        Console::new().command(&command);
+        let status = command.status().map_err(|e| OperationError::Command {
+            command: "cargo install".to_string(),
+            message: crate::tr!(keys::ERROR_UNABLE_TO_EXECUTE, error = e),
+        })?;
 
         if status.success() {
             Ok(format!("{} installed", tool.crate_name))
@@ -108,13 +110,12 @@ impl RustUpgrader {
             command.current_dir(path);
         }
 
-        let status = command
-            .stdin(std::process::Stdio::null())
-            .status()
-            .map_err(|e| OperationError::Command {
-                command: step.command.to_string(),
-                message: crate::tr!(keys::ERROR_UNABLE_TO_EXECUTE, error = e),
-            })?;
+        command.stdin(std::process::Stdio::null());
+        Console::new().command(&command);
+        let status = command.status().map_err(|e| OperationError::Command {
+            command: step.command.to_string(),
+            message: crate::tr!(keys::ERROR_UNABLE_TO_EXECUTE, error = e),
+        })?;
 
         if status.success() {
             Ok(format!("{} completed", step.command))
@@ -0,0 +1,78 @@
+use crate::core::{OperationError, Result};
+use crate::i18n::keys;
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use std::path::Path;
+
+/// Per-repo ignore file with one glob per line (`#` starts a comment)
+pub const IGNORE_FILE_NAME: &str = ".ops-scan-ignore";
+
+/// Combine command-line patterns with the repo's ignore file, if present
+pub fn load_patterns(repo_root: &Path, extra: &[String]) -> Result<Vec<String>> {
+    let mut patterns = extra.to_vec();
+    let ignore_file = repo_root.join(IGNORE_FILE_NAME);
+    if ignore_file.is_file() {
+        let content = std::fs::read_to_string(&ignore_file)
+            .map_err(|err| OperationError::io(ignore_file.display().to_string(), err))?;
+        patterns.extend(parse_ignore_file(&content));
+    }
+    Ok(patterns)
+}
+
+fn parse_ignore_file(content: &str) -> Vec<String> {
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(str::to_string)
+        .collect()
+}
+
+/// Compile patterns into a matcher for repo-relative paths
+pub fn build_matcher(patterns: &[String]) -> Result<GlobSet> {
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        let glob = Glob::new(pattern).map_err(|err| {
+            OperationError::Validation(crate::tr!(
+                keys::SECURITY_SCANNER_EXCLUDE_INVALID,
+                pattern = pattern,
+                error = err
+            ))
+        })?;
+        builder.add(glob);
+    }
+    builder
+        .build()
+        .map_err(|err| OperationError::Validation(err.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_ignore_file_skips_comments_and_blanks() {
+        let patterns = parse_ignore_file("# fixtures\nvendor/**\n\n  testdata/**  \n");
+        assert_eq!(patterns, ["vendor/**", "testdata/**"]);
+    }
+
+    #[test]
+    fn test_matcher_uses_repo_relative_paths() {
+        let matcher = build_matcher(&["vendor/**".to_string(), "*.pem".to_string()]).unwrap();
+        assert!(matcher.is_match("vendor/lib/secret.rs"));
+        assert!(matcher.is_match("certs/dev.pem"));
+        assert!(!matcher.is_match("src/main.rs"));
+    }
+
+    #[test]
+    fn test_invalid_pattern_is_rejected() {
+        assert!(build_matcher(&["vendor/[".to_string()]).is_err());
+    }
+
+    #[test]
+    fn test_load_patterns_reads_ignore_file() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join(IGNORE_FILE_NAME), "testdata/**\n").unwrap();
+        let patterns = load_patterns(dir.path(), &["vendor/**".to_string()]).unwrap();
+        assert_eq!(patterns, ["vendor/**", "testdata/**"]);
+    }
+}
@@ -1,5 +1,7 @@
-use crate::core::{OperationError, Result};
+pub use crate::core::requirements::is_command_available;
+use crate::core::{OperationError, Result, TempDir};
 use crate::i18n::{self, keys};
+use crate::ui::Console;
 use std::env;
 use std::path::{Path, PathBuf};
 use std::process::Command;
@@ -86,37 +88,6 @@ pub fn resolve_tool_path(tool: ScanTool) -> Option<PathBuf> {
     find_go_binary(tool.binary_name())
 }
 
-pub fn is_command_available(command: &str) -> Option<PathBuf> {
-    let path = Path::new(command);
-    if path.is_absolute() || command.contains(std::path::MAIN_SEPARATOR) {
-        if path.is_file() {
-            return Some(path.to_path_buf());
-        }
-        return None;
-    }
-
-    let path_var = env::var_os("PATH")?;
-    for dir in env::split_paths(&path_var) {
-        let candidate = dir.join(command);
-        if candidate.is_file() {
-            return Some(candidate);
-        }
-
-        #[cfg(windows)]
-        {
-            let extensions = ["exe", "cmd", "bat"];
-            for ext in extensions {
-                let candidate = dir.join(format!("{}.{}", command, ext));
-                if candidate.is_file() {
-                    return Some(candidate);
-                }
-            }
-        }
-    }
-
-    None
-}
-
 fn run_install_strategy(strategy: &InstallStrategy) -> Result<()> {
     let mut program = strategy.program.to_string();
     let mut args = strategy.args.clone();
@@ -129,14 +100,13 @@ fn run_install_strategy(strategy: &InstallStrategy) -> Result<()> {
         args = sudo_args;
     }
 
-    let status = Command::new(&program)
-        .args(&args)
-        .stdin(std::process::Stdio::null())
-        .status()
-        .map_err(|err| OperationError::Command {
-            command: program.clone(),
-            message: crate::tr!(keys::ERROR_UNABLE_TO_EXECUTE, error = err),
-        })?;
+    let mut command = Command::new(&program);
+    command.args(&args).stdin(std::process::Stdio::null());
+    Console::new().command(&command);
+    let status = command.status().map_err(|err| OperationError::Command {
+        command: program.clone(),
+        message: crate::tr!(keys::ERROR_UNABLE_TO_EXECUTE, error = err),
+    })?;
 
     if status.success() {
         Ok(())
@@ -171,7 +141,9 @@ fn install_from_github_release(tool: ScanTool) -> Result<ReleaseInstallOutcome>
         ));
     };
 
-    let archive = download_to_temp(&download.url, download.extension)?;
+    // Held until the binary is copied out; removed on return, even on failure
+    let download_dir = TempDir::new("scanner-download")?;
+    let archive = download_to(&download_dir, &download.url, download.extension)?;
     let extract_dir = extract_archive(&archive, download.extension)?;
     let binary = find_binary_in_dir(&extract_dir, tool.binary_name()).ok_or_else(|| {
         OperationError::Command {
@@ -383,13 +355,7 @@ fn fetch_url(url: &str) -> Result<String> {
     })
 }
 
-fn download_to_temp(url: &str, extension: ArchiveKind) -> Result<PathBuf> {
-    let temp_dir = env::temp_dir().join("ops-tools").join("git-scanner");
-    std::fs::create_dir_all(&temp_dir).map_err(|err| OperationError::Io {
-        path: temp_dir.display().to_string(),
-        source: err,
-    })?;
-
+fn download_to(temp_dir: &Path, url: &str, extension: ArchiveKind) -> Result<PathBuf> {
     let file_name = match extension {
         ArchiveKind::TarGz => "download.tar.gz",
         ArchiveKind::Zip => "download.zip",
@@ -452,10 +418,8 @@ fn extract_archive(path: &Path, extension: ArchiveKind) -> Result<PathBuf> {
         .parent()
         .unwrap_or_else(|| Path::new("."))
         .join("extract");
-    std::fs::create_dir_all(&extract_dir).map_err(|err| OperationError::Io {
-        path: extract_dir.display().to_string(),
-        source: err,
-    })?;
+    std::fs::create_dir_all(&extract_dir)
+        .map_err(|err| OperationError::io(extract_dir.display().to_string(), err))?;
 
     match extension {
         ArchiveKind::TarGz => {
@@ -562,31 +526,22 @@ fn install_binary(source: &Path, binary: &str) -> Result<PathBuf> {
         });
     };
 
-    std::fs::create_dir_all(&target_dir).map_err(|err| OperationError::Io {
-        path: target_dir.display().to_string(),
-        source: err,
-    })?;
+    std::fs::create_dir_all(&target_dir)
+        .map_err(|err| OperationError::io(target_dir.display().to_string(), err))?;
 
     let target_path = target_dir.join(binary);
-    std::fs::copy(source, &target_path).map_err(|err| OperationError::Io {
-        path: target_path.display().to_string(),
-        source: err,
-    })?;
+    std::fs::copy(source, &target_path)
+        .map_err(|err| OperationError::io(target_path.display().to_string(), err))?;
 
     #[cfg(unix)]
     {
         use std::os::unix::fs::PermissionsExt;
         let mut perms = std::fs::metadata(&target_path)
-            .map_err(|err| OperationError::Io {
-                path: target_path.display().to_string(),
-                source: err,
-            })?
+            .map_err(|err| OperationError::io(target_path.display().to_string(), err))?
             .permissions();
         perms.set_mode(0o755);
-        std::fs::set_permissions(&target_path, perms).map_err(|err| OperationError::Io {
-            path: target_path.display().to_string(),
-            source: err,
-        })?;
+        std::fs::set_permissions(&target_path, perms)
+            .map_err(|err| OperationError::io(target_path.display().to_string(), err))?;
     }
 
     Ok(target_path)
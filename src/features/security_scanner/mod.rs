@@ -1,63 +1,178 @@
+mod exclude;
 mod installer;
 mod scanner;
 mod supply_chain;
 mod tools;
 
-use crate::core::{OperationError, Result};
+use crate::core::requirements::require_commands;
+use crate::core::{OperationError, Result, TempDir, load_config, process, save_config};
 use crate::i18n::{self, keys};
-use crate::ui::{Console, Prompts};
-use installer::{InstallStatus, ensure_installed, is_command_available, resolve_tool_path};
-use scanner::{ScanStatus, run_scans};
+use crate::ui::{Console, PromptError, Prompts};
+use globset::GlobSet;
+use installer::{InstallStatus, ensure_installed, resolve_tool_path};
+use scanner::{ScanStatus, run_scans_parallel};
 use std::path::{Path, PathBuf};
-use std::process::{Command, Stdio};
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::process::{Command, ExitCode, Stdio};
 use supply_chain::{Severity, SupplyChainReport, scan_supply_chain};
-use tools::all_tools;
+use tools::{ScanScope, ScanTool, all_tools, enabled_tools};
+
+/// Options supplied up front (e.g. from the command line)
+#[derive(Clone, Debug, Default)]
+pub struct ScanOptions {
+    /// Repo-relative glob patterns to leave out of the working tree snapshot
+    pub excludes: Vec<String>,
+    /// Audit only: never install scanners, scan with the ones already present
+    pub no_install: bool,
+    /// Repo to scan instead of the one containing the current directory
+    pub repo: Option<PathBuf>,
+    /// Ask again which scanners to run, even when a selection is saved
+    pub choose_tools: bool,
+}
+
+/// Display name, binary name and resolved path (including `~/.local/bin` and the Go bin dir) of each scanner, for doctor
+pub fn scanner_tools() -> Vec<(&'static str, &'static str, Option<PathBuf>)> {
+    all_tools()
+        .into_iter()
+        .map(|tool| {
+            (
+                tool.display_name(),
+                tool.binary_name(),
+                resolve_tool_path(tool),
+            )
+        })
+        .collect()
+}
+
+/// How a scan ended, used as the process exit status with `--fail-on findings`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ScanResult {
+    /// Every check ran and nothing was reported
+    Clean,
+    /// At least one check reported findings
+    Findings,
+    /// The scan did not run, or a scanner failed without reporting findings
+    Failed,
+}
+
+impl ScanResult {
+    /// 0 when clean, 1 on findings, 2 when the scan itself failed
+    pub fn exit_code(self) -> ExitCode {
+        match self {
+            Self::Clean => ExitCode::SUCCESS,
+            Self::Findings => ExitCode::from(1),
+            Self::Failed => ExitCode::from(2),
+        }
+    }
+}
 
 /// Execute Security Scanner
 pub fn run() {
+    run_with(ScanOptions::default());
+}
+
+/// Execute Security Scanner with pre-selected options
+pub fn run_with(options: ScanOptions) -> ScanResult {
     let console = Console::new();
     let prompts = Prompts::new();
 
     console.header(i18n::t(keys::SECURITY_SCANNER_HEADER));
 
-    let current_dir = match std::env::current_dir() {
-        Ok(dir) => dir,
-        Err(err) => {
-            console.error(&crate::tr!(
-                keys::SECURITY_SCANNER_CURRENT_DIR_FAILED,
-                error = err
-            ));
-            return;
+    let repo_root = match &options.repo {
+        Some(path) => {
+            // Canonicalize so a relative path walks up real parents, not the cwd
+            let start = match std::fs::canonicalize(path) {
+                Ok(start) => start,
+                Err(err) => {
+                    console.error(&crate::tr!(
+                        keys::SECURITY_SCANNER_REPO_PATH_INVALID,
+                        path = path.display(),
+                        error = err
+                    ));
+                    return ScanResult::Failed;
+                }
+            };
+            let Some(root) = find_git_root(&start) else {
+                console.error(&crate::tr!(
+                    keys::SECURITY_SCANNER_REPO_NOT_GIT,
+                    path = path.display()
+                ));
+                return ScanResult::Failed;
+            };
+            root
+        }
+        None => {
+            let current_dir = match std::env::current_dir() {
+                Ok(dir) => dir,
+                Err(err) => {
+                    console.error(&crate::tr!(
+                        keys::SECURITY_SCANNER_CURRENT_DIR_FAILED,
+                        error = err
+                    ));
+                    return ScanResult::Failed;
+                }
+            };
+            let Some(root) = find_git_root(&current_dir) else {
+                console.error(i18n::t(keys::SECURITY_SCANNER_NOT_GIT_REPO));
+                return ScanResult::Failed;
+            };
+            root
         }
     };
 
-    let Some(repo_root) = find_git_root(&current_dir) else {
-        console.error(i18n::t(keys::SECURITY_SCANNER_NOT_GIT_REPO));
-        return;
-    };
-
-    if is_command_available("git").is_none() {
-        console.error(i18n::t(keys::SECURITY_SCANNER_GIT_NOT_FOUND));
-        return;
+    if let Err(err) = require_commands(&["git"]) {
+        console.error(&err.to_string());
+        return ScanResult::Failed;
     }
 
     console.info(&crate::tr!(
         keys::SECURITY_SCANNER_SCAN_DIR,
         path = repo_root.display()
     ));
-    console.info(i18n::t(keys::SECURITY_SCANNER_STRICT_MODE));
+
+    let scope_options = [
+        i18n::t(keys::SECURITY_SCANNER_SCOPE_WORKTREE_ONLY),
+        i18n::t(keys::SECURITY_SCANNER_SCOPE_FULL),
+    ];
+    let scope = match prompts.select_with_default(
+        i18n::t(keys::SECURITY_SCANNER_SCOPE_PROMPT),
+        &scope_options,
+        1,
+    ) {
+        Some(0) => ScanScope::Worktree,
+        Some(_) => ScanScope::FullHistory,
+        None => {
+            console.warning(i18n::t(keys::SECURITY_SCANNER_CANCELLED));
+            return ScanResult::Failed;
+        }
+    };
+    console.info(match scope {
+        ScanScope::Worktree => i18n::t(keys::SECURITY_SCANNER_STRICT_MODE_WORKTREE),
+        ScanScope::FullHistory => i18n::t(keys::SECURITY_SCANNER_STRICT_MODE),
+    });
     console.blank_line();
 
-    let worktree_snapshot = match build_worktree_snapshot(&repo_root, &console) {
+    let excludes = match exclude::load_patterns(&repo_root, &options.excludes)
+        .and_then(|patterns| exclude::build_matcher(&patterns))
+    {
+        Ok(matcher) => matcher,
+        Err(err) => {
+            console.error(&err.to_string());
+            return ScanResult::Failed;
+        }
+    };
+
+    let worktree_snapshot = match build_worktree_snapshot(&repo_root, &console, &excludes) {
         Ok(snapshot) => snapshot,
         Err(err) => {
             console.error(&err.to_string());
-            return;
+            return ScanResult::Failed;
         }
     };
 
-    let tools = all_tools();
+    let Some(tools) = select_tools(&console, &prompts, options.choose_tools) else {
+        console.warning(i18n::t(keys::SECURITY_SCANNER_CANCELLED));
+        return ScanResult::Failed;
+    };
     console.info(i18n::t(keys::SECURITY_SCANNER_TOOLS_INTRO));
     console.list_item(
         "🔎",
@@ -76,85 +191,41 @@ pub fn run() {
         console.list_item("🔎", &format!("{} ({})", tool.display_name(), status));
     }
 
-    if !prompts.confirm_with_options(i18n::t(keys::SECURITY_SCANNER_CONFIRM_INSTALL), true) {
-        console.warning(i18n::t(keys::SECURITY_SCANNER_CANCELLED));
-        return;
-    }
-
-    console.blank_line();
-
-    let mut install_attempted = 0;
-    let mut install_success = 0;
-    let mut install_failed = 0;
-
-    for tool in &tools {
-        if resolve_tool_path(*tool).is_some() {
-            console.success_item(&format!(
-                "{} {}",
-                tool.display_name(),
-                i18n::t(keys::SECURITY_SCANNER_STATUS_INSTALLED)
-            ));
-            continue;
-        }
-
-        console.info(&crate::tr!(
-            keys::SECURITY_SCANNER_INSTALLING,
-            tool = tool.display_name()
-        ));
-        install_attempted += 1;
-        match ensure_installed(*tool) {
-            Ok(InstallStatus::Installed(path)) => {
-                console.success_item(&crate::tr!(
-                    keys::SECURITY_SCANNER_INSTALL_DONE,
-                    tool = tool.display_name(),
-                    path = path.display()
-                ));
-                install_success += 1;
-            }
-            Ok(InstallStatus::AlreadyInstalled(path)) => {
-                console.success_item(&crate::tr!(
-                    keys::SECURITY_SCANNER_INSTALL_ALREADY,
-                    tool = tool.display_name(),
-                    path = path.display()
-                ));
-                install_success += 1;
-            }
-            Ok(InstallStatus::Failed(errors)) => {
-                let message = errors.join("; ");
-                console.error_item(
-                    &crate::tr!(
-                        keys::SECURITY_SCANNER_INSTALL_FAILED,
-                        tool = tool.display_name()
-                    ),
-                    &message,
-                );
-                install_failed += 1;
-            }
-            Err(err) => {
-                console.error_item(
-                    &crate::tr!(
-                        keys::SECURITY_SCANNER_INSTALL_FAILED,
-                        tool = tool.display_name()
-                    ),
-                    &err.to_string(),
-                );
-                install_failed += 1;
-            }
-        }
-    }
-
-    if install_attempted > 0 {
-        console.show_summary(
-            i18n::t(keys::SECURITY_SCANNER_INSTALL_SUMMARY),
-            install_success,
-            install_failed,
-        );
+    if options.no_install {
+        console.info(i18n::t(keys::SECURITY_SCANNER_AUDIT_MODE));
         console.blank_line();
+    } else {
+        let missing: Vec<ScanTool> = tools
+            .iter()
+            .copied()
+            .filter(|tool| resolve_tool_path(*tool).is_none())
+            .collect();
+        if !missing.is_empty() {
+            let names: Vec<String> = missing
+                .iter()
+                .map(|tool| tool.display_name().to_string())
+                .collect();
+            let prompt = i18n::t(keys::SECURITY_SCANNER_SELECT_INSTALL);
+            let chosen: Vec<ScanTool> =
+                match prompts.try_multi_select(prompt, &names, &vec![true; names.len()]) {
+                    Ok(indices) => indices.into_iter().map(|index| missing[index]).collect(),
+                    Err(err) => {
+                        if err != PromptError::Cancelled {
+                            console.error(&format!("{prompt} — {err}"));
+                        }
+                        console.warning(i18n::t(keys::SECURITY_SCANNER_CANCELLED));
+                        return ScanResult::Failed;
+                    }
+                };
+            install_missing_tools(&console, &chosen);
+        }
     }
 
     let mut scan_success = 0;
     let mut scan_failed = 0;
-    let mut has_findings = false;
+    let mut has_errors = false;
+    // Checks with findings per tool, for the summary and the exit status
+    let mut findings_by_tool: Vec<(&str, usize)> = Vec::new();
 
     console.info(i18n::t(keys::SECURITY_SCANNER_SUPPLY_CHAIN_START));
     match scan_supply_chain(worktree_snapshot.root()) {
@@ -163,7 +234,7 @@ pub fn run() {
             if report.findings.is_empty() {
                 scan_success += 1;
             } else {
-                has_findings = true;
+                findings_by_tool.push((i18n::t(keys::SECURITY_SCANNER_SUPPLY_CHAIN_TOOL), 1));
                 scan_failed += 1;
             }
         }
@@ -172,26 +243,41 @@ pub fn run() {
                 i18n::t(keys::SECURITY_SCANNER_SUPPLY_CHAIN_FAILED),
                 &err.to_string(),
             );
+            has_errors = true;
             scan_failed += 1;
         }
     }
     console.blank_line();
 
+    let mut runnable = Vec::new();
+    let mut skipped = Vec::new();
     for tool in &tools {
-        let Some(_) = resolve_tool_path(*tool) else {
+        if resolve_tool_path(*tool).is_none() {
             console.warning(&crate::tr!(
                 keys::SECURITY_SCANNER_SKIP_TOOL,
                 tool = tool.display_name()
             ));
+            skipped.push(tool.display_name());
             continue;
-        };
-
+        }
         console.info(&crate::tr!(
             keys::SECURITY_SCANNER_START_SCAN,
             tool = tool.display_name()
         ));
-        match run_scans(*tool, &repo_root, worktree_snapshot.root()) {
+        runnable.push(*tool);
+    }
+
+    for (tool, result) in run_scans_parallel(&runnable, scope, &repo_root, worktree_snapshot.root())
+    {
+        match result {
             Ok(outcomes) => {
+                let findings = outcomes
+                    .iter()
+                    .filter(|outcome| matches!(outcome.status, ScanStatus::Findings))
+                    .count();
+                if findings > 0 {
+                    findings_by_tool.push((tool.display_name(), findings));
+                }
                 for outcome in outcomes {
                     console.separator();
                     console.info(&crate::tr!(
@@ -222,7 +308,6 @@ pub fn run() {
                             scan_success += 1;
                         }
                         ScanStatus::Findings => {
-                            has_findings = true;
                             console.error_item(
                                 &crate::tr!(keys::SECURITY_SCANNER_FINDINGS, label = outcome.label),
                                 &format_exit_code(outcome.exit_code),
@@ -237,6 +322,7 @@ pub fn run() {
                                 ),
                                 &format_exit_code(outcome.exit_code),
                             );
+                            has_errors = true;
                             scan_failed += 1;
                         }
                     }
@@ -250,6 +336,7 @@ pub fn run() {
                     ),
                     &err.to_string(),
                 );
+                has_errors = true;
                 scan_failed += 1;
             }
         }
@@ -262,9 +349,36 @@ pub fn run() {
         scan_success,
         scan_failed,
     );
-    if has_findings {
-        console.warning(i18n::t(keys::SECURITY_SCANNER_FINDINGS_WARNING));
+    if !skipped.is_empty() {
+        console.warning(&crate::tr!(
+            keys::SECURITY_SCANNER_SKIPPED_SUMMARY,
+            tools = skipped.join(", ")
+        ));
+    }
+    if findings_by_tool.is_empty() {
+        return if has_errors {
+            ScanResult::Failed
+        } else {
+            ScanResult::Clean
+        };
     }
+
+    console.raw(&format!(
+        "{}\n",
+        i18n::t(keys::SECURITY_SCANNER_FINDINGS_BY_TOOL)
+    ));
+    for (tool, count) in &findings_by_tool {
+        console.list_item(
+            "•",
+            &crate::tr!(
+                keys::SECURITY_SCANNER_FINDINGS_COUNT,
+                tool = tool,
+                count = count
+            ),
+        );
+    }
+    console.warning(i18n::t(keys::SECURITY_SCANNER_FINDINGS_WARNING));
+    ScanResult::Findings
 }
 
 fn print_supply_chain_report(console: &Console, report: &SupplyChainReport) {
@@ -346,46 +460,47 @@ fn find_git_root(start: &Path) -> Option<PathBuf> {
 }
 
 struct WorktreeSnapshot {
-    root: PathBuf,
-    cleanup_path: PathBuf,
+    dir: TempDir,
 }
 
 impl WorktreeSnapshot {
     fn root(&self) -> &Path {
-        &self.root
-    }
-}
-
-impl Drop for WorktreeSnapshot {
-    fn drop(&mut self) {
-        let _ = std::fs::remove_dir_all(&self.cleanup_path);
+        self.dir.path()
     }
 }
 
-fn build_worktree_snapshot(repo_root: &Path, console: &Console) -> Result<WorktreeSnapshot> {
-    let snapshot_root = create_temp_dir()?;
+fn build_worktree_snapshot(
+    repo_root: &Path,
+    console: &Console,
+    excludes: &GlobSet,
+) -> Result<WorktreeSnapshot> {
+    let snapshot = WorktreeSnapshot {
+        dir: TempDir::new("security-scan")?,
+    };
+    let snapshot_root = snapshot.root();
 
     let scan_files = git_list_scan_files(repo_root)?;
     if scan_files.is_empty() {
         console.warning(i18n::t(keys::SECURITY_SCANNER_NO_TRACKED_FILES));
-        return Ok(WorktreeSnapshot {
-            root: snapshot_root.clone(),
-            cleanup_path: snapshot_root,
-        });
+        return Ok(snapshot);
     }
 
     let ignored = git_list_ignored(repo_root, &scan_files)?;
-    let filtered: Vec<String> = scan_files
+    let (excluded, filtered): (Vec<String>, Vec<String>) = scan_files
         .into_iter()
         .filter(|path| !ignored.contains(path))
-        .collect();
+        .partition(|path| excludes.is_match(path));
+
+    if !excluded.is_empty() {
+        console.info(&crate::tr_plural!(
+            keys::SECURITY_SCANNER_EXCLUDED,
+            count = excluded.len()
+        ));
+    }
 
     if filtered.is_empty() {
         console.warning(i18n::t(keys::SECURITY_SCANNER_ALL_IGNORED));
-        return Ok(WorktreeSnapshot {
-            root: snapshot_root.clone(),
-            cleanup_path: snapshot_root,
-        });
+        return Ok(snapshot);
     }
     for rel_path in filtered {
         let source = repo_root.join(&rel_path);
@@ -394,65 +509,29 @@ fn build_worktree_snapshot(repo_root: &Path, console: &Console) -> Result<Worktr
         }
         let destination = snapshot_root.join(&rel_path);
         if let Some(parent) = destination.parent() {
-            std::fs::create_dir_all(parent).map_err(|err| OperationError::Io {
-                path: parent.display().to_string(),
-                source: err,
-            })?;
+            std::fs::create_dir_all(parent)
+                .map_err(|err| OperationError::io(parent.display().to_string(), err))?;
         }
 
         if std::fs::hard_link(&source, &destination).is_err() {
-            std::fs::copy(&source, &destination).map_err(|err| OperationError::Io {
-                path: destination.display().to_string(),
-                source: err,
-            })?;
+            std::fs::copy(&source, &destination)
+                .map_err(|err| OperationError::io(destination.display().to_string(), err))?;
         }
     }
 
-    Ok(WorktreeSnapshot {
-        root: snapshot_root.clone(),
-        cleanup_path: snapshot_root,
-    })
-}
-
-fn create_temp_dir() -> Result<PathBuf> {
-    let base = std::env::temp_dir().join("ops-tools");
-    std::fs::create_dir_all(&base).map_err(|err| OperationError::Io {
-        path: base.display().to_string(),
-        source: err,
-    })?;
-
-    let timestamp = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .unwrap_or_default()
-        .as_nanos();
-    let dir = base.join(format!(
-        "security-scan-{}-{}",
-        std::process::id(),
-        timestamp
-    ));
-    std::fs::create_dir_all(&dir).map_err(|err| OperationError::Io {
-        path: dir.display().to_string(),
-        source: err,
-    })?;
-    Ok(dir)
+    Ok(snapshot)
 }
 
 fn git_list_scan_files(repo_root: &Path) -> Result<Vec<String>> {
-    let output = Command::new("git")
-        .args([
-            "-C",
-            &repo_root.display().to_string(),
-            "ls-files",
-            "-z",
-            "--cached",
-            "--others",
-            "--exclude-standard",
-        ])
-        .output()
-        .map_err(|err| OperationError::Command {
-            command: "git ls-files".to_string(),
-            message: crate::tr!(keys::ERROR_UNABLE_TO_EXECUTE, error = err),
-        })?;
+    let output = process::run_with_timeout(Command::new("git").args([
+        "-C",
+        &repo_root.display().to_string(),
+        "ls-files",
+        "-z",
+        "--cached",
+        "--others",
+        "--exclude-standard",
+    ]))?;
 
     if !output.status.success() {
         return Err(OperationError::Command {
@@ -499,10 +578,9 @@ fn git_list_ignored(
             buffer.push(0);
         }
         use std::io::Write;
-        stdin.write_all(&buffer).map_err(|err| OperationError::Io {
-            path: "stdin".to_string(),
-            source: err,
-        })?;
+        stdin
+            .write_all(&buffer)
+            .map_err(|err| OperationError::io("stdin".to_string(), err))?;
     }
 
     let output = child
@@ -538,6 +616,132 @@ fn split_nul(bytes: &[u8]) -> Vec<String> {
         .collect()
 }
 
+/// Scanners to run from the saved selection, asking once when none is saved yet
+/// (or every time with `--choose-tools`) and persisting the answer
+fn select_tools(console: &Console, prompts: &Prompts, choose: bool) -> Option<Vec<ScanTool>> {
+    let mut config = load_config().ok().flatten().unwrap_or_default();
+    let saved = config.scanner_tools.as_deref();
+    if !choose && (saved.is_some() || !Prompts::is_interactive()) {
+        return Some(enabled_tools(saved));
+    }
+
+    let candidates = all_tools();
+    let enabled = enabled_tools(saved);
+    let names: Vec<String> = candidates
+        .iter()
+        .map(|tool| tool.display_name().to_string())
+        .collect();
+    let defaults: Vec<bool> = candidates
+        .iter()
+        .map(|tool| enabled.contains(tool))
+        .collect();
+    let prompt = i18n::t(keys::SECURITY_SCANNER_SELECT_TOOLS);
+    let selected: Vec<ScanTool> = match prompts.try_multi_select(prompt, &names, &defaults) {
+        Ok(indices) => indices.into_iter().map(|index| candidates[index]).collect(),
+        Err(PromptError::Cancelled) => return None,
+        Err(err) => {
+            console.error(&format!("{prompt} — {err}"));
+            return None;
+        }
+    };
+
+    config.scanner_tools = Some(
+        selected
+            .iter()
+            .map(|tool| tool.binary_name().to_string())
+            .collect(),
+    );
+    match save_config(&config) {
+        Ok(()) if selected.is_empty() => {
+            console.info(i18n::t(keys::SECURITY_SCANNER_TOOLS_SAVED_NONE));
+        }
+        Ok(()) => console.info(&crate::tr!(
+            keys::SECURITY_SCANNER_TOOLS_SAVED,
+            tools = selected
+                .iter()
+                .map(|tool| tool.display_name())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )),
+        Err(err) => console.warning(&crate::tr!(keys::CONFIG_SAVE_FAILED, error = err)),
+    }
+    Some(selected)
+}
+
+/// Install the given scanners that are not found yet and print an install summary
+fn install_missing_tools(console: &Console, tools: &[ScanTool]) {
+    console.blank_line();
+
+    let mut install_attempted = 0;
+    let mut install_success = 0;
+    let mut install_failed = 0;
+
+    for tool in tools {
+        if resolve_tool_path(*tool).is_some() {
+            console.success_item(&format!(
+                "{} {}",
+                tool.display_name(),
+                i18n::t(keys::SECURITY_SCANNER_STATUS_INSTALLED)
+            ));
+            continue;
+        }
+
+        console.info(&crate::tr!(
+            keys::SECURITY_SCANNER_INSTALLING,
+            tool = tool.display_name()
+        ));
+        install_attempted += 1;
+        match ensure_installed(*tool) {
+            Ok(InstallStatus::Installed(path)) => {
+                console.success_item(&crate::tr!(
+                    keys::SECURITY_SCANNER_INSTALL_DONE,
+                    tool = tool.display_name(),
+                    path = path.display()
+                ));
+                install_success += 1;
+            }
+            Ok(InstallStatus::AlreadyInstalled(path)) => {
+                console.success_item(&crate::tr!(
+                    keys::SECURITY_SCANNER_INSTALL_ALREADY,
+                    tool = tool.display_name(),
+                    path = path.display()
+                ));
+                install_success += 1;
+            }
+            Ok(InstallStatus::Failed(errors)) => {
+                let message = errors.join("; ");
+                console.error_item(
+                    &crate::tr!(
+                        keys::SECURITY_SCANNER_INSTALL_FAILED,
+                        tool = tool.display_name()
+                    ),
+                    &message,
+                );
+                install_failed += 1;
+            }
+            Err(err) => {
+                console.error_item(
+                    &crate::tr!(
+                        keys::SECURITY_SCANNER_INSTALL_FAILED,
+                        tool = tool.display_name()
+                    ),
+                    &err.to_string(),
+                );
+                install_failed += 1;
+            }
+        }
+    }
+
+    if install_attempted > 0 {
+        console.show_summary(
+            i18n::t(keys::SECURITY_SCANNER_INSTALL_SUMMARY),
+            install_success,
+            install_failed,
+        );
+        console.blank_line();
+    }
+}
+
 fn ensure_trailing_newline(text: &str) -> String {
     if text.ends_with('\n') {
         text.to_string()
@@ -549,6 +753,7 @@ fn ensure_trailing_newline(text: &str) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use installer::is_command_available;
     use std::fs;
 
     #[test]
@@ -611,9 +816,38 @@ mod tests {
         fs::create_dir_all(dir.path().join("ignored")).unwrap();
         fs::write(dir.path().join("ignored/package.json"), "{}").unwrap();
 
-        let snapshot = build_worktree_snapshot(dir.path(), &Console::new()).unwrap();
+        let snapshot =
+            build_worktree_snapshot(dir.path(), &Console::new(), &GlobSet::empty()).unwrap();
         assert!(snapshot.root().join("tracked/package.json").is_file());
         assert!(snapshot.root().join("untracked/package.json").is_file());
         assert!(!snapshot.root().join("ignored/package.json").exists());
     }
+
+    #[test]
+    fn test_worktree_snapshot_skips_excluded_paths() {
+        if is_command_available("git").is_none() {
+            return;
+        }
+
+        let dir = tempfile::tempdir().unwrap();
+        assert!(
+            Command::new("git")
+                .args(["init"])
+                .current_dir(dir.path())
+                .output()
+                .unwrap()
+                .status
+                .success()
+        );
+
+        fs::create_dir_all(dir.path().join("vendor/lib")).unwrap();
+        fs::write(dir.path().join("vendor/lib/key.txt"), "fake").unwrap();
+        fs::create_dir_all(dir.path().join("src")).unwrap();
+        fs::write(dir.path().join("src/main.rs"), "fn main() {}").unwrap();
+
+        let excludes = exclude::build_matcher(&["vendor/**".to_string()]).unwrap();
+        let snapshot = build_worktree_snapshot(dir.path(), &Console::new(), &excludes).unwrap();
+        assert!(snapshot.root().join("src/main.rs").is_file());
+        assert!(!snapshot.root().join("vendor/lib/key.txt").exists());
+    }
 }
@@ -1,10 +1,12 @@
-use crate::core::{OperationError, Result};
+use crate::core::{OperationError, Result, process};
 use crate::i18n::{self, keys};
+use crate::ui::Console;
 use std::path::Path;
 use std::process::Command;
+use std::sync::{Mutex, mpsc};
 
 use super::installer::resolve_tool_path;
-use super::tools::{ScanCommand, ScanTool};
+use super::tools::{ScanCommand, ScanScope, ScanTool};
 
 pub enum ScanStatus {
     Clean,
@@ -22,6 +24,7 @@ pub struct ScanOutcome {
 
 pub fn run_scans(
     tool: ScanTool,
+    scope: ScanScope,
     repo_root: &Path,
     worktree_root: &Path,
 ) -> Result<Vec<ScanOutcome>> {
@@ -32,7 +35,7 @@ pub fn run_scans(
         });
     };
 
-    let steps = tool.scan_commands(repo_root, worktree_root);
+    let steps = tool.scan_commands(scope, repo_root, worktree_root);
     let mut outcomes = Vec::with_capacity(steps.len());
 
     for step in steps {
@@ -42,17 +45,64 @@ pub fn run_scans(
     Ok(outcomes)
 }
 
+/// 同時執行的掃描工具上限（每個工具本身也會使用多核心）
+const MAX_PARALLEL_SCANS: usize = 4;
+
+/// 以有限的執行緒數同時執行多個工具的掃描，結果依工具名稱排序
+pub fn run_scans_parallel(
+    tools: &[ScanTool],
+    scope: ScanScope,
+    repo_root: &Path,
+    worktree_root: &Path,
+) -> Vec<(ScanTool, Result<Vec<ScanOutcome>>)> {
+    let workers = std::thread::available_parallelism()
+        .map(|count| count.get())
+        .unwrap_or(1)
+        .min(MAX_PARALLEL_SCANS);
+    run_parallel(tools, workers, |tool| {
+        run_scans(tool, scope, repo_root, worktree_root)
+    })
+}
+
+fn run_parallel<R, F>(tools: &[ScanTool], workers: usize, scan: F) -> Vec<(ScanTool, R)>
+where
+    R: Send,
+    F: Fn(ScanTool) -> R + Sync,
+{
+    let queue = Mutex::new(tools.iter().copied());
+    let (sender, receiver) = mpsc::channel();
+
+    // scope 會等待所有執行緒結束，呼叫端持有的快照在此之後才會被清理
+    std::thread::scope(|scope| {
+        for _ in 0..workers.clamp(1, tools.len().max(1)) {
+            let sender = sender.clone();
+            let queue = &queue;
+            let scan = &scan;
+            scope.spawn(move || {
+                while let Some(tool) = queue.lock().ok().and_then(|mut tools| tools.next()) {
+                    if sender.send((tool, scan(tool))).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+    });
+    drop(sender);
+
+    let mut results: Vec<_> = receiver.into_iter().collect();
+    results.sort_by_key(|(tool, _)| tool.display_name());
+    results
+}
+
 fn run_step(tool_path: &Path, step: &ScanCommand) -> Result<ScanOutcome> {
     let mut command = Command::new(tool_path);
     command.args(&step.args);
     if let Some(dir) = &step.workdir {
         command.current_dir(dir);
     }
+    Console::new().command(&command);
 
-    let output = command.output().map_err(|err| OperationError::Command {
-        command: tool_path.display().to_string(),
-        message: crate::tr!(keys::ERROR_UNABLE_TO_EXECUTE, error = err),
-    })?;
+    let output = process::run_with_timeout(&mut command)?;
 
     let exit_code = output.status.code();
     let status = if output.status.success() {
@@ -71,3 +121,39 @@ fn run_step(tool_path: &Path, step: &ScanCommand) -> Result<ScanOutcome> {
         stderr: String::from_utf8_lossy(&output.stderr).to_string(),
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn test_run_parallel_runs_every_tool_once_sorted_by_name() {
+        let tools = [
+            ScanTool::Trivy,
+            ScanTool::Gitleaks,
+            ScanTool::Semgrep,
+            ScanTool::GitSecrets,
+            ScanTool::Trufflehog,
+        ];
+        let calls = AtomicUsize::new(0);
+
+        let results = run_parallel(&tools, 3, |tool| {
+            calls.fetch_add(1, Ordering::SeqCst);
+            tool.binary_name()
+        });
+
+        assert_eq!(calls.load(Ordering::SeqCst), tools.len());
+        let names: Vec<&str> = results.iter().map(|(_, name)| *name).collect();
+        assert_eq!(
+            names,
+            ["git-secrets", "gitleaks", "semgrep", "trivy", "trufflehog"]
+        );
+    }
+
+    #[test]
+    fn test_run_parallel_empty() {
+        let results = run_parallel(&[], 4, |tool| tool.display_name());
+        assert!(results.is_empty());
+    }
+}
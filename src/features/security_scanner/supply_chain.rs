@@ -350,14 +350,14 @@ pub fn scan_supply_chain(root: &Path) -> Result<SupplyChainReport> {
         .into_iter()
         .filter_entry(should_visit)
     {
-        let entry = entry.map_err(|err| OperationError::Io {
-            path: err
-                .path()
-                .map(|path| path.display().to_string())
-                .unwrap_or_else(|| root.display().to_string()),
-            source: err
-                .into_io_error()
-                .unwrap_or_else(|| std::io::Error::other("walkdir error")),
+        let entry = entry.map_err(|err| {
+            OperationError::io(
+                err.path()
+                    .map(|path| path.display().to_string())
+                    .unwrap_or_else(|| root.display().to_string()),
+                err.into_io_error()
+                    .unwrap_or_else(|| std::io::Error::other("walkdir error")),
+            )
         })?;
 
         if !entry.file_type().is_file() {
@@ -412,10 +412,7 @@ fn relative_path(root: &Path, path: &Path) -> PathBuf {
 }
 
 fn read_to_string(path: &Path) -> Result<String> {
-    fs::read_to_string(path).map_err(|err| OperationError::Io {
-        path: path.display().to_string(),
-        source: err,
-    })
+    fs::read_to_string(path).map_err(|err| OperationError::io(path.display().to_string(), err))
 }
 
 fn scan_npm_package(
@@ -6,7 +6,7 @@ const TRIVY_INSTALL_WGET_SCRIPT: &str = r#"set -e; command -v wget >/dev/null 2>
 const SEMGREP_PIPX_APT_SCRIPT: &str = r#"set -e; command -v apt-get >/dev/null 2>&1; if command -v sudo >/dev/null 2>&1; then sudo apt-get install -y pipx; else apt-get install -y pipx; fi; mkdir -p "$HOME/.local/bin"; pipx install semgrep"#;
 const SEMGREP_VENV_SCRIPT: &str = r#"set -e; command -v python3 >/dev/null 2>&1; venv_dir="$HOME/.local/share/ops-tools/semgrep-venv"; python3 -m venv "$venv_dir"; "$venv_dir/bin/pip" install semgrep; mkdir -p "$HOME/.local/bin"; ln -sf "$venv_dir/bin/semgrep" "$HOME/.local/bin/semgrep""#;
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ScanTool {
     Gitleaks,
     Trufflehog,
@@ -15,10 +15,21 @@ pub enum ScanTool {
     Semgrep,
 }
 
+/// 掃描範圍
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ScanScope {
+    /// 只掃描工作樹快照
+    Worktree,
+    /// 工作樹快照加上完整 Git 歷史
+    FullHistory,
+}
+
 pub struct ScanCommand {
     pub label: String,
     pub args: Vec<String>,
     pub workdir: Option<PathBuf>,
+    /// 是否掃描 Git 歷史（而非工作樹快照）
+    pub history: bool,
 }
 
 pub struct InstallStrategy {
@@ -49,6 +60,21 @@ pub fn all_tools() -> Vec<ScanTool> {
     ]
 }
 
+/// 設定中啟用的掃描工具（依執行檔名稱）；未設定時全部啟用，未知名稱會被忽略
+pub fn enabled_tools(names: Option<&[String]>) -> Vec<ScanTool> {
+    let Some(names) = names else {
+        return all_tools();
+    };
+    all_tools()
+        .into_iter()
+        .filter(|tool| {
+            names
+                .iter()
+                .any(|name| name.trim().eq_ignore_ascii_case(tool.binary_name()))
+        })
+        .collect()
+}
+
 impl ScanTool {
     pub fn display_name(&self) -> &'static str {
         match self {
@@ -70,7 +96,20 @@ impl ScanTool {
         }
     }
 
-    pub fn scan_commands(&self, repo_root: &Path, worktree_root: &Path) -> Vec<ScanCommand> {
+    pub fn scan_commands(
+        &self,
+        scope: ScanScope,
+        repo_root: &Path,
+        worktree_root: &Path,
+    ) -> Vec<ScanCommand> {
+        let mut commands = self.all_scan_commands(repo_root, worktree_root);
+        if scope == ScanScope::Worktree {
+            commands.retain(|command| !command.history);
+        }
+        commands
+    }
+
+    fn all_scan_commands(&self, repo_root: &Path, worktree_root: &Path) -> Vec<ScanCommand> {
         let repo_path = repo_root
             .canonicalize()
             .unwrap_or_else(|_| repo_root.to_path_buf());
@@ -105,6 +144,7 @@ impl ScanTool {
                         "1".to_string(),
                     ],
                     workdir: Some(repo_path.clone()),
+                    history: true,
                 },
                 ScanCommand {
                     label: label_for(worktree_scope),
@@ -119,6 +159,7 @@ impl ScanTool {
                         "1".to_string(),
                     ],
                     workdir: Some(worktree_path.clone()),
+                    history: false,
                 },
             ],
             ScanTool::Trufflehog => vec![
@@ -131,6 +172,7 @@ impl ScanTool {
                         "--json".to_string(),
                     ],
                     workdir: Some(repo_path.clone()),
+                    history: true,
                 },
                 ScanCommand {
                     label: label_for(worktree_scope),
@@ -141,6 +183,7 @@ impl ScanTool {
                         "--json".to_string(),
                     ],
                     workdir: Some(worktree_path.clone()),
+                    history: false,
                 },
             ],
             ScanTool::GitSecrets => vec![
@@ -148,11 +191,13 @@ impl ScanTool {
                     label: label_for(worktree_scope),
                     args: vec!["--scan".to_string(), "-r".to_string()],
                     workdir: Some(worktree_path.clone()),
+                    history: false,
                 },
                 ScanCommand {
                     label: label_for(history_scope),
                     args: vec!["--scan-history".to_string()],
                     workdir: Some(repo_path),
+                    history: true,
                 },
             ],
             ScanTool::Trivy => vec![ScanCommand {
@@ -167,6 +212,7 @@ impl ScanTool {
                     "--no-progress".to_string(),
                 ],
                 workdir: Some(worktree_path.clone()),
+                history: false,
             }],
             ScanTool::Semgrep => vec![ScanCommand {
                 label: label_for("SAST"),
@@ -178,6 +224,7 @@ impl ScanTool {
                     worktree_str.clone(),
                 ],
                 workdir: Some(worktree_path.clone()),
+                history: false,
             }],
         }
     }
@@ -269,3 +316,50 @@ impl ScanTool {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_worktree_scope_drops_history_commands() {
+        let repo = Path::new("/repo");
+        let worktree = Path::new("/snapshot");
+        for tool in all_tools() {
+            let full = tool.scan_commands(ScanScope::FullHistory, repo, worktree);
+            let worktree_only = tool.scan_commands(ScanScope::Worktree, repo, worktree);
+            assert!(!worktree_only.is_empty());
+            assert!(worktree_only.iter().all(|command| !command.history));
+            assert_eq!(
+                full.len() - worktree_only.len(),
+                full.iter().filter(|command| command.history).count()
+            );
+        }
+    }
+
+    #[test]
+    fn test_enabled_tools() {
+        assert_eq!(enabled_tools(None).len(), all_tools().len());
+        assert_eq!(
+            enabled_tools(Some(&[
+                "semgrep".into(),
+                "Gitleaks".into(),
+                "unknown".into()
+            ])),
+            [ScanTool::Gitleaks, ScanTool::Semgrep]
+        );
+        assert!(enabled_tools(Some(&[])).is_empty());
+    }
+
+    #[test]
+    fn test_gitleaks_history_scans_repo_with_git() {
+        let commands = ScanTool::Gitleaks.scan_commands(
+            ScanScope::FullHistory,
+            Path::new("/repo"),
+            Path::new("/snapshot"),
+        );
+        let history = commands.iter().find(|command| command.history).unwrap();
+        assert!(history.args.contains(&"/repo".to_string()));
+        assert!(!history.args.contains(&"--no-git".to_string()));
+    }
+}
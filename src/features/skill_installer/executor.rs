@@ -1,6 +1,7 @@
 use super::tools::{CliType, Extension, ExtensionType, InstallScope, SkillsCliSpec};
-use crate::core::{OperationError, Result};
+use crate::core::{OperationError, Result, checksum};
 use crate::i18n::keys;
+use crate::ui::Console;
 use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
@@ -186,15 +187,167 @@ fn configure_noninteractive_git(command: &mut Command) {
     }
 }
 
+/// Run a non-interactive git command and return its trimmed stdout on success
+fn git_output(args: &[&str]) -> Option<String> {
+    let mut command = Command::new("git");
+    command.args(args);
+    configure_noninteractive_git(&mut command);
+    let output = command
+        .output()
+        .ok()
+        .filter(|output| output.status.success())?;
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Extract the commit hash from `git ls-remote` output, preferring the peeled
+/// `^{}` line that annotated tags resolve to
+fn parse_ls_remote(output: &str) -> Option<String> {
+    let line = output
+        .lines()
+        .find(|line| line.ends_with("^{}"))
+        .or_else(|| output.lines().next())?;
+    line.split_whitespace().next().map(String::from)
+}
+
+/// Full commit SHAs cannot be passed to `git clone --branch` and are fetched directly
+fn is_commit_sha(git_ref: &str) -> bool {
+    git_ref.len() == 40 && git_ref.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+/// GitHub source archive for `git_ref`, or the `main` branch tip when None
+fn archive_url(repo: &str, git_ref: Option<&str>) -> String {
+    match git_ref {
+        Some(git_ref) => format!("https://github.com/{repo}/archive/{git_ref}.tar.gz"),
+        None => format!("https://github.com/{repo}/archive/refs/heads/main.tar.gz"),
+    }
+}
+
+/// Git commands that shallow-clone `repo_url` into `dest` at `git_ref` (default branch when None)
+fn clone_commands(repo_url: &str, dest: &str, git_ref: Option<&str>) -> Vec<Vec<String>> {
+    let commands: Vec<Vec<&str>> = match git_ref {
+        Some(sha) if is_commit_sha(sha) => vec![
+            vec!["init", "--quiet", dest],
+            vec!["-C", dest, "fetch", "--depth", "1", repo_url, sha],
+            vec!["-C", dest, "checkout", "--quiet", "--detach", "FETCH_HEAD"],
+        ],
+        Some(git_ref) => vec![vec![
+            "clone", "--depth", "1", "--branch", git_ref, repo_url, dest,
+        ]],
+        None => vec![vec!["clone", "--depth", "1", repo_url, dest]],
+    };
+    commands
+        .into_iter()
+        .map(|args| args.into_iter().map(String::from).collect())
+        .collect()
+}
+
+fn clone_repo(repo_url: &str, dest: &Path, git_ref: Option<&str>) -> Result<()> {
+    for args in clone_commands(repo_url, &dest.display().to_string(), git_ref) {
+        let command_line = format_command("git", &args);
+        let mut command = Command::new("git");
+        command.args(&args);
+        configure_noninteractive_git(&mut command);
+        Console::new().command(&command);
+        let output = command.output().map_err(|e| OperationError::Command {
+            command: "git".to_string(),
+            message: crate::tr!(keys::ERROR_UNABLE_TO_EXECUTE, error = e),
+        })?;
+
+        if !output.status.success() {
+            return Err(OperationError::Command {
+                command: "git clone".to_string(),
+                message: command_failure_message(
+                    &command_line,
+                    output.status,
+                    &output.stderr,
+                    &output.stdout,
+                ),
+            });
+        }
+    }
+    Ok(())
+}
+
+fn compare_commits(current: &str, latest: &str) -> UpdateStatus {
+    if current == latest {
+        UpdateStatus::UpToDate
+    } else {
+        let short = |sha: &str| sha.chars().take(7).collect::<String>();
+        UpdateStatus::Available {
+            current: short(current),
+            latest: short(latest),
+        }
+    }
+}
+
+/// Build an installed_plugins.json entry, keeping the original install time on updates
+fn installed_plugin_entry(
+    existing: &serde_json::Value,
+    install_path: &Path,
+    version: &str,
+    git_ref: Option<&str>,
+    commit: Option<&str>,
+    now: &str,
+) -> serde_json::Value {
+    let installed_at = existing[0]["installedAt"].as_str().unwrap_or(now);
+    let mut entry = serde_json::json!({
+        "scope": "user",
+        "installPath": install_path.display().to_string(),
+        "version": version,
+        "installedAt": installed_at,
+        "lastUpdated": now,
+        "isLocal": true
+    });
+    if let Some(git_ref) = git_ref {
+        entry["gitRef"] = serde_json::json!(git_ref);
+    }
+    if let Some(commit) = commit {
+        entry["gitCommitSha"] = serde_json::json!(commit);
+    }
+    serde_json::json!([entry])
+}
+
+/// Whether an installed extension differs from its source
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UpdateStatus {
+    /// The remote has a different commit than the installed one
+    Available {
+        current: String,
+        latest: String,
+    },
+    UpToDate,
+    /// The installed revision is not recorded; updating re-downloads it
+    Unknown,
+}
+
 /// Extension executor for installing and removing extensions
 pub struct ExtensionExecutor {
     cli: CliType,
     scope: InstallScope,
+    /// Git refs chosen at install time, keyed by extension name (None = default branch)
+    git_refs: HashMap<&'static str, Option<String>>,
 }
 
 impl ExtensionExecutor {
     pub fn new(cli: CliType, scope: InstallScope) -> Self {
-        Self { cli, scope }
+        Self {
+            cli,
+            scope,
+            git_refs: HashMap::new(),
+        }
+    }
+
+    /// Override the git ref an extension is installed from
+    pub fn set_git_ref(&mut self, ext: &Extension, git_ref: Option<String>) {
+        self.git_refs.insert(ext.name, git_ref);
+    }
+
+    /// The git ref an extension is installed from: the override, else its pinned ref
+    pub fn git_ref(&self, ext: &Extension) -> Option<&str> {
+        match self.git_refs.get(ext.name) {
+            Some(git_ref) => git_ref.as_deref(),
+            None => ext.git_ref,
+        }
     }
 
     /// Get the installation directory for a specific extension type
@@ -402,10 +555,8 @@ impl ExtensionExecutor {
 
         // Create parent directory if needed
         if let Some(parent) = dest.parent() {
-            fs::create_dir_all(parent).map_err(|err| OperationError::Io {
-                path: parent.display().to_string(),
-                source: err,
-            })?;
+            fs::create_dir_all(parent)
+                .map_err(|err| OperationError::io(parent.display().to_string(), err))?;
         }
 
         if install_as_skill_from_command {
@@ -421,7 +572,7 @@ impl ExtensionExecutor {
             };
 
             // Download and extract
-            self.download_and_extract(ext.source_repo, &source_path, &dest)?;
+            self.download_and_extract(ext, &source_path, &dest)?;
 
             // Convert SKILL.md format for target CLI (for skill installations)
             if install_as_skill_from_subpath || ext.extension_type == ExtensionType::Skill {
@@ -432,6 +583,54 @@ impl ExtensionExecutor {
         Ok(())
     }
 
+    /// Compare the installed revision with the remote (or pinned ref) where it is recorded
+    /// (marketplace plugins keep a git checkout); other extensions report `Unknown`
+    pub fn check_update(&self, ext: &Extension) -> UpdateStatus {
+        let Some(marketplace_name) = ext.marketplace_name.filter(|_| self.cli == CliType::Claude)
+        else {
+            return UpdateStatus::Unknown;
+        };
+
+        let marketplace_dir = self.marketplace_dir(marketplace_name);
+        let Some(current) = git_output(&[
+            "-C",
+            &marketplace_dir.display().to_string(),
+            "rev-parse",
+            "HEAD",
+        ]) else {
+            return UpdateStatus::Unknown;
+        };
+
+        let repo_url = format!("https://github.com/{}.git", ext.source_repo);
+        let latest = match self.git_ref(ext) {
+            Some(sha) if is_commit_sha(sha) => Some(sha.to_string()),
+            Some(git_ref) => {
+                git_output(&["ls-remote", &repo_url, git_ref, &format!("{git_ref}^{{}}")])
+                    .and_then(|out| parse_ls_remote(&out))
+            }
+            None => {
+                git_output(&["ls-remote", &repo_url, "HEAD"]).and_then(|out| parse_ls_remote(&out))
+            }
+        };
+        match latest {
+            Some(latest) => compare_commits(&current, &latest),
+            None => UpdateStatus::Unknown,
+        }
+    }
+
+    /// Update an installed extension to the latest version.
+    /// Installing replaces the existing copy: marketplace plugins are cloned again and
+    /// re-registered, downloads are fetched and extracted again.
+    pub fn update(&self, ext: &Extension) -> Result<()> {
+        self.install(ext)
+    }
+
+    fn marketplace_dir(&self, marketplace_name: &str) -> PathBuf {
+        let home = dirs::home_dir().expect("Cannot find home directory");
+        home.join(".claude/plugins/marketplaces")
+            .join(marketplace_name)
+    }
+
     fn skills_cli_agent(&self) -> &'static str {
         match self.cli {
             CliType::Claude => "claude-code",
@@ -486,6 +685,7 @@ impl ExtensionExecutor {
         let mut command = Command::new("npx");
         command.args(&args);
         configure_noninteractive_git(&mut command);
+        Console::new().command(&command);
         let output = command.output().map_err(|err| OperationError::Command {
             command: "npx".to_string(),
             message: crate::tr!(keys::ERROR_UNABLE_TO_EXECUTE, error = err),
@@ -515,57 +715,30 @@ impl ExtensionExecutor {
         let version = ext.version.unwrap_or("1.0.0");
 
         // 1. Clone the full repo to marketplaces directory
-        let marketplaces_dir = home.join(".claude/plugins/marketplaces");
-        let marketplace_dir = marketplaces_dir.join(marketplace_name);
+        let marketplace_dir = self.marketplace_dir(marketplace_name);
+        let marketplaces_dir = marketplace_dir.parent().unwrap_or(&marketplace_dir);
 
-        fs::create_dir_all(&marketplaces_dir).map_err(|err| OperationError::Io {
-            path: marketplaces_dir.display().to_string(),
-            source: err,
-        })?;
+        fs::create_dir_all(marketplaces_dir)
+            .map_err(|err| OperationError::io(marketplaces_dir.display().to_string(), err))?;
 
         // Remove existing marketplace directory if it exists
         if marketplace_dir.exists() {
-            fs::remove_dir_all(&marketplace_dir).map_err(|err| OperationError::Io {
-                path: marketplace_dir.display().to_string(),
-                source: err,
-            })?;
+            fs::remove_dir_all(&marketplace_dir)
+                .map_err(|err| OperationError::io(marketplace_dir.display().to_string(), err))?;
         }
 
-        // Git clone the repository
+        // Git clone the repository at the requested ref
         let repo_url = format!("https://github.com/{}.git", ext.source_repo);
-        let status = Command::new("git")
-            .args([
-                "clone",
-                "--depth",
-                "1",
-                &repo_url,
-                marketplace_dir.to_str().unwrap(),
-            ])
-            .status()
-            .map_err(|e| OperationError::Command {
-                command: "git".to_string(),
-                message: crate::tr!(keys::ERROR_UNABLE_TO_EXECUTE, error = e),
-            })?;
-
-        if !status.success() {
-            return Err(OperationError::Command {
-                command: "git clone".to_string(),
-                message: crate::tr!(
-                    keys::SKILL_INSTALLER_DOWNLOAD_FAILED,
-                    error = "git clone failed"
-                ),
-            });
-        }
+        let git_ref = self.git_ref(ext);
+        clone_repo(&repo_url, &marketplace_dir, git_ref)?;
 
         // 2. Create cache directory and symlink
         let cache_dir = home
             .join(".claude/plugins/cache")
             .join(marketplace_name)
             .join(ext.name);
-        fs::create_dir_all(&cache_dir).map_err(|err| OperationError::Io {
-            path: cache_dir.display().to_string(),
-            source: err,
-        })?;
+        fs::create_dir_all(&cache_dir)
+            .map_err(|err| OperationError::io(cache_dir.display().to_string(), err))?;
 
         let version_link = cache_dir.join(version);
         let plugin_source = marketplace_dir.join(plugin_path);
@@ -579,20 +752,12 @@ impl ExtensionExecutor {
 
         // Create symlink
         #[cfg(unix)]
-        std::os::unix::fs::symlink(&plugin_source, &version_link).map_err(|err| {
-            OperationError::Io {
-                path: version_link.display().to_string(),
-                source: err,
-            }
-        })?;
+        std::os::unix::fs::symlink(&plugin_source, &version_link)
+            .map_err(|err| OperationError::io(version_link.display().to_string(), err))?;
 
         #[cfg(windows)]
-        std::os::windows::fs::symlink_dir(&plugin_source, &version_link).map_err(|err| {
-            OperationError::Io {
-                path: version_link.display().to_string(),
-                source: err,
-            }
-        })?;
+        std::os::windows::fs::symlink_dir(&plugin_source, &version_link)
+            .map_err(|err| OperationError::io(version_link.display().to_string(), err))?;
 
         // 3. Install dependencies in the plugin directory
         let package_json = plugin_source.join("package.json");
@@ -614,7 +779,20 @@ impl ExtensionExecutor {
         self.update_known_marketplaces(marketplace_name, ext.source_repo, &marketplace_dir)?;
 
         // 5. Update installed_plugins.json
-        self.update_installed_plugins(ext.name, marketplace_name, &version_link, version)?;
+        let commit = git_output(&[
+            "-C",
+            &marketplace_dir.display().to_string(),
+            "rev-parse",
+            "HEAD",
+        ]);
+        self.update_installed_plugins(
+            ext.name,
+            marketplace_name,
+            &version_link,
+            version,
+            git_ref,
+            commit.as_deref(),
+        )?;
 
         // 6. Update settings.json enabledPlugins
         self.update_settings_enabled_plugins(ext.name, marketplace_name, true)?;
@@ -629,18 +807,14 @@ impl ExtensionExecutor {
         let plugin_dir = plugins_dir.join(ext.name);
 
         // Create plugin directory
-        fs::create_dir_all(&plugin_dir).map_err(|err| OperationError::Io {
-            path: plugin_dir.display().to_string(),
-            source: err,
-        })?;
+        fs::create_dir_all(&plugin_dir)
+            .map_err(|err| OperationError::io(plugin_dir.display().to_string(), err))?;
 
         // Download the full plugin to temp dir
-        let temp_dir = tempfile::tempdir().map_err(|err| OperationError::Io {
-            path: "tempdir".to_string(),
-            source: err,
-        })?;
+        let temp_dir =
+            tempfile::tempdir().map_err(|err| OperationError::io("tempdir".to_string(), err))?;
         let temp_plugin = temp_dir.path().join("plugin");
-        self.download_and_extract(ext.source_repo, ext.source_path, &temp_plugin)?;
+        self.download_and_extract(ext, ext.source_path, &temp_plugin)?;
 
         // Find hooks directory in the downloaded plugin
         let hooks_source = self.find_hooks_dir(&temp_plugin);
@@ -706,10 +880,8 @@ impl ExtensionExecutor {
 
         // Read existing hooks.json or create new
         let mut hooks_config: serde_json::Value = if hooks_file.exists() {
-            let content = fs::read_to_string(&hooks_file).map_err(|err| OperationError::Io {
-                path: hooks_file.display().to_string(),
-                source: err,
-            })?;
+            let content = fs::read_to_string(&hooks_file)
+                .map_err(|err| OperationError::io(hooks_file.display().to_string(), err))?;
             serde_json::from_str(&content).unwrap_or_else(|_| serde_json::json!({"hooks": {}}))
         } else {
             serde_json::json!({"hooks": {}})
@@ -792,16 +964,12 @@ impl ExtensionExecutor {
 
         // Write hooks.json
         if let Some(parent) = hooks_file.parent() {
-            fs::create_dir_all(parent).map_err(|err| OperationError::Io {
-                path: parent.display().to_string(),
-                source: err,
-            })?;
+            fs::create_dir_all(parent)
+                .map_err(|err| OperationError::io(parent.display().to_string(), err))?;
         }
         let content = serde_json::to_string_pretty(&hooks_config).unwrap_or_default();
-        fs::write(&hooks_file, content).map_err(|err| OperationError::Io {
-            path: hooks_file.display().to_string(),
-            source: err,
-        })?;
+        fs::write(&hooks_file, content)
+            .map_err(|err| OperationError::io(hooks_file.display().to_string(), err))?;
 
         Ok(())
     }
@@ -819,15 +987,11 @@ impl ExtensionExecutor {
         let content = enable_hooks_feature_config(content);
 
         if let Some(parent) = config_file.parent() {
-            fs::create_dir_all(parent).map_err(|err| OperationError::Io {
-                path: parent.display().to_string(),
-                source: err,
-            })?;
+            fs::create_dir_all(parent)
+                .map_err(|err| OperationError::io(parent.display().to_string(), err))?;
         }
-        fs::write(&config_file, content).map_err(|err| OperationError::Io {
-            path: config_file.display().to_string(),
-            source: err,
-        })?;
+        fs::write(&config_file, content)
+            .map_err(|err| OperationError::io(config_file.display().to_string(), err))?;
 
         Ok(())
     }
@@ -839,10 +1003,8 @@ impl ExtensionExecutor {
             return Ok(());
         }
 
-        let content = fs::read_to_string(&hooks_file).map_err(|err| OperationError::Io {
-            path: hooks_file.display().to_string(),
-            source: err,
-        })?;
+        let content = fs::read_to_string(&hooks_file)
+            .map_err(|err| OperationError::io(hooks_file.display().to_string(), err))?;
 
         let mut hooks_config: serde_json::Value =
             serde_json::from_str(&content).unwrap_or_else(|_| serde_json::json!({"hooks": {}}));
@@ -868,10 +1030,8 @@ impl ExtensionExecutor {
         }
 
         let content = serde_json::to_string_pretty(&hooks_config).unwrap_or_default();
-        fs::write(&hooks_file, content).map_err(|err| OperationError::Io {
-            path: hooks_file.display().to_string(),
-            source: err,
-        })?;
+        fs::write(&hooks_file, content)
+            .map_err(|err| OperationError::io(hooks_file.display().to_string(), err))?;
 
         Ok(())
     }
@@ -888,10 +1048,8 @@ impl ExtensionExecutor {
 
         // Read existing content or create new
         let mut marketplaces: serde_json::Value = if file_path.exists() {
-            let content = fs::read_to_string(&file_path).map_err(|err| OperationError::Io {
-                path: file_path.display().to_string(),
-                source: err,
-            })?;
+            let content = fs::read_to_string(&file_path)
+                .map_err(|err| OperationError::io(file_path.display().to_string(), err))?;
             serde_json::from_str(&content).unwrap_or_else(|_| serde_json::json!({}))
         } else {
             serde_json::json!({})
@@ -910,10 +1068,8 @@ impl ExtensionExecutor {
 
         // Write back
         let content = serde_json::to_string_pretty(&marketplaces).unwrap_or_default();
-        fs::write(&file_path, content).map_err(|err| OperationError::Io {
-            path: file_path.display().to_string(),
-            source: err,
-        })?;
+        fs::write(&file_path, content)
+            .map_err(|err| OperationError::io(file_path.display().to_string(), err))?;
 
         Ok(())
     }
@@ -925,16 +1081,16 @@ impl ExtensionExecutor {
         marketplace_name: &str,
         install_path: &Path,
         version: &str,
+        git_ref: Option<&str>,
+        commit: Option<&str>,
     ) -> Result<()> {
         let home = dirs::home_dir().expect("Cannot find home directory");
         let file_path = home.join(".claude/plugins/installed_plugins.json");
 
         // Read existing content or create new
         let mut installed: serde_json::Value = if file_path.exists() {
-            let content = fs::read_to_string(&file_path).map_err(|err| OperationError::Io {
-                path: file_path.display().to_string(),
-                source: err,
-            })?;
+            let content = fs::read_to_string(&file_path)
+                .map_err(|err| OperationError::io(file_path.display().to_string(), err))?;
             serde_json::from_str(&content).unwrap_or_else(|_| {
                 serde_json::json!({
                     "version": 2,
@@ -953,21 +1109,19 @@ impl ExtensionExecutor {
         let now = chrono::Utc::now().to_rfc3339();
 
         // Add/update plugin entry
-        installed["plugins"][&plugin_key] = serde_json::json!([{
-            "scope": "user",
-            "installPath": install_path.display().to_string(),
-            "version": version,
-            "installedAt": now,
-            "lastUpdated": now,
-            "isLocal": true
-        }]);
+        installed["plugins"][&plugin_key] = installed_plugin_entry(
+            &installed["plugins"][&plugin_key],
+            install_path,
+            version,
+            git_ref,
+            commit,
+            &now,
+        );
 
         // Write back
         let content = serde_json::to_string_pretty(&installed).unwrap_or_default();
-        fs::write(&file_path, content).map_err(|err| OperationError::Io {
-            path: file_path.display().to_string(),
-            source: err,
-        })?;
+        fs::write(&file_path, content)
+            .map_err(|err| OperationError::io(file_path.display().to_string(), err))?;
 
         Ok(())
     }
@@ -975,87 +1129,30 @@ impl ExtensionExecutor {
     /// Install extension by converting command file to SKILL.md
     fn install_from_command(&self, ext: &Extension, dest: &Path) -> Result<()> {
         let command_file = ext.command_file.unwrap();
-        let url = format!(
-            "https://github.com/{}/archive/refs/heads/main.tar.gz",
-            ext.source_repo
-        );
 
         // Create temporary directory
-        let temp_dir = tempfile::tempdir().map_err(|err| OperationError::Io {
-            path: "tempdir".to_string(),
-            source: err,
-        })?;
+        let temp_dir =
+            tempfile::tempdir().map_err(|err| OperationError::io("tempdir".to_string(), err))?;
 
-        let archive = temp_dir.path().join("archive.tar.gz");
+        // Download the plugin source and read the command file from it
+        let source = temp_dir.path().join("source");
+        self.download_and_extract(ext, ext.source_path, &source)?;
 
-        // Download using curl
-        let status = Command::new("curl")
-            .args(["-L", "-s", "-o", archive.to_str().unwrap(), &url])
-            .status()
-            .map_err(|e| OperationError::Command {
-                command: "curl".to_string(),
-                message: crate::tr!(keys::ERROR_UNABLE_TO_EXECUTE, error = e),
-            })?;
-
-        if !status.success() {
-            return Err(OperationError::Command {
-                command: "curl".to_string(),
-                message: crate::tr!(keys::SKILL_INSTALLER_DOWNLOAD_FAILED, error = "curl failed"),
-            });
-        }
-
-        // Extract the command file from the archive
-        let repo_name = ext
-            .source_repo
-            .split('/')
-            .next_back()
-            .unwrap_or(ext.source_repo);
-        let extract_path = format!("{}-main/{}/{}", repo_name, ext.source_path, command_file);
-
-        let status = Command::new("tar")
-            .args([
-                "-xzf",
-                archive.to_str().unwrap(),
-                "-C",
-                temp_dir.path().to_str().unwrap(),
-                &extract_path,
-            ])
-            .status()
-            .map_err(|e| OperationError::Command {
-                command: "tar".to_string(),
-                message: crate::tr!(keys::ERROR_UNABLE_TO_EXECUTE, error = e),
-            })?;
-
-        if !status.success() {
-            return Err(OperationError::Command {
-                command: "tar".to_string(),
-                message: crate::tr!(keys::SKILL_INSTALLER_EXTRACT_FAILED, error = "tar failed"),
-            });
-        }
-
-        // Read the command file
-        let command_path = temp_dir.path().join(&extract_path);
-        let command_content =
-            fs::read_to_string(&command_path).map_err(|err| OperationError::Io {
-                path: command_path.display().to_string(),
-                source: err,
-            })?;
+        let command_path = source.join(command_file);
+        let command_content = fs::read_to_string(&command_path)
+            .map_err(|err| OperationError::io(command_path.display().to_string(), err))?;
 
         // Convert command to SKILL.md format
         let skill_content = self.convert_command_to_skill(ext.name, &command_content);
 
         // Create destination directory
-        fs::create_dir_all(dest).map_err(|err| OperationError::Io {
-            path: dest.display().to_string(),
-            source: err,
-        })?;
+        fs::create_dir_all(dest)
+            .map_err(|err| OperationError::io(dest.display().to_string(), err))?;
 
         // Write SKILL.md
         let skill_md = dest.join("SKILL.md");
-        fs::write(&skill_md, skill_content).map_err(|err| OperationError::Io {
-            path: skill_md.display().to_string(),
-            source: err,
-        })?;
+        fs::write(&skill_md, skill_content)
+            .map_err(|err| OperationError::io(skill_md.display().to_string(), err))?;
 
         Ok(())
     }
@@ -1100,10 +1197,8 @@ impl ExtensionExecutor {
         if self.cli == CliType::Codex && ext.has_hooks {
             let plugin_dir = self.codex_plugins_dir().join(ext.name);
             if plugin_dir.exists() {
-                fs::remove_dir_all(&plugin_dir).map_err(|err| OperationError::Io {
-                    path: plugin_dir.display().to_string(),
-                    source: err,
-                })?;
+                fs::remove_dir_all(&plugin_dir)
+                    .map_err(|err| OperationError::io(plugin_dir.display().to_string(), err))?;
             }
             self.remove_codex_plugin_hooks(ext.name)?;
             return Ok(());
@@ -1138,10 +1233,8 @@ impl ExtensionExecutor {
         let dest = self.extension_install_dir(install_type).join(dest_name);
 
         if dest.exists() {
-            fs::remove_dir_all(&dest).map_err(|err| OperationError::Io {
-                path: dest.display().to_string(),
-                source: err,
-            })?;
+            fs::remove_dir_all(&dest)
+                .map_err(|err| OperationError::io(dest.display().to_string(), err))?;
         }
 
         Ok(())
@@ -1153,6 +1246,7 @@ impl ExtensionExecutor {
         let mut command = Command::new("npx");
         command.args(&args);
         configure_noninteractive_git(&mut command);
+        Console::new().command(&command);
         let output = command.output().map_err(|err| OperationError::Command {
             command: "npx".to_string(),
             message: crate::tr!(keys::ERROR_UNABLE_TO_EXECUTE, error = err),
@@ -1187,10 +1281,8 @@ impl ExtensionExecutor {
             .join(marketplace_name)
             .join(ext.name);
         if cache_dir.exists() {
-            fs::remove_dir_all(&cache_dir).map_err(|err| OperationError::Io {
-                path: cache_dir.display().to_string(),
-                source: err,
-            })?;
+            fs::remove_dir_all(&cache_dir)
+                .map_err(|err| OperationError::io(cache_dir.display().to_string(), err))?;
         }
 
         // 3. Remove marketplace directory
@@ -1198,10 +1290,8 @@ impl ExtensionExecutor {
             .join(".claude/plugins/marketplaces")
             .join(marketplace_name);
         if marketplace_dir.exists() {
-            fs::remove_dir_all(&marketplace_dir).map_err(|err| OperationError::Io {
-                path: marketplace_dir.display().to_string(),
-                source: err,
-            })?;
+            fs::remove_dir_all(&marketplace_dir)
+                .map_err(|err| OperationError::io(marketplace_dir.display().to_string(), err))?;
         }
 
         // 4. Remove from known_marketplaces.json
@@ -1226,10 +1316,8 @@ impl ExtensionExecutor {
             return Ok(());
         }
 
-        let content = fs::read_to_string(&file_path).map_err(|err| OperationError::Io {
-            path: file_path.display().to_string(),
-            source: err,
-        })?;
+        let content = fs::read_to_string(&file_path)
+            .map_err(|err| OperationError::io(file_path.display().to_string(), err))?;
 
         let mut installed: serde_json::Value =
             serde_json::from_str(&content).unwrap_or_else(|_| {
@@ -1247,10 +1335,8 @@ impl ExtensionExecutor {
 
         // Write back
         let content = serde_json::to_string_pretty(&installed).unwrap_or_default();
-        fs::write(&file_path, content).map_err(|err| OperationError::Io {
-            path: file_path.display().to_string(),
-            source: err,
-        })?;
+        fs::write(&file_path, content)
+            .map_err(|err| OperationError::io(file_path.display().to_string(), err))?;
 
         Ok(())
     }
@@ -1264,10 +1350,8 @@ impl ExtensionExecutor {
             return Ok(());
         }
 
-        let content = fs::read_to_string(&file_path).map_err(|err| OperationError::Io {
-            path: file_path.display().to_string(),
-            source: err,
-        })?;
+        let content = fs::read_to_string(&file_path)
+            .map_err(|err| OperationError::io(file_path.display().to_string(), err))?;
 
         let mut marketplaces: serde_json::Value =
             serde_json::from_str(&content).unwrap_or_else(|_| serde_json::json!({}));
@@ -1279,10 +1363,8 @@ impl ExtensionExecutor {
 
         // Write back
         let content = serde_json::to_string_pretty(&marketplaces).unwrap_or_default();
-        fs::write(&file_path, content).map_err(|err| OperationError::Io {
-            path: file_path.display().to_string(),
-            source: err,
-        })?;
+        fs::write(&file_path, content)
+            .map_err(|err| OperationError::io(file_path.display().to_string(), err))?;
 
         Ok(())
     }
@@ -1298,10 +1380,8 @@ impl ExtensionExecutor {
         let file_path = home.join(".claude/settings.json");
 
         let mut settings: serde_json::Value = if file_path.exists() {
-            let content = fs::read_to_string(&file_path).map_err(|err| OperationError::Io {
-                path: file_path.display().to_string(),
-                source: err,
-            })?;
+            let content = fs::read_to_string(&file_path)
+                .map_err(|err| OperationError::io(file_path.display().to_string(), err))?;
             serde_json::from_str(&content).unwrap_or_else(|_| serde_json::json!({}))
         } else {
             serde_json::json!({})
@@ -1323,49 +1403,35 @@ impl ExtensionExecutor {
         }
 
         let content = serde_json::to_string_pretty(&settings).unwrap_or_default();
-        fs::write(&file_path, content).map_err(|err| OperationError::Io {
-            path: file_path.display().to_string(),
-            source: err,
-        })?;
+        fs::write(&file_path, content)
+            .map_err(|err| OperationError::io(file_path.display().to_string(), err))?;
 
         Ok(())
     }
 
-    /// Download and extract from GitHub
-    fn download_and_extract(&self, repo: &str, path: &str, dest: &Path) -> Result<()> {
-        if repo.starts_with("git@") || repo.starts_with("ssh://") || repo.starts_with("https://") {
-            let temp_dir = tempfile::tempdir().map_err(|err| OperationError::Io {
-                path: "tempdir".to_string(),
-                source: err,
-            })?;
+    /// Download and extract from GitHub; a pinned ref is always fetched with git
+    fn download_and_extract(&self, ext: &Extension, path: &str, dest: &Path) -> Result<()> {
+        let repo = ext.source_repo;
+        let git_ref = self.git_ref(ext);
+        let is_git_url =
+            repo.starts_with("git@") || repo.starts_with("ssh://") || repo.starts_with("https://");
+        // A checksum pins one exact archive, so checksummed extensions are never cloned
+        if is_git_url && ext.sha256.is_some() {
+            return Err(OperationError::Validation(crate::tr!(
+                keys::SKILL_INSTALLER_CHECKSUM_UNSUPPORTED,
+                name = ext.name
+            )));
+        }
+        if ext.sha256.is_none() && (is_git_url || git_ref.is_some()) {
+            let repo_url = if is_git_url {
+                repo.to_string()
+            } else {
+                format!("https://github.com/{}.git", repo)
+            };
+            let temp_dir = tempfile::tempdir()
+                .map_err(|err| OperationError::io("tempdir".to_string(), err))?;
             let clone_dir = temp_dir.path().join("repo");
-            let args = vec![
-                "clone".to_string(),
-                "--depth".to_string(),
-                "1".to_string(),
-                repo.to_string(),
-                clone_dir.to_str().unwrap_or("repo").to_string(),
-            ];
-            let command_line = format_command("git", &args);
-            let mut command = Command::new("git");
-            command.args(&args);
-            configure_noninteractive_git(&mut command);
-            let output = command.output().map_err(|e| OperationError::Command {
-                command: "git clone".to_string(),
-                message: crate::tr!(keys::ERROR_UNABLE_TO_EXECUTE, error = e),
-            })?;
-
-            if !output.status.success() {
-                return Err(OperationError::Command {
-                    command: "git clone".to_string(),
-                    message: command_failure_message(
-                        &command_line,
-                        output.status,
-                        &output.stderr,
-                        &output.stdout,
-                    ),
-                });
-            }
+            clone_repo(&repo_url, &clone_dir, git_ref)?;
 
             let extracted = if path.is_empty() || path == "." {
                 clone_dir
@@ -1384,29 +1450,25 @@ impl ExtensionExecutor {
             }
 
             if dest.exists() {
-                fs::remove_dir_all(dest).map_err(|err| OperationError::Io {
-                    path: dest.display().to_string(),
-                    source: err,
-                })?;
+                fs::remove_dir_all(dest)
+                    .map_err(|err| OperationError::io(dest.display().to_string(), err))?;
             }
 
             self.move_directory(&extracted, dest)?;
             return Ok(());
         }
 
-        let url = format!("https://github.com/{}/archive/refs/heads/main.tar.gz", repo);
+        let url = archive_url(repo, git_ref);
 
         // Create temporary directory
-        let temp_dir = tempfile::tempdir().map_err(|err| OperationError::Io {
-            path: "tempdir".to_string(),
-            source: err,
-        })?;
+        let temp_dir =
+            tempfile::tempdir().map_err(|err| OperationError::io("tempdir".to_string(), err))?;
 
         let archive = temp_dir.path().join("archive.tar.gz");
 
         // Download using curl
         let status = Command::new("curl")
-            .args(["-L", "-s", "-o", archive.to_str().unwrap(), &url])
+            .args(["-L", "-s", "-f", "-o", archive.to_str().unwrap(), &url])
             .status()
             .map_err(|e| OperationError::Command {
                 command: "curl".to_string(),
@@ -1420,17 +1482,22 @@ impl ExtensionExecutor {
             });
         }
 
-        // Extract the specific path from the archive
-        let repo_name = repo.split('/').next_back().unwrap_or(repo);
-        let extract_path = format!("{}-main/{}", repo_name, path);
+        // Verify the archive before anything from it is extracted
+        if let Some(expected) = ext.sha256 {
+            checksum::verify_sha256(&archive, expected)?;
+        }
 
+        // GitHub archives wrap the repo in one top-level directory whose name depends on the ref
+        let source_root = temp_dir.path().join("source");
+        fs::create_dir_all(&source_root)
+            .map_err(|err| OperationError::io(source_root.display().to_string(), err))?;
         let status = Command::new("tar")
             .args([
                 "-xzf",
                 archive.to_str().unwrap(),
                 "-C",
-                temp_dir.path().to_str().unwrap(),
-                &extract_path,
+                source_root.to_str().unwrap(),
+                "--strip-components=1",
             ])
             .status()
             .map_err(|e| OperationError::Command {
@@ -1446,7 +1513,11 @@ impl ExtensionExecutor {
         }
 
         // Move extracted content to destination
-        let extracted = temp_dir.path().join(&extract_path);
+        let extracted = if path.is_empty() || path == "." {
+            source_root
+        } else {
+            source_root.join(path)
+        };
         if !extracted.exists() {
             return Err(OperationError::Command {
                 command: "tar".to_string(),
@@ -1459,10 +1530,8 @@ impl ExtensionExecutor {
 
         // Remove existing destination if it exists
         if dest.exists() {
-            fs::remove_dir_all(dest).map_err(|err| OperationError::Io {
-                path: dest.display().to_string(),
-                source: err,
-            })?;
+            fs::remove_dir_all(dest)
+                .map_err(|err| OperationError::io(dest.display().to_string(), err))?;
         }
 
         // Move using shell command (cross-platform move)
@@ -1480,42 +1549,31 @@ impl ExtensionExecutor {
 
         // Fall back to copy + remove for cross-device moves
         self.copy_dir_recursive(src, dest)?;
-        fs::remove_dir_all(src).map_err(|err| OperationError::Io {
-            path: src.display().to_string(),
-            source: err,
-        })?;
+        fs::remove_dir_all(src)
+            .map_err(|err| OperationError::io(src.display().to_string(), err))?;
 
         Ok(())
     }
 
     /// Recursively copy a directory
     fn copy_dir_recursive(&self, src: &Path, dest: &Path) -> Result<()> {
-        fs::create_dir_all(dest).map_err(|err| OperationError::Io {
-            path: dest.display().to_string(),
-            source: err,
-        })?;
+        fs::create_dir_all(dest)
+            .map_err(|err| OperationError::io(dest.display().to_string(), err))?;
 
-        for entry in fs::read_dir(src).map_err(|err| OperationError::Io {
-            path: src.display().to_string(),
-            source: err,
-        })? {
-            let entry = entry.map_err(|err| OperationError::Io {
-                path: src.display().to_string(),
-                source: err,
-            })?;
-            let file_type = entry.file_type().map_err(|err| OperationError::Io {
-                path: entry.path().display().to_string(),
-                source: err,
-            })?;
+        for entry in
+            fs::read_dir(src).map_err(|err| OperationError::io(src.display().to_string(), err))?
+        {
+            let entry = entry.map_err(|err| OperationError::io(src.display().to_string(), err))?;
+            let file_type = entry
+                .file_type()
+                .map_err(|err| OperationError::io(entry.path().display().to_string(), err))?;
             let dest_path = dest.join(entry.file_name());
 
             if file_type.is_dir() {
                 self.copy_dir_recursive(&entry.path(), &dest_path)?;
             } else {
-                fs::copy(entry.path(), &dest_path).map_err(|err| OperationError::Io {
-                    path: entry.path().display().to_string(),
-                    source: err,
-                })?;
+                fs::copy(entry.path(), &dest_path)
+                    .map_err(|err| OperationError::io(entry.path().display().to_string(), err))?;
             }
         }
 
@@ -1529,10 +1587,8 @@ impl ExtensionExecutor {
             return Ok(()); // No SKILL.md to convert
         }
 
-        let content = fs::read_to_string(&skill_md).map_err(|err| OperationError::Io {
-            path: skill_md.display().to_string(),
-            source: err,
-        })?;
+        let content = fs::read_to_string(&skill_md)
+            .map_err(|err| OperationError::io(skill_md.display().to_string(), err))?;
 
         let converted = match self.cli {
             CliType::Claude => {
@@ -1545,10 +1601,8 @@ impl ExtensionExecutor {
             }
         };
 
-        fs::write(&skill_md, converted).map_err(|err| OperationError::Io {
-            path: skill_md.display().to_string(),
-            source: err,
-        })?;
+        fs::write(&skill_md, converted)
+            .map_err(|err| OperationError::io(skill_md.display().to_string(), err))?;
 
         Ok(())
     }
@@ -1632,6 +1686,116 @@ fn enable_hooks_feature_config(content: String) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::features::skill_installer::tools::get_available_extensions;
+
+    #[test]
+    fn test_parse_ls_remote() {
+        assert_eq!(
+            parse_ls_remote("3f2a9c1d8e7b6a5f4e3d2c1b0a9f8e7d6c5b4a39\tHEAD\n"),
+            Some("3f2a9c1d8e7b6a5f4e3d2c1b0a9f8e7d6c5b4a39".to_string())
+        );
+        assert_eq!(
+            parse_ls_remote("1111111111\trefs/tags/v1.0\n2222222222\trefs/tags/v1.0^{}\n"),
+            Some("2222222222".to_string())
+        );
+        assert_eq!(parse_ls_remote(""), None);
+    }
+
+    #[test]
+    fn test_clone_commands_for_git_refs() {
+        let url = "https://github.com/thedotmack/claude-mem.git";
+        assert_eq!(
+            clone_commands(url, "/tmp/mp", None),
+            [["clone", "--depth", "1", url, "/tmp/mp"]]
+        );
+        assert_eq!(
+            clone_commands(url, "/tmp/mp", Some("v10.1.0")),
+            [[
+                "clone", "--depth", "1", "--branch", "v10.1.0", url, "/tmp/mp"
+            ]]
+        );
+
+        let sha = "9f1c2e4b7a3d5c8e0f6b2a1d4c7e9b3a5f8d2c6e";
+        let commands = clone_commands(url, "/tmp/mp", Some(sha));
+        assert_eq!(commands.len(), 3);
+        assert_eq!(
+            commands[1],
+            ["-C", "/tmp/mp", "fetch", "--depth", "1", url, sha]
+        );
+        assert!(!is_commit_sha("9f1c2e4"));
+    }
+
+    #[test]
+    fn test_archive_url() {
+        assert_eq!(
+            archive_url("owner/repo", None),
+            "https://github.com/owner/repo/archive/refs/heads/main.tar.gz"
+        );
+        assert_eq!(
+            archive_url("owner/repo", Some("v1.2.0")),
+            "https://github.com/owner/repo/archive/v1.2.0.tar.gz"
+        );
+    }
+
+    #[test]
+    fn test_git_ref_override() {
+        let ext = get_available_extensions(CliType::Claude, InstallScope::Global)
+            .into_iter()
+            .next()
+            .unwrap();
+        let mut executor = ExtensionExecutor::new(CliType::Claude, InstallScope::Global);
+        assert_eq!(executor.git_ref(&ext), ext.git_ref);
+
+        executor.set_git_ref(&ext, Some("v1.2.0".to_string()));
+        assert_eq!(executor.git_ref(&ext), Some("v1.2.0"));
+
+        executor.set_git_ref(&ext, None);
+        assert_eq!(executor.git_ref(&ext), None);
+    }
+
+    #[test]
+    fn test_compare_commits() {
+        assert_eq!(
+            compare_commits("abc1234", "abc1234"),
+            UpdateStatus::UpToDate
+        );
+        assert_eq!(
+            compare_commits("1111111aaaa", "2222222bbbb"),
+            UpdateStatus::Available {
+                current: "1111111".to_string(),
+                latest: "2222222".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_installed_plugin_entry_keeps_install_time() {
+        let path = Path::new("/cache/claude-mem/1.0.0");
+        let fresh = installed_plugin_entry(
+            &serde_json::Value::Null,
+            path,
+            "1.0.0",
+            None,
+            None,
+            "2025-01-01T00:00:00Z",
+        );
+        assert_eq!(fresh[0]["installedAt"], "2025-01-01T00:00:00Z");
+        assert!(fresh[0].get("gitCommitSha").is_none());
+        assert!(fresh[0].get("gitRef").is_none());
+
+        let updated = installed_plugin_entry(
+            &fresh,
+            path,
+            "1.0.0",
+            Some("v10.1.0"),
+            Some("abc1234"),
+            "2025-06-01T00:00:00Z",
+        );
+        assert_eq!(updated[0]["gitRef"], "v10.1.0");
+        assert_eq!(updated[0]["installedAt"], "2025-01-01T00:00:00Z");
+        assert_eq!(updated[0]["lastUpdated"], "2025-06-01T00:00:00Z");
+        assert_eq!(updated[0]["gitCommitSha"], "abc1234");
+    }
 
     #[test]
     fn test_install_dir_claude_skill() {
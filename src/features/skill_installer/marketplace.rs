@@ -0,0 +1,261 @@
+//! Extra plugin marketplaces
+//!
+//! Reads `.claude-plugin/marketplace.json` from a GitHub repo and turns each plugin with a
+//! path source into a marketplace-installed `Extension` (Claude only).
+
+use super::tools::{CliType, Extension, ExtensionType};
+use crate::core::{OperationError, Result};
+use crate::i18n::keys;
+use serde::Deserialize;
+use std::process::{Command, Stdio};
+
+/// Location of the manifest inside a marketplace repo
+const MANIFEST_PATH: &str = ".claude-plugin/marketplace.json";
+
+#[derive(Deserialize)]
+struct Manifest {
+    name: String,
+    #[serde(default)]
+    plugins: Vec<ManifestPlugin>,
+}
+
+#[derive(Deserialize)]
+struct ManifestPlugin {
+    #[serde(default)]
+    name: String,
+    /// A path inside the repo (`./plugins/foo`); object sources point at other repos
+    #[serde(default)]
+    source: serde_json::Value,
+    #[serde(default)]
+    version: Option<String>,
+}
+
+/// Plugins listed by one marketplace repo
+#[derive(Default)]
+pub struct MarketplacePlugins {
+    pub extensions: Vec<Extension>,
+    /// Plugins that were skipped, with the reason
+    pub warnings: Vec<String>,
+}
+
+/// Normalize user input (`owner/repo`, a GitHub URL, or `.git` URL) to `owner/repo`
+pub fn normalize_repo(input: &str) -> Option<String> {
+    let repo = input
+        .trim()
+        .trim_start_matches("https://")
+        .trim_start_matches("http://")
+        .trim_start_matches("github.com/")
+        .trim_end_matches('/')
+        .trim_end_matches(".git");
+    let mut parts = repo.split('/');
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some(owner), Some(name), None) if is_safe_name(owner) && is_safe_name(name) => {
+            Some(format!("{owner}/{name}"))
+        }
+        _ => None,
+    }
+}
+
+/// Download and parse the marketplace manifest of `repo` (`owner/repo`)
+pub fn fetch(repo: &str, builtin: &[Extension]) -> Result<MarketplacePlugins> {
+    let url = format!("https://raw.githubusercontent.com/{repo}/HEAD/{MANIFEST_PATH}");
+    let output = Command::new("curl")
+        .args(["-sSfL", "--max-time", "15", &url])
+        .stdin(Stdio::null())
+        .output()
+        .map_err(|e| OperationError::Command {
+            command: "curl".to_string(),
+            message: crate::tr!(keys::ERROR_UNABLE_TO_EXECUTE, error = e),
+        })?;
+
+    if !output.status.success() {
+        return Err(OperationError::Command {
+            command: "curl".to_string(),
+            message: crate::tr!(
+                keys::SKILL_INSTALLER_MARKETPLACE_FETCH_FAILED,
+                repo = repo,
+                error = String::from_utf8_lossy(&output.stderr).trim()
+            ),
+        });
+    }
+
+    parse(repo, &String::from_utf8_lossy(&output.stdout), builtin)
+}
+
+fn parse(repo: &str, content: &str, builtin: &[Extension]) -> Result<MarketplacePlugins> {
+    let manifest: Manifest =
+        serde_json::from_str(content).map_err(|err| OperationError::Config {
+            key: format!("{repo}/{MANIFEST_PATH}"),
+            message: err.to_string(),
+        })?;
+    let marketplace = manifest.name.trim();
+    if !is_safe_name(marketplace) {
+        return Err(OperationError::Config {
+            key: format!("{repo}/{MANIFEST_PATH}"),
+            message: crate::tr!(
+                keys::SKILL_INSTALLER_MARKETPLACE_INVALID_NAME,
+                name = marketplace
+            ),
+        });
+    }
+
+    let mut result = MarketplacePlugins::default();
+    for plugin in manifest.plugins {
+        let name = plugin.name.trim();
+        let reason = if !is_safe_name(name) {
+            Some(crate::tr!(
+                keys::SKILL_INSTALLER_MARKETPLACE_INVALID_NAME,
+                name = name
+            ))
+        } else if builtin
+            .iter()
+            .chain(result.extensions.iter())
+            .any(|ext| ext.name == name)
+        {
+            Some(crate::tr!(
+                keys::SKILL_INSTALLER_MARKETPLACE_DUPLICATE,
+                name = name
+            ))
+        } else {
+            None
+        };
+        let path = plugin.source.as_str().and_then(plugin_path);
+
+        match (reason, path) {
+            (None, Some(path)) => result.extensions.push(Extension {
+                name: leak(name.to_string()),
+                display_name_key: keys::SKILL_INSTALLER_MARKETPLACE_PLUGIN,
+                custom_display_name: Some(leak(format!("{name} ({marketplace})"))),
+                extension_type: ExtensionType::Plugin,
+                source_repo: leak(repo.to_string()),
+                source_path: leak(path.clone()),
+                cli_support: &[CliType::Claude],
+                skill_subpath: None,
+                command_file: None,
+                has_hooks: false,
+                marketplace_name: Some(leak(marketplace.to_string())),
+                marketplace_plugin_path: Some(leak(path)),
+                version: plugin.version.map(leak),
+                git_ref: None,
+                sha256: None,
+                skills_cli: None,
+            }),
+            (reason, _) => result.warnings.push(crate::tr!(
+                keys::SKILL_INSTALLER_MARKETPLACE_SKIPPED,
+                name = name,
+                reason = reason.unwrap_or_else(|| crate::tr!(
+                    keys::SKILL_INSTALLER_MARKETPLACE_UNSUPPORTED_SOURCE
+                ))
+            )),
+        }
+    }
+
+    Ok(result)
+}
+
+/// Repo-relative plugin directory, rejecting paths that escape the repo
+fn plugin_path(source: &str) -> Option<String> {
+    let path = source.trim().trim_start_matches("./").trim_end_matches('/');
+    if path.is_empty() || path == "." {
+        return Some(".".to_string());
+    }
+    let safe = !path.starts_with('/')
+        && path
+            .split('/')
+            .all(|part| !part.is_empty() && part != "." && part != "..");
+    safe.then(|| path.to_string())
+}
+
+/// Names end up as directory names under `~/.claude/plugins`
+fn is_safe_name(name: &str) -> bool {
+    !name.is_empty()
+        && name != "."
+        && name != ".."
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+}
+
+/// `Extension` holds `'static` strings; marketplace entries are loaded once per run
+fn leak(value: String) -> &'static str {
+    Box::leak(value.into_boxed_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MANIFEST: &str = r#"{
+        "name": "acme-tools",
+        "owner": { "name": "Acme" },
+        "plugins": [
+            { "name": "deploy-helper", "source": "./plugins/deploy-helper", "version": "2.1.0" },
+            { "name": "root-plugin", "source": "./" },
+            { "name": "remote", "source": { "source": "github", "repo": "acme/remote" } },
+            { "name": "escape", "source": "../outside" },
+            { "name": "frontend-design", "source": "./plugins/frontend-design" },
+            { "name": "../evil", "source": "./plugins/evil" }
+        ]
+    }"#;
+
+    #[test]
+    fn test_parse_marketplace_manifest() {
+        let builtin = [Extension {
+            name: "frontend-design",
+            display_name_key: keys::SKILL_FRONTEND_DESIGN,
+            custom_display_name: None,
+            extension_type: ExtensionType::Plugin,
+            source_repo: "anthropics/claude-code",
+            source_path: "plugins/frontend-design",
+            cli_support: &[CliType::Claude],
+            skill_subpath: None,
+            command_file: None,
+            has_hooks: false,
+            marketplace_name: None,
+            marketplace_plugin_path: None,
+            version: None,
+            git_ref: None,
+            sha256: None,
+            skills_cli: None,
+        }];
+
+        let result = parse("acme/claude-plugins", MANIFEST, &builtin).unwrap();
+
+        assert_eq!(result.extensions.len(), 2);
+        assert_eq!(result.warnings.len(), 4);
+
+        let deploy = &result.extensions[0];
+        assert_eq!(deploy.name, "deploy-helper");
+        assert_eq!(deploy.display_name(), "deploy-helper (acme-tools)");
+        assert_eq!(deploy.source_repo, "acme/claude-plugins");
+        assert_eq!(deploy.marketplace_name, Some("acme-tools"));
+        assert_eq!(
+            deploy.marketplace_plugin_path,
+            Some("plugins/deploy-helper")
+        );
+        assert_eq!(deploy.version, Some("2.1.0"));
+        assert_eq!(result.extensions[1].marketplace_plugin_path, Some("."));
+    }
+
+    #[test]
+    fn test_parse_rejects_unsafe_marketplace_name() {
+        let result = parse("acme/x", r#"{ "name": "../x", "plugins": [] }"#, &[]);
+        assert!(result.is_err());
+        assert!(parse("acme/x", "not json", &[]).is_err());
+    }
+
+    #[test]
+    fn test_normalize_repo() {
+        assert_eq!(normalize_repo("acme/plugins"), Some("acme/plugins".into()));
+        assert_eq!(
+            normalize_repo("https://github.com/acme/plugins.git"),
+            Some("acme/plugins".into())
+        );
+        assert_eq!(
+            normalize_repo("github.com/acme/plugins/"),
+            Some("acme/plugins".into())
+        );
+        assert_eq!(normalize_repo("acme"), None);
+        assert_eq!(normalize_repo("acme/plugins/extra"), None);
+    }
+}
@@ -1,10 +1,13 @@
 mod executor;
+mod marketplace;
 mod tools;
 
+use crate::core::{load_config, save_config};
 use crate::i18n::{self, keys};
 use crate::ui::{Console, Prompts};
-use executor::ExtensionExecutor;
-use tools::{CliType, Extension, InstallScope, get_available_extensions};
+use executor::{ExtensionExecutor, UpdateStatus};
+use std::collections::HashMap;
+use tools::{CliType, Extension, ExtensionType, InstallScope, get_available_extensions};
 
 /// Run the skill installer feature
 pub fn run() {
@@ -49,7 +52,7 @@ pub fn run() {
         InstallScope::Global
     };
 
-    let executor = ExtensionExecutor::new(cli, scope);
+    let mut executor = ExtensionExecutor::new(cli, scope);
 
     // Scan installed extensions
     console.info(i18n::t(keys::SKILL_INSTALLER_SCANNING));
@@ -71,13 +74,182 @@ pub fn run() {
     console.separator();
 
     // Get available extensions for this CLI
-    let available_extensions = get_available_extensions(cli, scope);
+    let mut available_extensions = get_available_extensions(cli, scope);
+
+    // Marketplace plugins need the Claude plugin layout
+    let marketplaces = if cli == CliType::Claude {
+        load_config()
+            .ok()
+            .flatten()
+            .unwrap_or_default()
+            .skill_marketplaces
+    } else {
+        Vec::new()
+    };
+    for repo in &marketplaces {
+        add_marketplace_plugins(&console, repo, &mut available_extensions);
+    }
 
     if available_extensions.is_empty() {
         console.warning(i18n::t(keys::SKILL_INSTALLER_NO_EXTENSIONS));
         return;
     }
 
+    console.blank_line();
+    let actions = available_actions(cli);
+    let Some(selection) = prompts.select(
+        i18n::t(keys::SKILL_INSTALLER_SELECT_ACTION),
+        &action_options(&actions),
+    ) else {
+        console.warning(i18n::t(keys::SKILL_INSTALLER_CANCELLED));
+        return;
+    };
+    // Back returns to the main menu quietly; only Esc reports a cancellation
+    match actions.get(selection) {
+        Some(Action::Manage) => manage_extensions(
+            &console,
+            &prompts,
+            cli,
+            &mut executor,
+            &available_extensions,
+            &installed,
+        ),
+        Some(Action::Update) => update_extensions(
+            &console,
+            &prompts,
+            &mut executor,
+            &available_extensions,
+            &installed,
+        ),
+        Some(Action::AddMarketplace) if add_marketplace(&console, &mut available_extensions) => {
+            manage_extensions(
+                &console,
+                &prompts,
+                cli,
+                &mut executor,
+                &available_extensions,
+                &installed,
+            )
+        }
+        Some(Action::AddMarketplace) | None => {}
+    }
+}
+
+/// Top-level skill installer actions
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Action {
+    Manage,
+    Update,
+    AddMarketplace,
+}
+
+impl Action {
+    fn label(self) -> &'static str {
+        match self {
+            Action::Manage => i18n::t(keys::SKILL_INSTALLER_ACTION_MANAGE),
+            Action::Update => i18n::t(keys::SKILL_INSTALLER_ACTION_UPDATE),
+            Action::AddMarketplace => i18n::t(keys::SKILL_INSTALLER_ACTION_ADD_MARKETPLACE),
+        }
+    }
+}
+
+/// Actions offered for `cli`, in menu order (marketplaces are Claude-only)
+fn available_actions(cli: CliType) -> Vec<Action> {
+    let mut actions = vec![Action::Manage, Action::Update];
+    if cli == CliType::Claude {
+        actions.push(Action::AddMarketplace);
+    }
+    actions
+}
+
+/// Menu labels for `actions`, followed by a Back entry
+fn action_options(actions: &[Action]) -> Vec<&'static str> {
+    actions
+        .iter()
+        .map(|action| action.label())
+        .chain([i18n::t(keys::MENU_BACK)])
+        .collect()
+}
+
+/// Merge the plugins of one marketplace repo into the list; returns how many were added
+fn add_marketplace_plugins(
+    console: &Console,
+    repo: &str,
+    extensions: &mut Vec<Extension>,
+) -> usize {
+    match marketplace::fetch(repo, extensions) {
+        Ok(plugins) => {
+            for warning in &plugins.warnings {
+                console.warning(warning);
+            }
+            let count = plugins.extensions.len();
+            console.info(&crate::tr_plural!(
+                keys::SKILL_INSTALLER_MARKETPLACE_LOADED,
+                count = count,
+                repo = repo
+            ));
+            extensions.extend(plugins.extensions);
+            count
+        }
+        Err(err) => {
+            console.warning(&err.to_string());
+            0
+        }
+    }
+}
+
+/// Ask for a marketplace repo, list its plugins and remember it in the config
+fn add_marketplace(console: &Console, extensions: &mut Vec<Extension>) -> bool {
+    use dialoguer::{Input, theme::ColorfulTheme};
+
+    let Ok(input) = Input::<String>::with_theme(&ColorfulTheme::default())
+        .with_prompt(i18n::t(keys::SKILL_INSTALLER_MARKETPLACE_PROMPT))
+        .interact_text()
+    else {
+        console.warning(i18n::t(keys::SKILL_INSTALLER_CANCELLED));
+        return false;
+    };
+    let Some(repo) = marketplace::normalize_repo(&input) else {
+        console.error(&crate::tr!(
+            keys::SKILL_INSTALLER_MARKETPLACE_INVALID_REPO,
+            repo = input.trim()
+        ));
+        return false;
+    };
+
+    let mut config = load_config().ok().flatten().unwrap_or_default();
+    if config
+        .skill_marketplaces
+        .iter()
+        .any(|existing| existing.eq_ignore_ascii_case(&repo))
+    {
+        console.info(&crate::tr!(
+            keys::SKILL_INSTALLER_MARKETPLACE_EXISTS,
+            repo = &repo
+        ));
+        return true;
+    }
+
+    if add_marketplace_plugins(console, &repo, extensions) == 0 {
+        return false;
+    }
+
+    config.add_skill_marketplace(&repo);
+    if let Err(err) = save_config(&config) {
+        console.warning(&crate::tr!(keys::CONFIG_SAVE_FAILED, error = err));
+    }
+    true
+}
+
+/// Install newly selected extensions and remove deselected ones
+fn manage_extensions(
+    console: &Console,
+    prompts: &Prompts,
+    cli: CliType,
+    executor: &mut ExtensionExecutor,
+    available_extensions: &[Extension],
+    installed: &HashMap<String, ExtensionType>,
+) {
     // Build display items with status
     let items: Vec<String> = available_extensions
         .iter()
@@ -107,11 +279,14 @@ pub fn run() {
     console.info(i18n::t(keys::SKILL_INSTALLER_SELECT_HELP));
     console.blank_line();
 
-    let selections = prompts.multi_select(
+    let Some(selections) = prompts.multi_select(
         i18n::t(keys::SKILL_INSTALLER_SELECT_PROMPT),
         &items,
         &defaults,
-    );
+    ) else {
+        console.warning(i18n::t(keys::SKILL_INSTALLER_CANCELLED));
+        return;
+    };
 
     // Calculate changes
     let mut to_install: Vec<&Extension> = Vec::new();
@@ -142,7 +317,7 @@ pub fn run() {
     if !to_install.is_empty() {
         console.success(i18n::t(keys::SKILL_INSTALLER_WILL_INSTALL));
         for ext in &to_install {
-            console.list_item("➕", ext.display_name());
+            console.list_item("➕", &extension_label(executor, ext));
         }
     }
 
@@ -154,6 +329,10 @@ pub fn run() {
     }
 
     console.blank_line();
+    if ask_git_refs(prompts, executor, &to_install).is_none() {
+        console.warning(i18n::t(keys::SKILL_INSTALLER_CANCELLED));
+        return;
+    }
     if !prompts.confirm(i18n::t(keys::SKILL_INSTALLER_CONFIRM_CHANGES)) {
         console.warning(i18n::t(keys::SKILL_INSTALLER_CANCELLED));
         return;
@@ -237,13 +416,197 @@ pub fn run() {
     }
 }
 
+/// Re-fetch installed extensions, pre-selecting the ones with a known or possible update
+fn update_extensions(
+    console: &Console,
+    prompts: &Prompts,
+    executor: &mut ExtensionExecutor,
+    available_extensions: &[Extension],
+    installed: &HashMap<String, ExtensionType>,
+) {
+    let installed_extensions: Vec<&Extension> = available_extensions
+        .iter()
+        .filter(|ext| installed.contains_key(ext.installed_name()))
+        .collect();
+
+    if installed_extensions.is_empty() {
+        console.warning(i18n::t(keys::SKILL_INSTALLER_NOTHING_TO_UPDATE));
+        return;
+    }
+
+    console.info(i18n::t(keys::SKILL_INSTALLER_CHECKING_UPDATES));
+    let statuses: Vec<UpdateStatus> = installed_extensions
+        .iter()
+        .map(|ext| executor.check_update(ext))
+        .collect();
+
+    let items: Vec<String> = installed_extensions
+        .iter()
+        .zip(&statuses)
+        .map(|(ext, status)| {
+            let status = match status {
+                UpdateStatus::Available { current, latest } => crate::tr!(
+                    keys::SKILL_INSTALLER_UPDATE_AVAILABLE,
+                    current = current,
+                    latest = latest
+                ),
+                UpdateStatus::UpToDate => i18n::t(keys::SKILL_INSTALLER_UP_TO_DATE).to_string(),
+                UpdateStatus::Unknown => i18n::t(keys::SKILL_INSTALLER_UPDATE_UNKNOWN).to_string(),
+            };
+            format!(
+                "{} ({}) {}",
+                ext.display_name(),
+                ext.extension_type.display_name(),
+                status
+            )
+        })
+        .collect();
+    let defaults: Vec<bool> = statuses
+        .iter()
+        .map(|status| *status != UpdateStatus::UpToDate)
+        .collect();
+
+    console.blank_line();
+    let Some(selections) = prompts.multi_select(
+        i18n::t(keys::SKILL_INSTALLER_SELECT_UPDATES),
+        &items,
+        &defaults,
+    ) else {
+        console.warning(i18n::t(keys::SKILL_INSTALLER_CANCELLED));
+        return;
+    };
+
+    if selections.is_empty() {
+        console.success(i18n::t(keys::SKILL_INSTALLER_NO_CHANGES));
+        return;
+    }
+
+    let selected: Vec<&Extension> = selections
+        .iter()
+        .map(|&index| installed_extensions[index])
+        .collect();
+    if ask_git_refs(prompts, executor, &selected).is_none() {
+        console.warning(i18n::t(keys::SKILL_INSTALLER_CANCELLED));
+        return;
+    }
+
+    console.blank_line();
+    let mut success_count = 0;
+    let mut failed_count = 0;
+
+    for (i, ext) in selected.iter().enumerate() {
+        console.show_progress(
+            i + 1,
+            selected.len(),
+            &crate::tr!(
+                keys::SKILL_INSTALLER_UPDATING,
+                name = extension_label(executor, ext)
+            ),
+        );
+
+        match executor.update(ext) {
+            Ok(()) => {
+                console.success_item(&crate::tr!(
+                    keys::SKILL_INSTALLER_UPDATE_SUCCESS,
+                    name = ext.display_name()
+                ));
+                success_count += 1;
+            }
+            Err(err) => {
+                console.error_item(
+                    &crate::tr!(
+                        keys::SKILL_INSTALLER_UPDATE_FAILED,
+                        name = ext.display_name()
+                    ),
+                    &err.to_string(),
+                );
+                failed_count += 1;
+            }
+        }
+    }
+
+    console.show_summary(
+        i18n::t(keys::SKILL_INSTALLER_SUMMARY),
+        success_count,
+        failed_count,
+    );
+}
+
+/// Extension name with the git ref it will be installed from
+fn extension_label(executor: &ExtensionExecutor, ext: &Extension) -> String {
+    match executor.git_ref(ext) {
+        Some(git_ref) => format!("{} @ {}", ext.display_name(), git_ref),
+        None => ext.display_name().to_string(),
+    }
+}
+
+/// Optionally override the git ref of each extension (Skills CLI entries are not pinnable).
+/// Returns None when the input prompt is cancelled.
+fn ask_git_refs(
+    prompts: &Prompts,
+    executor: &mut ExtensionExecutor,
+    extensions: &[&Extension],
+) -> Option<()> {
+    use dialoguer::{Input, theme::ColorfulTheme};
+
+    let pinnable: Vec<&Extension> = extensions
+        .iter()
+        .copied()
+        // Checksummed extensions are locked to the ref their checksum was taken from
+        .filter(|ext| ext.skills_cli.is_none() && ext.sha256.is_none())
+        .collect();
+    if pinnable.is_empty()
+        || !prompts.confirm_with_options(i18n::t(keys::SKILL_INSTALLER_CONFIRM_PIN), false)
+    {
+        return Some(());
+    }
+
+    for ext in pinnable {
+        let input: String = Input::with_theme(&ColorfulTheme::default())
+            .with_prompt(crate::tr!(
+                keys::SKILL_INSTALLER_INPUT_GIT_REF,
+                name = ext.display_name()
+            ))
+            .with_initial_text(executor.git_ref(ext).unwrap_or_default())
+            .allow_empty(true)
+            .interact_text()
+            .ok()?;
+        let git_ref = input.trim();
+        executor.set_git_ref(ext, (!git_ref.is_empty()).then(|| git_ref.to_string()));
+    }
+    Some(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::tools::{CliType, InstallScope, get_available_extensions};
+    use super::{Action, action_options, available_actions};
+    use crate::i18n::{self, keys};
 
     #[test]
     fn test_extensions_available() {
         let extensions = get_available_extensions(CliType::Claude, InstallScope::Global);
         assert!(!extensions.is_empty());
     }
+
+    #[test]
+    fn test_action_menu_ends_with_back() {
+        let claude = available_actions(CliType::Claude);
+        assert_eq!(
+            claude,
+            [Action::Manage, Action::Update, Action::AddMarketplace]
+        );
+        let codex = available_actions(CliType::Codex);
+        assert_eq!(codex, [Action::Manage, Action::Update]);
+
+        for actions in [claude, codex] {
+            let options = action_options(&actions);
+            assert_eq!(options.len(), actions.len() + 1);
+            for (index, action) in actions.iter().enumerate() {
+                assert_eq!(options[index], action.label());
+            }
+            assert_eq!(options.last(), Some(&i18n::t(keys::MENU_BACK)));
+            assert_eq!(actions.get(options.len() - 1), None);
+        }
+    }
 }
@@ -60,6 +60,8 @@ pub struct SkillsCliSpec {
 pub struct Extension {
     pub name: &'static str,
     pub display_name_key: &'static str,
+    /// Display name for entries loaded from a marketplace repo (built-ins use `display_name_key`)
+    pub custom_display_name: Option<&'static str>,
     pub extension_type: ExtensionType,
     pub source_repo: &'static str,
     pub source_path: &'static str,
@@ -87,13 +89,21 @@ pub struct Extension {
     pub marketplace_plugin_path: Option<&'static str>,
     /// Plugin version for marketplace-based installations
     pub version: Option<&'static str>,
+    /// Git ref (tag, branch or full commit SHA) to install instead of the default branch tip.
+    /// Can be overridden at install time; not used for Skills CLI entries.
+    pub git_ref: Option<&'static str>,
+    /// Expected SHA-256 of the source archive (`<repo>/archive/<git_ref>.tar.gz`).
+    /// When set, the archive is verified before extraction and the install aborts on mismatch;
+    /// pair it with `git_ref` so the archive stays stable.
+    pub sha256: Option<&'static str>,
     /// Install this entry through `npx skills add` instead of built-in GitHub extraction.
     pub skills_cli: Option<SkillsCliSpec>,
 }
 
 impl Extension {
     pub fn display_name(&self) -> &'static str {
-        i18n::t(self.display_name_key)
+        self.custom_display_name
+            .unwrap_or_else(|| i18n::t(self.display_name_key))
     }
 
     pub fn installed_name(&self) -> &'static str {
@@ -127,6 +137,7 @@ const EXTENSIONS: &[Extension] = &[
     Extension {
         name: "frontend-design",
         display_name_key: keys::SKILL_FRONTEND_DESIGN,
+        custom_display_name: None,
         extension_type: ExtensionType::Plugin,
         source_repo: "anthropics/claude-code",
         source_path: "plugins/frontend-design",
@@ -137,6 +148,8 @@ const EXTENSIONS: &[Extension] = &[
         marketplace_name: None,
         marketplace_plugin_path: None,
         version: None,
+        git_ref: None,
+        sha256: None,
         skills_cli: None,
     },
     // Third-party plugins requiring full marketplace structure
@@ -144,6 +157,7 @@ const EXTENSIONS: &[Extension] = &[
     Extension {
         name: "claude-mem",
         display_name_key: keys::SKILL_CLAUDE_MEM,
+        custom_display_name: None,
         extension_type: ExtensionType::Plugin,
         source_repo: "thedotmack/claude-mem",
         source_path: "plugin",           // Not used for marketplace installs
@@ -154,11 +168,14 @@ const EXTENSIONS: &[Extension] = &[
         marketplace_name: Some("thedotmack"),
         marketplace_plugin_path: Some("plugin"),
         version: Some("10.1.0"),
+        git_ref: None,
+        sha256: None,
         skills_cli: None,
     },
     Extension {
         name: "skills-frontend-ui-engineering",
         display_name_key: keys::SKILL_FRONTEND_UI_ENGINEERING,
+        custom_display_name: None,
         extension_type: ExtensionType::Skill,
         source_repo: "",
         source_path: "",
@@ -169,6 +186,8 @@ const EXTENSIONS: &[Extension] = &[
         marketplace_name: None,
         marketplace_plugin_path: None,
         version: None,
+        git_ref: None,
+        sha256: None,
         skills_cli: Some(SkillsCliSpec {
             source: "addyosmani/agent-skills",
             skill: Some("frontend-ui-engineering"),
@@ -179,6 +198,7 @@ const EXTENSIONS: &[Extension] = &[
     Extension {
         name: "skills-antfu-nuxt",
         display_name_key: keys::SKILL_ANTFU_NUXT,
+        custom_display_name: None,
         extension_type: ExtensionType::Skill,
         source_repo: "",
         source_path: "",
@@ -189,6 +209,8 @@ const EXTENSIONS: &[Extension] = &[
         marketplace_name: None,
         marketplace_plugin_path: None,
         version: None,
+        git_ref: None,
+        sha256: None,
         skills_cli: Some(SkillsCliSpec {
             source: "antfu/skills",
             skill: Some("nuxt"),
@@ -199,6 +221,7 @@ const EXTENSIONS: &[Extension] = &[
     Extension {
         name: "skills-nuxt-ui",
         display_name_key: keys::SKILL_NUXT_UI,
+        custom_display_name: None,
         extension_type: ExtensionType::Skill,
         source_repo: "",
         source_path: "",
@@ -209,6 +232,8 @@ const EXTENSIONS: &[Extension] = &[
         marketplace_name: None,
         marketplace_plugin_path: None,
         version: None,
+        git_ref: None,
+        sha256: None,
         skills_cli: Some(SkillsCliSpec {
             source: "nuxt/ui",
             skill: Some("nuxt-ui"),
@@ -219,6 +244,7 @@ const EXTENSIONS: &[Extension] = &[
     Extension {
         name: "skills-onmax-nuxt",
         display_name_key: keys::SKILL_ONMAX_NUXT,
+        custom_display_name: None,
         extension_type: ExtensionType::Skill,
         source_repo: "",
         source_path: "",
@@ -229,6 +255,8 @@ const EXTENSIONS: &[Extension] = &[
         marketplace_name: None,
         marketplace_plugin_path: None,
         version: None,
+        git_ref: None,
+        sha256: None,
         skills_cli: Some(SkillsCliSpec {
             source: "onmax/nuxt-skills",
             skill: Some("nuxt"),
@@ -239,6 +267,7 @@ const EXTENSIONS: &[Extension] = &[
     Extension {
         name: "skills-nextlevel-ui-ux-pro-max",
         display_name_key: keys::SKILL_NEXTLEVEL_UI_UX_PRO_MAX,
+        custom_display_name: None,
         extension_type: ExtensionType::Skill,
         source_repo: "",
         source_path: "",
@@ -249,6 +278,8 @@ const EXTENSIONS: &[Extension] = &[
         marketplace_name: None,
         marketplace_plugin_path: None,
         version: None,
+        git_ref: None,
+        sha256: None,
         skills_cli: Some(SkillsCliSpec {
             source: "nextlevelbuilder/ui-ux-pro-max-skill",
             skill: None,
@@ -259,6 +290,7 @@ const EXTENSIONS: &[Extension] = &[
     Extension {
         name: "skills-frontend-design-system",
         display_name_key: keys::SKILL_FRONTEND_DESIGN_SYSTEM,
+        custom_display_name: None,
         extension_type: ExtensionType::Skill,
         source_repo: "",
         source_path: "",
@@ -269,6 +301,8 @@ const EXTENSIONS: &[Extension] = &[
         marketplace_name: None,
         marketplace_plugin_path: None,
         version: None,
+        git_ref: None,
+        sha256: None,
         skills_cli: Some(SkillsCliSpec {
             source: "git@github.com:supercent-io/skills-template.git",
             skill: Some("frontend-design-system"),
@@ -279,6 +313,7 @@ const EXTENSIONS: &[Extension] = &[
     Extension {
         name: "skills-web-design-reviewer",
         display_name_key: keys::SKILL_WEB_DESIGN_REVIEWER,
+        custom_display_name: None,
         extension_type: ExtensionType::Skill,
         source_repo: "",
         source_path: "",
@@ -289,6 +324,8 @@ const EXTENSIONS: &[Extension] = &[
         marketplace_name: None,
         marketplace_plugin_path: None,
         version: None,
+        git_ref: None,
+        sha256: None,
         skills_cli: Some(SkillsCliSpec {
             source: "github/awesome-copilot",
             skill: Some("web-design-reviewer"),
@@ -299,6 +336,7 @@ const EXTENSIONS: &[Extension] = &[
     Extension {
         name: "skills-kimny-ui-ux-pro-max",
         display_name_key: keys::SKILL_KIMNY_UI_UX_PRO_MAX,
+        custom_display_name: None,
         extension_type: ExtensionType::Skill,
         source_repo: "git@github.com:kimny1143/claude-code-template.git",
         source_path: ".claude/skills/ui-ux-pro-max",
@@ -309,11 +347,14 @@ const EXTENSIONS: &[Extension] = &[
         marketplace_name: None,
         marketplace_plugin_path: None,
         version: None,
+        git_ref: None,
+        sha256: None,
         skills_cli: None,
     },
     Extension {
         name: "skills-impeccable-frontend-design",
         display_name_key: keys::SKILL_IMPECCABLE_FRONTEND_DESIGN,
+        custom_display_name: None,
         extension_type: ExtensionType::Skill,
         source_repo: "",
         source_path: "",
@@ -324,6 +365,8 @@ const EXTENSIONS: &[Extension] = &[
         marketplace_name: None,
         marketplace_plugin_path: None,
         version: None,
+        git_ref: None,
+        sha256: None,
         skills_cli: Some(SkillsCliSpec {
             source: "git@github.com:pbakaus/impeccable.git",
             skill: Some("impeccable"),
@@ -334,6 +377,7 @@ const EXTENSIONS: &[Extension] = &[
     Extension {
         name: "skills-threejs-animation",
         display_name_key: keys::SKILL_THREEJS_ANIMATION,
+        custom_display_name: None,
         extension_type: ExtensionType::Skill,
         source_repo: "",
         source_path: "",
@@ -344,6 +388,8 @@ const EXTENSIONS: &[Extension] = &[
         marketplace_name: None,
         marketplace_plugin_path: None,
         version: None,
+        git_ref: None,
+        sha256: None,
         skills_cli: Some(SkillsCliSpec {
             source: "cloudai-x/threejs-skills",
             skill: Some("threejs-animation"),
@@ -354,6 +400,7 @@ const EXTENSIONS: &[Extension] = &[
     Extension {
         name: "skills-ui-animation",
         display_name_key: keys::SKILL_UI_ANIMATION,
+        custom_display_name: None,
         extension_type: ExtensionType::Skill,
         source_repo: "",
         source_path: "",
@@ -364,6 +411,8 @@ const EXTENSIONS: &[Extension] = &[
         marketplace_name: None,
         marketplace_plugin_path: None,
         version: None,
+        git_ref: None,
+        sha256: None,
         skills_cli: Some(SkillsCliSpec {
             source: "mblode/agent-skills",
             skill: Some("ui-animation"),
@@ -374,6 +423,7 @@ const EXTENSIONS: &[Extension] = &[
     Extension {
         name: "skills-framer-motion-animator",
         display_name_key: keys::SKILL_FRAMER_MOTION_ANIMATOR,
+        custom_display_name: None,
         extension_type: ExtensionType::Skill,
         source_repo: "",
         source_path: "",
@@ -384,6 +434,8 @@ const EXTENSIONS: &[Extension] = &[
         marketplace_name: None,
         marketplace_plugin_path: None,
         version: None,
+        git_ref: None,
+        sha256: None,
         skills_cli: Some(SkillsCliSpec {
             source: "patricio0312rev/skills",
             skill: Some("framer-motion-animator"),
@@ -394,6 +446,7 @@ const EXTENSIONS: &[Extension] = &[
     Extension {
         name: "skills-code-review-expert",
         display_name_key: keys::SKILL_CODE_REVIEW_EXPERT,
+        custom_display_name: None,
         extension_type: ExtensionType::Skill,
         source_repo: "",
         source_path: "",
@@ -404,6 +457,8 @@ const EXTENSIONS: &[Extension] = &[
         marketplace_name: None,
         marketplace_plugin_path: None,
         version: None,
+        git_ref: None,
+        sha256: None,
         skills_cli: Some(SkillsCliSpec {
             source: "sanyuan0704/sanyuan-skills",
             skill: None,
@@ -414,6 +469,7 @@ const EXTENSIONS: &[Extension] = &[
     Extension {
         name: "skills-playwright-generate-test",
         display_name_key: keys::SKILL_PLAYWRIGHT_GENERATE_TEST,
+        custom_display_name: None,
         extension_type: ExtensionType::Skill,
         source_repo: "",
         source_path: "",
@@ -424,6 +480,8 @@ const EXTENSIONS: &[Extension] = &[
         marketplace_name: None,
         marketplace_plugin_path: None,
         version: None,
+        git_ref: None,
+        sha256: None,
         skills_cli: Some(SkillsCliSpec {
             source: "github/awesome-copilot",
             skill: Some("playwright-generate-test"),
@@ -434,6 +492,7 @@ const EXTENSIONS: &[Extension] = &[
     Extension {
         name: "skills-playwright-explore-website",
         display_name_key: keys::SKILL_PLAYWRIGHT_EXPLORE_WEBSITE,
+        custom_display_name: None,
         extension_type: ExtensionType::Skill,
         source_repo: "",
         source_path: "",
@@ -444,6 +503,8 @@ const EXTENSIONS: &[Extension] = &[
         marketplace_name: None,
         marketplace_plugin_path: None,
         version: None,
+        git_ref: None,
+        sha256: None,
         skills_cli: Some(SkillsCliSpec {
             source: "github/awesome-copilot",
             skill: Some("playwright-explore-website"),
@@ -454,6 +515,7 @@ const EXTENSIONS: &[Extension] = &[
     Extension {
         name: "skills-typescript-clean-code",
         display_name_key: keys::SKILL_TYPESCRIPT_CLEAN_CODE,
+        custom_display_name: None,
         extension_type: ExtensionType::Skill,
         source_repo: "",
         source_path: "",
@@ -464,6 +526,8 @@ const EXTENSIONS: &[Extension] = &[
         marketplace_name: None,
         marketplace_plugin_path: None,
         version: None,
+        git_ref: None,
+        sha256: None,
         skills_cli: Some(SkillsCliSpec {
             source: "bmad-labs/skills",
             skill: Some("typescript-clean-code"),
@@ -474,6 +538,7 @@ const EXTENSIONS: &[Extension] = &[
     Extension {
         name: "skills-typescript-unit-testing",
         display_name_key: keys::SKILL_TYPESCRIPT_UNIT_TESTING,
+        custom_display_name: None,
         extension_type: ExtensionType::Skill,
         source_repo: "",
         source_path: "",
@@ -484,6 +549,8 @@ const EXTENSIONS: &[Extension] = &[
         marketplace_name: None,
         marketplace_plugin_path: None,
         version: None,
+        git_ref: None,
+        sha256: None,
         skills_cli: Some(SkillsCliSpec {
             source: "bmad-labs/skills",
             skill: Some("typescript-unit-testing"),
@@ -494,6 +561,7 @@ const EXTENSIONS: &[Extension] = &[
     Extension {
         name: "skills-mastering-typescript",
         display_name_key: keys::SKILL_MASTERING_TYPESCRIPT,
+        custom_display_name: None,
         extension_type: ExtensionType::Skill,
         source_repo: "",
         source_path: "",
@@ -504,6 +572,8 @@ const EXTENSIONS: &[Extension] = &[
         marketplace_name: None,
         marketplace_plugin_path: None,
         version: None,
+        git_ref: None,
+        sha256: None,
         skills_cli: Some(SkillsCliSpec {
             source: "SpillwaveSolutions/mastering-typescript-skill",
             skill: Some("mastering-typescript"),
@@ -558,6 +628,7 @@ mod tests {
     }
 
     #[test]
+    #[rustfmt::skip]
     fn test_get_available_extensions_codex() {
         let extensions = get_available_extensions(CliType::Codex, InstallScope::Global);
         assert!(!extensions.is_empty());
@@ -621,6 +692,26 @@ mod tests {
         i18n::set_language(previous);
     }
 
+    #[test]
+    fn test_checksums_are_pinned_sha256() {
+        for ext in EXTENSIONS.iter().filter(|ext| ext.sha256.is_some()) {
+            let sha256 = ext.sha256.unwrap();
+            assert!(
+                ext.git_ref.is_some(),
+                "{} has a checksum but no git_ref",
+                ext.name
+            );
+            assert!(ext.skills_cli.is_none(), "{}", ext.name);
+            // Marketplace plugins are cloned; pin them to a commit SHA instead
+            assert!(ext.marketplace_name.is_none(), "{}", ext.name);
+            assert!(
+                sha256.len() == 64 && sha256.chars().all(|c| c.is_ascii_hexdigit()),
+                "{}",
+                ext.name
+            );
+        }
+    }
+
     #[test]
     fn test_direct_skill_uses_source_path_installed_name() {
         let extensions = get_available_extensions(CliType::Codex, InstallScope::Global);
@@ -11,6 +11,7 @@ pub mod ports;
 #[allow(dead_code)]
 pub mod testing;
 
+use crate::core::dry_run;
 use crate::i18n::{self, keys};
 use crate::ui::{Console, Prompts};
 use application::cli::{CliCommand, CliOptions};
@@ -55,7 +56,11 @@ pub fn run() {
         _ => unreachable!(),
     };
 
-    let dry_run = if !matches!(command, CliCommand::Scan) {
+    let dry_run = if matches!(command, CliCommand::Scan) {
+        false
+    } else if dry_run::is_enabled() {
+        true
+    } else {
         let dry_run_options = [
             i18n::t(keys::SYSTEM_UPDATER_EXECUTE),
             i18n::t(keys::SYSTEM_UPDATER_DRY_RUN),
@@ -73,8 +78,6 @@ pub fn run() {
                 return;
             }
         }
-    } else {
-        false
     };
 
     let profile = select_profile(&prompts, &console);
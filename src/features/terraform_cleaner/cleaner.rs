@@ -1,4 +1,4 @@
-use crate::core::{FileCleaner, OperationResult, OperationType};
+use crate::core::{FileCleaner, OperationError, OperationResult, OperationType};
 use crate::i18n::{self, keys};
 use crate::ui::Progress;
 use std::fs;
@@ -36,6 +36,10 @@ impl FileCleaner for Cleaner {
         for item in items {
             let result = match Self::remove_item(&item) {
                 Ok(()) => OperationResult::success(item, OperationType::Delete),
+                Err(e) if e.kind() == std::io::ErrorKind::PermissionDenied => {
+                    let message = OperationError::io(item.display().to_string(), e).to_string();
+                    OperationResult::failure(item, OperationType::Delete, message)
+                }
                 Err(e) => OperationResult::failure(item, OperationType::Delete, e.to_string()),
             };
 
@@ -2,15 +2,32 @@ mod cleaner;
 mod scanner;
 mod service;
 
+use crate::core::dry_run;
+use crate::core::path_utils::format_size;
 use crate::i18n::{self, keys};
 use crate::ui::{Console, Prompts};
 use cleaner::Cleaner;
-use scanner::TerraformScanner;
-use service::TerraformCleanerService;
-use std::path::Path;
+use scanner::{AgeFilter, TerraformScanner, format_age};
+use service::{ScanResult, TerraformCleanerService};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+pub use scanner::parse_age;
+
+/// 預先指定的選項（例如來自命令列）
+#[derive(Clone, Debug, Default)]
+pub struct CleanOptions {
+    /// 只清理超過此時間未修改的項目；None 時詢問
+    pub older_than: Option<Duration>,
+}
 
 /// 執行 Terraform 快取清理功能
 pub fn run() {
+    run_with(CleanOptions::default());
+}
+
+/// 以預先指定的選項執行 Terraform 快取清理功能
+pub fn run_with(options: CleanOptions) {
     let console = Console::new();
     let prompts = Prompts::new();
 
@@ -22,20 +39,65 @@ pub fn run() {
         }
     };
 
-    execute(&current_dir, &console, &prompts);
+    let older_than = match options.older_than {
+        Some(age) => Some(age),
+        None => match input_older_than(&console) {
+            Some(age) => age,
+            None => {
+                console.warning(i18n::t(keys::TERRAFORM_DELETE_CANCELLED));
+                return;
+            }
+        },
+    };
+
+    execute(&current_dir, &console, &prompts, older_than);
+}
+
+/// 詢問最短未修改時間；空白表示不過濾，取消時回傳 None
+fn input_older_than(console: &Console) -> Option<Option<Duration>> {
+    use dialoguer::{Input, theme::ColorfulTheme};
+
+    loop {
+        let input: String = Input::with_theme(&ColorfulTheme::default())
+            .with_prompt(i18n::t(keys::TERRAFORM_INPUT_OLDER_THAN))
+            .allow_empty(true)
+            .interact_text()
+            .ok()?;
+        if input.trim().is_empty() {
+            return Some(None);
+        }
+        match parse_age(&input) {
+            Ok(age) => return Some(Some(age)),
+            Err(err) => console.warning(&err),
+        }
+    }
 }
 
-fn execute(root: &Path, console: &Console, prompts: &Prompts) {
+fn execute(root: &Path, console: &Console, prompts: &Prompts, older_than: Option<Duration>) {
     console.info(i18n::t(keys::TERRAFORM_SCAN_START));
     console.info(&crate::tr!(keys::TERRAFORM_SCAN_DIR, path = root.display()));
 
     let scanner = TerraformScanner::new();
     let cleaner = Cleaner::new();
-    let service = TerraformCleanerService::new(scanner, cleaner);
+    let service = TerraformCleanerService::new(scanner, cleaner)
+        .with_age_filter(older_than.map(AgeFilter::new));
 
     // 1. 掃描
     let scan_result = service.scan(root);
 
+    if let Some(age) = older_than
+        && !scan_result.too_new.is_empty()
+    {
+        console.info(&crate::tr_plural!(
+            keys::TERRAFORM_SKIPPED_RECENT,
+            count = scan_result.too_new.len(),
+            age = format_age(age)
+        ));
+        for item in &scan_result.too_new {
+            console.list_item("⏳", &item.display().to_string());
+        }
+    }
+
     if scan_result.is_empty() {
         console.warning(i18n::t(keys::TERRAFORM_NO_CACHE));
         return;
@@ -43,29 +105,57 @@ fn execute(root: &Path, console: &Console, prompts: &Prompts) {
 
     // 2. 顯示找到的項目
     console.show_paths_with_title(
-        &crate::tr!(keys::TERRAFORM_FOUND_ITEMS, count = scan_result.count()),
+        &crate::tr_plural!(keys::TERRAFORM_FOUND_ITEMS, count = scan_result.count()),
         &scan_result.items,
         |item| {
-            if item.is_dir() {
+            let item_type = if item.is_dir() {
                 i18n::t(keys::TERRAFORM_ITEM_DIR)
             } else {
                 i18n::t(keys::TERRAFORM_ITEM_FILE)
-            }
+            };
+            format!(
+                "{} {:>10}",
+                item_type,
+                format_size(scan_result.size_of(item))
+            )
         },
     );
 
-    // 3. 確認刪除
-    if !prompts.confirm_with_options(i18n::t(keys::TERRAFORM_CONFIRM_DELETE), false) {
+    // 3. 選擇要刪除的項目（預設全選）
+    let Some(selected) = select_items(prompts, &scan_result) else {
         console.warning(i18n::t(keys::TERRAFORM_DELETE_CANCELLED));
         return;
+    };
+    if selected.is_empty() {
+        console.warning(i18n::t(keys::TERRAFORM_NONE_SELECTED));
+        return;
     }
+    console.info(&crate::tr!(
+        keys::TERRAFORM_WILL_FREE,
+        size = format_size(scan_result.total_size(&selected))
+    ));
 
-    // 4. 執行刪除
-    let clean_result = service.clean(scan_result.items);
+    // 4. 確認刪除（模擬執行不需確認）
+    let dry_run = dry_run::is_enabled();
+    if !dry_run && !prompts.confirm(i18n::t(keys::TERRAFORM_CONFIRM_DELETE)) {
+        console.warning(i18n::t(keys::TERRAFORM_DELETE_CANCELLED));
+        return;
+    }
 
-    // 5. 顯示結果
+    // 5. 執行刪除
+    let clean_result = service.clean(selected, &scan_result.sizes, dry_run);
+
+    // 6. 顯示結果
     for result in &clean_result.results {
-        if result.success {
+        if clean_result.dry_run {
+            console.list_item(
+                "🔍",
+                &crate::tr!(
+                    keys::CONSOLE_DRY_RUN_WOULD_DELETE,
+                    path = result.path.display()
+                ),
+            );
+        } else if result.success {
             console.success_item(&crate::tr!(
                 keys::TERRAFORM_DELETED,
                 path = result.path.display()
@@ -78,12 +168,45 @@ fn execute(root: &Path, console: &Console, prompts: &Prompts) {
         }
     }
 
-    // 6. 顯示統計
+    // 7. 顯示統計
+    if clean_result.dry_run {
+        console.show_dry_run_summary(clean_result.stats.success);
+        return;
+    }
     console.show_summary(
         i18n::t(keys::TERRAFORM_SUMMARY_TITLE),
         clean_result.stats.success,
         clean_result.stats.failed,
     );
+    console.info(&crate::tr!(
+        keys::TERRAFORM_FREED,
+        size = format_size(clean_result.freed_bytes)
+    ));
+}
+
+/// 列出每個項目讓使用者取消勾選要保留的快取
+/// 勾選要刪除的項目；取消或無法取得選擇時回傳 None
+fn select_items(prompts: &Prompts, scan_result: &ScanResult) -> Option<Vec<PathBuf>> {
+    let labels: Vec<String> = scan_result
+        .items
+        .iter()
+        .map(|item| {
+            format!(
+                "{}  ({})",
+                item.display(),
+                format_size(scan_result.size_of(item))
+            )
+        })
+        .collect();
+    let defaults = vec![true; labels.len()];
+
+    let chosen = prompts.multi_select(i18n::t(keys::TERRAFORM_SELECT_ITEMS), &labels, &defaults)?;
+    Some(
+        chosen
+            .into_iter()
+            .map(|index| scan_result.items[index].clone())
+            .collect(),
+    )
 }
 
 #[cfg(test)]
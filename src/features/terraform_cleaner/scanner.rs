@@ -3,6 +3,7 @@ use crate::core::path_utils;
 use crate::i18n::{self, keys};
 use crate::ui::Progress;
 use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
 use walkdir::WalkDir;
 
 /// Terraform/Terragrunt 快取掃描器
@@ -64,6 +65,83 @@ impl FileScanner for TerraformScanner {
     }
 }
 
+/// 只保留最近一次修改早於門檻的項目
+#[derive(Clone, Copy, Debug)]
+pub struct AgeFilter {
+    pub min_age: Duration,
+    pub now: SystemTime,
+}
+
+impl AgeFilter {
+    pub fn new(min_age: Duration) -> Self {
+        Self {
+            min_age,
+            now: SystemTime::now(),
+        }
+    }
+
+    /// 分成（夠舊可刪除的項目, 太新而保留的項目）
+    pub fn partition(&self, items: Vec<PathBuf>) -> (Vec<PathBuf>, Vec<PathBuf>) {
+        items.into_iter().partition(|item| self.is_old_enough(item))
+    }
+
+    fn is_old_enough(&self, path: &Path) -> bool {
+        match last_modified(path) {
+            Some(modified) => self
+                .now
+                .duration_since(modified)
+                .is_ok_and(|age| age >= self.min_age),
+            // 讀不到修改時間時視為太新，避免誤刪
+            None => false,
+        }
+    }
+}
+
+/// 項目內最近一次修改的時間（目錄會遞迴檢查所有內容）
+fn last_modified(path: &Path) -> Option<SystemTime> {
+    WalkDir::new(path)
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| entry.metadata().ok()?.modified().ok())
+        .max()
+}
+
+/// 解析 `30m`、`24h`、`7d`、`2w` 形式的時間長度
+pub fn parse_age(input: &str) -> Result<Duration, String> {
+    let input = input.trim();
+    let split = input
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(input.len());
+    let (number, unit) = input.split_at(split);
+    let value: u64 = number
+        .parse()
+        .map_err(|_| crate::tr!(keys::TERRAFORM_INVALID_AGE, value = input))?;
+    let seconds = match unit.trim() {
+        "s" => 1,
+        "m" => 60,
+        "h" => 60 * 60,
+        "d" => 24 * 60 * 60,
+        "w" => 7 * 24 * 60 * 60,
+        _ => return Err(crate::tr!(keys::TERRAFORM_INVALID_AGE, value = input)),
+    };
+    Ok(Duration::from_secs(value * seconds))
+}
+
+/// 以最大的整數單位顯示時間長度（例如 `7d`）
+pub fn format_age(age: Duration) -> String {
+    let seconds = age.as_secs();
+    [
+        ("w", 7 * 24 * 60 * 60),
+        ("d", 24 * 60 * 60),
+        ("h", 60 * 60),
+        ("m", 60),
+    ]
+    .into_iter()
+    .find(|(_, unit)| seconds > 0 && seconds.is_multiple_of(*unit))
+    .map(|(suffix, unit)| format!("{}{}", seconds / unit, suffix))
+    .unwrap_or_else(|| format!("{seconds}s"))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -100,4 +178,47 @@ mod tests {
 
         assert_eq!(results, vec![terragrunt_cache]);
     }
+
+    #[test]
+    fn test_parse_age() {
+        assert_eq!(parse_age("7d"), Ok(Duration::from_secs(7 * 86_400)));
+        assert_eq!(parse_age(" 24h "), Ok(Duration::from_secs(86_400)));
+        assert_eq!(parse_age("30m"), Ok(Duration::from_secs(1_800)));
+        assert_eq!(parse_age("2w"), Ok(Duration::from_secs(14 * 86_400)));
+        assert!(parse_age("7").is_err());
+        assert!(parse_age("d").is_err());
+        assert!(parse_age("7y").is_err());
+    }
+
+    #[test]
+    fn test_format_age() {
+        assert_eq!(format_age(Duration::from_secs(7 * 86_400)), "1w");
+        assert_eq!(format_age(Duration::from_secs(3 * 86_400)), "3d");
+        assert_eq!(format_age(Duration::from_secs(36 * 3_600)), "36h");
+        assert_eq!(format_age(Duration::from_secs(90)), "90s");
+    }
+
+    #[test]
+    fn test_age_filter_keeps_recent_items() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let cache = temp_dir.path().join(".terraform");
+        fs::create_dir_all(&cache).unwrap();
+        fs::write(cache.join("plugin"), "x").unwrap();
+
+        let day = Duration::from_secs(86_400);
+        let now = SystemTime::now();
+
+        let recent = AgeFilter { min_age: day, now };
+        let (old, too_new) = recent.partition(vec![cache.clone()]);
+        assert!(old.is_empty());
+        assert_eq!(too_new, vec![cache.clone()]);
+
+        let later = AgeFilter {
+            min_age: day,
+            now: now + day * 2,
+        };
+        let (old, too_new) = later.partition(vec![cache.clone()]);
+        assert_eq!(old, vec![cache]);
+        assert!(too_new.is_empty());
+    }
 }
@@ -1,9 +1,16 @@
-use crate::core::{FileCleaner, FileScanner, OperationResult, OperationStats};
-use std::path::Path;
+use super::scanner::AgeFilter;
+use crate::core::path_utils;
+use crate::core::{FileCleaner, FileScanner, OperationResult, OperationStats, OperationType};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 
 /// 掃描結果
 pub struct ScanResult {
-    pub items: Vec<std::path::PathBuf>,
+    pub items: Vec<PathBuf>,
+    /// 各項目佔用的位元組數（目錄為遞迴加總）
+    pub sizes: HashMap<PathBuf, u64>,
+    /// 因修改時間太近而排除的項目
+    pub too_new: Vec<PathBuf>,
     #[allow(dead_code)]
     pub filtered_count: usize,
 }
@@ -16,18 +23,59 @@ impl ScanResult {
     pub fn count(&self) -> usize {
         self.items.len()
     }
+
+    pub fn size_of(&self, path: &Path) -> u64 {
+        self.sizes.get(path).copied().unwrap_or(0)
+    }
+
+    /// 刪除指定項目可釋放的空間
+    pub fn total_size(&self, items: &[PathBuf]) -> u64 {
+        items.iter().map(|item| self.size_of(item)).sum()
+    }
 }
 
 /// 清理結果
 pub struct CleanResult {
     pub results: Vec<OperationResult>,
     pub stats: OperationStats,
+    /// 是否為模擬執行（沒有實際刪除）
+    pub dry_run: bool,
+    /// 成功刪除（或模擬執行時將刪除）的項目合計大小
+    pub freed_bytes: u64,
 }
 
 impl CleanResult {
     pub fn from_results(results: Vec<OperationResult>) -> Self {
         let stats = OperationStats::from_results(&results);
-        Self { results, stats }
+        Self {
+            results,
+            stats,
+            dry_run: false,
+            freed_bytes: 0,
+        }
+    }
+
+    /// 依掃描時的大小加總成功項目
+    fn with_freed(mut self, sizes: &HashMap<PathBuf, u64>) -> Self {
+        self.freed_bytes = self
+            .results
+            .iter()
+            .filter(|result| result.success)
+            .filter_map(|result| sizes.get(&result.path))
+            .sum();
+        self
+    }
+
+    /// 模擬執行：每個項目都視為將被刪除
+    pub fn preview(items: Vec<PathBuf>) -> Self {
+        let results = items
+            .into_iter()
+            .map(|path| OperationResult::success(path, OperationType::Delete))
+            .collect();
+        Self {
+            dry_run: true,
+            ..Self::from_results(results)
+        }
     }
 }
 
@@ -35,26 +83,55 @@ impl CleanResult {
 pub struct TerraformCleanerService<S: FileScanner, C: FileCleaner> {
     scanner: S,
     cleaner: C,
+    age_filter: Option<AgeFilter>,
 }
 
 impl<S: FileScanner, C: FileCleaner> TerraformCleanerService<S, C> {
     pub fn new(scanner: S, cleaner: C) -> Self {
-        Self { scanner, cleaner }
+        Self {
+            scanner,
+            cleaner,
+            age_filter: None,
+        }
+    }
+
+    /// 只回報修改時間早於門檻的項目
+    pub fn with_age_filter(mut self, age_filter: Option<AgeFilter>) -> Self {
+        self.age_filter = age_filter;
+        self
     }
 
     /// 掃描快取檔案
     pub fn scan(&self, root: &Path) -> ScanResult {
-        let items = self.scanner.scan(root);
+        let found = self.scanner.scan(root);
+        let (items, too_new) = match &self.age_filter {
+            Some(filter) => filter.partition(found),
+            None => (found, Vec::new()),
+        };
+        let sizes = items
+            .iter()
+            .map(|item| (item.clone(), path_utils::disk_usage(item)))
+            .collect();
         ScanResult {
             items,
+            sizes,
+            too_new,
             filtered_count: 0,
         }
     }
 
-    /// 清理指定的檔案
-    pub fn clean(&self, items: Vec<std::path::PathBuf>) -> CleanResult {
+    /// 清理指定的檔案；`dry_run` 時只回報將刪除的項目
+    pub fn clean(
+        &self,
+        items: Vec<PathBuf>,
+        sizes: &HashMap<PathBuf, u64>,
+        dry_run: bool,
+    ) -> CleanResult {
+        if dry_run {
+            return CleanResult::preview(items).with_freed(sizes);
+        }
         let results = self.cleaner.clean(items);
-        CleanResult::from_results(results)
+        CleanResult::from_results(results).with_freed(sizes)
     }
 }
 
@@ -62,7 +139,6 @@ impl<S: FileScanner, C: FileCleaner> TerraformCleanerService<S, C> {
 mod tests {
     use super::*;
     use crate::core::{FileCleaner, FileScanner, OperationResult, OperationType};
-    use std::path::{Path, PathBuf};
 
     struct MockScanner {
         items: Vec<PathBuf>,
@@ -111,8 +187,70 @@ mod tests {
         let cleaner = MockCleaner;
         let service = TerraformCleanerService::new(scanner, cleaner);
 
-        let result = service.clean(items);
+        let sizes = HashMap::from([(items[0].clone(), 2048)]);
+        let result = service.clean(items, &sizes, false);
         assert_eq!(result.stats.success, 1);
         assert_eq!(result.stats.failed, 0);
+        assert_eq!(result.freed_bytes, 2048);
+        assert!(!result.dry_run);
+    }
+
+    #[test]
+    fn test_scan_excludes_recent_items() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let cache = temp_dir.path().join(".terraform");
+        std::fs::create_dir_all(&cache).unwrap();
+
+        let scanner = MockScanner {
+            items: vec![cache.clone()],
+        };
+        let service = TerraformCleanerService::new(scanner, MockCleaner)
+            .with_age_filter(Some(AgeFilter::new(std::time::Duration::from_secs(3_600))));
+
+        let result = service.scan(temp_dir.path());
+        assert!(result.is_empty());
+        assert_eq!(result.too_new, vec![cache]);
+    }
+
+    #[test]
+    fn test_scan_reports_sizes() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let cache = temp_dir.path().join(".terraform");
+        std::fs::create_dir_all(cache.join("providers")).unwrap();
+        std::fs::write(cache.join("providers/plugin"), vec![0u8; 300]).unwrap();
+        let lock = temp_dir.path().join(".terraform.lock.hcl");
+        std::fs::write(&lock, vec![0u8; 20]).unwrap();
+
+        let scanner = MockScanner {
+            items: vec![cache.clone(), lock.clone()],
+        };
+        let service = TerraformCleanerService::new(scanner, MockCleaner);
+
+        let result = service.scan(temp_dir.path());
+        assert_eq!(result.size_of(&cache), 300);
+        assert_eq!(result.size_of(&lock), 20);
+        assert_eq!(result.total_size(&result.items), 320);
+    }
+
+    struct PanicCleaner;
+
+    impl FileCleaner for PanicCleaner {
+        fn clean(&self, _items: Vec<PathBuf>) -> Vec<OperationResult> {
+            panic!("dry run must not reach the cleaner");
+        }
+    }
+
+    #[test]
+    fn test_clean_dry_run_skips_cleaner() {
+        let items = vec![PathBuf::from("/test/a"), PathBuf::from("/test/b")];
+        let scanner = MockScanner {
+            items: items.clone(),
+        };
+        let service = TerraformCleanerService::new(scanner, PanicCleaner);
+
+        let result = service.clean(items, &HashMap::new(), true);
+        assert!(result.dry_run);
+        assert_eq!(result.stats.success, 2);
+        assert_eq!(result.results.len(), 2);
     }
 }
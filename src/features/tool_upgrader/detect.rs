@@ -0,0 +1,231 @@
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+/// 可能擁有全域 CLI 的套件管理器
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InstallManager {
+    Npm,
+    Pnpm,
+    Yarn,
+    Bun,
+    Brew,
+}
+
+impl InstallManager {
+    /// 會安裝到 node_modules 的管理器，用於偵測不明確時讓使用者選擇
+    pub const NODE: [InstallManager; 4] = [
+        InstallManager::Npm,
+        InstallManager::Pnpm,
+        InstallManager::Yarn,
+        InstallManager::Bun,
+    ];
+
+    pub fn program(&self) -> &'static str {
+        match self {
+            InstallManager::Npm => "npm",
+            InstallManager::Pnpm => "pnpm",
+            InstallManager::Yarn => "yarn",
+            InstallManager::Bun => "bun",
+            InstallManager::Brew => "brew",
+        }
+    }
+
+    /// 升級套件的參數（Node 管理器會加上 @latest）
+    pub fn upgrade_args(&self, package: &str) -> Vec<String> {
+        let latest = format!("{package}@latest");
+        let args: Vec<&str> = match self {
+            InstallManager::Npm => vec!["install", "-g", &latest],
+            InstallManager::Pnpm => vec!["add", "-g", &latest],
+            InstallManager::Yarn => vec!["global", "add", &latest],
+            InstallManager::Bun => vec!["add", "-g", &latest],
+            InstallManager::Brew => vec!["upgrade", package],
+        };
+        args.into_iter().map(String::from).collect()
+    }
+
+    /// 移除全域套件的參數
+    pub fn uninstall_args(&self, package: &str) -> Vec<String> {
+        let args: &[&str] = match self {
+            InstallManager::Npm => &["uninstall", "-g"],
+            InstallManager::Pnpm => &["remove", "-g"],
+            InstallManager::Yarn => &["global", "remove"],
+            InstallManager::Bun => &["remove", "-g"],
+            InstallManager::Brew => &["uninstall"],
+        };
+        args.iter()
+            .copied()
+            .chain([package])
+            .map(String::from)
+            .collect()
+    }
+
+    /// 依執行檔名稱找回管理器
+    pub fn from_program(program: &str) -> Option<InstallManager> {
+        [InstallManager::Brew]
+            .into_iter()
+            .chain(InstallManager::NODE)
+            .find(|manager| manager.program() == program)
+    }
+
+    pub fn is_available(&self) -> bool {
+        Command::new(self.program())
+            .arg("--version")
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+            .map(|s| s.success())
+            .unwrap_or(false)
+    }
+}
+
+/// 安裝來源的偵測結果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Detection {
+    /// 可從安裝路徑判斷出管理器
+    Manager(InstallManager),
+    /// 裝在 node_modules 中但無法判斷是哪個管理器，附上可用的候選
+    Ambiguous(Vec<InstallManager>),
+    /// 未安裝或非套件管理器安裝（例如官方安裝程式）
+    Unknown,
+}
+
+/// 依 PATH 上的執行檔位置判斷工具由哪個管理器安裝
+pub fn detect(binary: &str) -> Detection {
+    let Some(path) = find_on_path(binary) else {
+        return Detection::Unknown;
+    };
+    let resolved = path.canonicalize().unwrap_or_else(|_| path.clone());
+
+    if let Some(manager) = classify_path(&path).or_else(|| classify_path(&resolved)) {
+        return Detection::Manager(manager);
+    }
+
+    if !is_node_modules(&resolved) {
+        return Detection::Unknown;
+    }
+
+    let candidates: Vec<InstallManager> = InstallManager::NODE
+        .into_iter()
+        .filter(|manager| manager.is_available())
+        .collect();
+    match candidates.as_slice() {
+        [] => Detection::Unknown,
+        [manager] => Detection::Manager(*manager),
+        _ => Detection::Ambiguous(candidates),
+    }
+}
+
+/// 以路徑特徵判斷管理器（symlink 本身或其實際位置）
+fn classify_path(path: &Path) -> Option<InstallManager> {
+    let path = path.to_string_lossy().replace('\\', "/").to_lowercase();
+
+    if path.contains("/.bun/") {
+        Some(InstallManager::Bun)
+    } else if path.contains("/pnpm/") || path.contains("/.pnpm/") {
+        Some(InstallManager::Pnpm)
+    } else if path.contains("/.yarn/") || path.contains("/yarn/global/") {
+        Some(InstallManager::Yarn)
+    } else if path.contains("/cellar/") || path.contains("/caskroom/") {
+        Some(InstallManager::Brew)
+    } else if path.contains("/lib/node_modules/") {
+        Some(InstallManager::Npm)
+    } else {
+        None
+    }
+}
+
+fn is_node_modules(path: &Path) -> bool {
+    path.components()
+        .any(|component| component.as_os_str() == "node_modules")
+}
+
+/// PATH 上第一個符合名稱的執行檔
+pub fn find_on_path(binary: &str) -> Option<PathBuf> {
+    let path_var = env::var_os("PATH")?;
+    env::split_paths(&path_var)
+        .map(|dir| dir.join(binary))
+        .find(|candidate| candidate.is_file())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify_path_by_install_location() {
+        let cases = [
+            (
+                "/home/me/.bun/install/global/node_modules/@openai/codex/bin/codex.js",
+                Some(InstallManager::Bun),
+            ),
+            (
+                "/home/me/.local/share/pnpm/global/5/node_modules/@openai/codex/bin/codex.js",
+                Some(InstallManager::Pnpm),
+            ),
+            (
+                "/home/me/.config/yarn/global/node_modules/@openai/codex/bin/codex.js",
+                Some(InstallManager::Yarn),
+            ),
+            (
+                "/opt/homebrew/Caskroom/claude-code/1.0.0/claude",
+                Some(InstallManager::Brew),
+            ),
+            (
+                "/home/me/.nvm/versions/node/v22.1.0/lib/node_modules/@anthropic-ai/claude-code/cli.js",
+                Some(InstallManager::Npm),
+            ),
+            ("/home/me/.local/bin/claude", None),
+            ("/srv/app/node_modules/.bin/codex", None),
+        ];
+
+        for (path, expected) in cases {
+            assert_eq!(classify_path(Path::new(path)), expected, "{path}");
+        }
+    }
+
+    #[test]
+    fn test_is_node_modules() {
+        assert!(is_node_modules(Path::new(
+            "/srv/app/node_modules/@openai/codex/bin/codex.js"
+        )));
+        assert!(!is_node_modules(Path::new("/usr/local/bin/codex")));
+    }
+
+    #[test]
+    fn test_upgrade_args_per_manager() {
+        assert_eq!(
+            InstallManager::Npm.upgrade_args("@openai/codex"),
+            ["install", "-g", "@openai/codex@latest"]
+        );
+        assert_eq!(
+            InstallManager::Yarn.upgrade_args("@openai/codex"),
+            ["global", "add", "@openai/codex@latest"]
+        );
+        assert_eq!(
+            InstallManager::Brew.upgrade_args("codex"),
+            ["upgrade", "codex"]
+        );
+    }
+
+    #[test]
+    fn test_uninstall_args_per_manager() {
+        assert_eq!(
+            InstallManager::Pnpm.uninstall_args("@openai/codex"),
+            ["remove", "-g", "@openai/codex"]
+        );
+        assert_eq!(
+            InstallManager::Yarn.uninstall_args("@openai/codex"),
+            ["global", "remove", "@openai/codex"]
+        );
+        assert_eq!(
+            InstallManager::Brew.uninstall_args("claude-code"),
+            ["uninstall", "claude-code"]
+        );
+        assert_eq!(
+            InstallManager::from_program("bun"),
+            Some(InstallManager::Bun)
+        );
+        assert_eq!(InstallManager::from_program("claude"), None);
+    }
+}
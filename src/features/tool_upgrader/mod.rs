@@ -1,36 +1,156 @@
+mod detect;
+mod registry;
 mod tools;
 mod upgrader;
+mod version;
 
+use crate::core::{dry_run, load_config};
 use crate::i18n::{self, keys};
-use crate::ui::{Console, Prompts};
-use tools::AI_TOOLS;
+use crate::ui::{Console, PromptError, Prompts};
+use detect::{Detection, InstallManager};
+use registry::{Registry, RegistrySource};
+use std::cmp::Ordering;
+
+use std::path::PathBuf;
+use tools::{AI_TOOLS, AiTool};
 use upgrader::{PackageUpgrader, SourceBuildExecutor};
+pub use version::compare as compare_versions;
 
 /// Codex source build 的固定參數
 const CODEX_CARGO_PACKAGE: &str = "codex-cli";
 const CODEX_BINARY_NAME: &str = "codex";
 
+/// 要對 AI 工具執行的動作
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ToolAction {
+    Upgrade,
+    Uninstall,
+}
+
+impl ToolAction {
+    /// 選單順序
+    const ALL: [ToolAction; 2] = [ToolAction::Upgrade, ToolAction::Uninstall];
+
+    fn label(self) -> &'static str {
+        match self {
+            ToolAction::Upgrade => i18n::t(keys::TOOL_UPGRADER_ACTION_UPGRADE),
+            ToolAction::Uninstall => i18n::t(keys::TOOL_UPGRADER_ACTION_UNINSTALL),
+        }
+    }
+}
+
+/// 預先指定的升級選項（例如來自命令列）
+#[derive(Clone, Debug, Default)]
+pub struct ToolUpgradeOptions {
+    /// 未指定時詢問要升級或移除
+    pub action: Option<ToolAction>,
+    /// npm registry 網址，優先於環境變數與設定檔
+    pub registry: Option<String>,
+}
+
 /// 執行 AI 工具升級功能
 pub fn run() {
+    run_with(ToolUpgradeOptions::default());
+}
+
+/// 以預先指定的選項執行 AI 工具升級
+pub fn run_with(options: ToolUpgradeOptions) {
     let console = Console::new();
     let prompts = Prompts::new();
 
     console.header(i18n::t(keys::TOOL_UPGRADER_HEADER));
 
+    let action = match options.action {
+        Some(action) => action,
+        None => {
+            let Some(index) = prompts.select_with_default(
+                i18n::t(keys::TOOL_UPGRADER_SELECT_ACTION),
+                &action_options(),
+                0,
+            ) else {
+                console.warning(i18n::t(keys::TOOL_UPGRADER_CANCELLED));
+                return;
+            };
+            // 選「返回」直接回主選單；Esc 取消才提示已取消
+            let Some(&action) = ToolAction::ALL.get(index) else {
+                return;
+            };
+            action
+        }
+    };
+    if action == ToolAction::Uninstall {
+        run_uninstall(&console, &prompts);
+        return;
+    }
+
+    let config_registry = load_config()
+        .ok()
+        .flatten()
+        .and_then(|config| config.npm_registry);
+    let registry = registry::resolve(options.registry.as_deref(), config_registry.as_deref());
+    if !registry::is_valid_url(&registry.url) {
+        console.error(&crate::tr!(
+            keys::TOOL_UPGRADER_REGISTRY_INVALID,
+            url = &registry.url
+        ));
+        return;
+    }
+    show_registry(&console, &registry);
+
     // 預先偵測 Codex source path
     let codex_source_dir = SourceBuildExecutor::resolve_source_dir();
+    let package_upgrader = PackageUpgrader::with_registry(registry.override_url());
+
+    let methods: Vec<UpgradeMethod> = AI_TOOLS
+        .iter()
+        .map(|tool| {
+            if tool.name == "OpenAI Codex"
+                && let Some(ref source_dir) = codex_source_dir
+            {
+                UpgradeMethod::SourceBuild(source_dir.clone())
+            } else {
+                UpgradeMethod::Package(resolve_manager(&prompts, &package_upgrader, tool))
+            }
+        })
+        .collect();
 
     console.info(i18n::t(keys::TOOL_UPGRADER_LIST_TITLE));
-    for tool in AI_TOOLS {
-        let mode = if tool.name == "OpenAI Codex" && codex_source_dir.is_some() {
-            "source build"
-        } else {
-            tool.display
+    for (tool, method) in AI_TOOLS.iter().zip(&methods) {
+        let mode = match method {
+            UpgradeMethod::SourceBuild(_) => "source build".to_string(),
+            UpgradeMethod::Package(Some(manager)) => crate::tr!(
+                keys::TOOL_UPGRADER_DETECTED,
+                manager = manager.program(),
+                command = package_upgrader.describe(tool, Some(*manager))
+            ),
+            UpgradeMethod::Package(None) => tool.display.to_string(),
         };
         console.list_item("📦", &format!("{} ({})", tool.name, mode));
     }
     console.separator();
 
+    let versions: Vec<ToolVersions> = AI_TOOLS
+        .iter()
+        .zip(&methods)
+        .map(|(tool, method)| ToolVersions::query(tool, method, &registry.url))
+        .collect();
+    show_version_table(&console, &versions);
+
+    let up_to_date = versions.iter().filter(|v| v.is_up_to_date()).count();
+    let include_current = up_to_date > 0
+        && prompts.confirm_with_options(
+            &crate::tr_plural!(keys::TOOL_UPGRADER_INCLUDE_CURRENT, count = up_to_date),
+            false,
+        );
+    let selected: Vec<usize> = (0..AI_TOOLS.len())
+        .filter(|&i| include_current || !versions[i].is_up_to_date())
+        .collect();
+
+    if selected.is_empty() {
+        console.success(i18n::t(keys::TOOL_UPGRADER_ALL_CURRENT));
+        return;
+    }
+
     if !prompts.confirm(i18n::t(keys::TOOL_UPGRADER_CONFIRM)) {
         console.warning(i18n::t(keys::TOOL_UPGRADER_CANCELLED));
         return;
@@ -38,30 +158,27 @@ pub fn run() {
 
     console.blank_line();
 
-    let package_upgrader = PackageUpgrader::new();
     let mut success_count = 0;
     let mut failed_count = 0;
+    // 成功升級的工具與升級前後的版本
+    let mut changes: Vec<(&str, VersionChange)> = Vec::new();
 
-    for (i, tool) in AI_TOOLS.iter().enumerate() {
+    for (i, &index) in selected.iter().enumerate() {
+        let (tool, method) = (&AI_TOOLS[index], &methods[index]);
         console.show_progress(
             i + 1,
-            AI_TOOLS.len(),
+            selected.len(),
             &crate::tr!(keys::TOOL_UPGRADER_PROGRESS, tool = tool.name),
         );
 
         // Codex: 有設 source path → source build，沒有 → 一般升級
-        let result = if tool.name == "OpenAI Codex" {
-            if let Some(ref source_dir) = codex_source_dir {
-                SourceBuildExecutor::execute_source_build(
-                    source_dir,
-                    CODEX_CARGO_PACKAGE,
-                    CODEX_BINARY_NAME,
-                )
-            } else {
-                package_upgrader.upgrade(tool)
-            }
-        } else {
-            package_upgrader.upgrade(tool)
+        let result = match method {
+            UpgradeMethod::SourceBuild(source_dir) => SourceBuildExecutor::execute_source_build(
+                source_dir,
+                CODEX_CARGO_PACKAGE,
+                CODEX_BINARY_NAME,
+            ),
+            UpgradeMethod::Package(manager) => package_upgrader.upgrade(tool, *manager),
         };
 
         match result {
@@ -72,6 +189,11 @@ pub fn run() {
                         console.list_item("  ", line);
                     }
                 }
+                let after = tool.binary.and_then(version::installed_version);
+                changes.push((
+                    tool.name,
+                    VersionChange::between(versions[index].current.as_deref(), after),
+                ));
                 success_count += 1;
             }
             Err(err) => {
@@ -90,15 +212,411 @@ pub fn run() {
         success_count,
         failed_count,
     );
+    show_version_changes(&console, &changes);
+}
+
+/// 動作選單的選項，依 [`ToolAction::ALL`] 排列，最後一項為「返回」
+fn action_options() -> Vec<&'static str> {
+    ToolAction::ALL
+        .iter()
+        .map(|action| action.label())
+        .chain([i18n::t(keys::MENU_BACK)])
+        .collect()
+}
+
+/// 移除選取的已安裝 AI 工具
+fn run_uninstall(console: &Console, prompts: &Prompts) {
+    let upgrader = PackageUpgrader::new();
+    let installed: Vec<&AiTool> = AI_TOOLS
+        .iter()
+        .filter(|tool| tool.binary.and_then(detect::find_on_path).is_some())
+        .collect();
+    if installed.is_empty() {
+        console.info(i18n::t(keys::TOOL_UPGRADER_NONE_INSTALLED));
+        return;
+    }
+
+    let items: Vec<String> = installed.iter().map(|tool| tool.name.to_string()).collect();
+    let prompt = i18n::t(keys::TOOL_UPGRADER_SELECT_UNINSTALL);
+    let selections = match prompts.try_multi_select(prompt, &items, &vec![false; items.len()]) {
+        Ok(selections) => selections,
+        Err(err) => {
+            if err != PromptError::Cancelled {
+                console.error(&format!("{prompt} — {err}"));
+            }
+            console.warning(i18n::t(keys::TOOL_UPGRADER_CANCELLED));
+            return;
+        }
+    };
+    if selections.is_empty() {
+        console.warning(i18n::t(keys::TOOL_UPGRADER_CANCELLED));
+        return;
+    }
+
+    let mut plan = Vec::new();
+    for tool in selections.into_iter().map(|index| installed[index]) {
+        let manager = resolve_uninstall_manager(prompts, &upgrader, tool);
+        match upgrader.uninstall_command(tool, manager) {
+            Some((program, args)) => {
+                let command = format!("{program} {}", args.join(" "));
+                console.list_item("🗑", &format!("{} ({command})", tool.name));
+                plan.push((tool, manager, command));
+            }
+            None => console.warning(&crate::tr!(
+                keys::TOOL_UPGRADER_UNINSTALL_UNSUPPORTED,
+                tool = tool.name
+            )),
+        }
+    }
+    if plan.is_empty() {
+        return;
+    }
+
+    if dry_run::is_enabled() {
+        for (_, _, command) in &plan {
+            console.list_item(
+                "🔍",
+                &crate::tr!(keys::TOOL_UPGRADER_UNINSTALL_DRY_RUN, command = command),
+            );
+        }
+        console.show_dry_run_summary(plan.len());
+        return;
+    }
+    if !prompts.confirm(i18n::t(keys::TOOL_UPGRADER_CONFIRM_UNINSTALL)) {
+        console.warning(i18n::t(keys::TOOL_UPGRADER_CANCELLED));
+        return;
+    }
+
+    console.blank_line();
+    let (mut success_count, mut failed_count) = (0, 0);
+    for (i, (tool, manager, _)) in plan.iter().enumerate() {
+        console.show_progress(
+            i + 1,
+            plan.len(),
+            &crate::tr!(keys::TOOL_UPGRADER_UNINSTALL_PROGRESS, tool = tool.name),
+        );
+        match upgrader.uninstall(tool, *manager) {
+            Ok(_) => {
+                console.success_item(&crate::tr!(
+                    keys::TOOL_UPGRADER_UNINSTALL_SUCCESS,
+                    tool = tool.name
+                ));
+                success_count += 1;
+            }
+            Err(err) => {
+                console.error_item(
+                    &crate::tr!(keys::TOOL_UPGRADER_UNINSTALL_FAILED, tool = tool.name),
+                    &err.to_string(),
+                );
+                failed_count += 1;
+            }
+        }
+        console.blank_line();
+    }
+
+    console.show_summary(
+        i18n::t(keys::TOOL_UPGRADER_UNINSTALL_SUMMARY),
+        success_count,
+        failed_count,
+    );
+}
+
+/// 偵測移除時要使用的管理器；無法判斷時讓使用者從候選中選擇
+fn resolve_uninstall_manager(
+    prompts: &Prompts,
+    upgrader: &PackageUpgrader,
+    tool: &AiTool,
+) -> Option<InstallManager> {
+    match detect::detect(tool.binary?) {
+        Detection::Manager(manager) => Some(manager),
+        Detection::Unknown => None,
+        Detection::Ambiguous(candidates) => {
+            let choices = uninstall_choices(upgrader, tool, &candidates);
+            if choices.is_empty() {
+                return None;
+            }
+            let items: Vec<&str> = choices.iter().map(|(_, label)| label.as_str()).collect();
+            let selection = prompts.select(
+                &crate::tr!(keys::TOOL_UPGRADER_SELECT_MANAGER, tool = tool.name),
+                &items,
+            )?;
+            choices.get(selection).map(|(manager, _)| *manager)
+        }
+    }
+}
+
+/// 可用來移除的管理器與對應指令；沒有移除指令的管理器不列出，選項索引與回傳清單一致
+fn uninstall_choices(
+    upgrader: &PackageUpgrader,
+    tool: &AiTool,
+    candidates: &[InstallManager],
+) -> Vec<(InstallManager, String)> {
+    candidates
+        .iter()
+        .filter_map(|manager| {
+            let (program, args) = upgrader.uninstall_command(tool, Some(*manager))?;
+            Some((*manager, format!("{program} {}", args.join(" "))))
+        })
+        .collect()
+}
+
+/// 升級前後的版本變化
+#[derive(Debug, PartialEq, Eq)]
+enum VersionChange {
+    /// 版本有變動；升級前未安裝或無法查詢時 `from` 為 None
+    Upgraded { from: Option<String>, to: String },
+    /// 版本沒有變動（原本就是最新版）
+    Unchanged(String),
+    /// 升級後查不到版本
+    Unknown,
+}
+
+impl VersionChange {
+    fn between(before: Option<&str>, after: Option<String>) -> Self {
+        match (before, after) {
+            (_, None) => Self::Unknown,
+            (Some(before), Some(after))
+                if version::compare(before, &after) == Some(Ordering::Equal) =>
+            {
+                Self::Unchanged(after)
+            }
+            (before, Some(after)) => Self::Upgraded {
+                from: before.map(String::from),
+                to: after,
+            },
+        }
+    }
+}
+
+/// 列出每個工具 `舊版本 -> 新版本`，版本沒變的標示為已是最新版
+fn show_version_changes(console: &Console, changes: &[(&str, VersionChange)]) {
+    if changes.is_empty() {
+        return;
+    }
+
+    console.info(i18n::t(keys::TOOL_UPGRADER_VERSION_CHANGES));
+    for (name, change) in changes {
+        let line = match change {
+            VersionChange::Upgraded { from, to } => {
+                format!("{name}: {} -> {to}", from.as_deref().unwrap_or("-"))
+            }
+            VersionChange::Unchanged(version) => crate::tr!(
+                keys::TOOL_UPGRADER_VERSION_UNCHANGED,
+                tool = name,
+                version = version
+            ),
+            VersionChange::Unknown => {
+                crate::tr!(keys::TOOL_UPGRADER_VERSION_UNKNOWN, tool = name)
+            }
+        };
+        let icon = match change {
+            VersionChange::Upgraded { .. } => "⬆",
+            _ => "•",
+        };
+        console.list_item(icon, &line);
+    }
+}
+
+/// 單一工具的升級方式
+enum UpgradeMethod {
+    SourceBuild(PathBuf),
+    /// 透過偵測到（或使用者選擇）的管理器升級；None 表示使用工具預設指令
+    Package(Option<InstallManager>),
+}
+
+/// 工具目前與最新的版本
+struct ToolVersions {
+    name: &'static str,
+    current: Option<String>,
+    /// source build 不查詢 npm，一律視為需要升級
+    latest: Option<String>,
+}
+
+impl ToolVersions {
+    fn query(tool: &AiTool, method: &UpgradeMethod, registry: &str) -> Self {
+        let current = tool.binary.and_then(version::installed_version);
+        let latest = match method {
+            UpgradeMethod::SourceBuild(_) => None,
+            UpgradeMethod::Package(_) => tool
+                .npm_package
+                .and_then(|package| version::latest_version(registry, package)),
+        };
+        Self {
+            name: tool.name,
+            current,
+            latest,
+        }
+    }
+
+    /// 已安裝且不低於最新版本
+    fn is_up_to_date(&self) -> bool {
+        match (&self.current, &self.latest) {
+            (Some(current), Some(latest)) => {
+                version::compare(current, latest).is_some_and(|order| order != Ordering::Less)
+            }
+            _ => false,
+        }
+    }
+}
+
+/// 顯示本次使用的 npm registry 與其來源
+fn show_registry(console: &Console, registry: &Registry) {
+    let source = match registry.source {
+        RegistrySource::Flag => "--registry",
+        RegistrySource::Env => "NPM_CONFIG_REGISTRY",
+        RegistrySource::Config => "npm_registry (config.toml)",
+        RegistrySource::Default => i18n::t(keys::TOOL_UPGRADER_REGISTRY_DEFAULT),
+    };
+    console.info(&crate::tr!(
+        keys::TOOL_UPGRADER_REGISTRY,
+        url = &registry.url,
+        source = source
+    ));
+}
+
+/// 顯示 name / current / latest / will-upgrade 表格
+fn show_version_table(console: &Console, versions: &[ToolVersions]) {
+    console.raw(&format!(
+        "  {:<14} {:<12} {:<12} {}",
+        i18n::t(keys::TOOL_UPGRADER_COLUMN_TOOL),
+        i18n::t(keys::TOOL_UPGRADER_COLUMN_CURRENT),
+        i18n::t(keys::TOOL_UPGRADER_COLUMN_LATEST),
+        i18n::t(keys::TOOL_UPGRADER_COLUMN_UPGRADE)
+    ));
+    for entry in versions {
+        let upgrade = if entry.is_up_to_date() {
+            i18n::t(keys::PROMPT_NO)
+        } else {
+            i18n::t(keys::PROMPT_YES)
+        };
+        console.raw(&format!(
+            "  {:<14} {:<12} {:<12} {}",
+            entry.name,
+            entry.current.as_deref().unwrap_or("-"),
+            entry.latest.as_deref().unwrap_or("-"),
+            upgrade
+        ));
+    }
+    console.separator();
+}
+
+/// 偵測工具的安裝來源，無法判斷時讓使用者選擇管理器
+fn resolve_manager(
+    prompts: &Prompts,
+    upgrader: &PackageUpgrader,
+    tool: &AiTool,
+) -> Option<InstallManager> {
+    let binary = tool.binary?;
+    match detect::detect(binary) {
+        Detection::Manager(manager) => Some(manager),
+        Detection::Unknown => None,
+        Detection::Ambiguous(candidates) => {
+            let mut options: Vec<String> = candidates
+                .iter()
+                .map(|manager| upgrader.describe(tool, Some(*manager)))
+                .collect();
+            options.push(crate::tr!(
+                keys::TOOL_UPGRADER_DEFAULT_COMMAND,
+                command = upgrader.describe(tool, None)
+            ));
+            let items: Vec<&str> = options.iter().map(String::as_str).collect();
+            let selection = prompts.select(
+                &crate::tr!(keys::TOOL_UPGRADER_SELECT_MANAGER, tool = tool.name),
+                &items,
+            )?;
+            candidates.get(selection).copied()
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use super::detect::InstallManager;
     use super::tools::AI_TOOLS;
+    use super::tools::{AiTool, UpgradeCommand};
+    use super::{
+        PackageUpgrader, ToolAction, ToolVersions, VersionChange, action_options, uninstall_choices,
+    };
+    use crate::i18n::{self, keys};
 
     #[test]
     #[allow(clippy::const_is_empty)]
     fn test_ai_tools_list() {
         assert!(!AI_TOOLS.is_empty());
     }
+
+    #[test]
+    fn test_action_menu_ends_with_back() {
+        let options = action_options();
+        assert_eq!(options.len(), ToolAction::ALL.len() + 1);
+        for (index, action) in ToolAction::ALL.iter().enumerate() {
+            assert_eq!(options[index], action.label());
+        }
+        assert_eq!(options.last(), Some(&i18n::t(keys::MENU_BACK)));
+        assert_eq!(ToolAction::ALL.get(options.len() - 1), None);
+    }
+
+    #[test]
+    fn test_uninstall_choices_skip_managers_without_command() {
+        let tool = AiTool {
+            name: "Tool",
+            display: "tool",
+            command: UpgradeCommand::Custom {
+                program: "tool",
+                args: &["update"],
+            },
+            binary: Some("tool"),
+            npm_package: None,
+            brew_package: Some("tool"),
+        };
+
+        let choices = uninstall_choices(
+            &PackageUpgrader::new(),
+            &tool,
+            &[InstallManager::Npm, InstallManager::Brew],
+        );
+        assert_eq!(choices.len(), 1);
+        assert_eq!(choices[0].0, InstallManager::Brew);
+        assert!(choices[0].1.starts_with("brew "));
+    }
+
+    #[test]
+    fn test_up_to_date_requires_both_versions() {
+        let versions = |current: Option<&str>, latest: Option<&str>| ToolVersions {
+            name: "tool",
+            current: current.map(String::from),
+            latest: latest.map(String::from),
+        };
+
+        assert!(versions(Some("1.2.0"), Some("1.2.0")).is_up_to_date());
+        assert!(!versions(Some("1.1.9"), Some("1.2.0")).is_up_to_date());
+        assert!(!versions(None, Some("1.2.0")).is_up_to_date());
+        assert!(!versions(Some("1.2.0"), None).is_up_to_date());
+    }
+
+    #[test]
+    fn test_version_change_between() {
+        assert_eq!(
+            VersionChange::between(Some("0.20.0"), Some("0.21.0".into())),
+            VersionChange::Upgraded {
+                from: Some("0.20.0".into()),
+                to: "0.21.0".into()
+            }
+        );
+        assert_eq!(
+            VersionChange::between(Some("1.0.3"), Some("1.0.3".into())),
+            VersionChange::Unchanged("1.0.3".into())
+        );
+        assert_eq!(
+            VersionChange::between(None, Some("1.0.3".into())),
+            VersionChange::Upgraded {
+                from: None,
+                to: "1.0.3".into()
+            }
+        );
+        assert_eq!(
+            VersionChange::between(Some("1.0.3"), None),
+            VersionChange::Unknown
+        );
+    }
 }
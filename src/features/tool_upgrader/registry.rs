@@ -0,0 +1,107 @@
+//! npm registry 選擇
+//!
+//! 依序採用 `--registry`、`NPM_CONFIG_REGISTRY` 環境變數、設定檔 `npm_registry`，都沒有時使用官方 registry
+
+/// 官方 npm registry
+pub const DEFAULT_REGISTRY: &str = "https://registry.npmjs.org";
+
+/// 環境變數名稱（npm 也會讀取小寫的 `npm_config_registry`）
+const REGISTRY_ENV_VARS: [&str; 2] = ["NPM_CONFIG_REGISTRY", "npm_config_registry"];
+
+/// registry 網址的來源
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RegistrySource {
+    Flag,
+    Env,
+    Config,
+    Default,
+}
+
+/// 本次升級使用的 registry
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Registry {
+    pub url: String,
+    pub source: RegistrySource,
+}
+
+impl Registry {
+    /// 需要明確傳給套件管理器的網址；使用預設值時交給管理器自己的設定（例如 .npmrc）
+    pub fn override_url(&self) -> Option<&str> {
+        (self.source != RegistrySource::Default).then_some(self.url.as_str())
+    }
+}
+
+/// 從命令列、環境變數與設定檔決定 registry
+pub fn resolve(flag: Option<&str>, config: Option<&str>) -> Registry {
+    let env = REGISTRY_ENV_VARS
+        .iter()
+        .find_map(|name| std::env::var(name).ok());
+    resolve_from(flag, env.as_deref(), config)
+}
+
+fn resolve_from(flag: Option<&str>, env: Option<&str>, config: Option<&str>) -> Registry {
+    let candidates = [
+        (flag, RegistrySource::Flag),
+        (env, RegistrySource::Env),
+        (config, RegistrySource::Config),
+    ];
+    candidates
+        .into_iter()
+        .find_map(|(url, source)| {
+            let url = url?.trim().trim_end_matches('/');
+            (!url.is_empty()).then(|| Registry {
+                url: url.to_string(),
+                source,
+            })
+        })
+        .unwrap_or_else(|| Registry {
+            url: DEFAULT_REGISTRY.to_string(),
+            source: RegistrySource::Default,
+        })
+}
+
+/// registry 網址須為 http(s)
+pub fn is_valid_url(url: &str) -> bool {
+    url.strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))
+        .is_some_and(|rest| !rest.trim_matches('/').is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_precedence() {
+        let flag = resolve_from(
+            Some("https://flag.example/"),
+            Some("https://env.example"),
+            Some("https://config.example"),
+        );
+        assert_eq!(flag.url, "https://flag.example");
+        assert_eq!(flag.source, RegistrySource::Flag);
+
+        let env = resolve_from(
+            None,
+            Some("https://env.example"),
+            Some("https://config.example"),
+        );
+        assert_eq!(env.source, RegistrySource::Env);
+
+        let config = resolve_from(None, Some("  "), Some("https://config.example"));
+        assert_eq!(config.url, "https://config.example");
+        assert_eq!(config.source, RegistrySource::Config);
+
+        let default = resolve_from(None, None, None);
+        assert_eq!(default.url, DEFAULT_REGISTRY);
+        assert_eq!(default.override_url(), None);
+    }
+
+    #[test]
+    fn test_is_valid_url() {
+        assert!(is_valid_url("https://npm.corp.example/repository/npm/"));
+        assert!(is_valid_url("http://localhost:4873"));
+        assert!(!is_valid_url("npm.corp.example"));
+        assert!(!is_valid_url("https://"));
+    }
+}
@@ -1,3 +1,5 @@
+use super::detect::InstallManager;
+
 /// 升級指令的型別
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum UpgradeCommand {
@@ -20,8 +22,14 @@ pub struct AiTool {
     pub name: &'static str,
     /// 清單顯示用的目標描述（套件名稱或指令）
     pub display: &'static str,
-    /// 升級方式
+    /// 升級方式（無法偵測安裝來源時使用）
     pub command: UpgradeCommand,
+    /// 用來偵測安裝來源的執行檔名稱
+    pub binary: Option<&'static str>,
+    /// Node 套件管理器使用的套件名稱
+    pub npm_package: Option<&'static str>,
+    /// Homebrew formula / cask 名稱
+    pub brew_package: Option<&'static str>,
 }
 
 impl AiTool {
@@ -39,6 +47,9 @@ impl AiTool {
             name,
             display: package,
             command: UpgradeCommand::PackageManager { manager, package },
+            binary: None,
+            npm_package: None,
+            brew_package: None,
         }
     }
 
@@ -52,11 +63,36 @@ impl AiTool {
             name,
             display,
             command: UpgradeCommand::Custom { program, args },
+            binary: None,
+            npm_package: None,
+            brew_package: None,
+        }
+    }
+
+    /// 設定偵測安裝來源所需的資訊，偵測到時改用對應管理器升級
+    pub const fn detected_by(
+        mut self,
+        binary: &'static str,
+        npm_package: &'static str,
+        brew_package: &'static str,
+    ) -> Self {
+        self.binary = Some(binary);
+        self.npm_package = Some(npm_package);
+        self.brew_package = Some(brew_package);
+        self
+    }
+
+    /// 指定管理器要升級的套件名稱
+    pub fn package_for(&self, manager: InstallManager) -> Option<&'static str> {
+        match manager {
+            InstallManager::Brew => self.brew_package,
+            _ => self.npm_package,
         }
     }
 }
 
 /// 預設的 AI 工具清單
+#[rustfmt::skip]
 pub const AI_TOOLS: &[AiTool] = &[
     // Claude Code: use built-in updater
     AiTool::with_custom_command(
@@ -64,13 +100,15 @@ pub const AI_TOOLS: &[AiTool] = &[
         "claude update",
         "claude",
         &["update"],
-    ),
+    )
+    .detected_by("claude", "@anthropic-ai/claude-code", "claude-code"),
     AiTool::with_custom_command(
         "OpenAI Codex",
         "bun install -g @openai/codex",
         "bun",
         &["install", "-g", "@openai/codex"],
-    ),
+    )
+    .detected_by("codex", "@openai/codex", "codex"),
 ];
 
 #[cfg(test)]
@@ -101,4 +139,18 @@ mod tests {
 
         assert!(matches!(claude.command, UpgradeCommand::Custom { .. }));
     }
+
+    #[test]
+    fn test_package_for_manager() {
+        let codex = AI_TOOLS
+            .iter()
+            .find(|t| t.name == "OpenAI Codex")
+            .expect("Codex tool should exist");
+
+        assert_eq!(
+            codex.package_for(InstallManager::Npm),
+            Some("@openai/codex")
+        );
+        assert_eq!(codex.package_for(InstallManager::Brew), Some("codex"));
+    }
 }
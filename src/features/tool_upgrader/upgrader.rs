@@ -1,15 +1,27 @@
+use super::detect::InstallManager;
 use super::tools::{AiTool, UpgradeCommand};
 use crate::core::{OperationError, Result, load_config};
 use crate::i18n::{self, keys};
+use crate::ui::Console;
 use std::path::{Path, PathBuf};
 use std::process::Command;
 
 /// 套件升級器：處理 PackageManager 和 Custom 兩種升級方式
-pub struct PackageUpgrader;
+pub struct PackageUpgrader {
+    /// 傳給 Node 套件管理器的 `--registry`；None 時使用管理器自己的設定
+    registry: Option<String>,
+}
 
 impl PackageUpgrader {
     pub fn new() -> Self {
-        Self
+        Self { registry: None }
+    }
+
+    /// 透過指定的 npm registry 安裝
+    pub fn with_registry(registry: Option<&str>) -> Self {
+        Self {
+            registry: registry.map(String::from),
+        }
     }
 
     /// 產生要執行的指令
@@ -34,27 +46,94 @@ impl PackageUpgrader {
         }
     }
 
-    /// 升級指定工具到最新版本
-    pub fn upgrade(&self, tool: &AiTool) -> Result<String> {
-        let (program, args) = self.build_command(tool);
-        let status = Command::new(&program)
-            .args(&args)
-            .stdin(std::process::Stdio::null())
-            .status()
-            .map_err(|e| OperationError::Command {
-                command: program.clone(),
-                message: crate::tr!(keys::ERROR_UNABLE_TO_EXECUTE, error = e),
-            })?;
-
-        if status.success() {
-            Ok(format!("{program} completed"))
-        } else {
-            let command_display = format!("{program} {}", args.join(" "));
-            Err(OperationError::Command {
-                command: command_display,
-                message: i18n::t(keys::ERROR_UNKNOWN).to_string(),
-            })
+    /// 偵測到管理器且工具有對應套件時改用該管理器，否則使用預設指令
+    fn command_for(&self, tool: &AiTool, manager: Option<InstallManager>) -> (String, Vec<String>) {
+        let (program, mut args) =
+            match manager.and_then(|manager| Some((manager, tool.package_for(manager)?))) {
+                Some((manager, package)) => {
+                    (manager.program().to_string(), manager.upgrade_args(package))
+                }
+                None => self.build_command(tool),
+            };
+
+        // 只有 Node 套件管理器支援 --registry（brew 與工具內建的 update 不適用）
+        let is_node = InstallManager::NODE
+            .iter()
+            .any(|manager| manager.program() == program);
+        if let Some(registry) = self.registry.as_ref().filter(|_| is_node) {
+            args.extend(["--registry".to_string(), registry.clone()]);
         }
+        (program, args)
+    }
+
+    /// 顯示用的升級指令
+    pub fn describe(&self, tool: &AiTool, manager: Option<InstallManager>) -> String {
+        let (program, args) = self.command_for(tool, manager);
+        format!("{program} {}", args.join(" "))
+    }
+
+    /// 升級指定工具到最新版本
+    pub fn upgrade(&self, tool: &AiTool, manager: Option<InstallManager>) -> Result<String> {
+        let (program, args) = self.command_for(tool, manager);
+        run_package_command(&program, &args)
+    }
+
+    /// 移除工具的指令：偵測到的管理器優先，否則改用預設指令所屬的管理器；
+    /// 以工具自身指令安裝（例如 `claude update`）時回傳 None
+    pub fn uninstall_command(
+        &self,
+        tool: &AiTool,
+        manager: Option<InstallManager>,
+    ) -> Option<(String, Vec<String>)> {
+        let (manager, package) =
+            match manager.and_then(|manager| Some((manager, tool.package_for(manager)?))) {
+                Some(found) => found,
+                None => match tool.command {
+                    UpgradeCommand::PackageManager { manager, package } => {
+                        (InstallManager::from_program(manager)?, package)
+                    }
+                    UpgradeCommand::Custom { program, .. } => {
+                        let manager = InstallManager::from_program(program)?;
+                        (manager, tool.package_for(manager)?)
+                    }
+                },
+            };
+        Some((
+            manager.program().to_string(),
+            manager.uninstall_args(package),
+        ))
+    }
+
+    /// 透過套件管理器移除工具
+    pub fn uninstall(&self, tool: &AiTool, manager: Option<InstallManager>) -> Result<String> {
+        let (program, args) = self.uninstall_command(tool, manager).ok_or_else(|| {
+            OperationError::Validation(crate::tr!(
+                keys::TOOL_UPGRADER_UNINSTALL_UNSUPPORTED,
+                tool = tool.name
+            ))
+        })?;
+        run_package_command(&program, &args)
+    }
+}
+
+/// 執行套件管理器指令（輸出直接顯示在終端機）
+fn run_package_command(program: &str, args: &[String]) -> Result<String> {
+    let mut command = Command::new(program);
+    command.args(args).stdin(std::process::Stdio::null());
+    Console::new().command(&command);
+    let status = command.status().map_err(|e| OperationError::Command {
+        command: program.to_string(),
+        message: crate::tr!(keys::ERROR_UNABLE_TO_EXECUTE, error = e),
+    })?;
+
+    if status.success() {
+        Ok(format!("{program} completed"))
+    } else {
+        let command_display = format!("{program} {}", args.join(" "));
+        Err(OperationError::Command {
+            command: command_display,
+            message: i18n::t(keys::ERROR_UNKNOWN).to_string(),
+        })
     }
 }
 
@@ -171,10 +250,8 @@ impl SourceBuildExecutor {
                 path = built.display()
             )));
         }
-        std::fs::copy(&built, &install_target).map_err(|e| OperationError::Io {
-            path: install_target.display().to_string(),
-            source: e,
-        })?;
+        std::fs::copy(&built, &install_target)
+            .map_err(|e| OperationError::io(install_target.display().to_string(), e))?;
         log.push(crate::tr!(
             keys::SOURCE_BUILD_INSTALLED,
             source = built.display(),
@@ -234,15 +311,16 @@ fn run_command_in_dir(
     working_dir: &Path,
     display_label: &str,
 ) -> Result<String> {
-    let status = Command::new(program)
+    let mut command = Command::new(program);
+    command
         .args(args)
         .current_dir(working_dir)
-        .stdin(std::process::Stdio::null())
-        .status()
-        .map_err(|e| OperationError::Command {
-            command: display_label.to_string(),
-            message: crate::tr!(keys::ERROR_UNABLE_TO_EXECUTE, error = e),
-        })?;
+        .stdin(std::process::Stdio::null());
+    Console::new().command(&command);
+    let status = command.status().map_err(|e| OperationError::Command {
+        command: display_label.to_string(),
+        message: crate::tr!(keys::ERROR_UNABLE_TO_EXECUTE, error = e),
+    })?;
 
     if status.success() {
         Ok(format!("{display_label} completed"))
@@ -289,6 +367,70 @@ mod tests {
         assert_eq!(args, vec!["update".to_string()]);
     }
 
+    #[test]
+    fn test_command_for_detected_manager() {
+        let upgrader = PackageUpgrader::new();
+        let codex = AI_TOOLS.iter().find(|t| t.name == "OpenAI Codex").unwrap();
+
+        assert_eq!(
+            upgrader.describe(codex, Some(InstallManager::Npm)),
+            "npm install -g @openai/codex@latest"
+        );
+        assert_eq!(
+            upgrader.describe(codex, Some(InstallManager::Brew)),
+            "brew upgrade codex"
+        );
+        assert_eq!(
+            upgrader.describe(codex, None),
+            "bun install -g @openai/codex"
+        );
+    }
+
+    #[test]
+    fn test_uninstall_command() {
+        let upgrader = PackageUpgrader::new();
+        let codex = AI_TOOLS.iter().find(|t| t.name == "OpenAI Codex").unwrap();
+        let claude = AI_TOOLS.iter().find(|t| t.name == "Claude Code").unwrap();
+
+        let describe = |command: Option<(String, Vec<String>)>| {
+            command.map(|(program, args)| format!("{program} {}", args.join(" ")))
+        };
+        assert_eq!(
+            describe(upgrader.uninstall_command(codex, Some(InstallManager::Npm))).as_deref(),
+            Some("npm uninstall -g @openai/codex")
+        );
+        assert_eq!(
+            describe(upgrader.uninstall_command(codex, None)).as_deref(),
+            Some("bun remove -g @openai/codex")
+        );
+        assert_eq!(
+            describe(upgrader.uninstall_command(claude, Some(InstallManager::Brew))).as_deref(),
+            Some("brew uninstall claude-code")
+        );
+        assert_eq!(upgrader.uninstall_command(claude, None), None);
+    }
+
+    #[test]
+    fn test_command_for_with_registry() {
+        let upgrader = PackageUpgrader::with_registry(Some("https://npm.corp.example"));
+        let codex = AI_TOOLS.iter().find(|t| t.name == "OpenAI Codex").unwrap();
+        let claude = AI_TOOLS.iter().find(|t| t.name == "Claude Code").unwrap();
+
+        assert_eq!(
+            upgrader.describe(codex, Some(InstallManager::Pnpm)),
+            "pnpm add -g @openai/codex@latest --registry https://npm.corp.example"
+        );
+        assert_eq!(
+            upgrader.describe(codex, None),
+            "bun install -g @openai/codex --registry https://npm.corp.example"
+        );
+        assert_eq!(
+            upgrader.describe(codex, Some(InstallManager::Brew)),
+            "brew upgrade codex"
+        );
+        assert_eq!(upgrader.describe(claude, None), "claude update");
+    }
+
     #[test]
     fn test_resolve_source_dir_from_env() {
         use std::env;
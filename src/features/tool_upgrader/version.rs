@@ -0,0 +1,105 @@
+use serde::Deserialize;
+use std::cmp::Ordering;
+use std::process::{Command, Stdio};
+
+#[derive(Deserialize)]
+struct RegistryLatest {
+    version: String,
+}
+
+/// 執行 `<binary> --version` 取得已安裝的版本
+pub fn installed_version(binary: &str) -> Option<String> {
+    let output = Command::new(binary)
+        .arg("--version")
+        .stdin(Stdio::null())
+        .stderr(Stdio::null())
+        .output()
+        .ok()
+        .filter(|output| output.status.success())?;
+    extract_version(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// 從 npm registry 取得套件的最新發佈版本
+pub fn latest_version(registry: &str, package: &str) -> Option<String> {
+    let url = format!("{registry}/{}/latest", package.replace('/', "%2f"));
+    let output = Command::new("curl")
+        .args(["-sSfL", "--max-time", "10", &url])
+        .stdin(Stdio::null())
+        .stderr(Stdio::null())
+        .output()
+        .ok()
+        .filter(|output| output.status.success())?;
+    parse_registry(&String::from_utf8_lossy(&output.stdout))
+}
+
+fn parse_registry(body: &str) -> Option<String> {
+    serde_json::from_str::<RegistryLatest>(body)
+        .ok()
+        .map(|latest| latest.version)
+}
+
+/// 取出輸出中第一個 `x.y[.z]` 形式的版本（例如 `codex-cli 0.20.0`、`1.0.3 (Claude Code)`）
+pub fn extract_version(output: &str) -> Option<String> {
+    output
+        .split(|c: char| c.is_whitespace() || c == '(' || c == ')' || c == ',')
+        .map(|token| token.trim_start_matches('v'))
+        .find(|token| parse_numbers(token).is_some_and(|numbers| numbers.len() >= 2))
+        .map(String::from)
+}
+
+/// 比較兩個版本；無法解析時回傳 None
+pub fn compare(current: &str, latest: &str) -> Option<Ordering> {
+    let current = parse_numbers(current)?;
+    let latest = parse_numbers(latest)?;
+    let len = current.len().max(latest.len());
+    let pad = |numbers: Vec<u64>| {
+        let mut numbers = numbers;
+        numbers.resize(len, 0);
+        numbers
+    };
+    Some(pad(current).cmp(&pad(latest)))
+}
+
+/// 解析版本的數字部分，忽略 `-beta` 等後綴
+fn parse_numbers(version: &str) -> Option<Vec<u64>> {
+    let core = version.split(['-', '+']).next()?;
+    core.split('.')
+        .map(|part| part.parse::<u64>().ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_version() {
+        assert_eq!(
+            extract_version("1.0.3 (Claude Code)\n"),
+            Some("1.0.3".to_string())
+        );
+        assert_eq!(
+            extract_version("codex-cli 0.20.0"),
+            Some("0.20.0".to_string())
+        );
+        assert_eq!(extract_version("tool v2.1"), Some("2.1".to_string()));
+        assert_eq!(extract_version("no version here"), None);
+    }
+
+    #[test]
+    fn test_compare_versions() {
+        assert_eq!(compare("1.0.3", "1.0.10"), Some(Ordering::Less));
+        assert_eq!(compare("0.20.0", "0.20"), Some(Ordering::Equal));
+        assert_eq!(compare("2.0.0-beta.1", "1.9.9"), Some(Ordering::Greater));
+        assert_eq!(compare("unknown", "1.0.0"), None);
+    }
+
+    #[test]
+    fn test_parse_registry() {
+        assert_eq!(
+            parse_registry(r#"{"name":"@openai/codex","version":"0.21.0"}"#),
+            Some("0.21.0".to_string())
+        );
+        assert_eq!(parse_registry("not json"), None);
+    }
+}
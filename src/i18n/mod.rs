@@ -1,4 +1,6 @@
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
 use std::sync::{OnceLock, RwLock};
 
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
@@ -8,14 +10,16 @@ pub enum Language {
     TraditionalChinese,
     SimplifiedChinese,
     Japanese,
+    Korean,
 }
 
 impl Language {
-    pub const ALL: [Language; 4] = [
+    pub const ALL: [Language; 5] = [
         Language::English,
         Language::TraditionalChinese,
         Language::SimplifiedChinese,
         Language::Japanese,
+        Language::Korean,
     ];
 
     pub fn display_name(self) -> &'static str {
@@ -24,6 +28,7 @@ impl Language {
             Language::TraditionalChinese => "繁體中文",
             Language::SimplifiedChinese => "简体中文",
             Language::Japanese => "日本語",
+            Language::Korean => "한국어",
         }
     }
 
@@ -33,6 +38,7 @@ impl Language {
             Language::TraditionalChinese => "zh-TW",
             Language::SimplifiedChinese => "zh-CN",
             Language::Japanese => "ja",
+            Language::Korean => "ko",
         }
     }
 
@@ -42,6 +48,7 @@ impl Language {
             Language::TraditionalChinese => 1,
             Language::SimplifiedChinese => 2,
             Language::Japanese => 3,
+            Language::Korean => 4,
         }
     }
 
@@ -51,6 +58,7 @@ impl Language {
             1 => Some(Language::TraditionalChinese),
             2 => Some(Language::SimplifiedChinese),
             3 => Some(Language::Japanese),
+            4 => Some(Language::Korean),
             _ => None,
         }
     }
@@ -61,13 +69,62 @@ impl Language {
             "zh-TW" | "zh-Hant" | "zh-Hant-TW" => Some(Language::TraditionalChinese),
             "zh-CN" | "zh-Hans" | "zh-Hans-CN" => Some(Language::SimplifiedChinese),
             "ja" | "ja-JP" => Some(Language::Japanese),
+            "ko" | "ko-KR" => Some(Language::Korean),
             _ => None,
         }
     }
+
+    /// Plural category for `count`; CJK languages do not inflect for number
+    pub fn plural_category(self, count: usize) -> PluralCategory {
+        match self {
+            Language::English if count == 1 => PluralCategory::One,
+            _ => PluralCategory::Other,
+        }
+    }
+
+    /// Detect the language from the POSIX locale variables (`LC_ALL` > `LC_MESSAGES` > `LANG`)
+    pub fn from_system() -> Option<Self> {
+        ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .iter()
+            .filter_map(|name| std::env::var(name).ok())
+            .find(|value| !value.trim().is_empty())
+            .and_then(|value| Self::from_locale(&value))
+    }
+
+    /// Map a POSIX locale such as `en_US.UTF-8` or `ja_JP` onto a supported language
+    pub fn from_locale(locale: &str) -> Option<Self> {
+        let tag = locale
+            .split(['.', '@'])
+            .next()
+            .unwrap_or_default()
+            .replace('_', "-");
+
+        Self::from_code(&tag).or_else(|| {
+            let primary = tag.split('-').next().unwrap_or_default();
+            Self::from_code(primary)
+        })
+    }
+}
+
+/// CLDR-style plural category used to pick `.one` / `.other` key variants
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PluralCategory {
+    One,
+    Other,
+}
+
+impl PluralCategory {
+    fn suffix(self) -> &'static str {
+        match self {
+            PluralCategory::One => "one",
+            PluralCategory::Other => "other",
+        }
+    }
 }
 
 struct Bundle {
     maps: HashMap<Language, HashMap<String, String>>,
+    override_errors: Vec<(PathBuf, String)>,
 }
 
 impl Bundle {
@@ -104,10 +161,63 @@ fn bundle() -> &'static Bundle {
             Language::Japanese,
             load_locale(include_str!("locales/ja.toml")),
         );
-        Bundle { maps }
+        maps.insert(
+            Language::Korean,
+            load_locale(include_str!("locales/ko.toml")),
+        );
+        let override_errors = crate::core::config::config_dir()
+            .map(|dir| apply_overrides(&mut maps, &dir.join("locales")))
+            .unwrap_or_default();
+        Bundle {
+            maps,
+            override_errors,
+        }
     })
 }
 
+/// Merge user-provided `<code>.toml` files on top of the built-in locales.
+/// Only keys that already exist are overridden; unreadable files are reported, not fatal.
+fn apply_overrides(
+    maps: &mut HashMap<Language, HashMap<String, String>>,
+    dir: &Path,
+) -> Vec<(PathBuf, String)> {
+    let mut errors = Vec::new();
+
+    for language in Language::ALL {
+        let path = dir.join(format!("{}.toml", language.code()));
+        if !path.is_file() {
+            continue;
+        }
+
+        let overrides = fs::read_to_string(&path)
+            .map_err(|err| err.to_string())
+            .and_then(|raw| {
+                toml::from_str::<HashMap<String, String>>(&raw).map_err(|err| err.to_string())
+            });
+
+        match overrides {
+            Ok(overrides) => {
+                let Some(map) = maps.get_mut(&language) else {
+                    continue;
+                };
+                for (key, value) in overrides {
+                    if let Some(existing) = map.get_mut(&key) {
+                        *existing = value;
+                    }
+                }
+            }
+            Err(err) => errors.push((path, err)),
+        }
+    }
+
+    errors
+}
+
+/// Locale override files that could not be loaded, with the reason
+pub fn override_errors() -> &'static [(PathBuf, String)] {
+    &bundle().override_errors
+}
+
 fn language_lock() -> &'static RwLock<Language> {
     CURRENT_LANGUAGE.get_or_init(|| RwLock::new(Language::default()))
 }
@@ -120,13 +230,94 @@ pub fn set_language(language: Language) {
     *language_lock().write().expect("Language lock poisoned") = language;
 }
 
-pub fn t(key: &str) -> &'static str {
+/// Environment variable that forces the UI language for a single run
+pub const LANGUAGE_ENV_VAR: &str = "OPS_TOOLS_LANG";
+
+/// Resolve a one-off language override, preferring the CLI flag over the environment.
+/// Codes that fail to parse are reported through `on_invalid` and the next candidate is tried.
+pub fn resolve_override(
+    cli_code: Option<&str>,
+    env_code: Option<&str>,
+    mut on_invalid: impl FnMut(&str),
+) -> Option<Language> {
+    [cli_code, env_code].into_iter().flatten().find_map(|code| {
+        let language = Language::from_code(code);
+        if language.is_none() {
+            on_invalid(code);
+        }
+        language
+    })
+}
+
+fn lookup(key: &str) -> Option<&'static str> {
     let bundle = bundle();
     let language = current_language();
     bundle
         .get(language, key)
         .or_else(|| bundle.get(Language::English, key))
-        .unwrap_or("??")
+}
+
+pub fn t(key: &str) -> &'static str {
+    lookup(key).unwrap_or("??")
+}
+
+/// Translate `key` using its `.one` / `.other` variant for `count`,
+/// falling back to `.other` and then to the bare key
+pub fn t_plural(key: &str, count: usize) -> &'static str {
+    let category = current_language().plural_category(count);
+    lookup(&format!("{}.{}", key, category.suffix()))
+        .or_else(|| lookup(&format!("{}.other", key)))
+        .unwrap_or_else(|| t(key))
+}
+
+/// Environment variable that enables the startup locale consistency check
+pub const DEBUG_ENV_VAR: &str = "OPS_TOOLS_DEBUG_I18N";
+
+/// Compare every non-English locale against the English key set.
+/// Each entry names the locale and describes one missing or extra key.
+pub fn validate_bundle() -> Vec<(Language, String)> {
+    let bundle = bundle();
+    let Some(reference) = bundle.maps.get(&Language::English) else {
+        return Vec::new();
+    };
+
+    Language::ALL
+        .iter()
+        .filter(|language| **language != Language::English)
+        .flat_map(|language| {
+            let empty = HashMap::new();
+            let locale = bundle.maps.get(language).unwrap_or(&empty);
+            let (missing, extra) = diff_keys(reference, locale);
+            missing
+                .into_iter()
+                .map(|key| crate::tr!(keys::LANGUAGE_KEY_MISSING, key = key))
+                .chain(
+                    extra
+                        .into_iter()
+                        .map(|key| crate::tr!(keys::LANGUAGE_KEY_EXTRA, key = key)),
+                )
+                .map(|issue| (*language, issue))
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// Keys missing from `locale` and keys only present in `locale`, both sorted
+fn diff_keys(
+    reference: &HashMap<String, String>,
+    locale: &HashMap<String, String>,
+) -> (Vec<String>, Vec<String>) {
+    let reference_keys: BTreeSet<&String> = reference.keys().collect();
+    let locale_keys: BTreeSet<&String> = locale.keys().collect();
+    let missing = reference_keys
+        .difference(&locale_keys)
+        .map(|key| key.to_string())
+        .collect();
+    let extra = locale_keys
+        .difference(&reference_keys)
+        .map(|key| key.to_string())
+        .collect();
+    (missing, extra)
 }
 
 #[macro_export]
@@ -143,6 +334,19 @@ macro_rules! tr {
     }};
 }
 
+/// Like `tr!`, but selects the plural variant of `$key` from the `count` argument
+#[macro_export]
+macro_rules! tr_plural {
+    ($key:expr, count = $count:expr $(, $name:ident = $value:expr)* $(,)?) => {{
+        let count: usize = $count;
+        let output = $crate::i18n::t_plural($key, count).replace("{count}", &count.to_string());
+        $(
+            let output = output.replace(concat!("{", stringify!($name), "}"), &$value.to_string());
+        )*
+        output
+    }};
+}
+
 pub mod keys {
     pub const MENU_PROMPT: &str = "menu.prompt";
     pub const MENU_TERRAFORM_CLEANER: &str = "menu.terraform_cleaner.name";
@@ -175,6 +379,7 @@ pub mod keys {
     pub const MENU_CATEGORIES: &str = "menu.categories.name";
     pub const MENU_BACK: &str = "menu.back";
     pub const MENU_CATEGORY_PROMPT: &str = "menu.category.prompt";
+    pub const MENU_NON_INTERACTIVE: &str = "menu.non_interactive";
     pub const MENU_SETTINGS: &str = "menu.settings.name";
     pub const MENU_SETTINGS_DESC: &str = "menu.settings.desc";
     pub const MENU_LANGUAGE: &str = "menu.language.name";
@@ -191,11 +396,20 @@ pub mod keys {
     pub const MENU_PIN_REORDER: &str = "menu.pin.reorder.name";
     pub const MENU_PIN_REORDER_DESC: &str = "menu.pin.reorder.desc";
     pub const MENU_PIN_REORDER_PROMPT: &str = "menu.pin.reorder.prompt";
+    pub const MENU_PIN_REORDER_MOVE_UP: &str = "menu.pin.reorder.move_up";
+    pub const MENU_PIN_REORDER_MOVE_DOWN: &str = "menu.pin.reorder.move_down";
+    pub const MENU_PIN_REORDER_SAVE: &str = "menu.pin.reorder.save";
     pub const MENU_PIN_REORDER_DONE: &str = "menu.pin.reorder.done";
     pub const MENU_PIN_REORDER_EMPTY: &str = "menu.pin.reorder.empty";
 
     pub const LANGUAGE_SELECT_PROMPT: &str = "language.select_prompt";
     pub const LANGUAGE_CHANGED: &str = "language.changed";
+    pub const LANGUAGE_KEY_MISSING: &str = "language.key_missing";
+    pub const LANGUAGE_KEY_EXTRA: &str = "language.key_extra";
+    pub const LANGUAGE_CHECK_ISSUE: &str = "language.check_issue";
+    pub const LANGUAGE_CHECK_SUMMARY: &str = "language.check_summary";
+    pub const LANGUAGE_CHECK_CLEAN: &str = "language.check_clean";
+    pub const LANGUAGE_OVERRIDE_INVALID: &str = "language.override_invalid";
 
     pub const CONFIG_LOAD_FAILED: &str = "config.load_failed";
     pub const CONFIG_SAVE_FAILED: &str = "config.save_failed";
@@ -203,18 +417,30 @@ pub mod keys {
 
     pub const CONSOLE_ERROR_PREFIX: &str = "console.error_prefix";
     pub const CONSOLE_SUMMARY: &str = "console.summary";
+    pub const CONSOLE_DRY_RUN_ENABLED: &str = "console.dry_run_enabled";
+    pub const CONSOLE_DRY_RUN_WOULD_DELETE: &str = "console.dry_run_would_delete";
+    pub const CONSOLE_DRY_RUN_WOULD_APPLY: &str = "console.dry_run_would_apply";
+    pub const CONSOLE_DRY_RUN_SUMMARY: &str = "console.dry_run_summary";
 
     pub const PROMPT_YES: &str = "prompt.yes";
     pub const PROMPT_NO: &str = "prompt.no";
+    pub const PROMPT_AUTO_ANSWERED: &str = "prompt.auto_answered";
+    pub const PROMPT_NO_DEFAULT: &str = "prompt.no_default";
+    pub const PROMPT_NON_INTERACTIVE: &str = "prompt.non_interactive";
 
     pub const ERROR_IO: &str = "error.io";
+    pub const ERROR_PERMISSION_DENIED: &str = "error.permission_denied";
     pub const ERROR_COMMAND: &str = "error.command";
     pub const ERROR_CONFIG: &str = "error.config";
     pub const ERROR_VALIDATION: &str = "error.validation";
+    pub const ERROR_CHECKSUM: &str = "error.checksum";
     pub const ERROR_CANCELLED: &str = "error.cancelled";
     pub const ERROR_UNABLE_TO_EXECUTE: &str = "error.unable_to_execute";
+    pub const ERROR_COMMAND_TIMED_OUT: &str = "error.command_timed_out";
     pub const ERROR_UNKNOWN: &str = "error.unknown";
     pub const ERROR_COMMAND_NOT_FOUND: &str = "error.command_not_found";
+    pub const ERROR_COMMAND_MISSING: &str = "error.command_missing";
+    pub const ERROR_COMMAND_MISSING_HINT: &str = "error.command_missing_hint";
 
     pub const TERRAFORM_CURRENT_DIR_FAILED: &str = "terraform.current_dir_failed";
     pub const TERRAFORM_SCAN_START: &str = "terraform.scan_start";
@@ -223,7 +449,14 @@ pub mod keys {
     pub const TERRAFORM_FOUND_ITEMS: &str = "terraform.found_items";
     pub const TERRAFORM_ITEM_DIR: &str = "terraform.item_dir";
     pub const TERRAFORM_ITEM_FILE: &str = "terraform.item_file";
+    pub const TERRAFORM_WILL_FREE: &str = "terraform.will_free";
+    pub const TERRAFORM_FREED: &str = "terraform.freed";
+    pub const TERRAFORM_INPUT_OLDER_THAN: &str = "terraform.input_older_than";
+    pub const TERRAFORM_INVALID_AGE: &str = "terraform.invalid_age";
+    pub const TERRAFORM_SKIPPED_RECENT: &str = "terraform.skipped_recent";
     pub const TERRAFORM_CONFIRM_DELETE: &str = "terraform.confirm_delete";
+    pub const TERRAFORM_SELECT_ITEMS: &str = "terraform.select_items";
+    pub const TERRAFORM_NONE_SELECTED: &str = "terraform.none_selected";
     pub const TERRAFORM_DELETE_CANCELLED: &str = "terraform.delete_cancelled";
     pub const TERRAFORM_DELETED: &str = "terraform.deleted";
     pub const TERRAFORM_DELETE_FAILED: &str = "terraform.delete_failed";
@@ -234,6 +467,21 @@ pub mod keys {
     pub const TERRAFORM_PROGRESS_DELETED: &str = "terraform.progress_deleted";
 
     pub const TOOL_UPGRADER_HEADER: &str = "tool_upgrader.header";
+    pub const TOOL_UPGRADER_SELECT_ACTION: &str = "tool_upgrader.select_action";
+    pub const TOOL_UPGRADER_ACTION_UPGRADE: &str = "tool_upgrader.action.upgrade";
+    pub const TOOL_UPGRADER_ACTION_UNINSTALL: &str = "tool_upgrader.action.uninstall";
+    pub const TOOL_UPGRADER_NONE_INSTALLED: &str = "tool_upgrader.none_installed";
+    pub const TOOL_UPGRADER_SELECT_UNINSTALL: &str = "tool_upgrader.select_uninstall";
+    pub const TOOL_UPGRADER_UNINSTALL_UNSUPPORTED: &str = "tool_upgrader.uninstall_unsupported";
+    pub const TOOL_UPGRADER_UNINSTALL_DRY_RUN: &str = "tool_upgrader.uninstall_dry_run";
+    pub const TOOL_UPGRADER_CONFIRM_UNINSTALL: &str = "tool_upgrader.confirm_uninstall";
+    pub const TOOL_UPGRADER_UNINSTALL_PROGRESS: &str = "tool_upgrader.uninstall_progress";
+    pub const TOOL_UPGRADER_UNINSTALL_SUCCESS: &str = "tool_upgrader.uninstall_success";
+    pub const TOOL_UPGRADER_UNINSTALL_FAILED: &str = "tool_upgrader.uninstall_failed";
+    pub const TOOL_UPGRADER_UNINSTALL_SUMMARY: &str = "tool_upgrader.uninstall_summary";
+    pub const TOOL_UPGRADER_REGISTRY: &str = "tool_upgrader.registry";
+    pub const TOOL_UPGRADER_REGISTRY_DEFAULT: &str = "tool_upgrader.registry_default";
+    pub const TOOL_UPGRADER_REGISTRY_INVALID: &str = "tool_upgrader.registry_invalid";
     pub const TOOL_UPGRADER_LIST_TITLE: &str = "tool_upgrader.list_title";
     pub const TOOL_UPGRADER_CONFIRM: &str = "tool_upgrader.confirm";
     pub const TOOL_UPGRADER_CANCELLED: &str = "tool_upgrader.cancelled";
@@ -241,6 +489,18 @@ pub mod keys {
     pub const TOOL_UPGRADER_SUCCESS: &str = "tool_upgrader.success";
     pub const TOOL_UPGRADER_FAILED: &str = "tool_upgrader.failed";
     pub const TOOL_UPGRADER_SUMMARY: &str = "tool_upgrader.summary";
+    pub const TOOL_UPGRADER_VERSION_CHANGES: &str = "tool_upgrader.version_changes";
+    pub const TOOL_UPGRADER_VERSION_UNCHANGED: &str = "tool_upgrader.version_unchanged";
+    pub const TOOL_UPGRADER_VERSION_UNKNOWN: &str = "tool_upgrader.version_unknown";
+    pub const TOOL_UPGRADER_DETECTED: &str = "tool_upgrader.detected";
+    pub const TOOL_UPGRADER_SELECT_MANAGER: &str = "tool_upgrader.select_manager";
+    pub const TOOL_UPGRADER_DEFAULT_COMMAND: &str = "tool_upgrader.default_command";
+    pub const TOOL_UPGRADER_COLUMN_TOOL: &str = "tool_upgrader.column_tool";
+    pub const TOOL_UPGRADER_COLUMN_CURRENT: &str = "tool_upgrader.column_current";
+    pub const TOOL_UPGRADER_COLUMN_LATEST: &str = "tool_upgrader.column_latest";
+    pub const TOOL_UPGRADER_COLUMN_UPGRADE: &str = "tool_upgrader.column_upgrade";
+    pub const TOOL_UPGRADER_INCLUDE_CURRENT: &str = "tool_upgrader.include_current";
+    pub const TOOL_UPGRADER_ALL_CURRENT: &str = "tool_upgrader.all_current";
 
     pub const SOURCE_BUILD_BINARY_NOT_FOUND: &str = "source_build.binary_not_found";
     pub const SOURCE_BUILD_ARTIFACT_NOT_FOUND: &str = "source_build.artifact_not_found";
@@ -252,8 +512,14 @@ pub mod keys {
     pub const PACKAGE_MANAGER_MODE_INSTALL: &str = "package_manager.mode_install";
     pub const PACKAGE_MANAGER_MODE_UPDATE: &str = "package_manager.mode_update";
     pub const PACKAGE_MANAGER_INSTALL_PROMPT: &str = "package_manager.install_prompt";
+    pub const PACKAGE_MANAGER_VERSION_PROMPT: &str = "package_manager.version_prompt";
     pub const PACKAGE_MANAGER_UPDATE_PROMPT: &str = "package_manager.update_prompt";
     pub const PACKAGE_MANAGER_NO_CHANGES: &str = "package_manager.no_changes";
+    pub const PACKAGE_MANAGER_REMOVE_TARGETS: &str = "package_manager.remove_targets";
+    pub const PACKAGE_MANAGER_REMOVE_CARGO_WARNING: &str = "package_manager.remove_cargo_warning";
+    pub const PACKAGE_MANAGER_REMOVE_CARGO_TOOLS: &str = "package_manager.remove_cargo_tools";
+    pub const PACKAGE_MANAGER_REMOVE_CONFIRM: &str = "package_manager.remove_confirm";
+    pub const PACKAGE_MANAGER_REMOVE_KEPT: &str = "package_manager.remove_kept";
     pub const PACKAGE_MANAGER_NO_INSTALLED: &str = "package_manager.no_installed";
     pub const PACKAGE_MANAGER_CANCELLED: &str = "package_manager.cancelled";
     pub const PACKAGE_MANAGER_ACTION_RUNNING: &str = "package_manager.action_running";
@@ -268,14 +534,53 @@ pub mod keys {
     pub const PACKAGE_MANAGER_RUSTUP_MISSING: &str = "package_manager.rustup_missing";
     pub const PACKAGE_MANAGER_GO_VERSION_MISSING: &str = "package_manager.go_version_missing";
     pub const PACKAGE_MANAGER_GO_FILE_MISSING: &str = "package_manager.go_file_missing";
+    pub const PACKAGE_MANAGER_GO_RELEASE_NOT_FOUND: &str = "package_manager.go_release_not_found";
+    pub const PACKAGE_MANAGER_CUSTOM_LOADED: &str = "package_manager.custom_loaded";
+    pub const PACKAGE_MANAGER_CUSTOM_PARSE_FAILED: &str = "package_manager.custom_parse_failed";
+    pub const PACKAGE_MANAGER_CUSTOM_SKIPPED: &str = "package_manager.custom_skipped";
+    pub const PACKAGE_MANAGER_CUSTOM_MISSING_NAME: &str = "package_manager.custom_missing_name";
+    pub const PACKAGE_MANAGER_CUSTOM_MISSING_INSTALL: &str =
+        "package_manager.custom_missing_install";
+    pub const PACKAGE_MANAGER_CUSTOM_DUPLICATE: &str = "package_manager.custom_duplicate";
+    pub const PACKAGE_MANAGER_CUSTOM_UNKNOWN_MANAGER: &str =
+        "package_manager.custom_unknown_manager";
+    pub const PACKAGE_MANAGER_CUSTOM_NO_COMMAND: &str = "package_manager.custom_no_command";
+    pub const PACKAGE_MANAGER_VERSION_UNAVAILABLE: &str = "package_manager.version_unavailable";
+    pub const PACKAGE_MANAGER_VERSION_PIN_UNSUPPORTED: &str =
+        "package_manager.version_pin_unsupported";
     pub const PACKAGE_MANAGER_CODENAME_MISSING: &str = "package_manager.codename_missing";
+    pub const PACKAGE_MANAGER_CODENAME_AMBIGUOUS: &str = "package_manager.codename_ambiguous";
+    pub const PACKAGE_MANAGER_CODENAME_PROMPT: &str = "package_manager.codename_prompt";
+    pub const PACKAGE_MANAGER_APT_CODENAME: &str = "package_manager.apt_codename";
     pub const PACKAGE_MANAGER_ARCH_UNSUPPORTED: &str = "package_manager.arch_unsupported";
     pub const PACKAGE_MANAGER_GIT_REQUIRED: &str = "package_manager.git_required";
     pub const PACKAGE_MANAGER_BINARY_NOT_FOUND: &str = "package_manager.binary_not_found";
     pub const PACKAGE_MANAGER_RELEASE_ASSET_MISSING: &str = "package_manager.release_asset_missing";
+    pub const PACKAGE_MANAGER_GITHUB_RATE_LIMITED: &str = "package_manager.github_rate_limited";
+    pub const PACKAGE_MANAGER_CONFIG_DIFF_HEADER: &str = "package_manager.config_diff_header";
+    pub const PACKAGE_MANAGER_CONFIG_OVERWRITE_CONFIRM: &str =
+        "package_manager.config_overwrite_confirm";
+    pub const PACKAGE_MANAGER_CONFIG_KEPT: &str = "package_manager.config_kept";
+    pub const PACKAGE_MANAGER_CHECKSUM_MISSING: &str = "package_manager.checksum_missing";
     pub const PACKAGE_MANAGER_UV_MISSING: &str = "package_manager.uv_missing";
     pub const PACKAGE_MANAGER_SUDO_REQUIRED: &str = "package_manager.sudo_required";
     pub const PACKAGE_MANAGER_VIM_PLUG_HINT: &str = "package_manager.vim_plug_hint";
+    pub const SELF_UPDATE_HEADER: &str = "self_update.header";
+    pub const SELF_UPDATE_CHECK_FAILED: &str = "self_update.check_failed";
+    pub const SELF_UPDATE_UP_TO_DATE: &str = "self_update.up_to_date";
+    pub const SELF_UPDATE_AVAILABLE: &str = "self_update.available";
+    pub const SELF_UPDATE_DRY_RUN: &str = "self_update.dry_run";
+    pub const SELF_UPDATE_CONFIRM: &str = "self_update.confirm";
+    pub const SELF_UPDATE_CANCELLED: &str = "self_update.cancelled";
+    pub const SELF_UPDATE_DONE: &str = "self_update.done";
+    pub const SELF_UPDATE_FAILED: &str = "self_update.failed";
+    pub const DOCTOR_HEADER: &str = "doctor.header";
+    pub const DOCTOR_CHECKING: &str = "doctor.checking";
+    pub const DOCTOR_TOOLS_TITLE: &str = "doctor.tools_title";
+    pub const DOCTOR_TOOL_MISSING: &str = "doctor.tool_missing";
+    pub const DOCTOR_FEATURES_TITLE: &str = "doctor.features_title";
+    pub const DOCTOR_FEATURE_NEEDS: &str = "doctor.feature_needs";
+    pub const DOCTOR_SUMMARY: &str = "doctor.summary";
 
     pub const RUST_UPGRADER_HEADER: &str = "rust_upgrader.header";
     pub const RUST_UPGRADER_CHECKING_ENV: &str = "rust_upgrader.checking_env";
@@ -294,7 +599,8 @@ pub mod keys {
     pub const RUST_UPGRADER_ALL_TOOLS_INSTALLED: &str = "rust_upgrader.all_tools_installed";
     pub const RUST_UPGRADER_UPGRADE_STEPS: &str = "rust_upgrader.upgrade_steps";
     pub const RUST_UPGRADER_REQUIRES_PROJECT_TAG: &str = "rust_upgrader.requires_project_tag";
-    pub const RUST_UPGRADER_CONFIRM_UPGRADE: &str = "rust_upgrader.confirm_upgrade";
+    pub const RUST_UPGRADER_SELECT_STEPS: &str = "rust_upgrader.select_steps";
+    pub const RUST_UPGRADER_STEPS_DESELECTED: &str = "rust_upgrader.steps_deselected";
     pub const RUST_UPGRADER_CANCELLED: &str = "rust_upgrader.cancelled";
     pub const RUST_UPGRADER_RUNNING_STEP: &str = "rust_upgrader.running_step";
     pub const RUST_UPGRADER_STEP_DONE: &str = "rust_upgrader.step_done";
@@ -302,19 +608,45 @@ pub mod keys {
     pub const RUST_UPGRADER_STEP_FAILED: &str = "rust_upgrader.step_failed";
     pub const RUST_UPGRADER_SUMMARY: &str = "rust_upgrader.summary";
     pub const RUST_UPGRADER_SKIPPED_COUNT: &str = "rust_upgrader.skipped_count";
+    pub const RUST_UPGRADER_VERIFY_PASSED: &str = "rust_upgrader.verify_passed";
+    pub const RUST_UPGRADER_VERIFY_FAILED: &str = "rust_upgrader.verify_failed";
     pub const RUST_UPGRADER_OUTPUT_MORE_LINES: &str = "rust_upgrader.output_more_lines";
 
     pub const RUST_BUILDER_HEADER: &str = "rust_builder.header";
     pub const RUST_BUILDER_NO_CARGO_TOML: &str = "rust_builder.no_cargo_toml";
-    pub const RUST_BUILDER_RUSTUP_MISSING: &str = "rust_builder.rustup_missing";
+    pub const RUST_BUILDER_LIST_INSTALLED: &str = "rust_builder.list.installed";
+    pub const RUST_BUILDER_LIST_MISSING: &str = "rust_builder.list.missing";
+    pub const RUST_BUILDER_LIST_HOST: &str = "rust_builder.list.host";
+    pub const RUST_BUILDER_LIST_CROSS: &str = "rust_builder.list.cross";
+    pub const RUST_BUILDER_LIST_SUMMARY: &str = "rust_builder.list.summary";
     pub const RUST_BUILDER_SELECT_BUILDER: &str = "rust_builder.select_builder";
     pub const RUST_BUILDER_BUILDER_CARGO: &str = "rust_builder.builder.cargo";
     pub const RUST_BUILDER_BUILDER_CROSS: &str = "rust_builder.builder.cross";
+    pub const RUST_BUILDER_CROSS_RECOMMENDED: &str = "rust_builder.cross.recommended";
+    pub const RUST_BUILDER_CROSS_INSTALL_HINT: &str = "rust_builder.cross.install_hint";
+    pub const RUST_BUILDER_CONFIRM_SWITCH_CROSS: &str = "rust_builder.cross.confirm_switch";
+    pub const RUST_BUILDER_CROSS_CONFIG_FOUND: &str = "rust_builder.cross.config_found";
+    pub const RUST_BUILDER_CROSS_CONFIG_INVALID: &str = "rust_builder.cross.config_invalid";
+    pub const RUST_BUILDER_CROSS_IMAGE_DEFAULT: &str = "rust_builder.cross.image_default";
+    pub const RUST_BUILDER_CROSS_DOCKERFILE: &str = "rust_builder.cross.dockerfile";
     pub const RUST_BUILDER_SELECT_PROFILE: &str = "rust_builder.select_profile";
     pub const RUST_BUILDER_PROFILE_RELEASE: &str = "rust_builder.profile.release";
     pub const RUST_BUILDER_PROFILE_DEBUG: &str = "rust_builder.profile.debug";
+    pub const RUST_BUILDER_CONFIRM_STRIP: &str = "rust_builder.confirm_strip";
+    pub const RUST_BUILDER_CONFIRM_UPX: &str = "rust_builder.confirm_upx";
+    pub const RUST_BUILDER_TOOL_MISSING_SKIP: &str = "rust_builder.tool_missing_skip";
+    pub const RUST_BUILDER_POST_PROCESS_FAILED: &str = "rust_builder.post_process_failed";
     pub const RUST_BUILDER_SELECT_TARGETS: &str = "rust_builder.select_targets";
+    pub const RUST_BUILDER_CUSTOM_TARGET_OPTION: &str = "rust_builder.custom_target_option";
+    pub const RUST_BUILDER_TARGET_CUSTOM: &str = "rust_builder.target.custom";
+    pub const RUST_BUILDER_INPUT_CUSTOM_TARGETS: &str = "rust_builder.input_custom_targets";
+    pub const RUST_BUILDER_INVALID_TRIPLE: &str = "rust_builder.invalid_triple";
+    pub const RUST_BUILDER_INPUT_FEATURES: &str = "rust_builder.input_features";
+    pub const RUST_BUILDER_CONFIRM_NO_DEFAULT_FEATURES: &str =
+        "rust_builder.confirm_no_default_features";
     pub const RUST_BUILDER_NO_TARGET_SELECTED: &str = "rust_builder.no_target_selected";
+    pub const RUST_BUILDER_SELECT_PACKAGES: &str = "rust_builder.select_packages";
+    pub const RUST_BUILDER_NO_PACKAGE_SELECTED: &str = "rust_builder.no_package_selected";
     pub const RUST_BUILDER_MISSING_TARGETS: &str = "rust_builder.missing_targets";
     pub const RUST_BUILDER_CONFIRM_INSTALL_TARGETS: &str = "rust_builder.confirm_install_targets";
     pub const RUST_BUILDER_INSTALLING_TARGET: &str = "rust_builder.installing_target";
@@ -324,7 +656,15 @@ pub mod keys {
     pub const RUST_BUILDER_BUILDING: &str = "rust_builder.building";
     pub const RUST_BUILDER_BUILD_SUCCESS: &str = "rust_builder.build_success";
     pub const RUST_BUILDER_BUILD_FAILED: &str = "rust_builder.build_failed";
+    pub const RUST_BUILDER_METADATA_FAILED: &str = "rust_builder.metadata_failed";
+    pub const RUST_BUILDER_CONFIRM_COPY_DIST: &str = "rust_builder.confirm_copy_dist";
+    pub const RUST_BUILDER_COPIED_TO_DIST: &str = "rust_builder.copied_to_dist";
+    pub const RUST_BUILDER_COPY_DIST_FAILED: &str = "rust_builder.copy_dist_failed";
+    pub const RUST_BUILDER_CONFIRM_ARCHIVE: &str = "rust_builder.confirm_archive";
+    pub const RUST_BUILDER_ARCHIVE_CREATED: &str = "rust_builder.archive_created";
+    pub const RUST_BUILDER_ARCHIVE_FAILED: &str = "rust_builder.archive_failed";
     pub const RUST_BUILDER_SUMMARY_TITLE: &str = "rust_builder.summary_title";
+    pub const RUST_BUILDER_TIMING_TOTAL: &str = "rust_builder.timing_total";
     pub const RUST_BUILDER_CANCELLED: &str = "rust_builder.cancelled";
 
     pub const RUST_BUILDER_TARGET_LINUX_X86_64_GNU: &str = "rust_builder.target.linux_x86_64_gnu";
@@ -356,18 +696,26 @@ pub mod keys {
     pub const RUST_UPGRADER_STEP_DESC_CARGO_OUTDATED: &str =
         "rust_upgrader.step_desc.cargo_outdated";
     pub const RUST_UPGRADER_STEP_DESC_CARGO_AUDIT: &str = "rust_upgrader.step_desc.cargo_audit";
+    pub const RUST_UPGRADER_STEP_DESC_CARGO_BUILD: &str = "rust_upgrader.step_desc.cargo_build";
+    pub const RUST_UPGRADER_STEP_DESC_CARGO_TEST: &str = "rust_upgrader.step_desc.cargo_test";
 
     pub const SECURITY_SCANNER_HEADER: &str = "security_scanner.header";
     pub const SECURITY_SCANNER_CURRENT_DIR_FAILED: &str = "security_scanner.current_dir_failed";
     pub const SECURITY_SCANNER_NOT_GIT_REPO: &str = "security_scanner.not_git_repo";
-    pub const SECURITY_SCANNER_GIT_NOT_FOUND: &str = "security_scanner.git_not_found";
+    pub const SECURITY_SCANNER_REPO_PATH_INVALID: &str = "security_scanner.repo_path_invalid";
+    pub const SECURITY_SCANNER_REPO_NOT_GIT: &str = "security_scanner.repo_not_git";
     pub const SECURITY_SCANNER_SCAN_DIR: &str = "security_scanner.scan_dir";
     pub const SECURITY_SCANNER_STRICT_MODE: &str = "security_scanner.strict_mode";
+    pub const SECURITY_SCANNER_STRICT_MODE_WORKTREE: &str = "security_scanner.strict_mode_worktree";
     pub const SECURITY_SCANNER_TOOLS_INTRO: &str = "security_scanner.tools_intro";
     pub const SECURITY_SCANNER_STATUS_INSTALLED: &str = "security_scanner.status_installed";
     pub const SECURITY_SCANNER_STATUS_MISSING: &str = "security_scanner.status_missing";
     pub const SECURITY_SCANNER_STATUS_BUILTIN: &str = "security_scanner.status_builtin";
-    pub const SECURITY_SCANNER_CONFIRM_INSTALL: &str = "security_scanner.confirm_install";
+    pub const SECURITY_SCANNER_SELECT_TOOLS: &str = "security_scanner.select_tools";
+    pub const SECURITY_SCANNER_SELECT_INSTALL: &str = "security_scanner.select_install";
+    pub const SECURITY_SCANNER_TOOLS_SAVED: &str = "security_scanner.tools_saved";
+    pub const SECURITY_SCANNER_TOOLS_SAVED_NONE: &str = "security_scanner.tools_saved_none";
+    pub const SECURITY_SCANNER_AUDIT_MODE: &str = "security_scanner.audit_mode";
     pub const SECURITY_SCANNER_CANCELLED: &str = "security_scanner.cancelled";
     pub const SECURITY_SCANNER_INSTALLING: &str = "security_scanner.installing";
     pub const SECURITY_SCANNER_INSTALL_DONE: &str = "security_scanner.install_done";
@@ -383,13 +731,21 @@ pub mod keys {
     pub const SECURITY_SCANNER_FINDINGS: &str = "security_scanner.findings";
     pub const SECURITY_SCANNER_SCAN_FAILED: &str = "security_scanner.scan_failed";
     pub const SECURITY_SCANNER_SCAN_SUMMARY: &str = "security_scanner.scan_summary";
+    pub const SECURITY_SCANNER_FINDINGS_BY_TOOL: &str = "security_scanner.findings_by_tool";
+    pub const SECURITY_SCANNER_FINDINGS_COUNT: &str = "security_scanner.findings_count";
+    pub const SECURITY_SCANNER_SKIPPED_SUMMARY: &str = "security_scanner.skipped_summary";
     pub const SECURITY_SCANNER_FINDINGS_WARNING: &str = "security_scanner.findings_warning";
     pub const SECURITY_SCANNER_EXIT_CODE: &str = "security_scanner.exit_code";
     pub const SECURITY_SCANNER_EXIT_CODE_UNKNOWN: &str = "security_scanner.exit_code_unknown";
     pub const SECURITY_SCANNER_NO_TRACKED_FILES: &str = "security_scanner.no_tracked_files";
     pub const SECURITY_SCANNER_ALL_IGNORED: &str = "security_scanner.all_ignored";
+    pub const SECURITY_SCANNER_EXCLUDE_INVALID: &str = "security_scanner.exclude_invalid";
+    pub const SECURITY_SCANNER_EXCLUDED: &str = "security_scanner.excluded";
     pub const SECURITY_SCANNER_SCOPE_GIT_HISTORY: &str = "security_scanner.scope.git_history";
     pub const SECURITY_SCANNER_SCOPE_WORKTREE: &str = "security_scanner.scope.worktree";
+    pub const SECURITY_SCANNER_SCOPE_PROMPT: &str = "security_scanner.scope.prompt";
+    pub const SECURITY_SCANNER_SCOPE_FULL: &str = "security_scanner.scope.full";
+    pub const SECURITY_SCANNER_SCOPE_WORKTREE_ONLY: &str = "security_scanner.scope.worktree_only";
     pub const SECURITY_SCANNER_COMMAND_LABEL: &str = "security_scanner.command_label";
     pub const SECURITY_SCANNER_INSTALL_MISSING_AFTER: &str =
         "security_scanner.install_missing_after";
@@ -440,13 +796,36 @@ pub mod keys {
     pub const MCP_MANAGER_FOUND_INSTALLED: &str = "mcp_manager.found_installed";
     pub const MCP_MANAGER_STATUS_INSTALLED: &str = "mcp_manager.status_installed";
     pub const MCP_MANAGER_STATUS_MISSING: &str = "mcp_manager.status_missing";
+    pub const MCP_MANAGER_STATUS_CHANGED: &str = "mcp_manager.status_changed";
+    pub const MCP_MANAGER_STATUS_UNTRACKED: &str = "mcp_manager.status_untracked";
+    pub const MCP_MANAGER_REINSTALL_UNTRACKED: &str = "mcp_manager.reinstall_untracked";
+    pub const MCP_MANAGER_STATUS_NEEDS_ENV: &str = "mcp_manager.status_needs_env";
     pub const MCP_MANAGER_SELECT_INSTALL: &str = "mcp_manager.select_install";
     pub const MCP_MANAGER_SELECT_HELP: &str = "mcp_manager.select_help";
     pub const MCP_MANAGER_SELECT_PROMPT: &str = "mcp_manager.select_prompt";
+    pub const MCP_MANAGER_FILTER_PROMPT: &str = "mcp_manager.filter_prompt";
+    pub const MCP_MANAGER_FILTER_NO_MATCH: &str = "mcp_manager.filter_no_match";
+    pub const MCP_MANAGER_ACTION_PROMPT: &str = "mcp_manager.action_prompt";
+    pub const MCP_MANAGER_ACTION_MANAGE: &str = "mcp_manager.action_manage";
+    pub const MCP_MANAGER_ACTION_EXPORT: &str = "mcp_manager.action_export";
+    pub const MCP_MANAGER_ACTION_APPLY: &str = "mcp_manager.action_apply";
+    pub const MCP_MANAGER_EXPORT_PATH_PROMPT: &str = "mcp_manager.export_path_prompt";
+    pub const MCP_MANAGER_EXPORT_SKIPPED: &str = "mcp_manager.export_skipped";
+    pub const MCP_MANAGER_EXPORT_DONE: &str = "mcp_manager.export_done";
+    pub const MCP_MANAGER_EXPORT_FAILED: &str = "mcp_manager.export_failed";
+    pub const MCP_MANAGER_APPLY_PATH_PROMPT: &str = "mcp_manager.apply_path_prompt";
+    pub const MCP_MANAGER_APPLY_FAILED: &str = "mcp_manager.apply_failed";
+    pub const MCP_MANAGER_APPLY_UNMANAGED: &str = "mcp_manager.apply_unmanaged";
+    pub const MCP_MANAGER_DRY_RUN: &str = "mcp_manager.dry_run";
+    pub const MCP_MANAGER_PROFILE_UNKNOWN: &str = "mcp_manager.profile_unknown";
+    pub const MCP_MANAGER_PROFILE_CLI_MISMATCH: &str = "mcp_manager.profile_cli_mismatch";
     pub const MCP_MANAGER_NO_CHANGES: &str = "mcp_manager.no_changes";
     pub const MCP_MANAGER_CHANGE_SUMMARY: &str = "mcp_manager.change_summary";
     pub const MCP_MANAGER_WILL_INSTALL: &str = "mcp_manager.will_install";
     pub const MCP_MANAGER_WILL_REMOVE: &str = "mcp_manager.will_remove";
+    pub const MCP_MANAGER_WILL_REINSTALL: &str = "mcp_manager.will_reinstall";
+    pub const MCP_MANAGER_SKIP_MISSING_ENV: &str = "mcp_manager.skip_missing_env";
+    pub const MCP_MANAGER_SELECT_REINSTALL: &str = "mcp_manager.select_reinstall";
     pub const MCP_MANAGER_CONFIRM_CHANGES: &str = "mcp_manager.confirm_changes";
     pub const MCP_MANAGER_CONFIGURE_TOOL: &str = "mcp_manager.configure_tool";
     pub const MCP_MANAGER_CHROME_HEADLESS_PROMPT: &str = "mcp_manager.chrome_headless_prompt";
@@ -460,9 +839,21 @@ pub mod keys {
     pub const MCP_MANAGER_REMOVING: &str = "mcp_manager.removing";
     pub const MCP_MANAGER_REMOVE_SUCCESS: &str = "mcp_manager.remove_success";
     pub const MCP_MANAGER_REMOVE_FAILED: &str = "mcp_manager.remove_failed";
+    pub const MCP_MANAGER_REINSTALLING: &str = "mcp_manager.reinstalling";
+    pub const MCP_MANAGER_REINSTALL_SUCCESS: &str = "mcp_manager.reinstall_success";
+    pub const MCP_MANAGER_REINSTALL_FAILED: &str = "mcp_manager.reinstall_failed";
+    pub const MCP_MANAGER_REINSTALL_RESTORED: &str = "mcp_manager.reinstall_restored";
+    pub const MCP_MANAGER_REINSTALL_LOST: &str = "mcp_manager.reinstall_lost";
     pub const MCP_MANAGER_SUMMARY: &str = "mcp_manager.summary";
+    pub const MCP_MANAGER_CUSTOM_LOADED: &str = "mcp_manager.custom_loaded";
+    pub const MCP_MANAGER_CUSTOM_PARSE_FAILED: &str = "mcp_manager.custom_parse_failed";
+    pub const MCP_MANAGER_CUSTOM_SKIPPED: &str = "mcp_manager.custom_skipped";
+    pub const MCP_MANAGER_CUSTOM_MISSING_NAME: &str = "mcp_manager.custom_missing_name";
+    pub const MCP_MANAGER_CUSTOM_MISSING_ARGS: &str = "mcp_manager.custom_missing_args";
+    pub const MCP_MANAGER_CUSTOM_DUPLICATE: &str = "mcp_manager.custom_duplicate";
 
     pub const MCP_EXECUTOR_INTERACTIVE_FAILED: &str = "mcp_executor.interactive_failed";
+    pub const MCP_EXECUTOR_ENV_MISSING: &str = "mcp_executor.env_missing";
     pub const MCP_EXECUTOR_CONFIG_PARSE_FAILED: &str = "mcp_executor.config_parse_failed";
     pub const MCP_EXECUTOR_CONFIG_SERIALIZE_FAILED: &str = "mcp_executor.config_serialize_failed";
 
@@ -486,6 +877,7 @@ pub mod keys {
     pub const MCP_TOOL_CLOUDFLARE_DEX: &str = "mcp.tool.cloudflare_dex";
     pub const MCP_TOOL_CLOUDFLARE_CASB: &str = "mcp.tool.cloudflare_casb";
     pub const MCP_TOOL_CLOUDFLARE_GRAPHQL: &str = "mcp.tool.cloudflare_graphql";
+    pub const MCP_TOOL_CUSTOM: &str = "mcp.tool.custom";
     // Kubeconfig Manager
     pub const KUBECONFIG_HEADER: &str = "kubeconfig.header";
     pub const KUBECONFIG_SELECT_ACTION: &str = "kubeconfig.select_action";
@@ -494,7 +886,6 @@ pub mod keys {
     pub const KUBECONFIG_ACTION_LIST: &str = "kubeconfig.action_list";
     pub const KUBECONFIG_ACTION_CLEANUP_ALL: &str = "kubeconfig.action_cleanup_all";
     pub const KUBECONFIG_CANCELLED: &str = "kubeconfig.cancelled";
-    pub const KUBECONFIG_NOT_IN_TMUX: &str = "kubeconfig.not_in_tmux";
     pub const KUBECONFIG_WINDOW_ID: &str = "kubeconfig.window_id";
     pub const KUBECONFIG_WINDOW_ID_FAILED: &str = "kubeconfig.window_id_failed";
     pub const KUBECONFIG_SETUP_SUCCESS: &str = "kubeconfig.setup_success";
@@ -516,7 +907,24 @@ pub mod keys {
     pub const KUBECONFIG_NO_CONFIGS: &str = "kubeconfig.no_configs";
     pub const KUBECONFIG_LIST_TITLE: &str = "kubeconfig.list_title";
     pub const KUBECONFIG_CONFIRM_CLEANUP_ALL: &str = "kubeconfig.confirm_cleanup_all";
+    pub const KUBECONFIG_CLEANUP_ALL_WARNING: &str = "kubeconfig.cleanup_all_warning";
     pub const KUBECONFIG_CLEANUP_ALL_SUMMARY: &str = "kubeconfig.cleanup_all_summary";
+    pub const KUBECONFIG_ACTION_MERGE: &str = "kubeconfig.action_merge";
+    pub const KUBECONFIG_SELECT_SOURCES: &str = "kubeconfig.select_sources";
+    pub const KUBECONFIG_NO_SOURCES: &str = "kubeconfig.no_sources";
+    pub const KUBECONFIG_INPUT_EXTRA_SOURCES: &str = "kubeconfig.input_extra_sources";
+    pub const KUBECONFIG_NO_SOURCE_SELECTED: &str = "kubeconfig.no_source_selected";
+    pub const KUBECONFIG_INVALID_SOURCE: &str = "kubeconfig.invalid_source";
+    pub const KUBECONFIG_SOURCE_NOT_FOUND: &str = "kubeconfig.source_not_found";
+    pub const KUBECONFIG_MERGE_SUCCESS: &str = "kubeconfig.merge_success";
+    pub const KUBECONFIG_MERGE_FAILED: &str = "kubeconfig.merge_failed";
+    pub const KUBECONFIG_ACTION_SWITCH_CONTEXT: &str = "kubeconfig.action_switch_context";
+    pub const KUBECONFIG_READ_FAILED: &str = "kubeconfig.read_failed";
+    pub const KUBECONFIG_NO_CONTEXTS: &str = "kubeconfig.no_contexts";
+    pub const KUBECONFIG_SELECT_CONTEXT: &str = "kubeconfig.select_context";
+    pub const KUBECONFIG_INPUT_NAMESPACE: &str = "kubeconfig.input_namespace";
+    pub const KUBECONFIG_CONTEXT_SWITCHED: &str = "kubeconfig.context_switched";
+    pub const KUBECONFIG_SWITCH_FAILED: &str = "kubeconfig.switch_failed";
 
     // Container Builder
     pub const MENU_CONTAINER_BUILDER: &str = "menu.container_builder.name";
@@ -527,6 +935,9 @@ pub mod keys {
     pub const CONTAINER_BUILDER_SELECT_ENGINE: &str = "container_builder.select_engine";
     pub const CONTAINER_BUILDER_ENGINE_DOCKER_DESC: &str = "container_builder.engine_docker_desc";
     pub const CONTAINER_BUILDER_ENGINE_BUILDAH_DESC: &str = "container_builder.engine_buildah_desc";
+    pub const CONTAINER_BUILDER_ENGINE_BUILDX_DESC: &str = "container_builder.engine_buildx_desc";
+    pub const CONTAINER_BUILDER_BUILDX_PUSH_REQUIRED: &str =
+        "container_builder.buildx_push_required";
     pub const CONTAINER_BUILDER_ENGINE_NOT_FOUND: &str = "container_builder.engine_not_found";
     pub const CONTAINER_BUILDER_USING_ENGINE: &str = "container_builder.using_engine";
     pub const CONTAINER_BUILDER_SCANNING_DOCKERFILES: &str =
@@ -534,6 +945,13 @@ pub mod keys {
     pub const CONTAINER_BUILDER_NO_DOCKERFILE: &str = "container_builder.no_dockerfile";
     pub const CONTAINER_BUILDER_FOUND_DOCKERFILES: &str = "container_builder.found_dockerfiles";
     pub const CONTAINER_BUILDER_SELECT_DOCKERFILE: &str = "container_builder.select_dockerfile";
+    pub const CONTAINER_BUILDER_ENTER_PATH_OPTION: &str = "container_builder.enter_path_option";
+    pub const CONTAINER_BUILDER_INPUT_DOCKERFILE_PATH: &str =
+        "container_builder.input_dockerfile_path";
+    pub const CONTAINER_BUILDER_PATH_NOT_FILE: &str = "container_builder.path_not_file";
+    pub const CONTAINER_BUILDER_INPUT_CONTEXT: &str = "container_builder.input_context";
+    pub const CONTAINER_BUILDER_CONTEXT_NOT_DIR: &str = "container_builder.context_not_dir";
+    pub const CONTAINER_BUILDER_CONTEXT_OUTSIDE: &str = "container_builder.context_outside";
     pub const CONTAINER_BUILDER_SELECTED_DOCKERFILE: &str = "container_builder.selected_dockerfile";
     pub const CONTAINER_BUILDER_SELECT_ARCH: &str = "container_builder.select_arch";
     pub const CONTAINER_BUILDER_SELECTED_ARCH: &str = "container_builder.selected_arch";
@@ -542,11 +960,17 @@ pub mod keys {
     pub const CONTAINER_BUILDER_NEW_IMAGE: &str = "container_builder.new_image";
     pub const CONTAINER_BUILDER_SELECT_TAG: &str = "container_builder.select_tag";
     pub const CONTAINER_BUILDER_INPUT_TAG: &str = "container_builder.input_tag";
+    pub const CONTAINER_BUILDER_TAG_RESOLVED: &str = "container_builder.tag_resolved";
+    pub const CONTAINER_BUILDER_TAG_UNRESOLVED: &str = "container_builder.tag_unresolved";
     pub const CONTAINER_BUILDER_NEW_TAG: &str = "container_builder.new_tag";
     pub const CONTAINER_BUILDER_ASK_PUSH: &str = "container_builder.ask_push";
     pub const CONTAINER_BUILDER_SELECT_REGISTRY: &str = "container_builder.select_registry";
     pub const CONTAINER_BUILDER_INPUT_REGISTRY: &str = "container_builder.input_registry";
     pub const CONTAINER_BUILDER_NEW_REGISTRY: &str = "container_builder.new_registry";
+    pub const CONTAINER_BUILDER_SELECT_BUILD_ARGS: &str = "container_builder.select_build_args";
+    pub const CONTAINER_BUILDER_INPUT_BUILD_ARG: &str = "container_builder.input_build_arg";
+    pub const CONTAINER_BUILDER_INPUT_LABEL: &str = "container_builder.input_label";
+    pub const CONTAINER_BUILDER_INVALID_KEY_VALUE: &str = "container_builder.invalid_key_value";
     pub const CONTAINER_BUILDER_BUILD_SUMMARY: &str = "container_builder.build_summary";
     pub const CONTAINER_BUILDER_CONFIRM_BUILD: &str = "container_builder.confirm_build";
     pub const CONTAINER_BUILDER_BUILDING: &str = "container_builder.building";
@@ -558,12 +982,77 @@ pub mod keys {
     pub const SETTINGS_COMMON_COUNT_DESC: &str = "settings.common_count.desc";
     pub const SETTINGS_COMMON_COUNT_PROMPT: &str = "settings.common_count.prompt";
     pub const SETTINGS_COMMON_COUNT_SAVED: &str = "settings.common_count.saved";
+    pub const SETTINGS_OVERVIEW_NAME: &str = "settings.overview.name";
+    pub const SETTINGS_OVERVIEW_DESC: &str = "settings.overview.desc";
+    pub const SETTINGS_VALUE_ON: &str = "settings.value.on";
+    pub const SETTINGS_VALUE_OFF: &str = "settings.value.off";
+    pub const SETTINGS_VALUE_NONE: &str = "settings.value.none";
+    pub const SETTINGS_DRY_RUN_NAME: &str = "settings.dry_run.name";
+    pub const SETTINGS_DRY_RUN_DESC: &str = "settings.dry_run.desc";
+    pub const SETTINGS_DRY_RUN_PROMPT: &str = "settings.dry_run.prompt";
+    pub const SETTINGS_DRY_RUN_SAVED: &str = "settings.dry_run.saved";
+    pub const SETTINGS_LOGGING_NAME: &str = "settings.logging.name";
+    pub const SETTINGS_LOGGING_DESC: &str = "settings.logging.desc";
+    pub const SETTINGS_LOGGING_PROMPT: &str = "settings.logging.prompt";
+    pub const SETTINGS_LOGGING_SAVED: &str = "settings.logging.saved";
+    pub const SETTINGS_LOG_LEVEL_NAME: &str = "settings.log_level.name";
+    pub const SETTINGS_LOG_LEVEL_DESC: &str = "settings.log_level.desc";
+    pub const SETTINGS_LOG_LEVEL_PROMPT: &str = "settings.log_level.prompt";
+    pub const SETTINGS_LOG_LEVEL_QUIET: &str = "settings.log_level.quiet";
+    pub const SETTINGS_LOG_LEVEL_NORMAL: &str = "settings.log_level.normal";
+    pub const SETTINGS_LOG_LEVEL_VERBOSE: &str = "settings.log_level.verbose";
+    pub const SETTINGS_LOG_LEVEL_SAVED: &str = "settings.log_level.saved";
+    pub const SETTINGS_TIMEOUT_NAME: &str = "settings.timeout.name";
+    pub const SETTINGS_TIMEOUT_DESC: &str = "settings.timeout.desc";
+    pub const SETTINGS_TIMEOUT_PROMPT: &str = "settings.timeout.prompt";
+    pub const SETTINGS_TIMEOUT_VALUE: &str = "settings.timeout.value";
+    pub const SETTINGS_TIMEOUT_INVALID: &str = "settings.timeout.invalid";
+    pub const SETTINGS_TIMEOUT_SAVED: &str = "settings.timeout.saved";
+    pub const SETTINGS_FEATURES_NAME: &str = "settings.features.name";
+    pub const SETTINGS_FEATURES_DESC: &str = "settings.features.desc";
+    pub const SETTINGS_FEATURES_PROMPT: &str = "settings.features.prompt";
+    pub const SETTINGS_PACKAGE_VERSIONS_NAME: &str = "settings.package_versions.name";
+    pub const SETTINGS_PACKAGE_VERSIONS_PROMPT: &str = "settings.package_versions.prompt";
+    pub const SETTINGS_MARKETPLACES_NAME: &str = "settings.marketplaces.name";
+    pub const SETTINGS_MARKETPLACES_PROMPT: &str = "settings.marketplaces.prompt";
+    pub const SETTINGS_SCANNER_TOOLS_NAME: &str = "settings.scanner_tools.name";
+    pub const SETTINGS_CODEX_SOURCE_NAME: &str = "settings.codex_source.name";
+    pub const SETTINGS_CODEX_REMOTE_NAME: &str = "settings.codex_remote.name";
+    pub const SETTINGS_CODEX_BRANCH_NAME: &str = "settings.codex_branch.name";
+    pub const SETTINGS_NPM_REGISTRY_NAME: &str = "settings.npm_registry.name";
+    pub const SETTINGS_VALUE_ALL: &str = "settings.value.all";
+    pub const SETTINGS_TEXT_PROMPT: &str = "settings.text.prompt";
+    pub const SETTINGS_FEATURE_SAVED: &str = "settings.features.saved";
     pub const SETTINGS_MENU_PROMPT: &str = "settings.menu.prompt";
+    pub const SETTINGS_EXPORT_NAME: &str = "settings.export.name";
+    pub const SETTINGS_EXPORT_DESC: &str = "settings.export.desc";
+    pub const SETTINGS_IMPORT_NAME: &str = "settings.import.name";
+    pub const SETTINGS_IMPORT_DESC: &str = "settings.import.desc";
+    pub const SETTINGS_EXPORT_PATH_PROMPT: &str = "settings.export.path_prompt";
+    pub const SETTINGS_IMPORT_PATH_PROMPT: &str = "settings.import.path_prompt";
+    pub const SETTINGS_EXPORT_DONE: &str = "settings.export.done";
+    pub const SETTINGS_EXPORT_FAILED: &str = "settings.export.failed";
+    pub const SETTINGS_IMPORT_FAILED: &str = "settings.import.failed";
+    pub const SETTINGS_IMPORT_UNCHANGED: &str = "settings.import.unchanged";
+    pub const SETTINGS_IMPORT_CHANGED: &str = "settings.import.changed";
     pub const CONTAINER_BUILDER_BUILD_ERROR: &str = "container_builder.build_error";
+    pub const CONTAINER_BUILDER_ASK_IMAGE_SCAN: &str = "container_builder.ask_image_scan";
+    pub const CONTAINER_BUILDER_IMAGE_SCANNING: &str = "container_builder.image_scanning";
+    pub const CONTAINER_BUILDER_IMAGE_SCAN_FAILED: &str = "container_builder.image_scan_failed";
+    pub const CONTAINER_BUILDER_IMAGE_SCAN_CLEAN: &str = "container_builder.image_scan_clean";
+    pub const CONTAINER_BUILDER_IMAGE_SCAN_FINDINGS: &str = "container_builder.image_scan_findings";
+    pub const CONTAINER_BUILDER_IMAGE_SCAN_PUSH_ANYWAY: &str =
+        "container_builder.image_scan_push_anyway";
+    pub const CONTAINER_BUILDER_PUSH_ABORTED: &str = "container_builder.push_aborted";
     pub const CONTAINER_BUILDER_PUSHING: &str = "container_builder.pushing";
     pub const CONTAINER_BUILDER_PUSH_SUCCESS: &str = "container_builder.push_success";
     pub const CONTAINER_BUILDER_PUSH_FAILED: &str = "container_builder.push_failed";
     pub const CONTAINER_BUILDER_PUSH_ERROR: &str = "container_builder.push_error";
+    pub const CONTAINER_BUILDER_ASK_LOGIN: &str = "container_builder.ask_login";
+    pub const CONTAINER_BUILDER_LOGIN_USERNAME: &str = "container_builder.login_username";
+    pub const CONTAINER_BUILDER_LOGIN_PASSWORD: &str = "container_builder.login_password";
+    pub const CONTAINER_BUILDER_LOGIN_SUCCESS: &str = "container_builder.login_success";
+    pub const CONTAINER_BUILDER_LOGIN_FAILED: &str = "container_builder.login_failed";
 
     // Skill Installer - Menu
     pub const MENU_SKILL_INSTALLER: &str = "menu.skill_installer.name";
@@ -596,12 +1085,43 @@ pub mod keys {
     pub const SKILL_INSTALLER_REMOVING: &str = "skill_installer.removing";
     pub const SKILL_INSTALLER_REMOVE_SUCCESS: &str = "skill_installer.remove_success";
     pub const SKILL_INSTALLER_REMOVE_FAILED: &str = "skill_installer.remove_failed";
+    pub const SKILL_INSTALLER_SELECT_ACTION: &str = "skill_installer.select_action";
+    pub const SKILL_INSTALLER_ACTION_MANAGE: &str = "skill_installer.action_manage";
+    pub const SKILL_INSTALLER_ACTION_UPDATE: &str = "skill_installer.action_update";
+    pub const SKILL_INSTALLER_ACTION_ADD_MARKETPLACE: &str =
+        "skill_installer.action_add_marketplace";
+    pub const SKILL_INSTALLER_MARKETPLACE_PROMPT: &str = "skill_installer.marketplace_prompt";
+    pub const SKILL_INSTALLER_MARKETPLACE_INVALID_REPO: &str =
+        "skill_installer.marketplace_invalid_repo";
+    pub const SKILL_INSTALLER_MARKETPLACE_EXISTS: &str = "skill_installer.marketplace_exists";
+    pub const SKILL_INSTALLER_MARKETPLACE_LOADED: &str = "skill_installer.marketplace_loaded";
+    pub const SKILL_INSTALLER_MARKETPLACE_FETCH_FAILED: &str =
+        "skill_installer.marketplace_fetch_failed";
+    pub const SKILL_INSTALLER_MARKETPLACE_INVALID_NAME: &str =
+        "skill_installer.marketplace_invalid_name";
+    pub const SKILL_INSTALLER_MARKETPLACE_DUPLICATE: &str = "skill_installer.marketplace_duplicate";
+    pub const SKILL_INSTALLER_MARKETPLACE_UNSUPPORTED_SOURCE: &str =
+        "skill_installer.marketplace_unsupported_source";
+    pub const SKILL_INSTALLER_MARKETPLACE_SKIPPED: &str = "skill_installer.marketplace_skipped";
+    pub const SKILL_INSTALLER_MARKETPLACE_PLUGIN: &str = "skill_installer.marketplace_plugin";
+    pub const SKILL_INSTALLER_NOTHING_TO_UPDATE: &str = "skill_installer.nothing_to_update";
+    pub const SKILL_INSTALLER_CHECKING_UPDATES: &str = "skill_installer.checking_updates";
+    pub const SKILL_INSTALLER_UPDATE_AVAILABLE: &str = "skill_installer.update_available";
+    pub const SKILL_INSTALLER_UP_TO_DATE: &str = "skill_installer.up_to_date";
+    pub const SKILL_INSTALLER_UPDATE_UNKNOWN: &str = "skill_installer.update_unknown";
+    pub const SKILL_INSTALLER_SELECT_UPDATES: &str = "skill_installer.select_updates";
+    pub const SKILL_INSTALLER_UPDATING: &str = "skill_installer.updating";
+    pub const SKILL_INSTALLER_UPDATE_SUCCESS: &str = "skill_installer.update_success";
+    pub const SKILL_INSTALLER_UPDATE_FAILED: &str = "skill_installer.update_failed";
+    pub const SKILL_INSTALLER_CONFIRM_PIN: &str = "skill_installer.confirm_pin";
+    pub const SKILL_INSTALLER_INPUT_GIT_REF: &str = "skill_installer.input_git_ref";
     pub const SKILL_INSTALLER_SUMMARY: &str = "skill_installer.summary";
     pub const SKILL_INSTALLER_CODEX_RESTART_REQUIRED: &str =
         "skill_installer.codex_restart_required";
     pub const SKILL_INSTALLER_CODEX_USAGE_HINT: &str = "skill_installer.codex_usage_hint";
     pub const SKILL_INSTALLER_DOWNLOAD_FAILED: &str = "skill_installer.download_failed";
     pub const SKILL_INSTALLER_EXTRACT_FAILED: &str = "skill_installer.extract_failed";
+    pub const SKILL_INSTALLER_CHECKSUM_UNSUPPORTED: &str = "skill_installer.checksum_unsupported";
 
     // Extension names
     pub const SKILL_FRONTEND_DESIGN: &str = "skill.frontend_design";
@@ -672,6 +1192,10 @@ pub mod keys {
     // System Updater - Menu
     pub const MENU_SYSTEM_UPDATER: &str = "menu.system_updater.name";
     pub const MENU_SYSTEM_UPDATER_DESC: &str = "menu.system_updater.desc";
+    pub const MENU_SELF_UPDATE: &str = "menu.self_update.name";
+    pub const MENU_SELF_UPDATE_DESC: &str = "menu.self_update.desc";
+    pub const MENU_DOCTOR: &str = "menu.doctor.name";
+    pub const MENU_DOCTOR_DESC: &str = "menu.doctor.desc";
 
     // System Updater - UI
     pub const SYSTEM_UPDATER_HEADER: &str = "system_updater.header";
@@ -720,6 +1244,7 @@ mod tests {
             Language::TraditionalChinese,
             Language::SimplifiedChinese,
             Language::Japanese,
+            Language::Korean,
         ] {
             let locale = bundle.maps.get(&language).expect("Missing locale data");
             let locale_keys: HashSet<&String> = locale.keys().collect();
@@ -731,6 +1256,81 @@ mod tests {
         }
     }
 
+    fn built_in_maps() -> HashMap<Language, HashMap<String, String>> {
+        let mut maps = HashMap::new();
+        maps.insert(
+            Language::English,
+            load_locale(include_str!("locales/en.toml")),
+        );
+        maps
+    }
+
+    #[test]
+    fn overrides_replace_existing_keys_only() {
+        let temp = tempfile::tempdir().unwrap();
+        fs::write(
+            temp.path().join("en.toml"),
+            "\"menu.exit\" = \"Quit\"\n\"custom.unknown\" = \"Ignored\"\n",
+        )
+        .unwrap();
+
+        let mut maps = built_in_maps();
+        let errors = apply_overrides(&mut maps, temp.path());
+
+        assert!(errors.is_empty());
+        let english = &maps[&Language::English];
+        assert_eq!(english.get("menu.exit").map(String::as_str), Some("Quit"));
+        assert!(!english.contains_key("custom.unknown"));
+    }
+
+    #[test]
+    fn overrides_report_parse_errors() {
+        let temp = tempfile::tempdir().unwrap();
+        fs::write(temp.path().join("en.toml"), "not = [valid").unwrap();
+
+        let mut maps = built_in_maps();
+        let errors = apply_overrides(&mut maps, temp.path());
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].0, temp.path().join("en.toml"));
+        assert_eq!(
+            maps[&Language::English]
+                .get("menu.exit")
+                .map(String::as_str),
+            Some("Exit")
+        );
+    }
+
+    #[test]
+    fn overrides_missing_dir_is_noop() {
+        let temp = tempfile::tempdir().unwrap();
+        let mut maps = built_in_maps();
+        let errors = apply_overrides(&mut maps, &temp.path().join("missing"));
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn validate_bundle_reports_no_drift() {
+        let _guard = test_lock();
+        assert!(validate_bundle().is_empty());
+    }
+
+    #[test]
+    fn diff_keys_reports_missing_and_extra() {
+        let reference: HashMap<String, String> = [("a", "A"), ("b", "B")]
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        let locale: HashMap<String, String> = [("b", "B"), ("c", "C")]
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+
+        let (missing, extra) = diff_keys(&reference, &locale);
+        assert_eq!(missing, vec!["a".to_string()]);
+        assert_eq!(extra, vec!["c".to_string()]);
+    }
+
     #[test]
     fn set_language_updates_translation() {
         let _guard = test_lock();
@@ -745,6 +1345,116 @@ mod tests {
         set_language(previous);
     }
 
+    #[test]
+    fn from_locale_maps_posix_locales() {
+        assert_eq!(
+            Language::from_locale("en_US.UTF-8"),
+            Some(Language::English)
+        );
+        assert_eq!(Language::from_locale("ja_JP"), Some(Language::Japanese));
+        assert_eq!(Language::from_locale("ko_KR.UTF-8"), Some(Language::Korean));
+        assert_eq!(
+            Language::from_locale("zh_TW.UTF-8"),
+            Some(Language::TraditionalChinese)
+        );
+        assert_eq!(
+            Language::from_locale("zh_CN.GB2312"),
+            Some(Language::SimplifiedChinese)
+        );
+    }
+
+    #[test]
+    fn from_locale_falls_back_to_primary_language() {
+        assert_eq!(
+            Language::from_locale("en_AU.UTF-8"),
+            Some(Language::English)
+        );
+        assert_eq!(
+            Language::from_locale("ja_JP@euro"),
+            Some(Language::Japanese)
+        );
+    }
+
+    #[test]
+    fn from_locale_rejects_unsupported_locales() {
+        assert_eq!(Language::from_locale("C"), None);
+        assert_eq!(Language::from_locale("POSIX"), None);
+        assert_eq!(Language::from_locale("fr_FR.UTF-8"), None);
+        assert_eq!(Language::from_locale(""), None);
+    }
+
+    #[test]
+    fn override_prefers_cli_over_env() {
+        let resolved = resolve_override(Some("ja"), Some("en"), |_| {});
+        assert_eq!(resolved, Some(Language::Japanese));
+    }
+
+    #[test]
+    fn override_falls_back_to_env() {
+        let resolved = resolve_override(None, Some("zh-CN"), |_| {});
+        assert_eq!(resolved, Some(Language::SimplifiedChinese));
+    }
+
+    #[test]
+    fn override_skips_invalid_codes() {
+        let mut invalid = Vec::new();
+        let resolved = resolve_override(Some("xx"), Some("en"), |code| {
+            invalid.push(code.to_string())
+        });
+        assert_eq!(resolved, Some(Language::English));
+        assert_eq!(invalid, vec!["xx".to_string()]);
+    }
+
+    #[test]
+    fn override_absent_returns_none() {
+        assert_eq!(resolve_override(None, None, |_| {}), None);
+    }
+
+    #[test]
+    fn plural_category_per_language() {
+        assert_eq!(Language::English.plural_category(1), PluralCategory::One);
+        assert_eq!(Language::English.plural_category(0), PluralCategory::Other);
+        assert_eq!(Language::English.plural_category(2), PluralCategory::Other);
+        assert_eq!(
+            Language::TraditionalChinese.plural_category(1),
+            PluralCategory::Other
+        );
+        assert_eq!(Language::Japanese.plural_category(1), PluralCategory::Other);
+    }
+
+    #[test]
+    fn tr_plural_selects_variant() {
+        let _guard = test_lock();
+        let previous = current_language();
+
+        set_language(Language::English);
+        assert_eq!(
+            crate::tr_plural!(keys::TERRAFORM_FOUND_ITEMS, count = 1),
+            "Found 1 item:"
+        );
+        assert_eq!(
+            crate::tr_plural!(keys::TERRAFORM_FOUND_ITEMS, count = 3),
+            "Found 3 items:"
+        );
+
+        set_language(Language::TraditionalChinese);
+        assert_eq!(
+            crate::tr_plural!(keys::TERRAFORM_FOUND_ITEMS, count = 1),
+            "找到 1 個項目:"
+        );
+
+        set_language(previous);
+    }
+
+    #[test]
+    fn t_plural_falls_back_to_bare_key() {
+        let _guard = test_lock();
+        let previous = current_language();
+        set_language(Language::English);
+        assert_eq!(t_plural(keys::MENU_EXIT, 2), "Exit");
+        set_language(previous);
+    }
+
     #[test]
     fn unknown_key_returns_placeholder() {
         let _guard = test_lock();
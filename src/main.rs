@@ -1,12 +1,17 @@
+mod cli;
 mod core;
 mod features;
 mod i18n;
 mod ui;
 
+use crate::core::log_level::LogLevel;
 use crate::core::{AppConfig, load_config, save_config};
+use clap::Parser;
+use cli::Cli;
 use colored::Colorize;
-use dialoguer::{Select, theme::ColorfulTheme};
+use dialoguer::{Input, Select, theme::ColorfulTheme};
 use i18n::{Language, keys};
+use std::process::ExitCode;
 use ui::{Console, Prompts};
 use unicode_width::UnicodeWidthStr;
 
@@ -102,6 +107,16 @@ fn all_actions() -> Vec<MenuItem> {
             desc_key: keys::MENU_SYSTEM_UPDATER_DESC,
             handler: features::system_updater::run,
         },
+        MenuItem {
+            name_key: keys::MENU_SELF_UPDATE,
+            desc_key: keys::MENU_SELF_UPDATE_DESC,
+            handler: features::package_manager::self_update::run,
+        },
+        MenuItem {
+            name_key: keys::MENU_DOCTOR,
+            desc_key: keys::MENU_DOCTOR_DESC,
+            handler: features::doctor::run,
+        },
     ]
 }
 
@@ -110,7 +125,11 @@ fn sort_by_usage(items: &mut [MenuItem], config: &AppConfig) {
     items.sort_by(|a, b| {
         let usage_a = config.get_usage(a.name_key);
         let usage_b = config.get_usage(b.name_key);
-        usage_b.cmp(&usage_a)
+        usage_b.cmp(&usage_a).then_with(|| {
+            config
+                .get_last_used(b.name_key)
+                .cmp(&config.get_last_used(a.name_key))
+        })
     });
 }
 
@@ -149,6 +168,8 @@ fn build_categories(items: &[MenuItem]) -> Vec<Category> {
                 find_action(items, keys::MENU_TOOL_UPGRADER),
                 find_action(items, keys::MENU_RUST_UPGRADER),
                 find_action(items, keys::MENU_PACKAGE_MANAGER),
+                find_action(items, keys::MENU_SELF_UPDATE),
+                find_action(items, keys::MENU_DOCTOR),
             ],
         },
         Category {
@@ -326,6 +347,7 @@ fn open_settings(prompts: &Prompts, console: &Console) {
 
     loop {
         let settings_items = [
+            (keys::SETTINGS_OVERVIEW_NAME, keys::SETTINGS_OVERVIEW_DESC),
             (keys::MENU_LANGUAGE, keys::MENU_LANGUAGE_DESC),
             (
                 keys::SETTINGS_COMMON_COUNT_NAME,
@@ -333,6 +355,13 @@ fn open_settings(prompts: &Prompts, console: &Console) {
             ),
             (keys::MENU_PIN_MANAGE, keys::MENU_PIN_MANAGE_DESC),
             (keys::MENU_PIN_REORDER, keys::MENU_PIN_REORDER_DESC),
+            (keys::SETTINGS_DRY_RUN_NAME, keys::SETTINGS_DRY_RUN_DESC),
+            (keys::SETTINGS_LOGGING_NAME, keys::SETTINGS_LOGGING_DESC),
+            (keys::SETTINGS_LOG_LEVEL_NAME, keys::SETTINGS_LOG_LEVEL_DESC),
+            (keys::SETTINGS_TIMEOUT_NAME, keys::SETTINGS_TIMEOUT_DESC),
+            (keys::SETTINGS_FEATURES_NAME, keys::SETTINGS_FEATURES_DESC),
+            (keys::SETTINGS_EXPORT_NAME, keys::SETTINGS_EXPORT_DESC),
+            (keys::SETTINGS_IMPORT_NAME, keys::SETTINGS_IMPORT_DESC),
         ];
 
         let max_name_width = settings_items
@@ -362,15 +391,498 @@ fn open_settings(prompts: &Prompts, console: &Console) {
             .unwrap();
 
         match selection_opt {
-            Some(0) => select_language(prompts, console),
-            Some(1) => configure_common_actions(prompts, console, &mut config),
-            Some(2) => manage_pins(console, &mut config),
-            Some(3) => reorder_pins(console, &mut config),
+            Some(0) => show_settings(console, &config),
+            Some(1) => {
+                select_language(prompts, console);
+                // The language is saved on its own; reload so later saves keep it
+                config = load_config().ok().flatten().unwrap_or_default();
+            }
+            Some(2) => configure_common_actions(prompts, console, &mut config),
+            Some(3) => manage_pins(console, &mut config),
+            Some(4) => reorder_pins(console, &mut config),
+            Some(5) => configure_dry_run(prompts, console, &mut config),
+            Some(6) => configure_logging(prompts, console, &mut config),
+            Some(7) => configure_log_level(prompts, console, &mut config),
+            Some(8) => configure_timeout(console, &mut config),
+            Some(9) => configure_feature_settings(prompts, console, &mut config),
+            Some(10) => export_settings(prompts, console, &config),
+            Some(11) => {
+                if import_settings(prompts, console) {
+                    config = load_config().ok().flatten().unwrap_or_default();
+                }
+            }
             _ => break,
         }
     }
 }
 
+fn export_settings(prompts: &Prompts, console: &Console, config: &AppConfig) {
+    let Some(path) = prompts.input_path(
+        i18n::t(keys::SETTINGS_EXPORT_PATH_PROMPT),
+        Some("ops-tools-config.toml"),
+    ) else {
+        return;
+    };
+
+    match core::config::export_config(config, &path) {
+        Ok(()) => console.success(&crate::tr!(
+            keys::SETTINGS_EXPORT_DONE,
+            path = path.display()
+        )),
+        Err(err) => console.error(&crate::tr!(keys::SETTINGS_EXPORT_FAILED, error = err)),
+    }
+}
+
+/// Merge an exported config into the saved one; returns true when something changed
+fn import_settings(prompts: &Prompts, console: &Console) -> bool {
+    let Some(path) = prompts.input_path(i18n::t(keys::SETTINGS_IMPORT_PATH_PROMPT), None) else {
+        return false;
+    };
+
+    let changed = match core::config::import_config(&path) {
+        Ok(changed) => changed,
+        Err(err) => {
+            console.error(&crate::tr!(keys::SETTINGS_IMPORT_FAILED, error = err));
+            return false;
+        }
+    };
+
+    if changed.is_empty() {
+        console.info(i18n::t(keys::SETTINGS_IMPORT_UNCHANGED));
+        return false;
+    }
+
+    console.success(&crate::tr_plural!(
+        keys::SETTINGS_IMPORT_CHANGED,
+        count = changed.len(),
+        keys = changed.join(", ")
+    ));
+    if changed.iter().any(|key| key == "language") {
+        apply_saved_language(console);
+    }
+    true
+}
+
+/// Print every persisted preference with its current value
+fn show_settings(console: &Console, config: &AppConfig) {
+    let pinned = if config.pinned_items.is_empty() {
+        i18n::t(keys::SETTINGS_VALUE_NONE).to_string()
+    } else {
+        config
+            .pinned_items
+            .iter()
+            .map(|key| i18n::t(key))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+
+    let rows = [
+        (
+            keys::MENU_LANGUAGE,
+            i18n::current_language().display_name().to_string(),
+        ),
+        (
+            keys::SETTINGS_COMMON_COUNT_NAME,
+            config.common_actions_limit().to_string(),
+        ),
+        (keys::MENU_PIN_MANAGE, pinned),
+        (
+            keys::SETTINGS_DRY_RUN_NAME,
+            on_off_label(config.dry_run).to_string(),
+        ),
+        (
+            keys::SETTINGS_LOGGING_NAME,
+            on_off_label(config.logging_enabled).to_string(),
+        ),
+        (
+            keys::SETTINGS_LOG_LEVEL_NAME,
+            i18n::t(log_level_label(config.log_level)).to_string(),
+        ),
+        (
+            keys::SETTINGS_TIMEOUT_NAME,
+            timeout_label(config.command_timeout_secs()),
+        ),
+    ];
+
+    let feature_rows =
+        FeatureSetting::ALL.map(|setting| (setting.name_key(), setting.value_label(config)));
+
+    console.header(i18n::t(keys::SETTINGS_OVERVIEW_NAME));
+    for (name_key, value) in rows.into_iter().chain(feature_rows) {
+        console.list_item("•", &format!("{}: {value}", i18n::t(name_key)));
+    }
+    console.blank_line();
+}
+
+/// Preferences owned by individual features, editable from Settings
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum FeatureSetting {
+    PackageVersions,
+    SkillMarketplaces,
+    ScannerTools,
+    CodexSourcePath,
+    CodexPrivateRemote,
+    CodexFeatureBranch,
+    NpmRegistry,
+}
+
+impl FeatureSetting {
+    const ALL: [FeatureSetting; 7] = [
+        FeatureSetting::PackageVersions,
+        FeatureSetting::SkillMarketplaces,
+        FeatureSetting::ScannerTools,
+        FeatureSetting::CodexSourcePath,
+        FeatureSetting::CodexPrivateRemote,
+        FeatureSetting::CodexFeatureBranch,
+        FeatureSetting::NpmRegistry,
+    ];
+
+    fn name_key(self) -> &'static str {
+        match self {
+            FeatureSetting::PackageVersions => keys::SETTINGS_PACKAGE_VERSIONS_NAME,
+            FeatureSetting::SkillMarketplaces => keys::SETTINGS_MARKETPLACES_NAME,
+            FeatureSetting::ScannerTools => keys::SETTINGS_SCANNER_TOOLS_NAME,
+            FeatureSetting::CodexSourcePath => keys::SETTINGS_CODEX_SOURCE_NAME,
+            FeatureSetting::CodexPrivateRemote => keys::SETTINGS_CODEX_REMOTE_NAME,
+            FeatureSetting::CodexFeatureBranch => keys::SETTINGS_CODEX_BRANCH_NAME,
+            FeatureSetting::NpmRegistry => keys::SETTINGS_NPM_REGISTRY_NAME,
+        }
+    }
+
+    fn value_label(self, config: &AppConfig) -> String {
+        let none = || i18n::t(keys::SETTINGS_VALUE_NONE).to_string();
+        let list = |items: &[String]| {
+            if items.is_empty() {
+                none()
+            } else {
+                items.join(", ")
+            }
+        };
+        match self {
+            FeatureSetting::PackageVersions => list(&pin_labels(&package_pins(config))),
+            FeatureSetting::SkillMarketplaces => list(&config.skill_marketplaces),
+            FeatureSetting::ScannerTools => match &config.scanner_tools {
+                Some(tools) => list(tools),
+                None => i18n::t(keys::SETTINGS_VALUE_ALL).to_string(),
+            },
+            FeatureSetting::CodexSourcePath
+            | FeatureSetting::CodexPrivateRemote
+            | FeatureSetting::CodexFeatureBranch
+            | FeatureSetting::NpmRegistry => {
+                self.text_value(config).map_or_else(none, str::to_string)
+            }
+        }
+    }
+
+    fn text_value(self, config: &AppConfig) -> Option<&str> {
+        match self {
+            FeatureSetting::CodexSourcePath => config.codex_source_path.as_deref(),
+            FeatureSetting::CodexPrivateRemote => config.codex_private_remote.as_deref(),
+            FeatureSetting::CodexFeatureBranch => config.codex_feature_branch.as_deref(),
+            FeatureSetting::NpmRegistry => config.npm_registry.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// The field behind a free-text setting; `None` for list settings
+    fn text_field(self, config: &mut AppConfig) -> Option<&mut Option<String>> {
+        match self {
+            FeatureSetting::CodexSourcePath => Some(&mut config.codex_source_path),
+            FeatureSetting::CodexPrivateRemote => Some(&mut config.codex_private_remote),
+            FeatureSetting::CodexFeatureBranch => Some(&mut config.codex_feature_branch),
+            FeatureSetting::NpmRegistry => Some(&mut config.npm_registry),
+            _ => None,
+        }
+    }
+}
+
+/// Pinned package versions as `(package, version)`, sorted by package
+fn package_pins(config: &AppConfig) -> Vec<(String, String)> {
+    let mut pins: Vec<(String, String)> = config
+        .package_versions
+        .iter()
+        .map(|(package, version)| (package.clone(), version.clone()))
+        .collect();
+    pins.sort();
+    pins
+}
+
+fn pin_labels(pins: &[(String, String)]) -> Vec<String> {
+    pins.iter()
+        .map(|(package, version)| format!("{package}={version}"))
+        .collect()
+}
+
+fn configure_feature_settings(prompts: &Prompts, console: &Console, config: &mut AppConfig) {
+    loop {
+        let options: Vec<String> = FeatureSetting::ALL
+            .iter()
+            .map(|setting| {
+                format!(
+                    "{}: {}",
+                    i18n::t(setting.name_key()),
+                    setting.value_label(config)
+                )
+            })
+            .chain([i18n::t(keys::MENU_BACK).to_string()])
+            .collect();
+        let option_refs: Vec<&str> = options.iter().map(String::as_str).collect();
+
+        let Some(index) = prompts.select(i18n::t(keys::SETTINGS_FEATURES_PROMPT), &option_refs)
+        else {
+            return;
+        };
+        let Some(&setting) = FeatureSetting::ALL.get(index) else {
+            return;
+        };
+        let changed = match setting {
+            FeatureSetting::PackageVersions => remove_package_pins(prompts, config),
+            FeatureSetting::SkillMarketplaces => remove_marketplaces(prompts, config),
+            FeatureSetting::ScannerTools => select_scanner_tools(prompts, config),
+            _ => edit_text_setting(setting, config),
+        };
+        if changed {
+            save_setting(
+                console,
+                config,
+                &crate::tr!(
+                    keys::SETTINGS_FEATURE_SAVED,
+                    name = i18n::t(setting.name_key()),
+                    value = setting.value_label(config)
+                ),
+            );
+        }
+    }
+}
+
+/// Drop the chosen version pins so those packages install the latest version again
+fn remove_package_pins(prompts: &Prompts, config: &mut AppConfig) -> bool {
+    let pins = package_pins(config);
+    if pins.is_empty() {
+        return false;
+    }
+    let items = pin_labels(&pins);
+    let Some(selected) = prompts.multi_select(
+        i18n::t(keys::SETTINGS_PACKAGE_VERSIONS_PROMPT),
+        &items,
+        &vec![false; items.len()],
+    ) else {
+        return false;
+    };
+    for index in &selected {
+        config.set_package_version(&pins[*index].0, None);
+    }
+    !selected.is_empty()
+}
+
+/// Remove saved marketplaces; new ones are added (and validated) from the Skill Installer
+fn remove_marketplaces(prompts: &Prompts, config: &mut AppConfig) -> bool {
+    if config.skill_marketplaces.is_empty() {
+        return false;
+    }
+    let Some(selected) = prompts.multi_select(
+        i18n::t(keys::SETTINGS_MARKETPLACES_PROMPT),
+        &config.skill_marketplaces,
+        &vec![false; config.skill_marketplaces.len()],
+    ) else {
+        return false;
+    };
+    let remove: Vec<String> = selected
+        .iter()
+        .map(|index| config.skill_marketplaces[*index].clone())
+        .collect();
+    config
+        .skill_marketplaces
+        .retain(|repo| !remove.contains(repo));
+    !remove.is_empty()
+}
+
+/// Choose which scanners the Security Scanner runs; selecting all of them resets to the default
+fn select_scanner_tools(prompts: &Prompts, config: &mut AppConfig) -> bool {
+    let tools = features::security_scanner::scanner_tools();
+    let names: Vec<String> = tools.iter().map(|(name, _, _)| name.to_string()).collect();
+    let defaults: Vec<bool> = tools
+        .iter()
+        .map(|(_, binary, _)| {
+            config
+                .scanner_tools
+                .as_ref()
+                .is_none_or(|saved| saved.iter().any(|tool| tool == binary))
+        })
+        .collect();
+    let Some(selected) = prompts.multi_select(
+        i18n::t(keys::SECURITY_SCANNER_SELECT_TOOLS),
+        &names,
+        &defaults,
+    ) else {
+        return false;
+    };
+
+    config.scanner_tools = (selected.len() < tools.len()).then(|| {
+        selected
+            .iter()
+            .map(|index| tools[*index].1.to_string())
+            .collect()
+    });
+    true
+}
+
+/// Edit a free-text setting, starting from its current value; empty input unsets it
+fn edit_text_setting(setting: FeatureSetting, config: &mut AppConfig) -> bool {
+    let Some(field) = setting.text_field(config) else {
+        return false;
+    };
+    let Ok(input) = Input::<String>::with_theme(&ColorfulTheme::default())
+        .with_prompt(crate::tr!(
+            keys::SETTINGS_TEXT_PROMPT,
+            name = i18n::t(setting.name_key())
+        ))
+        .with_initial_text(field.clone().unwrap_or_default())
+        .allow_empty(true)
+        .interact_text()
+    else {
+        return false;
+    };
+    let input = input.trim();
+    *field = (!input.is_empty()).then(|| input.to_string());
+    true
+}
+
+fn log_level_label(level: LogLevel) -> &'static str {
+    match level {
+        LogLevel::Quiet => keys::SETTINGS_LOG_LEVEL_QUIET,
+        LogLevel::Normal => keys::SETTINGS_LOG_LEVEL_NORMAL,
+        LogLevel::Verbose => keys::SETTINGS_LOG_LEVEL_VERBOSE,
+    }
+}
+
+fn timeout_label(secs: u64) -> String {
+    if secs == 0 {
+        i18n::t(keys::SETTINGS_VALUE_OFF).to_string()
+    } else {
+        crate::tr!(keys::SETTINGS_TIMEOUT_VALUE, secs = secs)
+    }
+}
+
+/// Ask a yes/no question defaulting to the current value; `None` when cancelled
+fn prompt_toggle(prompts: &Prompts, prompt: &str, current: bool) -> Option<bool> {
+    let options = [i18n::t(keys::PROMPT_YES), i18n::t(keys::PROMPT_NO)];
+    let default = if current { 0 } else { 1 };
+    prompts
+        .select_with_default(prompt, &options, default)
+        .map(|index| index == 0)
+}
+
+fn save_setting(console: &Console, config: &AppConfig, saved: &str) {
+    match save_config(config) {
+        Ok(_) => console.success(saved),
+        Err(err) => console.warning(&crate::tr!(keys::CONFIG_SAVE_FAILED, error = err)),
+    }
+}
+
+fn configure_dry_run(prompts: &Prompts, console: &Console, config: &mut AppConfig) {
+    let Some(enabled) = prompt_toggle(
+        prompts,
+        i18n::t(keys::SETTINGS_DRY_RUN_PROMPT),
+        config.dry_run,
+    ) else {
+        return;
+    };
+    config.dry_run = enabled;
+    core::dry_run::set_enabled(enabled);
+    save_setting(
+        console,
+        config,
+        &crate::tr!(keys::SETTINGS_DRY_RUN_SAVED, value = on_off_label(enabled)),
+    );
+}
+
+fn configure_logging(prompts: &Prompts, console: &Console, config: &mut AppConfig) {
+    let Some(enabled) = prompt_toggle(
+        prompts,
+        i18n::t(keys::SETTINGS_LOGGING_PROMPT),
+        config.logging_enabled,
+    ) else {
+        return;
+    };
+    config.logging_enabled = enabled;
+    core::command_log::set_enabled(enabled);
+    save_setting(
+        console,
+        config,
+        &crate::tr!(keys::SETTINGS_LOGGING_SAVED, value = on_off_label(enabled)),
+    );
+}
+
+fn on_off_label(enabled: bool) -> &'static str {
+    i18n::t(if enabled {
+        keys::SETTINGS_VALUE_ON
+    } else {
+        keys::SETTINGS_VALUE_OFF
+    })
+}
+
+fn configure_log_level(prompts: &Prompts, console: &Console, config: &mut AppConfig) {
+    let levels = [LogLevel::Quiet, LogLevel::Normal, LogLevel::Verbose];
+    let options: Vec<&str> = levels
+        .iter()
+        .map(|level| i18n::t(log_level_label(*level)))
+        .collect();
+    let default = levels
+        .iter()
+        .position(|level| *level == config.log_level)
+        .unwrap_or(1);
+
+    let Some(index) =
+        prompts.select_with_default(i18n::t(keys::SETTINGS_LOG_LEVEL_PROMPT), &options, default)
+    else {
+        return;
+    };
+    config.log_level = levels[index];
+    core::log_level::set_level(config.log_level);
+    save_setting(
+        console,
+        config,
+        &crate::tr!(keys::SETTINGS_LOG_LEVEL_SAVED, level = options[index]),
+    );
+}
+
+/// Ask for the command timeout until the input is empty (default), 0 (disabled) or a number
+fn configure_timeout(console: &Console, config: &mut AppConfig) {
+    let theme = ColorfulTheme::default();
+    let current = config.command_timeout_secs().to_string();
+    let secs = loop {
+        let Ok(input) = Input::<String>::with_theme(&theme)
+            .with_prompt(crate::tr!(
+                keys::SETTINGS_TIMEOUT_PROMPT,
+                default = core::process::DEFAULT_TIMEOUT_SECS
+            ))
+            .default(current.clone())
+            .allow_empty(true)
+            .interact_text()
+        else {
+            return;
+        };
+        let input = input.trim();
+        if input.is_empty() {
+            break None;
+        }
+        match input.parse::<u64>() {
+            Ok(secs) => break Some(secs),
+            Err(_) => console.warning(&crate::tr!(keys::SETTINGS_TIMEOUT_INVALID, input = input)),
+        }
+    };
+
+    config.command_timeout_secs = secs;
+    let secs = config.command_timeout_secs();
+    core::process::set_timeout_secs(secs);
+    save_setting(
+        console,
+        config,
+        &crate::tr!(keys::SETTINGS_TIMEOUT_SAVED, value = timeout_label(secs)),
+    );
+}
+
 fn configure_common_actions(prompts: &Prompts, console: &Console, config: &mut AppConfig) {
     let options: Vec<String> = (1..=6).map(|n| n.to_string()).collect();
     let default = config
@@ -444,7 +956,7 @@ fn manage_pins(console: &Console, config: &mut AppConfig) {
                     console.success(&format!(
                         "{} {}",
                         pin_icon,
-                        crate::tr!(keys::MENU_PIN_COUNT, count = count)
+                        crate::tr_plural!(keys::MENU_PIN_COUNT, count = count)
                     ));
                 } else {
                     console.info(i18n::t(keys::MENU_PIN_CLEARED));
@@ -459,66 +971,58 @@ fn reorder_pins(console: &Console, config: &mut AppConfig) {
     use dialoguer::Select;
 
     let actions = all_actions();
-    let pinned_keys: Vec<String> = config.pinned_items().to_vec();
+    let mut draft = config.clone();
+    draft
+        .pinned_items
+        .retain(|key| actions.iter().any(|a| a.name_key == key));
 
-    if pinned_keys.is_empty() {
+    if draft.pinned_items().is_empty() {
         console.info(i18n::t(keys::MENU_PIN_REORDER_EMPTY));
         return;
     }
 
-    // Get display names for pinned items
-    let pinned_items: Vec<(&str, String)> = pinned_keys
-        .iter()
-        .filter_map(|key| {
-            actions
-                .iter()
-                .find(|a| a.name_key == key)
-                .map(|a| (a.name_key, i18n::t(a.name_key).to_string()))
-        })
-        .collect();
-
-    let mut new_order: Vec<&str> = Vec::new();
-    let mut remaining: Vec<(&str, String)> = pinned_items;
-
-    while !remaining.is_empty() {
-        let options: Vec<String> = remaining
+    let mut cursor = 0;
+    loop {
+        let pinned_count = draft.pinned_items().len();
+        let mut options: Vec<String> = draft
+            .pinned_items()
             .iter()
             .enumerate()
-            .map(|(i, (_, name))| format!("{}. {}", i + 1, name))
+            .map(|(i, key)| format!("{}. {}", i + 1, i18n::t(key)))
             .collect();
+        options.push(i18n::t(keys::MENU_PIN_REORDER_SAVE).to_string());
 
-        let prompt = format!(
-            "{} ({}/{})",
-            i18n::t(keys::MENU_PIN_REORDER_PROMPT),
-            new_order.len() + 1,
-            new_order.len() + remaining.len()
-        );
+        let selection = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt(i18n::t(keys::MENU_PIN_REORDER_PROMPT))
+            .items(&options)
+            .default(cursor)
+            .interact_opt();
 
-        let option_refs: Vec<&str> = options.iter().map(|s| s.as_str()).collect();
+        let idx = match selection {
+            Ok(Some(idx)) if idx == pinned_count => break,
+            Ok(Some(idx)) => idx,
+            // User cancelled - keep original order
+            _ => return,
+        };
 
-        let selection = Select::with_theme(&ColorfulTheme::default())
-            .with_prompt(&prompt)
-            .items(&option_refs)
-            .default(0)
+        let directions = [
+            i18n::t(keys::MENU_PIN_REORDER_MOVE_UP),
+            i18n::t(keys::MENU_PIN_REORDER_MOVE_DOWN),
+        ];
+        let direction = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt(&options[idx])
+            .items(directions)
+            .default(if idx == 0 { 1 } else { 0 })
             .interact_opt();
 
-        match selection {
-            Ok(Some(idx)) => {
-                let (key, _) = remaining.remove(idx);
-                new_order.push(key);
-            }
-            _ => {
-                // User cancelled - keep original order
-                return;
-            }
-        }
+        cursor = match direction {
+            Ok(Some(choice)) => draft.move_pinned(idx, choice == 0).unwrap_or(idx),
+            _ => idx,
+        };
     }
 
     // Update config with new order
-    config.pinned_items.clear();
-    for key in new_order {
-        config.pinned_items.push(key.to_string());
-    }
+    config.pinned_items = draft.pinned_items;
 
     match save_config(config) {
         Ok(_) => console.success(i18n::t(keys::MENU_PIN_REORDER_DONE)),
@@ -526,11 +1030,82 @@ fn reorder_pins(console: &Console, config: &mut AppConfig) {
     }
 }
 
-fn main() {
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    core::json_report::set_enabled(cli.json);
+    let code = run(cli);
+    if let Some(report) = core::json_report::render() {
+        println!("{report}");
+    }
+    code
+}
+
+fn run(cli: Cli) -> ExitCode {
+    if cli.no_color || Console::no_color_requested() {
+        Console::disable_colors();
+    }
+    if let Some(dir) = &cli.config_dir {
+        core::config::set_config_dir(dir.clone());
+    }
+    let config_dir_error = core::config::ensure_config_dir_override().err();
+
+    let log_level = cli.log_level().unwrap_or_else(|| {
+        load_config()
+            .ok()
+            .flatten()
+            .map(|config| config.log_level)
+            .unwrap_or_default()
+    });
+    core::log_level::set_level(log_level);
+    Prompts::set_assume_yes(cli.yes);
+
     let prompts = Prompts::new();
     let console = Console::new();
 
-    if !apply_saved_language(&console) {
+    let language_applied = apply_language_override(cli.language.as_deref(), &console)
+        || apply_saved_language(&console);
+
+    for (path, error) in i18n::override_errors() {
+        console.warning(&crate::tr!(
+            keys::LANGUAGE_OVERRIDE_INVALID,
+            path = path.display(),
+            error = error
+        ));
+    }
+
+    if let Some(err) = config_dir_error {
+        console.error(&err.to_string());
+    }
+
+    if std::env::var_os(i18n::DEBUG_ENV_VAR).is_some() {
+        report_locale_drift(&console);
+    }
+
+    let dry_run = cli.dry_run
+        || load_config()
+            .ok()
+            .flatten()
+            .is_some_and(|config| config.dry_run);
+    core::dry_run::set_enabled(dry_run);
+    let saved_config = load_config().ok().flatten().unwrap_or_default();
+    core::process::set_timeout_secs(saved_config.command_timeout_secs());
+    core::command_log::set_enabled(saved_config.logging_enabled);
+    if dry_run {
+        console.warning(i18n::t(keys::CONSOLE_DRY_RUN_ENABLED));
+    }
+    // Remove temp dirs left behind by earlier runs that crashed
+    core::temp_dir::sweep_stale(&std::env::temp_dir(), core::temp_dir::STALE_AFTER);
+
+    if let Some(command) = cli.command {
+        return cli::dispatch(command);
+    }
+
+    if !Prompts::is_interactive() {
+        console.error(i18n::t(keys::MENU_NON_INTERACTIVE));
+        return ExitCode::FAILURE;
+    }
+
+    if !language_applied {
         select_language_on_start(&prompts, &console);
     }
 
@@ -564,12 +1139,12 @@ fn main() {
         match &options[selection].choice {
             TopLevelChoice::Action(item) => {
                 record_usage(item.name_key, &console);
-                (item.handler)();
+                run_item(*item);
             }
             TopLevelChoice::Category(category) => {
                 if let Some(item) = select_category_item(category, &config) {
                     record_usage(item.name_key, &console);
-                    (item.handler)();
+                    run_item(item);
                 }
             }
             TopLevelChoice::Settings => {
@@ -584,6 +1159,19 @@ fn main() {
 
         println!();
     }
+
+    ExitCode::SUCCESS
+}
+
+/// Run a menu item, naming it like its subcommand for `--json` (`menu.rust_builder.name` -> `rust-builder`)
+fn run_item(item: MenuItem) {
+    let feature = item
+        .name_key
+        .trim_start_matches("menu.")
+        .trim_end_matches(".name")
+        .replace('_', "-");
+    core::json_report::set_feature(&feature);
+    (item.handler)();
 }
 
 /// Record menu usage to config
@@ -600,9 +1188,9 @@ fn select_language_on_start(prompts: &Prompts, console: &Console) {
         .iter()
         .map(|lang| lang.display_name())
         .collect();
-    let prompt = "Select language / 選擇語言 / 选择语言 / 言語を選択";
-    if let Some(index) =
-        prompts.select_with_default(prompt, &options, i18n::current_language().index())
+    let prompt = "Select language / 選擇語言 / 选择语言 / 言語を選択 / 언어 선택";
+    let detected = Language::from_system().unwrap_or_else(i18n::current_language);
+    if let Some(index) = prompts.select_with_default(prompt, &options, detected.index())
         && let Some(language) = Language::from_index(index)
     {
         i18n::set_language(language);
@@ -629,6 +1217,43 @@ fn select_language(prompts: &Prompts, console: &Console) {
     }
 }
 
+/// Print locale key discrepancies (enabled via `OPS_TOOLS_DEBUG_I18N`)
+fn report_locale_drift(console: &Console) {
+    let issues = i18n::validate_bundle();
+    if issues.is_empty() {
+        console.info(i18n::t(keys::LANGUAGE_CHECK_CLEAN));
+        return;
+    }
+
+    for (language, issue) in &issues {
+        console.warning(&crate::tr!(
+            keys::LANGUAGE_CHECK_ISSUE,
+            language = language.code(),
+            issue = issue
+        ));
+    }
+    console.warning(&crate::tr!(
+        keys::LANGUAGE_CHECK_SUMMARY,
+        count = issues.len()
+    ));
+}
+
+/// Apply a one-off language from `--language` or `OPS_TOOLS_LANG` without persisting it
+fn apply_language_override(cli_code: Option<&str>, console: &Console) -> bool {
+    let env_code = std::env::var(i18n::LANGUAGE_ENV_VAR).ok();
+    let resolved = i18n::resolve_override(cli_code, env_code.as_deref(), |code| {
+        console.warning(&crate::tr!(keys::CONFIG_LANGUAGE_INVALID, code = code));
+    });
+
+    match resolved {
+        Some(language) => {
+            i18n::set_language(language);
+            true
+        }
+        None => false,
+    }
+}
+
 fn apply_saved_language(console: &Console) -> bool {
     match load_config() {
         Ok(Some(config)) => {
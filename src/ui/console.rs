@@ -1,32 +1,85 @@
+use super::progress::{ProgressHandle, suspend_progress};
+use crate::core::json_report::{self, Level};
+use crate::core::log_level::{self, LogLevel};
+use crate::core::redact::redact;
 use crate::i18n::{self, keys};
 use colored::Colorize;
 use std::path::PathBuf;
+use std::process::Command;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// 輸出一行；有進度條顯示中時先暫時隱藏，避免訊息與進度條互相覆蓋
+///
+/// `--json` 時改寫到 stderr，stdout 只輸出 JSON 報告
+macro_rules! print_line {
+    ($($arg:tt)*) => {
+        suspend_progress(|| {
+            if json_report::is_enabled() {
+                eprintln!($($arg)*)
+            } else {
+                println!($($arg)*)
+            }
+        })
+    };
+}
+
+macro_rules! eprint_line {
+    ($($arg:tt)*) => {
+        suspend_progress(|| eprintln!($($arg)*))
+    };
+}
+
+/// 是否曾輸出過錯誤訊息（供非互動模式決定結束碼）
+static ERROR_REPORTED: AtomicBool = AtomicBool::new(false);
 
 /// 控制台輸出工具
 #[derive(Clone, Copy)]
-pub struct Console;
+pub struct Console {
+    level: LogLevel,
+}
 
 impl Console {
+    /// 使用全域輸出層級
     pub fn new() -> Self {
-        Self
+        Self::new_with_level(log_level::current())
+    }
+
+    pub fn new_with_level(level: LogLevel) -> Self {
+        Self { level }
+    }
+
+    /// 安靜模式：只保留錯誤、警告與摘要
+    fn is_quiet(&self) -> bool {
+        self.level == LogLevel::Quiet
     }
 
     // === 基本訊息輸出 ===
 
     pub fn info(&self, message: &str) {
-        println!("{}", message.cyan());
+        json_report::record(Level::Info, message);
+        if self.is_quiet() {
+            return;
+        }
+        print_line!("{}", message.cyan());
     }
 
     pub fn success(&self, message: &str) {
-        println!("{}", message.green());
+        json_report::record(Level::Success, message);
+        if self.is_quiet() {
+            return;
+        }
+        print_line!("{}", message.green());
     }
 
     pub fn warning(&self, message: &str) {
-        println!("{}", message.yellow());
+        json_report::record(Level::Warning, message);
+        print_line!("{}", message.yellow());
     }
 
     pub fn error(&self, message: &str) {
-        eprintln!(
+        ERROR_REPORTED.store(true, Ordering::Relaxed);
+        json_report::record(Level::Error, message);
+        eprint_line!(
             "{} {}",
             i18n::t(keys::CONSOLE_ERROR_PREFIX).red().bold(),
             message
@@ -34,63 +87,99 @@ impl Console {
     }
 
     pub fn raw(&self, message: &str) {
-        println!("{}", message);
+        print_line!("{}", message);
+    }
+
+    /// 詳細模式：在執行前顯示完整命令
+    pub fn command(&self, command: &Command) {
+        if self.level == LogLevel::Verbose {
+            print_line!(
+                "{} {}",
+                "$".bright_black(),
+                format_command(command).bright_black()
+            );
+        }
     }
 
     // === 結構化輸出 ===
 
     pub fn header(&self, title: &str) {
-        println!("\n{}", "=".repeat(50).cyan());
-        println!("{}", title.bold().cyan());
-        println!("{}", "=".repeat(50).cyan());
+        if self.is_quiet() {
+            return;
+        }
+        print_line!("\n{}", "=".repeat(50).cyan());
+        print_line!("{}", title.bold().cyan());
+        print_line!("{}", "=".repeat(50).cyan());
     }
 
     pub fn separator(&self) {
-        println!("{}", "-".repeat(50).bright_black());
+        if self.is_quiet() {
+            return;
+        }
+        print_line!("{}", "-".repeat(50).bright_black());
     }
 
     pub fn blank_line(&self) {
-        println!();
+        if self.is_quiet() {
+            return;
+        }
+        print_line!();
     }
 
     // === 列表輸出 ===
 
     pub fn list_item(&self, icon: &str, message: &str) {
-        println!("  {} {}", icon, message);
+        if self.is_quiet() {
+            return;
+        }
+        print_line!("  {} {}", icon, message);
     }
 
     pub fn success_item(&self, message: &str) {
-        println!("{} {}", "✓".green(), message);
+        json_report::record(Level::Success, message);
+        if self.is_quiet() {
+            return;
+        }
+        print_line!("{} {}", "✓".green(), message);
     }
 
     pub fn error_item(&self, message: &str, error: &str) {
-        eprintln!("{} {} - {}", "✗".red(), message, error.red());
+        ERROR_REPORTED.store(true, Ordering::Relaxed);
+        json_report::record(Level::Error, &format!("{message} - {error}"));
+        eprint_line!("{} {} - {}", "✗".red(), message, error.red());
     }
 
     // === 路徑列表 ===
 
-    pub fn show_paths(&self, paths: &[PathBuf], type_fn: impl Fn(&PathBuf) -> &str) {
+    pub fn show_paths<T: AsRef<str>>(&self, paths: &[PathBuf], type_fn: impl Fn(&PathBuf) -> T) {
+        if self.is_quiet() {
+            return;
+        }
         for path in paths {
             let item_type = type_fn(path);
-            println!("  {} {}", item_type.blue(), path.display());
+            print_line!("  {} {}", item_type.as_ref().blue(), path.display());
         }
     }
 
-    pub fn show_paths_with_title(
+    pub fn show_paths_with_title<T: AsRef<str>>(
         &self,
         title: &str,
         paths: &[PathBuf],
-        type_fn: impl Fn(&PathBuf) -> &str,
+        type_fn: impl Fn(&PathBuf) -> T,
     ) {
-        println!("\n{}", title);
+        if self.is_quiet() {
+            return;
+        }
+        print_line!("\n{}", title);
         self.show_paths(paths, type_fn);
     }
 
     // === 統計與摘要 ===
 
     pub fn show_summary(&self, title: &str, success: usize, failed: usize) {
-        println!("\n{}", "=".repeat(50).cyan());
-        println!(
+        json_report::record_summary(title, success, failed);
+        print_line!("\n{}", "=".repeat(50).cyan());
+        print_line!(
             "{}",
             crate::tr!(
                 keys::CONSOLE_SUMMARY,
@@ -99,11 +188,47 @@ impl Console {
                 failed = failed.to_string().red()
             )
         );
-        println!("{}", "=".repeat(50).cyan());
+        print_line!("{}", "=".repeat(50).cyan());
+    }
+
+    /// 模擬執行的統計：只顯示將會刪除的項目數
+    pub fn show_dry_run_summary(&self, count: usize) {
+        let summary = crate::tr_plural!(keys::CONSOLE_DRY_RUN_SUMMARY, count = count);
+        json_report::record(Level::Summary, &summary);
+        print_line!("\n{}", "=".repeat(50).cyan());
+        print_line!("{}", summary.yellow());
+        print_line!("{}", "=".repeat(50).cyan());
     }
 
     pub fn show_progress(&self, current: usize, total: usize, message: &str) {
-        println!("[{}/{}] {}", current, total, message);
+        if self.is_quiet() {
+            return;
+        }
+        print_line!("[{}/{}] {}", current, total, message);
+    }
+
+    /// 逐項作業的進度條；非終端機輸出時改用 `show_progress` 逐行顯示
+    pub fn progress_bar(&self, total: usize) -> ProgressHandle {
+        ProgressHandle::new(*self, total, self.level)
+    }
+
+    // === 狀態 ===
+
+    /// 全域停用顏色（`colored` 輸出與 dialoguer/indicatif 使用的 `console` 樣式）
+    pub fn disable_colors() {
+        colored::control::set_override(false);
+        console::set_colors_enabled(false);
+        console::set_colors_enabled_stderr(false);
+    }
+
+    /// 是否設定了非空的 `NO_COLOR` 環境變數（https://no-color.org）
+    pub fn no_color_requested() -> bool {
+        std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty())
+    }
+
+    /// 本次執行期間是否曾輸出過錯誤
+    pub fn has_reported_errors() -> bool {
+        ERROR_REPORTED.load(Ordering::Relaxed)
     }
 }
 
@@ -113,6 +238,22 @@ impl Default for Console {
     }
 }
 
+/// 以 shell 形式顯示命令，含空白的參數加上引號
+fn format_command(command: &Command) -> String {
+    std::iter::once(command.get_program())
+        .chain(command.get_args())
+        .map(|part| {
+            let part = redact(&part.to_string_lossy());
+            if part.is_empty() || part.contains(char::is_whitespace) {
+                format!("'{}'", part)
+            } else {
+                part.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -125,6 +266,37 @@ mod tests {
         console.warning("test warning");
     }
 
+    #[test]
+    fn test_error_marks_failure() {
+        let console = Console::new();
+        console.error("test error");
+        assert!(Console::has_reported_errors());
+    }
+
+    #[test]
+    fn test_quiet_console_keeps_errors() {
+        let console = Console::new_with_level(LogLevel::Quiet);
+        assert!(console.is_quiet());
+        console.info("hidden");
+        console.error("still shown");
+        assert!(Console::has_reported_errors());
+    }
+
+    #[test]
+    fn test_disable_colors_renders_plain() {
+        Console::disable_colors();
+        assert_eq!("✓".green().to_string(), "✓");
+        assert_eq!("title".bold().cyan().to_string(), "title");
+        assert_eq!(console::style("prompt").red().to_string(), "prompt");
+    }
+
+    #[test]
+    fn test_format_command() {
+        let mut command = Command::new("git");
+        command.args(["commit", "-m", "two words", ""]);
+        assert_eq!(format_command(&command), "git commit -m 'two words' ''");
+    }
+
     #[test]
     fn test_show_paths() {
         let console = Console::new();
@@ -3,5 +3,5 @@ mod progress;
 mod prompts;
 
 pub use console::Console;
-pub use progress::Progress;
-pub use prompts::Prompts;
+pub use progress::{Progress, suspend_progress};
+pub use prompts::{PromptError, Prompts};
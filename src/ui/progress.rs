@@ -1,4 +1,34 @@
-use indicatif::{ProgressBar, ProgressStyle};
+use super::Console;
+use crate::core::log_level::{self, LogLevel};
+use indicatif::{ProgressBar, ProgressDrawTarget, ProgressStyle};
+use std::cell::Cell;
+use std::io::IsTerminal;
+use std::sync::Mutex;
+
+/// 目前顯示中的逐項進度條，輸出訊息前需先暫時隱藏
+static ACTIVE_BAR: Mutex<Option<ProgressBar>> = Mutex::new(None);
+
+thread_local! {
+    /// 是否已在 `suspend_progress` 內（巢狀呼叫直接執行，避免重複鎖定進度條）
+    static SUSPENDED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// 暫時隱藏進行中的進度條執行 `f`（輸出訊息、互動提示或會直接寫入終端的子程序）
+pub fn suspend_progress<R>(f: impl FnOnce() -> R) -> R {
+    if SUSPENDED.get() {
+        return f();
+    }
+    let active = ACTIVE_BAR.lock().ok().and_then(|bar| bar.clone());
+    match active {
+        Some(bar) => bar.suspend(|| {
+            SUSPENDED.set(true);
+            let result = f();
+            SUSPENDED.set(false);
+            result
+        }),
+        None => f(),
+    }
+}
 
 /// 進度追蹤器
 pub struct Progress {
@@ -16,6 +46,9 @@ impl Progress {
                 .progress_chars("=>-"),
         );
         bar.set_message(message.to_string());
+        if log_level::current() == LogLevel::Quiet {
+            bar.set_draw_target(ProgressDrawTarget::hidden());
+        }
 
         Self { bar }
     }
@@ -49,6 +82,73 @@ impl Progress {
     }
 }
 
+/// 逐項作業的進度：終端機上顯示就地更新的進度條（含百分比與 ETA），
+/// 輸出被導向時退回逐行的 `[n/total]` 訊息
+pub struct ProgressHandle {
+    console: Console,
+    bar: Option<ProgressBar>,
+    total: usize,
+    current: usize,
+}
+
+impl ProgressHandle {
+    pub(super) fn new(console: Console, total: usize, level: LogLevel) -> Self {
+        let interactive = std::io::stdout().is_terminal() && std::io::stderr().is_terminal();
+        let bar = (interactive && level != LogLevel::Quiet).then(|| {
+            let bar = ProgressBar::new(total as u64);
+            bar.set_style(
+                ProgressStyle::default_bar()
+                    .template("[{bar:30.cyan/blue}] {pos}/{len} ({percent}%) ETA {eta} {msg}")
+                    .expect("Failed to create progress style")
+                    .progress_chars("=>-"),
+            );
+            if let Ok(mut active) = ACTIVE_BAR.lock() {
+                *active = Some(bar.clone());
+            }
+            bar
+        });
+
+        Self {
+            console,
+            bar,
+            total,
+            current: 0,
+        }
+    }
+
+    /// 開始下一個項目
+    pub fn tick(&mut self, message: &str) {
+        self.current += 1;
+        match &self.bar {
+            Some(bar) => {
+                bar.set_position((self.current - 1) as u64);
+                bar.set_message(message.to_string());
+            }
+            None => self
+                .console
+                .show_progress(self.current, self.total, message),
+        }
+    }
+
+    /// 所有項目完成，清除進度條
+    pub fn finish(self) {
+        if let Some(bar) = &self.bar {
+            bar.set_position(self.total as u64);
+        }
+    }
+}
+
+impl Drop for ProgressHandle {
+    fn drop(&mut self) {
+        if let Some(bar) = self.bar.take() {
+            if let Ok(mut active) = ACTIVE_BAR.lock() {
+                *active = None;
+            }
+            bar.finish_and_clear();
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -59,4 +159,16 @@ mod tests {
         progress.inc();
         progress.finish();
     }
+
+    #[test]
+    fn test_progress_handle_without_tty() {
+        let console = Console::new_with_level(LogLevel::Quiet);
+        let mut handle = ProgressHandle::new(console, 2, LogLevel::Quiet);
+        assert!(handle.bar.is_none());
+        handle.tick("first");
+        handle.tick("second");
+        assert_eq!(handle.current, 2);
+        handle.finish();
+        assert_eq!(suspend_progress(|| suspend_progress(|| 42)), 42);
+    }
 }
@@ -1,46 +1,97 @@
+use super::Console;
 use crate::i18n::{self, keys};
-use dialoguer::{MultiSelect, Select, theme::ColorfulTheme};
+use dialoguer::{Input, MultiSelect, Select, theme::ColorfulTheme};
+use std::fmt;
+use std::io::{ErrorKind, IsTerminal};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// 全域自動確認旗標（由 `--yes` 啟用）
+static ASSUME_YES: AtomicBool = AtomicBool::new(false);
 
 /// 使用者輸入提示工具
 pub struct Prompts {
     theme: ColorfulTheme,
+    /// 自動回答：執行確認一律為「是」、選項確認與有預設值的選單直接採用預設值，不等待輸入
+    assume_yes: bool,
+    /// stdin 是否為終端機
+    interactive: bool,
 }
 
 impl Prompts {
     pub fn new() -> Self {
         Self {
             theme: ColorfulTheme::default(),
+            assume_yes: ASSUME_YES.load(Ordering::Relaxed),
+            interactive: Self::is_interactive(),
         }
     }
 
-    /// 簡單確認（預設否）
+    /// 設定之後建立的 `Prompts` 是否自動確認
+    pub fn set_assume_yes(enabled: bool) {
+        ASSUME_YES.store(enabled, Ordering::Relaxed);
+    }
+
+    /// stdin 是否為終端機；管線輸入或已關閉時無法互動
+    pub fn is_interactive() -> bool {
+        std::io::stdin().is_terminal()
+    }
+
+    /// 是否執行的確認（預設否）；`--yes` 時視為同意
     pub fn confirm(&self, prompt: &str) -> bool {
-        self.confirm_with_options(prompt, false)
+        self.ask_yes_no(prompt, false, true)
     }
 
-    /// 確認對話框（使用選項式）
+    /// 可選功能的確認（使用選項式）；`--yes` 時採用 `default_yes`，不會自動開啟預設關閉的選項
     pub fn confirm_with_options(&self, prompt: &str, default_yes: bool) -> bool {
-        let options = vec![i18n::t(keys::PROMPT_YES), i18n::t(keys::PROMPT_NO)];
-        let default = if default_yes { 0 } else { 1 };
+        self.ask_yes_no(prompt, default_yes, default_yes)
+    }
 
-        let selection = Select::with_theme(&self.theme)
-            .with_prompt(prompt)
-            .items(&options)
-            .default(default)
-            .interact()
-            .unwrap_or(1);
+    fn ask_yes_no(&self, prompt: &str, default_yes: bool, assumed_yes: bool) -> bool {
+        let options = [i18n::t(keys::PROMPT_YES), i18n::t(keys::PROMPT_NO)];
+        let index = |yes: bool| if yes { 0 } else { 1 };
+        let result = if self.assume_yes {
+            report_auto_answer(prompt, options[index(assumed_yes)]);
+            Ok(index(assumed_yes))
+        } else {
+            self.try_select(prompt, &options, Some(index(default_yes)))
+        };
+
+        report_failure(prompt, result) == Some(0)
+    }
+
+    /// 高風險操作的確認：必須輸入 `expected` 其中之一才會繼續（`--yes` 時自動通過）
+    pub fn confirm_typed(&self, prompt: &str, expected: &[&str]) -> bool {
+        let result = if self.assume_yes {
+            let answer = expected.first().copied().unwrap_or_default();
+            report_auto_answer(prompt, answer);
+            Ok(answer.to_string())
+        } else {
+            self.try_input(prompt, None)
+        };
 
-        selection == 0
+        report_failure(prompt, result).is_some_and(|input| expected.contains(&input.trim()))
+    }
+
+    /// 輸入檔案路徑；取消或留空時回傳 `None`（`--yes` 時採用預設值）
+    pub fn input_path(&self, prompt: &str, default: Option<&str>) -> Option<PathBuf> {
+        let result = match (self.assume_yes, default) {
+            (true, Some(default)) => {
+                report_auto_answer(prompt, default);
+                Ok(default.to_string())
+            }
+            (true, None) => Err(PromptError::NoDefault),
+            (false, _) => self.try_input(prompt, default),
+        };
+
+        let path = report_failure(prompt, result)?;
+        let path = path.trim();
+        (!path.is_empty()).then(|| PathBuf::from(path))
     }
 
     /// 單選選單
     pub fn select(&self, prompt: &str, items: &[&str]) -> Option<usize> {
-        Select::with_theme(&self.theme)
-            .with_prompt(prompt)
-            .items(items)
-            .interact_opt()
-            .ok()
-            .flatten()
+        report_failure(prompt, self.try_select(prompt, items, None))
     }
 
     /// 單選選單（帶預設值）
@@ -50,26 +101,153 @@ impl Prompts {
         items: &[&str],
         default: usize,
     ) -> Option<usize> {
-        Select::with_theme(&self.theme)
-            .with_prompt(prompt)
-            .items(items)
-            .default(default)
+        report_failure(prompt, self.try_select(prompt, items, Some(default)))
+    }
+
+    /// 多選選單；取消（Esc）或無法取得選擇時回傳 `None`，與「全部取消勾選」的空清單不同
+    pub fn multi_select(
+        &self,
+        prompt: &str,
+        items: &[String],
+        defaults: &[bool],
+    ) -> Option<Vec<usize>> {
+        report_failure(prompt, self.try_multi_select(prompt, items, defaults))
+    }
+
+    /// 單選選單；無法取得選擇時回傳原因
+    pub fn try_select(
+        &self,
+        prompt: &str,
+        items: &[&str],
+        default: Option<usize>,
+    ) -> Result<usize, PromptError> {
+        if self.assume_yes {
+            let index = default
+                .filter(|&index| index < items.len())
+                .ok_or(PromptError::NoDefault)?;
+            report_auto_answer(prompt, items[index]);
+            return Ok(index);
+        }
+        if !self.interactive {
+            return Err(PromptError::NonInteractive);
+        }
+
+        let mut select = Select::with_theme(&self.theme);
+        select = select.with_prompt(prompt).items(items);
+        if let Some(default) = default {
+            select = select.default(default);
+        }
+        select
             .interact_opt()
-            .ok()
-            .flatten()
+            .map_err(PromptError::from)?
+            .ok_or(PromptError::Cancelled)
+    }
+
+    /// 文字輸入；無法取得輸入時回傳原因
+    fn try_input(&self, prompt: &str, default: Option<&str>) -> Result<String, PromptError> {
+        if !self.interactive {
+            return Err(PromptError::NonInteractive);
+        }
+
+        let mut input = Input::<String>::with_theme(&self.theme)
+            .with_prompt(prompt)
+            .allow_empty(true);
+        if let Some(default) = default {
+            input = input.default(default.to_string());
+        }
+        input.interact_text().map_err(PromptError::from)
     }
 
-    /// 多選選單
-    pub fn multi_select(&self, prompt: &str, items: &[String], defaults: &[bool]) -> Vec<usize> {
+    /// 多選選單；無法取得選擇時回傳原因
+    pub fn try_multi_select(
+        &self,
+        prompt: &str,
+        items: &[String],
+        defaults: &[bool],
+    ) -> Result<Vec<usize>, PromptError> {
+        if self.assume_yes {
+            let selected: Vec<usize> = (0..items.len())
+                .filter(|&index| defaults.get(index).copied().unwrap_or(false))
+                .collect();
+            if selected.is_empty() {
+                return Err(PromptError::NoDefault);
+            }
+            let names: Vec<&str> = selected
+                .iter()
+                .map(|&index| items[index].as_str())
+                .collect();
+            report_auto_answer(prompt, &names.join(", "));
+            return Ok(selected);
+        }
+        if !self.interactive {
+            return Err(PromptError::NonInteractive);
+        }
+
         MultiSelect::with_theme(&self.theme)
             .with_prompt(prompt)
             .items(items)
             .defaults(defaults)
-            .interact()
-            .unwrap_or_default()
+            .interact_opt()
+            .map_err(PromptError::from)?
+            .ok_or(PromptError::Cancelled)
+    }
+}
+
+/// 提示無法取得選擇的原因
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PromptError {
+    /// 使用者按下 Esc / q 取消
+    Cancelled,
+    /// stdin 不是終端機（管線、CI）或輸入已結束
+    NonInteractive,
+    /// 以 `--yes` 執行，但選單沒有可自動採用的預設值
+    NoDefault,
+}
+
+impl fmt::Display for PromptError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let key = match self {
+            PromptError::Cancelled => keys::ERROR_CANCELLED,
+            PromptError::NonInteractive => keys::PROMPT_NON_INTERACTIVE,
+            PromptError::NoDefault => keys::PROMPT_NO_DEFAULT,
+        };
+        f.write_str(i18n::t(key))
+    }
+}
+
+impl From<dialoguer::Error> for PromptError {
+    fn from(err: dialoguer::Error) -> Self {
+        let dialoguer::Error::IO(err) = err;
+        match err.kind() {
+            ErrorKind::UnexpectedEof | ErrorKind::NotConnected | ErrorKind::BrokenPipe => {
+                PromptError::NonInteractive
+            }
+            _ => PromptError::Cancelled,
+        }
+    }
+}
+
+/// 無法互動或無預設值時顯示可行的做法；使用者自行取消則不另外顯示
+fn report_failure<T>(prompt: &str, result: Result<T, PromptError>) -> Option<T> {
+    match result {
+        Ok(value) => Some(value),
+        Err(PromptError::Cancelled) => None,
+        Err(err) => {
+            Console::new().error(&format!("{prompt} — {err}"));
+            None
+        }
     }
 }
 
+/// 顯示自動回答的內容，讓紀錄中看得出做了哪些選擇
+fn report_auto_answer(prompt: &str, answer: &str) {
+    Console::new().info(&crate::tr!(
+        keys::PROMPT_AUTO_ANSWERED,
+        prompt = prompt,
+        answer = answer
+    ));
+}
+
 impl Default for Prompts {
     fn default() -> Self {
         Self::new()
@@ -84,4 +262,73 @@ mod tests {
     fn test_prompts_creation() {
         let _prompts = Prompts::new();
     }
+
+    #[test]
+    fn test_assume_yes_uses_defaults() {
+        let prompts = Prompts {
+            theme: ColorfulTheme::default(),
+            assume_yes: true,
+            interactive: false,
+        };
+        let items = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+
+        assert!(prompts.confirm("Delete?"));
+        assert!(prompts.confirm_typed("Type delete", &["delete", "3"]));
+        assert!(prompts.confirm_with_options("Build?", true));
+        assert!(!prompts.confirm_with_options("Strip binaries?", false));
+        assert_eq!(prompts.select_with_default("Pick", &["x", "y"], 1), Some(1));
+        assert_eq!(
+            prompts.multi_select("Pick", &items, &[true, false, true]),
+            Some(vec![0, 2])
+        );
+        assert_eq!(prompts.select("Pick", &["x", "y"]), None);
+        assert_eq!(
+            prompts.input_path("Export to", Some("out.toml")),
+            Some(PathBuf::from("out.toml"))
+        );
+        assert_eq!(prompts.input_path("Import from", None), None);
+        assert_eq!(prompts.multi_select("Pick", &items, &[]), None);
+        assert_eq!(
+            prompts.try_select("Pick", &["x", "y"], None),
+            Err(PromptError::NoDefault)
+        );
+    }
+
+    #[test]
+    fn test_non_interactive_stdin_is_reported() {
+        let prompts = Prompts {
+            theme: ColorfulTheme::default(),
+            assume_yes: false,
+            interactive: false,
+        };
+        let items = vec!["a".to_string()];
+
+        assert_eq!(
+            prompts.try_select("Pick", &["x"], Some(0)),
+            Err(PromptError::NonInteractive)
+        );
+        assert_eq!(
+            prompts.try_multi_select("Pick", &items, &[true]),
+            Err(PromptError::NonInteractive)
+        );
+        assert!(!prompts.confirm_with_options("Build?", true));
+        assert!(!prompts.confirm_typed("Type delete", &["delete"]));
+    }
+
+    #[test]
+    fn test_cancelled_multi_select_is_not_an_empty_selection() {
+        let prompts = Prompts {
+            theme: ColorfulTheme::default(),
+            assume_yes: false,
+            interactive: false,
+        };
+        let items = vec!["installed".to_string()];
+
+        // 取消不可被當成「全部取消勾選」，否則呼叫端會規劃移除所有已安裝項目
+        assert_eq!(prompts.multi_select("Pick", &items, &[true]), None);
+        assert_eq!(
+            report_failure::<Vec<usize>>("Pick", Err(PromptError::Cancelled)),
+            None
+        );
+    }
 }